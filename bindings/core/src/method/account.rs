@@ -18,7 +18,7 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            CreateAliasParamsDto, FilterOptions, MintNativeTokenParamsDto, MintNftParamsDto, OutputParamsDto,
+            CreateAliasParamsDto, CreateNativeTokenParamsDto, FilterOptions, MintNftParamsDto, OutputParamsDto,
             OutputsToClaim, SyncOptions, TransactionOptionsDto,
         },
         SendAmountParams, SendNativeTokensParams, SendNftParams,
@@ -199,7 +199,7 @@ pub enum AccountMethod {
     /// Prepare to Mint native token.
     /// Expected response: [`PreparedMintTokenTransaction`](crate::Response::PreparedMintTokenTransaction)
     PrepareMintNativeToken {
-        params: MintNativeTokenParamsDto,
+        params: CreateNativeTokenParamsDto,
         options: Option<TransactionOptionsDto>,
     },
     /// Prepare an output.
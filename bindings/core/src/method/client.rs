@@ -7,8 +7,8 @@ use iota_sdk::client::mqtt::Topic;
 use iota_sdk::{
     client::{
         api::{
-            ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            input_selection::InputSelectionStrategy, ClientBlockBuilderOptions as BuildBlockOptions,
+            GetAddressesOptions as GenerateAddressesOptions, PreparedTransactionDataDto,
         },
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
@@ -192,6 +192,12 @@ pub enum ClientMethod {
         /// Block ID
         block_id: BlockId,
     },
+    /// Get the status of a block, derived from its metadata
+    #[serde(rename_all = "camelCase")]
+    BlockStatus {
+        /// Block ID
+        block_id: BlockId,
+    },
     /// Get block raw
     #[serde(rename_all = "camelCase")]
     GetBlockRaw {
@@ -265,6 +271,15 @@ pub enum ClientMethod {
         /// Transaction ID
         transaction_id: TransactionId,
     },
+    /// Looks up the inclusion state of a transaction.
+    #[serde(rename_all = "camelCase")]
+    LookupTransaction {
+        /// Transaction ID
+        transaction_id: TransactionId,
+        /// IDs of the blocks the transaction was posted in
+        #[serde(default)]
+        known_block_ids: Vec<BlockId>,
+    },
 
     //////////////////////////////////////////////////////////////////////
     // Node indexer API
@@ -353,6 +368,20 @@ pub enum ClientMethod {
         /// Maximum attempts
         max_attempts: Option<u64>,
     },
+    /// Retries (promotes or reattaches) a transaction until it's included, with the newest known attachment. Returns
+    /// the block that included the transaction.
+    #[serde(rename_all = "camelCase")]
+    RetryTransactionUntilIncluded {
+        /// Transaction ID
+        transaction_id: TransactionId,
+        /// IDs of the blocks the transaction was posted in
+        #[serde(default)]
+        known_block_ids: Vec<BlockId>,
+        /// Interval
+        interval: Option<u64>,
+        /// Maximum attempts
+        max_attempts: Option<u64>,
+    },
     /// Function to consolidate all funds from a range of addresses to the address with the lowest index in that range
     /// Returns the address to which the funds got consolidated, if any were available
     #[serde(rename_all = "camelCase")]
@@ -369,6 +398,9 @@ pub enum ClientMethod {
         addresses: Vec<Bech32Address>,
         /// Amount
         amount: u64,
+        /// Strategy to select the inputs, largest first if not provided
+        #[serde(default)]
+        strategy: Option<InputSelectionStrategy>,
     },
     /// Find all outputs based on the requests criteria. This method will try to query multiple nodes if
     /// the request amount exceeds individual node limit.
@@ -392,6 +424,12 @@ pub enum ClientMethod {
         /// Block ID
         block_id: BlockId,
     },
+    /// Reattach a transaction block only if the inputs of its transaction are still unspent
+    #[serde(rename_all = "camelCase")]
+    ReattachWithUnspentInputs {
+        /// Block ID
+        block_id: BlockId,
+    },
     /// Promotes a block. The method should validate if a promotion is necessary through get_block. If not, the
     /// method should error out and should not allow unnecessary promotions.
     #[serde(rename_all = "camelCase")]
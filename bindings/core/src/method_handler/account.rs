@@ -19,7 +19,7 @@ use iota_sdk::{
             Account, CreateAliasParams, OutputDataDto, OutputParams, PreparedMintTokenTransactionDto,
             TransactionOptions,
         },
-        MintNativeTokenParams, MintNftParams,
+        CreateNativeTokenParams, MintNftParams,
     },
 };
 use primitive_types::U256;
@@ -67,9 +67,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
         }
         AccountMethod::GetOutputsWithAdditionalUnlockConditions { outputs_to_claim } => {
-            let output_ids = account
-                .get_unlockable_outputs_with_additional_unlock_conditions(outputs_to_claim)
-                .await?;
+            let output_ids = account.claimable_outputs(outputs_to_claim).await?;
             Response::OutputIds(output_ids)
         }
         #[cfg(feature = "participation")]
@@ -158,7 +156,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             options,
         } => {
             let data = account
-                .prepare_decrease_native_token_supply(
+                .prepare_melt_native_token(
                     token_id,
                     U256::try_from(&melt_amount).map_err(|_| Error::InvalidField("melt_amount"))?,
                     options.map(TransactionOptions::try_from_dto).transpose()?,
@@ -181,7 +179,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             options,
         } => {
             let data = account
-                .prepare_increase_native_token_supply(
+                .prepare_mint_native_token(
                     token_id,
                     U256::try_from(&mint_amount).map_err(|_| Error::InvalidField("mint_amount"))?,
                     options.map(TransactionOptions::try_from_dto).transpose()?,
@@ -212,8 +210,8 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
         }
         AccountMethod::PrepareMintNativeToken { params, options } => {
             let data = account
-                .prepare_mint_native_token(
-                    MintNativeTokenParams::try_from(params)?,
+                .prepare_create_native_token(
+                    CreateNativeTokenParams::try_from(params)?,
                     options.map(TransactionOptions::try_from_dto).transpose()?,
                 )
                 .await?;
@@ -335,7 +333,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             prepared_transaction_data,
         } => {
             let signed_transaction_data = account
-                .sign_transaction_essence(&PreparedTransactionData::try_from_dto(
+                .sign_transaction(&PreparedTransactionData::try_from_dto(
                     prepared_transaction_data,
                     &account.client().get_protocol_parameters().await?,
                 )?)
@@ -3,7 +3,10 @@
 
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        api::{
+            input_selection::InputSelectionStrategy, InclusionLookupDto, PreparedTransactionData,
+            PreparedTransactionDataDto,
+        },
         request_funds_from_faucet, Client,
     },
     types::{
@@ -304,6 +307,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetBlockMetadata { block_id } => {
             Response::BlockMetadata(client.get_block_metadata(&block_id).await?)
         }
+        ClientMethod::BlockStatus { block_id } => Response::BlockStatus(client.block_status(&block_id).await?),
         ClientMethod::GetBlockRaw { block_id } => Response::BlockRaw(client.get_block_raw(&block_id).await?),
         ClientMethod::GetOutput { output_id } => Response::OutputWithMetadataResponse(
             client
@@ -343,6 +347,12 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetIncludedBlockMetadata { transaction_id } => {
             Response::BlockMetadata(client.get_included_block_metadata(&transaction_id).await?)
         }
+        ClientMethod::LookupTransaction {
+            transaction_id,
+            known_block_ids,
+        } => Response::InclusionLookup(InclusionLookupDto::from(
+            &client.lookup_transaction(&transaction_id, &known_block_ids).await?,
+        )),
         ClientMethod::BasicOutputIds { query_parameters } => {
             Response::OutputIdsResponse(client.basic_output_ids(query_parameters).await?)
         }
@@ -400,6 +410,17 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 .collect();
             Response::RetryUntilIncludedSuccessful(res)
         }
+        ClientMethod::RetryTransactionUntilIncluded {
+            transaction_id,
+            known_block_ids,
+            interval,
+            max_attempts,
+        } => {
+            let (block_id, block) = client
+                .retry_transaction_until_included(&transaction_id, &known_block_ids, interval, max_attempts)
+                .await?;
+            Response::BlockIdWithBlock(block_id, BlockDto::from(&block))
+        }
         ClientMethod::ConsolidateFunds {
             secret_manager,
             generate_addresses_options,
@@ -411,9 +432,17 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                     .await?,
             )
         }
-        ClientMethod::FindInputs { addresses, amount } => Response::Inputs(
+        ClientMethod::FindInputs {
+            addresses,
+            amount,
+            strategy,
+        } => Response::Inputs(
             client
-                .find_inputs(addresses, amount)
+                .find_inputs(
+                    addresses,
+                    amount,
+                    strategy.unwrap_or(InputSelectionStrategy::LargestFirst),
+                )
                 .await?
                 .iter()
                 .map(UtxoInputDto::from)
@@ -436,6 +465,10 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
             let (block_id, block) = client.reattach_unchecked(&block_id).await?;
             Response::Reattached((block_id, BlockDto::from(&block)))
         }
+        ClientMethod::ReattachWithUnspentInputs { block_id } => {
+            let (block_id, block) = client.reattach_with_unspent_inputs(&block_id).await?;
+            Response::Reattached((block_id, BlockDto::from(&block)))
+        }
         ClientMethod::Promote { block_id } => {
             let (block_id, block) = client.promote(&block_id).await?;
             Response::Promoted((block_id, BlockDto::from(&block)))
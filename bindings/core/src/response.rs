@@ -9,7 +9,7 @@ use derivative::Derivative;
 use iota_sdk::client::secret::LedgerNanoStatus;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{BlockStatus, InclusionLookupDto, PreparedTransactionDataDto, SignedTransactionDataDto},
         node_manager::node::Node,
         NetworkInfoDto, NodeInfoWrapper,
     },
@@ -128,11 +128,18 @@ pub enum Response {
     /// - [`BuildAndPostBlock`](crate::method::ClientMethod::BuildAndPostBlock)
     /// - [`PostBlockPayload`](crate::method::ClientMethod::PostBlockPayload)
     /// - [`Retry`](crate::method::ClientMethod::Retry)
+    /// - [`RetryTransactionUntilIncluded`](crate::method::ClientMethod::RetryTransactionUntilIncluded)
     BlockIdWithBlock(BlockId, BlockDto),
     /// Response for:
     /// - [`GetBlockMetadata`](crate::method::ClientMethod::GetBlockMetadata)
     BlockMetadata(BlockMetadataResponse),
     /// Response for:
+    /// - [`BlockStatus`](crate::method::ClientMethod::BlockStatus)
+    BlockStatus(BlockStatus),
+    /// Response for:
+    /// - [`LookupTransaction`](crate::method::ClientMethod::LookupTransaction)
+    InclusionLookup(InclusionLookupDto),
+    /// Response for:
     /// - [`GetBlockRaw`](crate::method::ClientMethod::GetBlockRaw)
     BlockRaw(Vec<u8>),
     /// Response for:
@@ -191,6 +198,7 @@ pub enum Response {
     /// Response for:
     /// - [`Reattach`](crate::method::ClientMethod::Reattach)
     /// - [`ReattachUnchecked`](crate::method::ClientMethod::ReattachUnchecked)
+    /// - [`ReattachWithUnspentInputs`](crate::method::ClientMethod::ReattachWithUnspentInputs)
     Reattached((BlockId, BlockDto)),
     /// Response for:
     /// - [`Promote`](crate::method::ClientMethod::Promote)
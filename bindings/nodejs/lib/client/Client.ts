@@ -19,6 +19,7 @@ import type {
     AliasQueryParameter,
     OutputIdsResponse,
     IBip32Chain,
+    InclusionLookup,
 } from '../types/client';
 import type {
     IUTXOInput,
@@ -580,6 +581,24 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Looks up the inclusion state of a transaction, the known block IDs are the blocks the transaction was posted in.
+     */
+    async lookupTransaction(
+        transactionId: string,
+        knownBlockIds?: string[],
+    ): Promise<InclusionLookup> {
+        const response = await this.methodHandler.callMethod({
+            name: 'lookupTransaction',
+            data: {
+                transactionId,
+                knownBlockIds,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Transforms a hex encoded address to a bech32 encoded address.
      */
@@ -250,6 +250,14 @@ export interface __GetIncludedBlockMetadataMethod__ {
     };
 }
 
+export interface __LookupTransactionMethod__ {
+    name: 'lookupTransaction';
+    data: {
+        transactionId: string;
+        knownBlockIds?: string[];
+    };
+}
+
 export interface __HexToBech32Method__ {
     name: 'hexToBech32';
     data: {
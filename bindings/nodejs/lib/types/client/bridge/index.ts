@@ -32,6 +32,7 @@ import type {
     __GetTreasuryMethod__,
     __GetIncludedBlockMethod__,
     __GetIncludedBlockMetadataMethod__,
+    __LookupTransactionMethod__,
     __HexToBech32Method__,
     __AliasIdToBech32Method__,
     __NftIdToBech32Method__,
@@ -104,6 +105,7 @@ export type __ClientMethods__ =
     | __GetTreasuryMethod__
     | __GetIncludedBlockMethod__
     | __GetIncludedBlockMetadataMethod__
+    | __LookupTransactionMethod__
     | __HexToBech32Method__
     | __AliasIdToBech32Method__
     | __NftIdToBech32Method__
@@ -34,6 +34,14 @@ export interface IClientOptions {
     powWorkerCount?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
+    /** Max allowed difference in seconds between the local time and the latest milestone timestamp */
+    timeSyncTolerance?: number;
+    /** Whether the local time is used without checking it against the latest milestone timestamp */
+    timeCheckDisabled?: boolean;
+    /** Time for which the latest milestone timestamp requested for the time check is reused */
+    timeCheckCacheTtl?: IDuration;
+    /** Max number of pages that are requested from an indexer route when following the cursor */
+    maxIndexerPages?: number;
 }
 
 /** Time duration */
@@ -0,0 +1,19 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { IBlock } from '@iota/types';
+
+/**
+ * The result of looking up the inclusion of a transaction.
+ */
+export type InclusionLookup =
+    /** The transaction got included in the ledger with this block. */
+    | { type: 'included'; block: IBlock }
+    /** The transaction isn't included yet, but these blocks containing it are known to the node. */
+    | { type: 'pending'; knownBlockIds: string[] }
+    /** The transaction is conflicting. */
+    | { type: 'conflicting'; reason: string }
+    /** No block containing the transaction was provided. */
+    | { type: 'unknown' }
+    /** None of the blocks containing the transaction are known to the node, they might have been pruned. */
+    | { type: 'pruned' };
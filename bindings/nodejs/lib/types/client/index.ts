@@ -4,6 +4,7 @@ export * from './burn';
 export * from './clientOptions';
 export * from './constants';
 export * from './generateAddressesOptions';
+export * from './inclusionLookup';
 export * from './ledgerNanoStatus';
 export * from './network';
 export * from './nodeInfo';
@@ -19,6 +19,11 @@ export interface SeedSecretManager {
 /** Placeholder secret manager that can't do anything. */
 export type PlaceholderSecretManager = 'placeholder';
 
+/** Secret manager that only has the public keys of the public addresses, by key index, so it can't sign. */
+export interface WatchOnlySecretManager {
+    watchOnly: HexEncodedString[];
+}
+
 /** Secret manager that uses Stronghold. */
 export interface StrongholdSecretManager {
     stronghold: {
@@ -32,7 +37,8 @@ export type SecretManagerType =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
     | StrongholdSecretManager
-    | PlaceholderSecretManager;
+    | PlaceholderSecretManager
+    | WatchOnlySecretManager;
 
 export interface EvmSignature {
     /**
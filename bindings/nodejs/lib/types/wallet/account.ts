@@ -33,6 +33,8 @@ export interface Balance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /** If the balance was returned while another sync of the account was still ongoing, so it can be outdated */
+    syncInProgress: boolean;
 }
 
 /** The balance of the base coin */
@@ -99,6 +101,12 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Max number of addresses that are synced at the same time, each of them sends several requests to the node. Default: 500. */
+    maxParallelRequests?: number;
+    /** Wait for an ongoing sync of the account and return its balance, instead of returning the current balance immediately. Default: true. */
+    waitForOngoingSync?: boolean;
+    /** Consolidate the outputs after syncing if their amount reached the default consolidation threshold. Default: false. */
+    consolidateOutputs?: boolean;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -43,6 +43,8 @@ enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    SyncProgress = 6,
+    AccountRecoveryProgress = 7,
 }
 
 abstract class WalletEvent {
@@ -271,6 +273,59 @@ class BroadcastingProgress extends TransactionProgress {
     }
 }
 
+/**
+ * The progress of an account sync.
+ */
+type SyncProgress =
+    /** The output ids of `synced` of the `total` addresses to sync were requested. */
+    | { type: 'addressesSynced'; synced: number; total: number }
+    /** The `count` outputs of the synced addresses were requested. */
+    | { type: 'outputsFetched'; count: number }
+    /** The pending transactions were synced. */
+    | { type: 'pendingTransactionsSynced' }
+    /** A transaction consolidating `outputs` outputs is sent, only with the `consolidateOutputs` sync option. */
+    | { type: 'consolidationStarted'; outputs: number };
+
+class SyncProgressWalletEvent extends WalletEvent {
+    private progress: SyncProgress;
+
+    constructor(progress: SyncProgress) {
+        super(WalletEventType.SyncProgress);
+        this.progress = progress;
+    }
+
+    /**
+     * The progress of the sync.
+     */
+    getProgress(): SyncProgress {
+        return this.progress;
+    }
+}
+
+/**
+ * The progress of the search of an account during the account recovery.
+ */
+type AccountRecoveryProgress =
+    | { type: 'scanningAccount' }
+    | { type: 'accountScanned'; outputs: number }
+    | { type: 'accountFailed'; error: string };
+
+class AccountRecoveryProgressWalletEvent extends WalletEvent {
+    private progress: AccountRecoveryProgress;
+
+    constructor(progress: AccountRecoveryProgress) {
+        super(WalletEventType.AccountRecoveryProgress);
+        this.progress = progress;
+    }
+
+    /**
+     * The progress of the account search.
+     */
+    getProgress(): AccountRecoveryProgress {
+        return this.progress;
+    }
+}
+
 export {
     Event,
     WalletEventType,
@@ -289,4 +344,8 @@ export {
     SigningTransactionProgress,
     PerformingPowProgress,
     BroadcastingProgress,
+    SyncProgress,
+    SyncProgressWalletEvent,
+    AccountRecoveryProgress,
+    AccountRecoveryProgressWalletEvent,
 };
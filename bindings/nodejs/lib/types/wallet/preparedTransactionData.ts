@@ -54,9 +54,7 @@ export class PreparedTransactionData {
      * A `Promise` that resolves to a `SignedTransactionEssence` object.
      */
     public async sign(): Promise<SignedTransactionEssence> {
-        return this._account.signTransactionEssence(
-            this.preparedTransactionData(),
-        );
+        return this._account.signTransaction(this.preparedTransactionData());
     }
 
     /**
@@ -18,7 +18,8 @@ export interface TransactionOptions {
 export type RemainderValueStrategy =
     | ChangeAddress
     | ReuseAddress
-    | CustomAddress;
+    | CustomAddress
+    | SweepIntoExisting;
 
 /** ChangeAddress variant of RemainderValueStrategy */
 export type ChangeAddress = {
@@ -38,6 +39,12 @@ export type CustomAddress = {
     value: string;
 };
 
+/** SweepIntoExisting variant of RemainderValueStrategy */
+export type SweepIntoExisting = {
+    strategy: 'SweepIntoExisting';
+    value: null;
+};
+
 /** Native token options for minting */
 export interface MintNativeTokenParams {
     aliasId?: string;
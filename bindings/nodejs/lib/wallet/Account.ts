@@ -1023,7 +1023,7 @@ export class Account {
      * @param preparedTransactionData The prepared transaction data to sign.
      * @returns The signed transaction essence.
      */
-    async signTransactionEssence(
+    async signTransaction(
         preparedTransactionData: IPreparedTransactionData,
     ): Promise<SignedTransactionEssence> {
         const response = await this.methodHandler.callAccountMethod(
@@ -1038,6 +1038,15 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * @deprecated Renamed to `signTransaction`.
+     */
+    async signTransactionEssence(
+        preparedTransactionData: IPreparedTransactionData,
+    ): Promise<SignedTransactionEssence> {
+        return this.signTransaction(preparedTransactionData);
+    }
+
     /**
      * Sign a prepared transaction, and send it.
      * @param preparedTransactionData The prepared transaction data to sign and submit.
@@ -42,6 +42,10 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
+        time_sync_tolerance: Optional[int] = None,
+        time_check_disabled: Optional[bool] = None,
+        time_check_cache_ttl: Optional[timedelta] = None,
+        max_indexer_pages: Optional[int] = None,
         client_handle=None
     ):
         """Initialize the IOTA Client.
@@ -81,6 +85,14 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Fallback to local proof of work if the node doesn't support remote PoW.
         pow_worker_count : int
             The amount of threads to be used for proof of work.
+        time_sync_tolerance : int
+            The maximum difference in seconds between the local time and the latest milestone timestamp, 300 by default.
+        time_check_disabled : bool
+            Whether the local time is used without checking it against the latest milestone timestamp.
+        time_check_cache_ttl : datetime.timedelta
+            Time for which the latest milestone timestamp requested for the time check is reused, 10 seconds by default.
+        max_indexer_pages : int
+            The maximum number of pages that are requested from an indexer route when following the cursor.
         """
         client_config = dict(locals())
         del client_config['self']
@@ -112,6 +124,9 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'remote_pow_timeout' in client_config:
             client_config['remote_pow_timeout'] = {'secs': int(client_config['remote_pow_timeout'].total_seconds(
             )), 'nanos': get_remaining_nano_seconds(client_config['remote_pow_timeout'])}
+        if 'time_check_cache_ttl' in client_config:
+            client_config['time_check_cache_ttl'] = {'secs': int(client_config['time_check_cache_ttl'].total_seconds(
+            )), 'nanos': get_remaining_nano_seconds(client_config['time_check_cache_ttl'])}
 
         client_config = humps.camelize(client_config)
         client_config_str = dumps(client_config)
@@ -402,8 +402,8 @@ class Account:
             }
         )
 
-    def sign_transaction_essence(self, prepared_transaction_data):
-        """Sign a transaction essence.
+    def sign_transaction(self, prepared_transaction_data):
+        """Sign a prepared transaction, useful for offline signing.
         """
         return self._call_account_method(
             'signTransactionEssence', {
@@ -411,6 +411,11 @@ class Account:
             }
         )
 
+    def sign_transaction_essence(self, prepared_transaction_data):
+        """Deprecated, renamed to `sign_transaction`.
+        """
+        return self.sign_transaction(prepared_transaction_data)
+
     def sign_and_submit_transaction(self, prepared_transaction_data):
         """Validate the transaction, sign it, submit it to a node and store it in the account.
         """
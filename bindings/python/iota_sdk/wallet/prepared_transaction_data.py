@@ -46,7 +46,7 @@ class PreparedTransactionData:
     :returns: A SignedTransactionEssence object.
     """
     def sign(self):
-        return self.account.sign_transaction_essence(self.prepared_transaction_data())
+        return self.account.sign_transaction(self.prepared_transaction_data())
 
     
     """
@@ -20,7 +20,7 @@ use iota_sdk::{
     },
     wallet::{
         account::{types::AccountAddress, Account, OutputsToClaim, TransactionOptions},
-        MintNativeTokenParams, MintNftParams, SendAmountParams, SendNativeTokensParams, SendNftParams,
+        CreateNativeTokenParams, MintNftParams, SendAmountParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
 };
@@ -323,18 +323,13 @@ pub async fn claim_command(account: &Account, output_id: Option<String>) -> Resu
     } else {
         println_log_info!("Claiming outputs.");
 
-        let output_ids = account
-            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
-            .await?;
+        let output_ids = account.claimable_outputs(OutputsToClaim::All).await?;
 
         if output_ids.is_empty() {
             println_log_info!("No outputs available to claim.");
         }
 
-        // Doing chunks of only 60, because we might need to create the double amount of outputs, because of potential
-        // storage deposit return unlock conditions and also consider the remainder output.
-        for output_ids_chunk in output_ids.chunks(60) {
-            let transaction = account.claim_outputs(output_ids_chunk.to_vec()).await?;
+        for transaction in account.claim_outputs_in_batches(output_ids).await? {
             println_log_info!(
                 "Claiming transaction sent:\n{:?}\n{:?}",
                 transaction.transaction_id,
@@ -425,7 +420,7 @@ pub async fn create_alias_outputs_command(account: &Account) -> Result<(), Error
 // `decrease-native-token-supply` command
 pub async fn decrease_native_token_command(account: &Account, token_id: String, amount: String) -> Result<(), Error> {
     let transaction = account
-        .decrease_native_token_supply(
+        .melt_native_token(
             TokenId::from_str(&token_id)?,
             U256::from_dec_str(&amount).map_err(|e| Error::Miscellaneous(e.to_string()))?,
             None,
@@ -497,7 +492,7 @@ pub async fn faucet_command(
 // `increase-native-token-supply` command
 pub async fn increase_native_token_command(account: &Account, token_id: String, amount: String) -> Result<(), Error> {
     let mint_transaction = account
-        .increase_native_token_supply(
+        .mint_native_token(
             TokenId::from_str(&token_id)?,
             U256::from_dec_str(&amount).map_err(|e| Error::Miscellaneous(e.to_string()))?,
             None,
@@ -520,29 +515,15 @@ pub async fn mint_native_token_command(
     maximum_supply: String,
     foundry_metadata: Option<Vec<u8>>,
 ) -> Result<(), Error> {
-    // If no alias output exists, create one first
-    if account.balance().await?.aliases().is_empty() {
-        let transaction = account.create_alias_output(None, None).await?;
-        println_log_info!(
-            "Alias output minting transaction sent:\n{:?}\n{:?}",
-            transaction.transaction_id,
-            transaction.block_id
-        );
-        account
-            .retry_transaction_until_included(&transaction.transaction_id, None, None)
-            .await?;
-        // Sync account after the transaction got confirmed, so the alias output is available
-        account.sync(None).await?;
-    }
-
-    let params = MintNativeTokenParams {
+    // An alias output is created first if the account has none
+    let params = CreateNativeTokenParams {
         alias_id: None,
         circulating_supply: U256::from_dec_str(&circulating_supply).map_err(|e| Error::Miscellaneous(e.to_string()))?,
         maximum_supply: U256::from_dec_str(&maximum_supply).map_err(|e| Error::Miscellaneous(e.to_string()))?,
         foundry_metadata,
     };
 
-    let mint_transaction = account.mint_native_token(params, None).await?;
+    let mint_transaction = account.create_native_token(params, None).await?;
 
     println_log_info!(
         "Native token minting transaction sent:\n{:?}\n{:?}",
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! In this example we get inputs and prepare a transaction.
+//! This is the only step besides sending that needs a node, the prepared transaction is written to a file to be signed
+//! on an offline machine by `2_transaction_signing`.
 //!
 //! `cargo run --example 1_transaction_preparation --release`
 
@@ -13,7 +15,7 @@ use std::{
 
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        api::{input_selection::InputSelectionStrategy, PreparedTransactionData, PreparedTransactionDataDto},
         Client, Result,
     },
     types::block::address::Bech32Address,
@@ -44,7 +46,9 @@ async fn main() -> Result<()> {
     // Recovers addresses from example `0_address_generation`.
     let addresses = read_addresses_from_file(ADDRESS_FILE_NAME)?;
     // Gets enough inputs related to these addresses to cover the amount.
-    let inputs = online_client.find_inputs(addresses, amount).await?;
+    let inputs = online_client
+        .find_inputs(addresses, amount, InputSelectionStrategy::LargestFirst)
+        .await?;
 
     // Prepares the transaction.
     let mut transaction_builder = online_client.block();
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! In this example we sign the prepared transaction.
+//! No node is needed, so this can run on an offline machine. The signed transaction is sent by `3_send_block`.
 //!
 //! `cargo run --example 2_transaction_signing --release`
 
@@ -7,7 +7,7 @@
 //! `cargo run --example mint_native_token --release`
 
 use iota_sdk::{
-    wallet::{MintNativeTokenParams, Result, Wallet},
+    wallet::{CreateNativeTokenParams, Result, Wallet},
     U256,
 };
 
@@ -48,14 +48,14 @@ async fn main() -> Result<()> {
 
     println!("Preparing minting transaction...");
 
-    let params = MintNativeTokenParams {
+    let params = CreateNativeTokenParams {
         alias_id: None,
         circulating_supply: U256::from(100),
         maximum_supply: U256::from(100),
         foundry_metadata: None,
     };
 
-    let mint_txn = account.mint_native_token(params, None).await?;
+    let mint_txn = account.create_native_token(params, None).await?;
     println!("Transaction sent: {}", mint_txn.transaction.transaction_id);
 
     // Wait for transaction to get included
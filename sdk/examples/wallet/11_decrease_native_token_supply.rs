@@ -41,9 +41,7 @@ async fn main() -> Result<()> {
 
     // Melt some of the circulating supply
     let melt_amount = U256::from(10);
-    let transaction = account
-        .decrease_native_token_supply(token_id, melt_amount, None)
-        .await?;
+    let transaction = account.melt_native_token(token_id, melt_amount, None).await?;
     println!("Transaction sent: {}", transaction.transaction_id);
 
     let block_id = account
@@ -8,9 +8,9 @@ use serde::{Deserialize, Serialize};
 use super::ADDRESS_GAP_RANGE;
 use crate::{
     client::{
-        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{GenerateAddressOptions, SecretManage, SecretManager},
-        Client, Result,
+        Client, Error, Result,
     },
     types::block::{
         address::{Address, Bech32Address, Hrp, ToBech32Ext},
@@ -18,6 +18,43 @@ use crate::{
     },
 };
 
+/// The hardened segments before the change and address index of the BIP-44 path
+/// `m/purpose'/coin_type'/account_index'/change/address_index`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Bip44PathPrefix {
+    /// Purpose, only 44 is supported
+    pub purpose: u32,
+    /// Coin type
+    pub coin_type: u32,
+    /// Account index
+    pub account_index: u32,
+}
+
+impl Bip44PathPrefix {
+    /// Creates the prefix `m/44'/coin_type'/account_index'`.
+    pub fn new(coin_type: u32, account_index: u32) -> Self {
+        Self {
+            purpose: HD_WALLET_TYPE,
+            coin_type,
+            account_index,
+        }
+    }
+
+    /// Set the purpose
+    pub fn with_purpose(mut self, purpose: u32) -> Self {
+        self.purpose = purpose;
+        self
+    }
+}
+
+impl Default for Bip44PathPrefix {
+    fn default() -> Self {
+        Self::new(SHIMMER_COIN_TYPE, 0)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -51,6 +88,17 @@ impl GetAddressesOptions {
         self
     }
 
+    /// Set the coin type and account index of the derivation path, for networks with a non-standard coin type. Fails if
+    /// the purpose isn't 44, since the inputs are always signed with BIP-44 paths.
+    pub fn with_custom_path_prefix(mut self, path_prefix: Bip44PathPrefix) -> Result<Self> {
+        if path_prefix.purpose != HD_WALLET_TYPE {
+            return Err(Error::UnsupportedBip44Purpose(path_prefix.purpose));
+        }
+        self.coin_type = path_prefix.coin_type;
+        self.account_index = path_prefix.account_index;
+        Ok(self)
+    }
+
     /// Set range to the builder
     pub fn with_range(mut self, range: Range<u32>) -> Self {
         self.range = range;
@@ -112,7 +112,7 @@ impl<'a> ClientBlockBuilder<'a> {
         .timestamp(current_time);
 
         if let Some(address) = self.custom_remainder_address {
-            input_selection = input_selection.remainder_address(address);
+            input_selection = input_selection.remainder_address(address.into_inner());
         }
 
         if let Ok(selected_transaction_data) = input_selection.select() {
@@ -200,7 +200,7 @@ impl<'a> ClientBlockBuilder<'a> {
                     .timestamp(current_time);
 
                     if let Some(address) = self.custom_remainder_address {
-                        input_selection = input_selection.remainder_address(address);
+                        input_selection = input_selection.remainder_address(address.into_inner());
                     }
 
                     let selected_transaction_data = match input_selection.select() {
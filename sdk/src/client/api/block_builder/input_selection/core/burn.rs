@@ -8,12 +8,14 @@ use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
 use crate::types::block::{
-    output::{AliasId, FoundryId, NativeToken, NftId, TokenId},
+    output::{AliasId, FoundryId, NativeToken, NftId, Output, OutputId, TokenId},
     Error,
 };
 
 /// A type to specify what needs to be burned during input selection.
 /// Nothing will be burned that has not been explicitly set with this struct.
+/// Zero amounts of native tokens are never stored, burning or melting nothing is the same as not setting the native
+/// token at all.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Burn {
@@ -25,6 +27,8 @@ pub struct Burn {
     pub(crate) foundries: HashSet<FoundryId>,
     /// Amounts of native tokens to burn.
     pub(crate) native_tokens: BTreeMap<TokenId, U256>,
+    /// Amounts of native tokens to melt through their foundries.
+    pub(crate) melt_native_tokens: BTreeMap<TokenId, U256>,
 }
 
 impl Burn {
@@ -33,6 +37,36 @@ impl Burn {
         Self::default()
     }
 
+    /// Creates a new [`Burn`] that burns everything contained in the given outputs: their aliases, NFTs and foundries
+    /// as well as the full amounts of their native tokens.
+    pub fn from_outputs<'a>(outputs: impl IntoIterator<Item = (&'a OutputId, &'a Output)>) -> Self {
+        let mut burn = Self::new();
+
+        for (output_id, output) in outputs {
+            match output {
+                Output::Alias(alias) => {
+                    burn.aliases.insert(alias.alias_id_non_null(output_id));
+                }
+                Output::Nft(nft) => {
+                    burn.nfts.insert(nft.nft_id_non_null(output_id));
+                }
+                Output::Foundry(foundry) => {
+                    burn.foundries.insert(foundry.id());
+                }
+                Output::Basic(_) | Output::Treasury(_) => {}
+            }
+
+            if let Some(native_tokens) = output.native_tokens() {
+                for native_token in native_tokens.iter() {
+                    let amount = burn.native_tokens.entry(*native_token.token_id()).or_default();
+                    *amount = amount.saturating_add(native_token.amount());
+                }
+            }
+        }
+
+        burn
+    }
+
     /// Adds an alias to [`Burn`].
     pub fn add_alias(mut self, alias_id: AliasId) -> Self {
         self.aliases.insert(alias_id);
@@ -85,17 +119,19 @@ impl Burn {
     }
 
     /// Adds an amount of native token to [`Burn`].
+    /// If the selected inputs hold more of the native token, the rest is kept on the remainder output.
     pub fn add_native_token(mut self, token_id: TokenId, amount: impl Into<U256>) -> Self {
-        self.native_tokens.insert(token_id, amount.into());
+        insert_non_zero(&mut self.native_tokens, token_id, amount.into());
         self
     }
 
     /// Sets the amounts of native tokens to [`Burn`].
     pub fn set_native_tokens(mut self, native_tokens: HashMap<TokenId, impl Into<U256>>) -> Self {
-        self.native_tokens = native_tokens
-            .into_iter()
-            .map(|(token_id, amount)| (token_id, amount.into()))
-            .collect();
+        self.native_tokens = non_zero(
+            native_tokens
+                .into_iter()
+                .map(|(token_id, amount)| (token_id, amount.into())),
+        );
         self
     }
 
@@ -103,6 +139,87 @@ impl Burn {
     pub fn native_tokens(&self) -> &BTreeMap<TokenId, U256> {
         &self.native_tokens
     }
+
+    /// Adds an amount of native token to melt to [`Burn`].
+    /// Contrary to burning, melting transitions the foundry of the native token to increase its melted tokens, so the
+    /// foundry needs to be available as input.
+    pub fn add_melt_native_token(mut self, token_id: TokenId, amount: impl Into<U256>) -> Self {
+        insert_non_zero(&mut self.melt_native_tokens, token_id, amount.into());
+        self
+    }
+
+    /// Sets the amounts of native tokens to melt to [`Burn`].
+    pub fn set_melt_native_tokens(mut self, melt_native_tokens: HashMap<TokenId, impl Into<U256>>) -> Self {
+        self.melt_native_tokens = non_zero(
+            melt_native_tokens
+                .into_iter()
+                .map(|(token_id, amount)| (token_id, amount.into())),
+        );
+        self
+    }
+
+    /// Returns the native tokens to melt to [`Burn`].
+    pub fn melt_native_tokens(&self) -> &BTreeMap<TokenId, U256> {
+        &self.melt_native_tokens
+    }
+
+    /// Returns whether nothing is set to be burned or melted.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+            && self.nfts.is_empty()
+            && self.foundries.is_empty()
+            && self.native_tokens.is_empty()
+            && self.melt_native_tokens.is_empty()
+    }
+
+    /// Merges another [`Burn`] into this one: aliases, NFTs and foundries are united and the amounts of native tokens
+    /// to burn or melt are added, failing with [`Error::NativeTokensOverflow`] if an amount overflows.
+    pub fn merge(mut self, other: Self) -> Result<Self, Error> {
+        self.aliases.extend(other.aliases);
+        self.nfts.extend(other.nfts);
+        self.foundries.extend(other.foundries);
+
+        for (token_id, amount) in other.native_tokens {
+            let merged_amount = self.native_tokens.entry(token_id).or_default();
+            *merged_amount = merged_amount.checked_add(amount).ok_or(Error::NativeTokensOverflow)?;
+        }
+        for (token_id, amount) in other.melt_native_tokens {
+            let merged_amount = self.melt_native_tokens.entry(token_id).or_default();
+            *merged_amount = merged_amount.checked_add(amount).ok_or(Error::NativeTokensOverflow)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Merges all burns into this one like [`Burn::merge()`]. If an amount of native tokens overflows, the error is
+    /// returned and this burn is left unchanged.
+    pub fn try_extend(&mut self, burns: impl IntoIterator<Item = Self>) -> Result<(), Error> {
+        let mut merged = self.clone();
+
+        for burn in burns {
+            merged = merged.merge(burn)?;
+        }
+        *self = merged;
+
+        Ok(())
+    }
+}
+
+// Inserts the amount of a native token, removing the native token instead if the amount is zero.
+fn insert_non_zero(native_tokens: &mut BTreeMap<TokenId, U256>, token_id: TokenId, amount: U256) {
+    if amount.is_zero() {
+        native_tokens.remove(&token_id);
+    } else {
+        native_tokens.insert(token_id, amount);
+    }
+}
+
+// Collects the amounts of native tokens, skipping zero amounts.
+fn non_zero(native_tokens: impl IntoIterator<Item = (TokenId, U256)>) -> BTreeMap<TokenId, U256> {
+    native_tokens
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect()
 }
 
 impl From<FoundryId> for Burn {
@@ -129,7 +246,25 @@ impl From<NativeToken> for Burn {
     }
 }
 
+impl TryFrom<Vec<NativeToken>> for Burn {
+    type Error = Error;
+
+    fn try_from(native_tokens: Vec<NativeToken>) -> Result<Self, Self::Error> {
+        let mut burn = Self::new();
+        burn.try_extend(native_tokens.into_iter().map(Self::from))?;
+        Ok(burn)
+    }
+}
+
+impl From<&[NftId]> for Burn {
+    fn from(nft_ids: &[NftId]) -> Self {
+        Self::new().set_nfts(nft_ids.iter().copied().collect())
+    }
+}
+
 /// A DTO for [`Burn`].
+/// Empty collections are serialized as absent fields and zero amounts of native tokens are dropped when converting
+/// back, so converting a [`Burn`] to a [`BurnDto`] and back always results in the same [`Burn`].
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BurnDto {
@@ -145,6 +280,9 @@ pub struct BurnDto {
     /// Amounts of native tokens to burn.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) native_tokens: Option<BTreeMap<TokenId, U256>>,
+    /// Amounts of native tokens to melt through their foundries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) melt_native_tokens: Option<BTreeMap<TokenId, U256>>,
 }
 
 impl From<&Burn> for BurnDto {
@@ -153,12 +291,8 @@ impl From<&Burn> for BurnDto {
             aliases: (!value.aliases.is_empty()).then_some(value.aliases.clone()),
             nfts: (!value.nfts.is_empty()).then_some(value.nfts.clone()),
             foundries: (!value.foundries.is_empty()).then_some(value.foundries.clone()),
-            native_tokens: (!value.native_tokens.is_empty()).then_some(BTreeMap::from_iter(
-                value
-                    .native_tokens
-                    .iter()
-                    .map(|(token_id, amount)| (*token_id, *amount)),
-            )),
+            native_tokens: (!value.native_tokens.is_empty()).then_some(value.native_tokens.clone()),
+            melt_native_tokens: (!value.melt_native_tokens.is_empty()).then_some(value.melt_native_tokens.clone()),
         }
     }
 }
@@ -171,7 +305,8 @@ impl TryFrom<BurnDto> for Burn {
             aliases: value.aliases.unwrap_or_default(),
             nfts: value.nfts.unwrap_or_default(),
             foundries: value.foundries.unwrap_or_default(),
-            native_tokens: value.native_tokens.unwrap_or_default(),
+            native_tokens: non_zero(value.native_tokens.unwrap_or_default()),
+            melt_native_tokens: non_zero(value.melt_native_tokens.unwrap_or_default()),
         })
     }
 }
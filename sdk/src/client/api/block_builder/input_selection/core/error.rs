@@ -10,7 +10,7 @@ use serde::{Serialize, Serializer};
 
 use crate::{
     client::api::input_selection::Requirement,
-    types::block::output::{ChainId, OutputId, TokenId},
+    types::block::output::{ChainId, FoundryId, OutputId, TokenId},
 };
 
 /// Errors related to input selection.
@@ -24,6 +24,20 @@ pub enum Error {
     /// Can't burn and transition an output at the same time.
     #[error("can't burn and transition an output at the same time, chain ID: {0}")]
     BurnAndTransition(ChainId),
+    /// Aliases, NFTs or foundries to burn are not available as inputs.
+    #[error("outputs to burn are not available: {0:?}")]
+    BurnTargetsNotFound(Vec<ChainId>),
+    /// No inputs can be selected without leaving a remainder below the minimum storage deposit.
+    #[error("selected inputs leave a remainder of {remainder} below the minimum storage deposit of {minimum}")]
+    DustRemainder {
+        /// The remainder amount.
+        remainder: u64,
+        /// The minimum storage deposit of a remainder output.
+        minimum: u64,
+    },
+    /// The foundry of native tokens to melt is not available as input.
+    #[error("foundry {0} of the native tokens to melt is not available")]
+    FoundryToMeltNotAvailable(FoundryId),
     /// Insufficient amount provided.
     #[error("insufficient amount: found {found}, required {required}")]
     InsufficientAmount {
@@ -42,6 +56,16 @@ pub enum Error {
         /// The required amount.
         required: U256,
     },
+    /// Insufficient native token amount provided to burn.
+    #[error("insufficient native token amount to burn: available {available}, requested {requested}")]
+    InsufficientNativeTokenToBurn {
+        /// The token ID.
+        token_id: TokenId,
+        /// The amount available in the inputs.
+        available: U256,
+        /// The amount requested to be burned.
+        requested: U256,
+    },
     /// Invalid amount of inputs.
     #[error("invalid amount of inputs: {0}")]
     InvalidInputCount(usize),
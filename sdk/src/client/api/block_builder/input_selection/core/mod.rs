@@ -3,8 +3,10 @@
 
 pub(crate) mod burn;
 pub(crate) mod error;
+pub(crate) mod preview;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
+pub(crate) mod strategy;
 pub(crate) mod transition;
 
 use core::ops::Deref;
@@ -16,7 +18,9 @@ pub(crate) use requirement::is_alias_transition;
 pub use self::{
     burn::{Burn, BurnDto},
     error::Error,
+    preview::SelectionPreview,
     requirement::Requirement,
+    strategy::InputSelectionStrategy,
 };
 use crate::{
     client::{api::types::RemainderData, secret::types::InputSigningData},
@@ -42,6 +46,7 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    strategy: InputSelectionStrategy,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
@@ -187,6 +192,7 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            strategy: InputSelectionStrategy::default(),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
@@ -212,6 +218,12 @@ impl InputSelection {
         self
     }
 
+    /// Sets the strategy of an [`InputSelection`] to select inputs for a missing amount.
+    pub fn strategy(mut self, strategy: InputSelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Sets the remainder address of an [`InputSelection`].
     pub fn remainder_address(mut self, address: impl Into<Option<Address>>) -> Self {
         self.remainder_address = address.into();
@@ -0,0 +1,64 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::{Burn, BurnDto, Error, InputSelection, Selected};
+use crate::types::block::{
+    address::Address,
+    output::{Output, OutputId, Rent, RentStructure},
+};
+
+/// What a transaction would consume and create, returned from [`InputSelection::preview()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionPreview {
+    /// The ids of the inputs that would be consumed.
+    pub inputs: Vec<OutputId>,
+    /// The outputs that would be created, including the remainder and storage deposit return outputs.
+    pub outputs: Vec<Output>,
+    /// What would be burned.
+    pub burned: BurnDto,
+    /// The address and amount of the remainder output, if there would be one.
+    pub remainder: Option<(Address, u64)>,
+    /// The storage deposit required by the created outputs minus the one freed by the consumed inputs.
+    pub storage_deposit_delta: i128,
+    /// The input that the wallet added to sweep it into the remainder, only set for its previews.
+    #[serde(default)]
+    pub swept_input: Option<OutputId>,
+}
+
+impl SelectionPreview {
+    pub(crate) fn new(selected: &Selected, burn: Option<&Burn>, rent_structure: &RentStructure) -> Self {
+        let storage_deposit = |outputs: &mut dyn Iterator<Item = &Output>| {
+            outputs
+                .map(|output| i128::from(output.rent_cost(rent_structure)))
+                .sum::<i128>()
+        };
+
+        Self {
+            inputs: selected.inputs.iter().map(|input| *input.output_id()).collect(),
+            outputs: selected.outputs.clone(),
+            burned: burn.map(BurnDto::from).unwrap_or_default(),
+            remainder: selected
+                .remainder
+                .as_ref()
+                .map(|remainder| (remainder.address, remainder.output.amount())),
+            storage_deposit_delta: storage_deposit(&mut selected.outputs.iter())
+                - storage_deposit(&mut selected.inputs.iter().map(|input| &input.output)),
+            swept_input: None,
+        }
+    }
+}
+
+impl InputSelection {
+    /// Runs the whole input selection, including burning and the remainder calculation, and returns what the
+    /// transaction would consume and create without building an essence.
+    pub fn preview(self) -> Result<SelectionPreview, Error> {
+        let burn = self.burn.clone();
+        let rent_structure = *self.protocol_parameters.rent_structure();
+        let selected = self.select()?;
+
+        Ok(SelectionPreview::new(&selected, burn.as_ref(), &rent_structure))
+    }
+}
@@ -5,7 +5,10 @@ use std::collections::HashMap;
 
 use super::{Error, InputSelection, Requirement};
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{
+        api::block_builder::input_selection::core::strategy::{exact_match, InputSelectionStrategy},
+        secret::types::InputSigningData,
+    },
     types::block::{
         address::Address,
         input::INPUT_COUNT_MAX,
@@ -294,32 +297,42 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
+        let exact_match = self.strategy == InputSelectionStrategy::ExactMatchThenLargest
+            && self.fulfil_exact_amount(&mut amount_selection);
 
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
+        if !exact_match {
+            let smallest_first = self.strategy == InputSelectionStrategy::SmallestFirst;
 
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+            // With the smallest first strategy, try to select outputs first with ordering from low to high amount, if
+            // that exceeds the max inputs, try reversed.
+            if smallest_first {
+                log::debug!("Ordering inputs from low to high amount");
+                // Sort inputs per amount, low to high.
+                self.available_inputs
+                    .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
 
-        if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
-            return Ok(r);
-        }
+                if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
+                    return Ok(r);
+                }
+            }
 
-        if self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into() {
-            // Clear before trying with reversed ordering.
-            log::debug!("Clearing amount selection");
-            amount_selection = AmountSelection::new(self)?;
+            if !smallest_first
+                || self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into()
+            {
+                if smallest_first {
+                    // Clear before trying with reversed ordering.
+                    log::debug!("Clearing amount selection");
+                    amount_selection = AmountSelection::new(self)?;
+                }
 
-            log::debug!("Ordering inputs from high to low amount");
-            // Sort inputs per amount, high to low.
-            self.available_inputs
-                .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+                log::debug!("Ordering inputs from high to low amount");
+                // Sort inputs per amount, high to low.
+                self.available_inputs
+                    .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
 
-            if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
-                return Ok(r);
+                if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
+                    return Ok(r);
+                }
             }
         }
 
@@ -344,6 +357,40 @@ impl InputSelection {
         Ok(amount_selection.into_newly_selected_inputs())
     }
 
+    // Tries to select basic inputs without native tokens and storage deposit return whose amounts sum up to exactly the
+    // missing amount, so that no remainder is needed. Without a tolerance, since an excess lower than the storage
+    // deposit of a remainder output couldn't be sent anywhere.
+    fn fulfil_exact_amount(&self, amount_selection: &mut AmountSelection) -> bool {
+        // A remainder is needed anyway
+        if amount_selection.native_tokens_remainder || amount_selection.inputs_sum > amount_selection.outputs_sum {
+            return false;
+        }
+
+        let inputs = self
+            .available_inputs
+            .iter()
+            .filter(|input| {
+                if let Output::Basic(output) = &input.output {
+                    output.native_tokens().is_empty()
+                        && sdruc_not_expired(&input.output, self.timestamp).is_none()
+                        && output
+                            .unlock_conditions()
+                            .locked_address(output.address(), self.timestamp)
+                            .is_ed25519()
+                } else {
+                    false
+                }
+            })
+            .collect::<Vec<_>>();
+        let amounts = inputs.iter().map(|input| input.output.amount()).collect::<Vec<_>>();
+        let max_inputs = usize::from(INPUT_COUNT_MAX).saturating_sub(self.selected_inputs.len());
+
+        exact_match(&amounts, amount_selection.missing_amount(), max_inputs).map_or(false, |indexes| {
+            log::debug!("Found inputs that exactly match the missing amount");
+            amount_selection.fulfil(indexes.into_iter().map(|index| inputs[index]))
+        })
+    }
+
     fn fulfill_amount_requirement_inner(
         &mut self,
         amount_selection: &mut AmountSelection,
@@ -11,7 +11,12 @@ pub(crate) mod nft;
 pub(crate) mod sender;
 
 pub(crate) use self::alias::is_alias_transition;
-use self::{alias::is_alias_with_id_non_null, foundry::is_foundry_with_id, nft::is_nft_with_id_non_null};
+use self::{
+    alias::is_alias_with_id_non_null,
+    foundry::is_foundry_with_id,
+    native_tokens::{get_minted_and_melted_native_tokens, get_native_tokens},
+    nft::is_nft_with_id_non_null,
+};
 use super::{Error, InputSelection};
 use crate::{
     client::secret::types::InputSigningData,
@@ -142,6 +147,66 @@ impl InputSelection {
         }
     }
 
+    /// Checks upfront that all outputs and native tokens to burn are available, reporting all missing ones at once.
+    pub fn validate_burn(&self) -> Result<(), Error> {
+        let Some(burn) = self.burn.as_ref() else {
+            return Ok(());
+        };
+        let inputs = self
+            .selected_inputs
+            .iter()
+            .chain(&self.available_inputs)
+            .filter(|input| !self.forbidden_inputs.contains(input.output_id()));
+
+        let mut missing = burn
+            .aliases
+            .iter()
+            .map(|alias_id| ChainId::from(*alias_id))
+            .chain(burn.nfts.iter().map(|nft_id| ChainId::from(*nft_id)))
+            .chain(burn.foundries.iter().map(|foundry_id| ChainId::from(*foundry_id)))
+            .filter(|chain_id| {
+                !inputs.clone().any(|input| {
+                    input
+                        .output
+                        .chain_id()
+                        .map(|input_chain_id| input_chain_id.or_from_output_id(input.output_id()))
+                        == Some(*chain_id)
+                })
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            missing.sort();
+            return Err(Error::BurnTargetsNotFound(missing));
+        }
+
+        if !burn.native_tokens.is_empty() || !burn.melt_native_tokens.is_empty() {
+            let inputs = inputs.cloned().collect::<Vec<_>>();
+            let mut available_native_tokens = get_native_tokens(inputs.iter().map(|input| &input.output))?;
+            let (minted_native_tokens, _) = get_minted_and_melted_native_tokens(&inputs, &self.outputs)?;
+            available_native_tokens.merge(minted_native_tokens)?;
+
+            let mut requested_native_tokens = burn.native_tokens.clone();
+            for (token_id, amount) in &burn.melt_native_tokens {
+                let requested = requested_native_tokens.entry(*token_id).or_default();
+                *requested = requested.saturating_add(*amount);
+            }
+
+            for (token_id, requested) in &requested_native_tokens {
+                let available = available_native_tokens.get(token_id).copied().unwrap_or_default();
+
+                if *requested > available {
+                    return Err(Error::InsufficientNativeTokenToBurn {
+                        token_id: *token_id,
+                        available,
+                        requested: *requested,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Gets requirements from burn.
     pub(crate) fn burn_requirements(&mut self) -> Result<(), Error> {
         if let Some(burn) = self.burn.as_ref() {
@@ -182,8 +247,34 @@ impl InputSelection {
                 log::debug!("Adding {requirement:?} from burn");
                 self.requirements.push(requirement);
             }
+
+            for token_id in burn.melt_native_tokens.keys() {
+                let foundry_id = FoundryId::from(*token_id);
+
+                if !self
+                    .selected_inputs
+                    .iter()
+                    .chain(&self.available_inputs)
+                    .any(|input| is_foundry_with_id(&input.output, &foundry_id))
+                {
+                    return Err(Error::FoundryToMeltNotAvailable(foundry_id));
+                }
+                if burn.foundries.contains(&foundry_id)
+                    || self
+                        .outputs
+                        .iter()
+                        .any(|output| is_foundry_with_id(output, &foundry_id))
+                {
+                    return Err(Error::BurnAndTransition(ChainId::from(foundry_id)));
+                }
+
+                let requirement = Requirement::Foundry(foundry_id);
+                log::debug!("Adding {requirement:?} from melt");
+                self.requirements.push(requirement);
+            }
         }
 
-        Ok(())
+        // Fails before fulfilling any requirement if something to burn isn't available
+        self.validate_burn()
     }
 }
@@ -0,0 +1,267 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+/// Max number of steps of the search for inputs that exactly match an amount, to bound the time it takes.
+const EXACT_MATCH_MAX_STEPS: usize = 100_000;
+
+/// The order in which inputs are selected to cover a missing amount.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum InputSelectionStrategy {
+    /// Selects the inputs with the lowest amounts first, which consolidates small outputs. Falls back to
+    /// [`InputSelectionStrategy::LargestFirst`] if that would exceed the max number of inputs.
+    #[default]
+    SmallestFirst,
+    /// Selects the inputs with the highest amounts first, which requires the least inputs.
+    LargestFirst,
+    /// Searches for inputs whose amounts sum up to exactly the missing amount, so that no remainder is needed. Falls
+    /// back to [`InputSelectionStrategy::LargestFirst`] if no such inputs are found within a bounded search.
+    /// There is no tolerance: inputs that exceed the missing amount by less than the storage deposit of a remainder
+    /// output don't count as a match, since such a remainder couldn't be created.
+    ExactMatchThenLargest,
+}
+
+impl InputSelectionStrategy {
+    /// Returns the indexes of the amounts to select to cover the target, with at most `max_inputs` of them, or `None`
+    /// if that's not possible.
+    pub(crate) fn select_amounts(self, amounts: &[u64], target: u64, max_inputs: usize) -> Option<Vec<usize>> {
+        match self {
+            Self::SmallestFirst => take_until_covered(amounts, false, target, max_inputs)
+                .or_else(|| take_until_covered(amounts, true, target, max_inputs)),
+            Self::LargestFirst => take_until_covered(amounts, true, target, max_inputs),
+            Self::ExactMatchThenLargest => exact_match(amounts, target, max_inputs)
+                .or_else(|| take_until_covered(amounts, true, target, max_inputs)),
+        }
+    }
+
+    /// Like [`Self::select_amounts()`], but the selected amounts must either exactly match the target or leave a
+    /// remainder of at least `minimum_remainder`, so that a valid remainder output can be created. More amounts are
+    /// selected or an exact match is searched if the first selection would leave a too low remainder.
+    pub(crate) fn select_amounts_without_dust(
+        self,
+        amounts: &[u64],
+        target: u64,
+        minimum_remainder: u64,
+        max_inputs: usize,
+    ) -> Result<Vec<usize>, Error> {
+        let Some(indexes) = self.select_amounts(amounts, target, max_inputs) else {
+            // The highest amounts that fit in a transaction
+            let mut amounts = amounts.to_vec();
+            amounts.sort_unstable_by(|left, right| right.cmp(left));
+            return Err(Error::InsufficientAmount {
+                found: amounts.iter().take(max_inputs).sum(),
+                required: target,
+            });
+        };
+
+        let remainder = indexes.iter().map(|index| amounts[*index]).sum::<u64>() - target;
+        if remainder == 0 || remainder >= minimum_remainder {
+            return Ok(indexes);
+        }
+
+        self.select_amounts(amounts, target.saturating_add(minimum_remainder), max_inputs)
+            .or_else(|| exact_match(amounts, target, max_inputs))
+            .ok_or(Error::DustRemainder {
+                remainder,
+                minimum: minimum_remainder,
+            })
+    }
+}
+
+fn sorted_indexes(amounts: &[u64], descending: bool) -> Vec<usize> {
+    let mut indexes = (0..amounts.len()).collect::<Vec<_>>();
+    if descending {
+        indexes.sort_by(|left, right| amounts[*right].cmp(&amounts[*left]));
+    } else {
+        indexes.sort_by_key(|index| amounts[*index]);
+    }
+    indexes
+}
+
+// Takes amounts in ascending or descending order until the target is covered.
+fn take_until_covered(amounts: &[u64], descending: bool, target: u64, max_inputs: usize) -> Option<Vec<usize>> {
+    let mut selected = Vec::new();
+    let mut sum = 0u64;
+
+    for index in sorted_indexes(amounts, descending) {
+        if sum >= target {
+            break;
+        }
+        if selected.len() == max_inputs {
+            return None;
+        }
+        selected.push(index);
+        sum = sum.saturating_add(amounts[index]);
+    }
+
+    (sum >= target).then_some(selected)
+}
+
+/// Depth-first search for amounts that sum up to exactly the target, trying high amounts first. Branches that already
+/// exceed the target, can't reach it anymore or would need more than `max_inputs` amounts are skipped. Sums above the
+/// target are never accepted, even if they are only off by less than the storage deposit of a remainder.
+pub(crate) fn exact_match(amounts: &[u64], target: u64, max_inputs: usize) -> Option<Vec<usize>> {
+    let order = sorted_indexes(amounts, true);
+    let sorted = order.iter().map(|index| amounts[*index]).collect::<Vec<_>>();
+    // The sum of all amounts from a position on
+    let mut remaining = vec![0u64; sorted.len() + 1];
+    for position in (0..sorted.len()).rev() {
+        remaining[position] = remaining[position + 1].saturating_add(sorted[position]);
+    }
+
+    // Positions of the currently included amounts
+    let mut included = Vec::new();
+    let mut sum = 0u64;
+    let mut position = 0;
+
+    for _ in 0..EXACT_MATCH_MAX_STEPS {
+        if sum == target {
+            return Some(included.into_iter().map(|position| order[position]).collect());
+        }
+
+        let backtrack = sum > target
+            || position == sorted.len()
+            || sum.saturating_add(remaining[position]) < target
+            || included.len() == max_inputs;
+
+        if backtrack {
+            // Exclude the last included amount and continue with the following ones
+            let last = included.pop()?;
+            sum -= sorted[last];
+            position = last + 1;
+        } else {
+            included.push(position);
+            sum += sorted[position];
+            position += 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::input::INPUT_COUNT_MAX;
+
+    const STRATEGIES: [InputSelectionStrategy; 3] = [
+        InputSelectionStrategy::SmallestFirst,
+        InputSelectionStrategy::LargestFirst,
+        InputSelectionStrategy::ExactMatchThenLargest,
+    ];
+
+    // Simple xorshift generator, to get reproducible amounts without an additional dependency
+    struct Amounts(u64);
+
+    impl Amounts {
+        fn next(&mut self, max: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % max + 1
+        }
+    }
+
+    fn sum(amounts: &[u64], indexes: &[usize]) -> u64 {
+        indexes.iter().map(|index| amounts[*index]).sum()
+    }
+
+    #[test]
+    fn strategies_cover_amount_within_max_inputs() {
+        let max_inputs = usize::from(INPUT_COUNT_MAX);
+        let mut generator = Amounts(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..200 {
+            let count = generator.next(300) as usize;
+            let amounts = (0..count).map(|_| generator.next(10_000_000)).collect::<Vec<_>>();
+            let total = amounts.iter().sum::<u64>();
+            let target = generator.next(total + total / 10);
+
+            for strategy in STRATEGIES {
+                match strategy.select_amounts(&amounts, target, max_inputs) {
+                    Some(indexes) => {
+                        assert!(indexes.len() <= max_inputs, "{strategy:?}");
+                        assert!(sum(&amounts, &indexes) >= target, "{strategy:?}");
+                        let mut unique = indexes.clone();
+                        unique.sort_unstable();
+                        unique.dedup();
+                        assert_eq!(unique.len(), indexes.len(), "{strategy:?}");
+                    }
+                    // Only possible if even the highest amounts don't cover the target
+                    None => {
+                        let mut sorted = amounts.clone();
+                        sorted.sort_unstable_by(|left, right| right.cmp(left));
+                        assert!(sorted.iter().take(max_inputs).sum::<u64>() < target, "{strategy:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn smallest_first_guard() {
+        // The smallest amounts would need more than the max inputs
+        let amounts = std::iter::repeat(1).take(200).chain([1_000]).collect::<Vec<_>>();
+
+        let indexes = InputSelectionStrategy::SmallestFirst
+            .select_amounts(&amounts, 150, INPUT_COUNT_MAX.into())
+            .unwrap();
+        assert_eq!(indexes, [200]);
+    }
+
+    #[test]
+    fn exact_match() {
+        let amounts = [5, 8, 3, 11, 7];
+
+        let indexes = InputSelectionStrategy::ExactMatchThenLargest
+            .select_amounts(&amounts, 15, INPUT_COUNT_MAX.into())
+            .unwrap();
+        assert_eq!(sum(&amounts, &indexes), 15);
+        // Largest first would need a remainder
+        let indexes = InputSelectionStrategy::LargestFirst
+            .select_amounts(&amounts, 15, INPUT_COUNT_MAX.into())
+            .unwrap();
+        assert_eq!(sum(&amounts, &indexes), 19);
+
+        // Falls back to largest first without exact match
+        let indexes = InputSelectionStrategy::ExactMatchThenLargest
+            .select_amounts(&[10, 10, 10], 15, INPUT_COUNT_MAX.into())
+            .unwrap();
+        assert_eq!(indexes.len(), 2);
+        // The exact match respects the max inputs
+        assert_eq!(super::exact_match(&amounts, 15, 1), None);
+        assert_eq!(super::exact_match(&amounts, 0, 1), Some(Vec::new()));
+    }
+
+    #[test]
+    fn without_dust() {
+        let max_inputs = INPUT_COUNT_MAX.into();
+        let select = |amounts: &[u64], target| {
+            InputSelectionStrategy::LargestFirst
+                .select_amounts_without_dust(amounts, target, 10, max_inputs)
+                .map(|indexes| sum(amounts, &indexes))
+        };
+
+        // The remainder is high enough or there is none
+        assert_eq!(select(&[30, 5], 20).unwrap(), 30);
+        assert_eq!(select(&[30, 5], 30).unwrap(), 30);
+        // Another amount is selected to avoid a remainder of 5
+        assert_eq!(select(&[25, 8, 3], 20).unwrap(), 33);
+        // Or amounts that exactly match, if there aren't enough for a valid remainder
+        assert_eq!(select(&[16, 8, 4], 20).unwrap(), 20);
+        assert!(matches!(
+            select(&[25, 3], 20),
+            Err(Error::DustRemainder {
+                remainder: 5,
+                minimum: 10
+            })
+        ));
+        assert!(matches!(
+            select(&[5, 3], 20),
+            Err(Error::InsufficientAmount { found: 8, required: 20 })
+        ));
+    }
+}
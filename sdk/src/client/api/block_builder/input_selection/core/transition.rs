@@ -8,12 +8,28 @@ use super::{
 use crate::{
     client::secret::types::InputSigningData,
     types::block::output::{
-        AliasOutput, AliasOutputBuilder, AliasTransition, ChainId, FoundryOutput, FoundryOutputBuilder, NftOutput,
-        NftOutputBuilder, Output, OutputId,
+        AliasOutput, AliasOutputBuilder, AliasTransition, ChainId, FoundryOutput, FoundryOutputBuilder, NativeToken,
+        NativeTokens, NftOutput, NftOutputBuilder, Output, OutputId, SimpleTokenScheme, TokenScheme,
     },
 };
 
 impl InputSelection {
+    /// Returns the native tokens an automatically transitioned output can keep from its input.
+    /// Native tokens that are burned or melted, even partially, are moved out of the output, so that the amount left
+    /// after the burn ends up in the remainder output instead of the burn being ignored.
+    fn unburned_native_tokens(&self, native_tokens: &NativeTokens) -> Vec<NativeToken> {
+        native_tokens
+            .iter()
+            .filter(|native_token| {
+                !self.burn.as_ref().map_or(false, |burn| {
+                    burn.native_tokens.contains_key(native_token.token_id())
+                        || burn.melt_native_tokens.contains_key(native_token.token_id())
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Transitions an alias input by creating a new alias output if required.
     fn transition_alias_input(
         &mut self,
@@ -58,6 +74,7 @@ impl InputSelection {
 
         let mut builder = AliasOutputBuilder::from(input)
             .with_alias_id(alias_id)
+            .with_native_tokens(self.unburned_native_tokens(input.native_tokens()))
             .with_foundry_counter(u32::max(highest_foundry_serial_number, input.foundry_counter()))
             .with_features(features);
 
@@ -105,6 +122,7 @@ impl InputSelection {
 
         let output = NftOutputBuilder::from(input)
             .with_nft_id(nft_id)
+            .with_native_tokens(self.unburned_native_tokens(input.native_tokens()))
             .with_features(features)
             .finish_output(self.protocol_parameters.token_supply())?;
 
@@ -144,7 +162,24 @@ impl InputSelection {
             return Ok(None);
         }
 
-        let output = FoundryOutputBuilder::from(input).finish_output(self.protocol_parameters.token_supply())?;
+        let mut builder =
+            FoundryOutputBuilder::from(input).with_native_tokens(self.unburned_native_tokens(input.native_tokens()));
+
+        // Increase the melted tokens if native tokens of the foundry are to be melted.
+        if let Some(melt_amount) = self
+            .burn
+            .as_ref()
+            .and_then(|burn| burn.melt_native_tokens.get(&input.token_id()))
+        {
+            let TokenScheme::Simple(token_scheme) = input.token_scheme();
+            builder = builder.with_token_scheme(TokenScheme::Simple(SimpleTokenScheme::new(
+                token_scheme.minted_tokens(),
+                token_scheme.melted_tokens().saturating_add(*melt_amount),
+                token_scheme.maximum_supply(),
+            )?));
+        }
+
+        let output = builder.finish_output(self.protocol_parameters.token_supply())?;
 
         self.automatically_transitioned.insert(ChainId::from(foundry_id), None);
 
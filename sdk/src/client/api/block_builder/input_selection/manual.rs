@@ -24,7 +24,8 @@ use crate::{
 
 impl<'a> ClientBlockBuilder<'a> {
     /// If custom inputs are provided we check if they are unspent, get the balance and search the Ed25519 addresses for
-    /// them with the provided input_range so we can later sign them.
+    /// them with the provided input_range so we can later sign them. Inputs with known signing data are used as they
+    /// are, without requesting them from a node.
     /// Forwards to [try_select_inputs()] with all inputs in `mandatory_inputs`, so they will all be included in the
     /// transaction, even if not required for the provided outputs.
     pub(crate) async fn get_custom_inputs(
@@ -39,6 +40,15 @@ impl<'a> ClientBlockBuilder<'a> {
 
         if let Some(inputs) = &self.inputs {
             for input in inputs {
+                if let Some(input_signing_data) = self
+                    .known_inputs
+                    .iter()
+                    .find(|input_signing_data| input_signing_data.output_id() == input.output_id())
+                {
+                    inputs_data.push(input_signing_data.clone());
+                    continue;
+                }
+
                 let output_with_meta = self.client.get_output(input.output_id()).await?;
 
                 if !output_with_meta.metadata().is_spent() {
@@ -126,7 +136,7 @@ impl<'a> ClientBlockBuilder<'a> {
         .timestamp(current_time);
 
         if let Some(address) = self.custom_remainder_address {
-            input_selection = input_selection.remainder_address(address);
+            input_selection = input_selection.remainder_address(address.into_inner());
         }
 
         if let Some(burn) = burn {
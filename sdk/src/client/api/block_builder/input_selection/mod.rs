@@ -12,6 +12,6 @@ mod utxo_chains;
 
 pub(crate) use self::core::is_alias_transition;
 pub use self::{
-    core::{Burn, BurnDto, Error, InputSelection, Requirement, Selected},
+    core::{Burn, BurnDto, Error, InputSelection, InputSelectionStrategy, Requirement, Selected, SelectionPreview},
     helpers::minimum_storage_deposit_basic_output,
 };
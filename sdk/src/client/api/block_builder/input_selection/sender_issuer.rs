@@ -150,7 +150,7 @@ impl<'a> ClientBlockBuilder<'a> {
                     // Check if already found or request new.
                     if !utxo_chain_inputs.iter().chain(required_inputs.iter()).any(|input| {
                         if let Output::Nft(nft_output) = &input.output {
-                            nft_id == nft_output.nft_id()
+                            *nft_id == nft_output.nft_id_non_null(input.output_id())
                         } else {
                             false
                         }
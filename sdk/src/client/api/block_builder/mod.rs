@@ -3,6 +3,7 @@
 
 pub mod input_selection;
 pub mod pow;
+pub mod tagged_data;
 pub mod transaction;
 
 use std::ops::Range;
@@ -13,11 +14,13 @@ use serde::{Deserialize, Serialize};
 pub use self::transaction::verify_semantic;
 use crate::{
     client::{
-        api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
-        Result,
+        api::block_builder::input_selection::Burn,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{types::InputSigningData, SecretManager},
+        Client, Error, Result,
     },
     types::block::{
-        address::{Address, Bech32Address, Ed25519Address},
+        address::{Bech32Address, Ed25519Address},
         input::{dto::UtxoInputDto, UtxoInput, INPUT_COUNT_MAX},
         output::{
             dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OUTPUT_COUNT_RANGE,
@@ -37,9 +40,10 @@ pub struct ClientBlockBuilder<'a> {
     account_index: u32,
     initial_address_index: u32,
     inputs: Option<Vec<UtxoInput>>,
+    known_inputs: Vec<InputSigningData>,
     input_range: Range<u32>,
     outputs: Vec<Output>,
-    custom_remainder_address: Option<Address>,
+    custom_remainder_address: Option<Bech32Address>,
     tag: Option<Vec<u8>>,
     data: Option<Vec<u8>>,
     parents: Option<Parents>,
@@ -99,6 +103,7 @@ impl<'a> ClientBlockBuilder<'a> {
             account_index: 0,
             initial_address_index: 0,
             inputs: None,
+            known_inputs: Vec::new(),
             input_range: 0..100,
             outputs: Vec::new(),
             custom_remainder_address: None,
@@ -150,6 +155,15 @@ impl<'a> ClientBlockBuilder<'a> {
         Ok(self)
     }
 
+    /// Set a custom input with its output and signing chain, so it doesn't need to be requested from a node. Together
+    /// with a client that has the protocol parameters set and the time check disabled,
+    /// [`ClientBlockBuilder::dry_run()`] then works offline.
+    pub fn with_input_signing_data(mut self, input_signing_data: InputSigningData) -> Result<Self> {
+        self = self.with_input(UtxoInput::from(*input_signing_data.output_id()))?;
+        self.known_inputs.push(input_signing_data);
+        Ok(self)
+    }
+
     /// Set a custom range in which to search for addresses for custom provided inputs. Default: 0..100
     pub fn with_input_range(mut self, range: Range<u32>) -> Self {
         self.input_range = range;
@@ -202,9 +216,10 @@ impl<'a> ClientBlockBuilder<'a> {
         Ok(self)
     }
 
-    /// Set a custom remainder address
+    /// Set a custom remainder address, which also receives the remainder of native tokens and of burned outputs. Its
+    /// HRP is validated against the network when the transaction is prepared.
     pub fn with_custom_remainder_address(mut self, address: &str) -> Result<Self> {
-        let address = Address::try_from_bech32(address)?;
+        let address = Bech32Address::try_from_str(address)?;
         self.custom_remainder_address.replace(address);
         Ok(self)
     }
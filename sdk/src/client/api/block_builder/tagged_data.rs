@@ -0,0 +1,239 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Splitting and reassembling of data that doesn't fit into a single tagged data payload.
+
+use std::collections::BTreeMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{Packable, PackableExt};
+
+use super::ClientBlockBuilder;
+use crate::{
+    client::{Client, Error},
+    types::block::{
+        parent::Parents,
+        payload::{Payload, TaggedDataPayload},
+        Block, BlockId,
+    },
+};
+
+// The length of a block with a tagged data payload without the tag and the data, with the maximum number of parents:
+// protocol version, parents, payload length, payload kind, tag length prefix, data length prefix and nonce
+const TAGGED_DATA_BLOCK_OVERHEAD: usize =
+    1 + 1 + *Parents::COUNT_RANGE.end() as usize * BlockId::LENGTH + 4 + 4 + 1 + 4 + 8;
+
+/// Header prepended to the data of every chunk created by [`ClientBlockBuilder::with_tagged_data_chunked`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Packable)]
+pub struct TaggedDataChunkHeader {
+    /// The index of the chunk in the stream.
+    pub index: u32,
+    /// The total number of chunks in the stream.
+    pub total: u32,
+    /// The identifier shared by all chunks of the stream.
+    pub stream_id: [u8; 16],
+}
+
+impl TaggedDataChunkHeader {
+    /// The length of a packed [`TaggedDataChunkHeader`].
+    pub const LENGTH: usize = 24;
+
+    /// Splits the data of a chunk into its header and the actual chunk data.
+    fn split(data: &[u8]) -> crate::client::Result<(Self, &[u8])> {
+        if data.len() < Self::LENGTH {
+            return Err(Error::TaggedData("missing chunk header".to_string()));
+        }
+        let (header, chunk) = data.split_at(Self::LENGTH);
+        let header =
+            Self::unpack_unverified(header).map_err(|_| Error::TaggedData("invalid chunk header".to_string()))?;
+
+        Ok((header, chunk))
+    }
+}
+
+impl<'a> ClientBlockBuilder<'a> {
+    /// Splits `data` into chunks of at most `chunk_size` bytes and returns one block builder per chunk, each with a
+    /// tagged data payload with the given `tag`. A [`TaggedDataChunkHeader`] is prepended to the data of every chunk,
+    /// so the original data can be reassembled with [`Client::reassemble_tagged_data`]. The chunk size is limited so
+    /// that a block with the tag, the header and the chunk still fits into [`Block::LENGTH_MAX`] with the maximum
+    /// number of parents.
+    /// Only the client and the parents are kept from this builder.
+    pub fn with_tagged_data_chunked(
+        self,
+        tag: Vec<u8>,
+        data: Vec<u8>,
+        chunk_size: usize,
+    ) -> crate::client::Result<Vec<Self>> {
+        if tag.len() > *TaggedDataPayload::TAG_LENGTH_RANGE.end() as usize {
+            return Err(Error::TaggedData(format!("tag length {} is too long", tag.len())));
+        }
+        let max_chunk_size = (*TaggedDataPayload::DATA_LENGTH_RANGE.end() as usize)
+            .min(Block::LENGTH_MAX - TAGGED_DATA_BLOCK_OVERHEAD - tag.len())
+            - TaggedDataChunkHeader::LENGTH;
+        if chunk_size == 0 || chunk_size > max_chunk_size {
+            return Err(Error::TaggedData(format!(
+                "invalid chunk size {chunk_size}, must be between 1 and {max_chunk_size}"
+            )));
+        }
+
+        // Empty data still results in a single chunk that only contains the header.
+        let chunks = if data.is_empty() {
+            vec![&data[..]]
+        } else {
+            data.chunks(chunk_size).collect::<Vec<_>>()
+        };
+        let total = u32::try_from(chunks.len()).map_err(|_| Error::TaggedData("too many chunks".to_string()))?;
+        let stream_id = Blake2b256::new().chain_update(&tag).chain_update(&data).finalize()[..16]
+            .try_into()
+            .unwrap();
+
+        Ok(chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut chunk_data = TaggedDataChunkHeader {
+                    index: index as u32,
+                    total,
+                    stream_id,
+                }
+                .pack_to_vec();
+                chunk_data.extend_from_slice(chunk);
+
+                let mut builder = Self::new(self.client).with_tag(tag.clone()).with_data(chunk_data);
+                builder.parents.clone_from(&self.parents);
+                builder
+            })
+            .collect())
+    }
+}
+
+impl Client {
+    /// Reassembles the data that was split with [`ClientBlockBuilder::with_tagged_data_chunked`] from the blocks of
+    /// all of its chunks. The blocks can be provided in any order, but every chunk only once.
+    pub fn reassemble_tagged_data(blocks: &[Block]) -> crate::client::Result<Vec<u8>> {
+        let mut stream = None;
+        let mut chunks = BTreeMap::new();
+
+        for block in blocks {
+            let Some(Payload::TaggedData(payload)) = block.payload() else {
+                return Err(Error::TaggedData(
+                    "block doesn't contain a tagged data payload".to_string(),
+                ));
+            };
+            let (header, chunk) = TaggedDataChunkHeader::split(payload.data())?;
+
+            if *stream.get_or_insert((header.stream_id, header.total)) != (header.stream_id, header.total) {
+                return Err(Error::TaggedData("chunks belong to different streams".to_string()));
+            }
+            if header.index >= header.total {
+                return Err(Error::TaggedData(format!(
+                    "chunk index {} out of bounds, total is {}",
+                    header.index, header.total
+                )));
+            }
+
+            if chunks.insert(header.index, chunk).is_some() {
+                return Err(Error::TaggedData(format!("duplicate chunk index {}", header.index)));
+            }
+        }
+
+        let (_, total) = stream.ok_or_else(|| Error::TaggedData("no chunks provided".to_string()))?;
+        if chunks.len() != total as usize {
+            return Err(Error::TaggedData(format!(
+                "missing chunks, got {} of {total}",
+                chunks.len()
+            )));
+        }
+
+        Ok(chunks.into_values().flatten().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{rand::block::rand_block_ids, BlockBuilder};
+
+    fn to_blocks(builders: Vec<ClientBlockBuilder<'_>>) -> Vec<Block> {
+        let parents = Parents::from_vec(rand_block_ids(2)).unwrap();
+
+        builders
+            .into_iter()
+            .map(|builder| {
+                let payload = TaggedDataPayload::new(builder.tag.unwrap(), builder.data.unwrap()).unwrap();
+                BlockBuilder::new(parents.clone())
+                    .with_payload(Payload::from(payload))
+                    .finish()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn chunk_and_reassemble() {
+        let client = Client::builder().finish().await.unwrap();
+        let data = (0..10_000u32).map(|i| i as u8).collect::<Vec<_>>();
+
+        let builders = client
+            .block()
+            .with_tagged_data_chunked(b"chunks".to_vec(), data.clone(), 3_000)
+            .unwrap();
+        assert_eq!(builders.len(), 4);
+
+        let mut blocks = to_blocks(builders);
+        blocks.reverse();
+        assert_eq!(Client::reassemble_tagged_data(&blocks).unwrap(), data);
+
+        let mut duplicated = blocks.clone();
+        duplicated.push(blocks[0].clone());
+        assert!(matches!(
+            Client::reassemble_tagged_data(&duplicated),
+            Err(Error::TaggedData(e)) if e == "duplicate chunk index 3"
+        ));
+
+        blocks.remove(1);
+        assert!(matches!(
+            Client::reassemble_tagged_data(&blocks),
+            Err(Error::TaggedData(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn max_chunk_size_fits_into_block() {
+        let client = Client::builder().finish().await.unwrap();
+        let tag = vec![1; *TaggedDataPayload::TAG_LENGTH_RANGE.end() as usize];
+        let max_chunk_size = Block::LENGTH_MAX - TAGGED_DATA_BLOCK_OVERHEAD - tag.len() - TaggedDataChunkHeader::LENGTH;
+
+        assert!(
+            client
+                .block()
+                .with_tagged_data_chunked(tag.clone(), vec![1], max_chunk_size + 1)
+                .is_err()
+        );
+
+        // A full chunk with the longest tag and the maximum number of parents is a block of the maximum length
+        let builders = client
+            .block()
+            .with_tagged_data_chunked(tag.clone(), vec![2; max_chunk_size], max_chunk_size)
+            .unwrap();
+        let payload = TaggedDataPayload::new(tag, builders[0].data.clone().unwrap()).unwrap();
+        let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(8)).unwrap())
+            .with_payload(Payload::from(payload))
+            .finish()
+            .unwrap();
+        assert_eq!(block.packed_len(), Block::LENGTH_MAX);
+    }
+
+    #[tokio::test]
+    async fn invalid_chunk_size() {
+        let client = Client::builder().finish().await.unwrap();
+
+        assert!(client.block().with_tagged_data_chunked(Vec::new(), vec![1], 0).is_err());
+        assert!(
+            client
+                .block()
+                .with_tagged_data_chunked(Vec::new(), vec![1], Block::LENGTH_MAX)
+                .is_err()
+        );
+    }
+}
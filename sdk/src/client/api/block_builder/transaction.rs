@@ -43,6 +43,11 @@ impl<'a> ClientBlockBuilder<'a> {
             output.verify_storage_deposit(*protocol_parameters.rent_structure(), token_supply)?;
         }
 
+        // Remainders must be sent to an address of the network
+        if let Some(address) = &self.custom_remainder_address {
+            self.client.bech32_hrp_matches(address.hrp()).await?;
+        }
+
         // Input selection
         let selected_transaction_data = if self.inputs.is_some() {
             self.get_custom_inputs(&protocol_parameters, self.burn.clone()).await?
@@ -84,11 +89,36 @@ impl<'a> ClientBlockBuilder<'a> {
             essence,
             inputs_data: selected_transaction_data.inputs,
             remainder: selected_transaction_data.remainder,
+            bech32_hrp: Some(self.client.get_bech32_hrp().await?),
         })
     }
 
+    /// Runs the same preparation, signing and validation as [`ClientBlockBuilder::finish()`] for a transaction and
+    /// returns the resulting transaction payload, without doing PoW or posting a block. No node is needed if the inputs
+    /// are set with [`ClientBlockBuilder::with_input_signing_data()`] and the time check is disabled.
+    pub async fn dry_run(self) -> Result<TransactionPayload> {
+        if self.outputs.is_empty() {
+            return Err(Error::MissingParameter("output"));
+        }
+        if self.secret_manager.is_none() && self.inputs.is_none() {
+            return Err(Error::MissingParameter("seed"));
+        }
+
+        let prepared_transaction_data = self.prepare_transaction().await?;
+        self.sign_transaction_payload(prepared_transaction_data).await
+    }
+
     /// Sign the transaction
     pub async fn sign_transaction(&self, prepared_transaction_data: PreparedTransactionData) -> Result<Payload> {
+        Ok(Payload::from(
+            self.sign_transaction_payload(prepared_transaction_data).await?,
+        ))
+    }
+
+    async fn sign_transaction_payload(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload> {
         log::debug!("[sign_transaction] {:?}", prepared_transaction_data);
         let secret_manager = self.secret_manager.ok_or(Error::MissingParameter("secret manager"))?;
         let current_time = self.client.get_time_checked().await?;
@@ -107,7 +137,7 @@ impl<'a> ClientBlockBuilder<'a> {
             return Err(Error::TransactionSemantic(conflict));
         }
 
-        Ok(Payload::from(tx_payload))
+        Ok(tx_payload)
     }
 }
 
@@ -1,58 +1,73 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{
-        api::{input_selection::Error as InputSelectionError, ClientBlockBuilder},
-        constants::{
-            DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT, FIVE_MINUTES_IN_SECONDS,
+        api::{
+            input_selection::{minimum_storage_deposit_basic_output, InputSelectionStrategy},
+            BlockStatus, ClientBlockBuilder, InclusionLookup, OutputKindFilter, PostBlockOptions, RetryEvent,
         },
         error::{Error, Result},
-        node_api::indexer::query_parameters::QueryParameter,
+        node_api::{error::Error as NodeApiError, indexer::query_parameters::QueryParameter},
         Client,
     },
     types::{
         api::core::response::LedgerInclusionState,
         block::{
-            address::Bech32Address,
+            address::{AliasAddress, Bech32Address, ToBech32Ext},
             input::{Input, UtxoInput, INPUT_COUNT_MAX},
-            output::{OutputId, OutputWithMetadata},
+            output::{Output, OutputId, OutputWithMetadata, Rent},
             parent::Parents,
             payload::{
                 transaction::{TransactionEssence, TransactionId},
                 Payload,
             },
-            Block, BlockId,
+            semantic::ConflictReason,
+            Block, BlockId, ConvertTo,
         },
     },
     utils::unix_timestamp_now,
 };
 
+/// Constraints on the inputs found by [`Client::find_inputs_with_constraints()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FindInputsConstraints {
+    /// The max number of inputs from the same address. The outputs with the highest amounts of an address are used.
+    pub max_inputs_per_address: Option<usize>,
+    /// Groups of addresses whose outputs must not be spent together, inputs are only taken from one of the groups.
+    /// Addresses that aren't part of a group can be spent together with any group. The groups are tried in order.
+    pub disjoint_address_groups: Vec<Vec<Bech32Address>>,
+}
+
+/// An input found by [`Client::find_inputs_with_constraints()`] and the address owning it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FoundInput {
+    /// The input.
+    pub input: UtxoInput,
+    /// The address that owns the input.
+    pub address: Bech32Address,
+}
+
+/// The outputs found by [`Client::find_outputs_partial()`] and the addresses whose outputs couldn't be requested.
+#[derive(Debug)]
+pub struct PartialOutputs {
+    /// The outputs of the output ids and of the addresses that didn't fail.
+    pub outputs: Vec<OutputWithMetadata>,
+    /// The addresses whose outputs couldn't be requested, with the error.
+    pub failed_addresses: Vec<(Bech32Address, Error)>,
+}
+
 impl Client {
     /// Get the inputs of a transaction for the given transaction id.
     pub async fn inputs_from_transaction_id(&self, transaction_id: &TransactionId) -> Result<Vec<OutputWithMetadata>> {
         let block = self.get_included_block(transaction_id).await?;
-
-        let inputs = match block.payload() {
-            Some(Payload::Transaction(t)) => match t.essence() {
-                TransactionEssence::Regular(e) => e.inputs(),
-            },
-            _ => {
-                unreachable!()
-            }
-        };
-
-        let input_ids = inputs
-            .iter()
-            .filter_map(|i| match i {
-                Input::Utxo(input) => Some(*input.output_id()),
-                Input::Treasury(_) => None,
-            })
-            .collect::<Vec<_>>();
+        let input_ids = transaction_input_ids(&block)?;
 
         self.get_outputs(&input_ids).await
     }
@@ -72,17 +87,20 @@ impl Client {
     /// Retries (promotes or reattaches) a block for provided block id. Block should only be
     /// retried only if they are valid and haven't been confirmed for a while.
     pub async fn retry(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
-        // Get the metadata to check if it needs to promote or reattach
-        let block_metadata = self.get_block_metadata(block_id).await?;
-        if block_metadata.should_promote.unwrap_or(false) {
-            self.promote_unchecked(block_id).await
-        } else if block_metadata.should_reattach.unwrap_or(false) {
-            self.reattach_unchecked(block_id).await
-        } else {
-            Err(Error::NoNeedPromoteOrReattach(block_id.to_string()))
+        // Get the status to check if it needs to promote or reattach
+        match self.block_status(block_id).await? {
+            BlockStatus::NeedsPromote => self.promote_unchecked(block_id).await,
+            BlockStatus::NeedsReattach => self.reattach_unchecked(block_id).await,
+            _ => Err(Error::NoNeedPromoteOrReattach(block_id.to_string())),
         }
     }
 
+    /// Returns the status of a block, derived from its inclusion state and whether it should be promoted or
+    /// reattached.
+    pub async fn block_status(&self, block_id: &BlockId) -> Result<BlockStatus> {
+        Ok(BlockStatus::from(&self.get_block_metadata(block_id).await?))
+    }
+
     /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
     /// milestone). Default interval is 5 seconds and max attempts is 40. Returns the included block at first position
     /// and additional reattached blocks
@@ -93,193 +111,396 @@ impl Client {
         max_attempts: Option<u64>,
     ) -> Result<Vec<(BlockId, Block)>> {
         log::debug!("[retry_until_included]");
-        // Attachments of the Block to check inclusion state
-        let mut block_ids = vec![*block_id];
         // Reattached Blocks that get returned
         let mut blocks_with_id = Vec::new();
-        for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-            #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(
-                (interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL) * 1000)
-                    .try_into()
-                    .unwrap(),
-            )
-            .await;
-
-            #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_secs(
-                interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL),
-            ))
-            .await;
-
-            // Check inclusion state for each attachment
-            let block_ids_len = block_ids.len();
-            let mut conflicting = false;
-            for (index, id) in block_ids.clone().iter().enumerate() {
-                let block_metadata = self.get_block_metadata(id).await?;
-                if let Some(inclusion_state) = block_metadata.ledger_inclusion_state {
-                    match inclusion_state {
-                        LedgerInclusionState::Included | LedgerInclusionState::NoTransaction => {
-                            // if original block, request it so we can return it on first position
-                            if id == block_id {
-                                let mut included_and_reattached_blocks =
-                                    vec![(*block_id, self.get_block(block_id).await?)];
-                                included_and_reattached_blocks.extend(blocks_with_id);
-                                return Ok(included_and_reattached_blocks);
-                            } else {
-                                // Move included block to first position
-                                blocks_with_id.rotate_left(index);
-                                return Ok(blocks_with_id);
-                            }
-                        }
-                        // only set it as conflicting here and don't return, because another reattached block could
-                        // have the included transaction
-                        LedgerInclusionState::Conflicting => conflicting = true,
-                    };
-                }
-                // Only reattach or promote latest attachment of the block
-                if index == block_ids_len - 1 {
-                    if block_metadata.should_promote.unwrap_or(false) {
-                        // Safe to unwrap since we iterate over it
-                        self.promote_unchecked(block_ids.last().unwrap()).await?;
-                    } else if block_metadata.should_reattach.unwrap_or(false) {
-                        // Safe to unwrap since we iterate over it
-                        let reattached = self.reattach_unchecked(block_ids.last().unwrap()).await?;
-                        block_ids.push(reattached.0);
-                        blocks_with_id.push(reattached);
-                    }
+        let events = self.retry_until_included_stream(block_id, interval, max_attempts);
+        futures::pin_mut!(events);
+
+        while let Some(event) = events.try_next().await? {
+            match event {
+                RetryEvent::Reattached(reattached_id, reattached) => blocks_with_id.push((reattached_id, reattached)),
+                RetryEvent::Included(included_id, included) => {
+                    // Move included block to first position
+                    blocks_with_id.retain(|(id, _)| *id != included_id);
+                    blocks_with_id.insert(0, (included_id, included));
+                    return Ok(blocks_with_id);
                 }
+                RetryEvent::Promoted(_) | RetryEvent::Conflicting(_) => {}
+                RetryEvent::AttemptsExhausted => break,
             }
-            // After we checked all our reattached blocks, check if the transaction got reattached in another block
-            // and confirmed
-            if conflicting {
-                let block = self.get_block(block_id).await?;
-                if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
-                    let included_block = self.get_included_block(&transaction_payload.id()).await?;
-                    let mut included_and_reattached_blocks = vec![(included_block.id(), included_block)];
-                    included_and_reattached_blocks.extend(blocks_with_id);
-                    return Ok(included_and_reattached_blocks);
+        }
+
+        Err(Error::TangleInclusion(block_id.to_string()))
+    }
+
+    /// Retries (promotes or reattaches) a transaction until it's included, like [`Client::retry_until_included()`] with
+    /// the newest of the `known_block_ids` the transaction was posted in that the node knows. Returns the block that
+    /// included the transaction, right away if it's included already, and fails with [`Error::TransactionConflicting`]
+    /// as soon as the transaction is conflicting, or with [`Error::NoKnownAttachment`] if the node knows none of the
+    /// `known_block_ids`.
+    pub async fn retry_transaction_until_included(
+        &self,
+        transaction_id: &TransactionId,
+        known_block_ids: &[BlockId],
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> Result<(BlockId, Block)> {
+        log::debug!("[retry_transaction_until_included]");
+        if let Some(included_block) = self.find_included_attachment(transaction_id).await? {
+            return Ok((included_block.id(), included_block));
+        }
+
+        let mut latest_attachment = None;
+        for block_id in known_block_ids {
+            let metadata = match self.get_block_metadata(block_id).await {
+                Ok(metadata) => metadata,
+                Err(Error::Node(NodeApiError::NotFound(_))) => continue,
+                Err(e) => return Err(e),
+            };
+            // The transaction isn't included in another attachment, so it can't be included anymore
+            if metadata.ledger_inclusion_state == Some(LedgerInclusionState::Conflicting) {
+                return Err(Error::TransactionConflicting {
+                    transaction_id: *transaction_id,
+                    reason: conflict_reason(metadata.conflict_reason)?,
+                });
+            }
+            latest_attachment = Some(*block_id);
+        }
+        let Some(latest_attachment) = latest_attachment else {
+            return Err(Error::NoKnownAttachment(*transaction_id));
+        };
+
+        let events = self.retry_until_included_stream(&latest_attachment, interval, max_attempts);
+        futures::pin_mut!(events);
+
+        while let Some(event) = events.try_next().await? {
+            match event {
+                RetryEvent::Included(included_id, included) => return Ok((included_id, included)),
+                RetryEvent::Conflicting(conflicting_id) => {
+                    // Another attachment could have been included in the meantime
+                    if let Some(included_block) = self.find_included_attachment(transaction_id).await? {
+                        return Ok((included_block.id(), included_block));
+                    }
+                    let metadata = self.get_block_metadata(&conflicting_id).await?;
+                    return Err(Error::TransactionConflicting {
+                        transaction_id: *transaction_id,
+                        reason: conflict_reason(metadata.conflict_reason)?,
+                    });
                 }
+                RetryEvent::Promoted(_) | RetryEvent::Reattached(..) => {}
+                RetryEvent::AttemptsExhausted => break,
             }
         }
-        Err(Error::TangleInclusion(block_id.to_string()))
+
+        Err(Error::TangleInclusion(transaction_id.to_string()))
     }
 
     /// Function to find inputs from addresses for a provided amount (useful for offline signing), ignoring outputs with
-    /// additional unlock conditions
-    pub async fn find_inputs(&self, addresses: Vec<Bech32Address>, amount: u64) -> Result<Vec<UtxoInput>> {
+    /// additional unlock conditions. The inputs are chosen with the given strategy,
+    /// [`InputSelectionStrategy::LargestFirst`] needs the least inputs. Inputs are added or exactly matching ones are
+    /// searched if the remainder would be below the minimum storage deposit of a basic output. All addresses are
+    /// validated before any request is sent.
+    pub async fn find_inputs(
+        &self,
+        addresses: impl IntoIterator<Item = impl ConvertTo<Bech32Address>> + Send,
+        amount: u64,
+        strategy: InputSelectionStrategy,
+    ) -> Result<Vec<UtxoInput>> {
+        Ok(self
+            .find_inputs_with_constraints(addresses, amount, strategy, FindInputsConstraints::default())
+            .await?
+            .into_iter()
+            .map(|found| found.input)
+            .collect())
+    }
+
+    /// Like [`Client::find_inputs()`], but the inputs also satisfy the constraints and are returned with the address
+    /// owning them, so the selection can be audited. Fails with [`Error::DisjointAddressGroups`] or
+    /// [`Error::MaxInputsPerAddress`] if the amount could only be reached by violating a constraint.
+    pub async fn find_inputs_with_constraints(
+        &self,
+        addresses: impl IntoIterator<Item = impl ConvertTo<Bech32Address>> + Send,
+        amount: u64,
+        strategy: InputSelectionStrategy,
+        constraints: FindInputsConstraints,
+    ) -> Result<Vec<FoundInput>> {
+        let addresses = addresses
+            .into_iter()
+            .map(ConvertTo::convert)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
         // Get outputs from node and select inputs
         let available_outputs = futures::stream::iter(addresses)
-            .then(|address| {
-                self.basic_output_ids([
-                    QueryParameter::Address(address),
-                    QueryParameter::HasExpiration(false),
-                    QueryParameter::HasTimelock(false),
-                    QueryParameter::HasStorageDepositReturn(false),
-                ])
-            })
-            .and_then(|res| async {
-                let items = res.items;
-                self.get_outputs(&items).await
+            .then(|address| async move {
+                let output_ids = self
+                    .basic_output_ids([
+                        QueryParameter::Address(address),
+                        QueryParameter::HasExpiration(false),
+                        QueryParameter::HasTimelock(false),
+                        QueryParameter::HasStorageDepositReturn(false),
+                    ])
+                    .await?
+                    .items;
+                Ok::<_, Error>((address, self.get_outputs(&output_ids).await?))
             })
             .try_collect::<Vec<_>>()
             .await?;
 
-        let mut basic_outputs = available_outputs
+        let candidates = available_outputs
             .into_iter()
-            .flatten()
-            .map(|output_with_meta| {
-                Ok((
-                    UtxoInput::new(
+            .flat_map(|(address, outputs)| outputs.into_iter().map(move |output| (address, output)))
+            .map(|(address, output_with_meta)| {
+                Ok(Candidate {
+                    input: UtxoInput::new(
                         output_with_meta.metadata().transaction_id().to_owned(),
                         output_with_meta.metadata().output_index(),
                     )?,
-                    output_with_meta.output().amount(),
-                ))
+                    address,
+                    amount: output_with_meta.output().amount(),
+                })
             })
             .collect::<Result<Vec<_>>>()?;
-        basic_outputs.sort_by(|l, r| r.1.cmp(&l.1));
-
-        let mut total_already_spent = 0;
-        let mut selected_inputs = Vec::new();
-        for (_offset, output_wrapper) in basic_outputs
-            .into_iter()
-            // Max inputs is 128
-            .take(INPUT_COUNT_MAX.into())
-            .enumerate()
-        {
-            // Break if we have enough funds and don't create dust for the remainder
-            if total_already_spent == amount || total_already_spent >= amount {
-                break;
-            }
-            selected_inputs.push(output_wrapper.0);
-            total_already_spent += output_wrapper.1;
-        }
 
-        if total_already_spent < amount {
-            return Err(InputSelectionError::InsufficientAmount {
-                found: total_already_spent,
-                required: amount,
-            })?;
-        }
+        // A remainder below the minimum storage deposit couldn't be sent back
+        let minimum_remainder = minimum_storage_deposit_basic_output(
+            &self.get_rent_structure().await?,
+            &None,
+            self.get_token_supply().await?,
+        )?;
 
-        Ok(selected_inputs)
+        select_inputs_with_constraints(&candidates, amount, strategy, minimum_remainder, &constraints)
     }
 
     /// Find all outputs based on the requests criteria. This method will try to query multiple nodes if
-    /// the request amount exceeds individual node limit.
+    /// the request amount exceeds individual node limit. All addresses are validated before any request is sent.
     pub async fn find_outputs(
         &self,
         output_ids: &[OutputId],
-        addresses: &[Bech32Address],
+        addresses: impl IntoIterator<Item = impl ConvertTo<Bech32Address>> + Send,
     ) -> Result<Vec<OutputWithMetadata>> {
+        let addresses = addresses
+            .into_iter()
+            .map(ConvertTo::convert)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
         let mut output_responses = self.get_outputs(output_ids).await?;
 
-        // Use `get_address()` API to get the address outputs first,
-        // then collect the `UtxoInput` in the HashSet.
         for address in addresses {
-            // Get output ids of outputs that can be controlled by this address without further unlock constraints
-            let output_ids_response = self
-                .basic_output_ids([
-                    QueryParameter::Address(*address),
-                    QueryParameter::HasExpiration(false),
-                    QueryParameter::HasTimelock(false),
-                    QueryParameter::HasStorageDepositReturn(false),
-                ])
-                .await?;
-
-            output_responses.extend(self.get_outputs(&output_ids_response.items).await?);
+            output_responses.extend(self.unconstrained_basic_outputs(address).await?);
+        }
+
+        Ok(output_responses)
+    }
+
+    /// Like [`Client::find_outputs()`], but the requests of the address outputs that fail don't abort the search.
+    /// Their addresses are returned with the error next to the outputs that were found, the given output ids still
+    /// need to be found.
+    pub async fn find_outputs_partial(
+        &self,
+        output_ids: &[OutputId],
+        addresses: impl IntoIterator<Item = impl ConvertTo<Bech32Address>> + Send,
+    ) -> Result<PartialOutputs> {
+        let addresses = addresses
+            .into_iter()
+            .map(ConvertTo::convert)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+        let mut partial_outputs = PartialOutputs {
+            outputs: self.get_outputs(output_ids).await?,
+            failed_addresses: Vec::new(),
+        };
+
+        for address in addresses {
+            match self.unconstrained_basic_outputs(address).await {
+                Ok(outputs) => partial_outputs.outputs.extend(outputs),
+                Err(e) => {
+                    log::debug!("[find_outputs_partial] requesting the outputs of {address} failed: {e}");
+                    partial_outputs.failed_addresses.push((address, e));
+                }
+            }
         }
 
-        Ok(output_responses.clone())
+        Ok(partial_outputs)
+    }
+
+    // The basic outputs that can be controlled by the address without further unlock constraints
+    async fn unconstrained_basic_outputs(&self, address: Bech32Address) -> Result<Vec<OutputWithMetadata>> {
+        let output_ids_response = self
+            .basic_output_ids([
+                QueryParameter::Address(address),
+                QueryParameter::HasExpiration(false),
+                QueryParameter::HasTimelock(false),
+                QueryParameter::HasStorageDepositReturn(false),
+            ])
+            .await?;
+
+        self.get_outputs(&output_ids_response.items).await
+    }
+
+    /// Returns the sorted and deduplicated ids of all outputs of the given kinds that can be unlocked by the address,
+    /// including outputs where it's the expiration or storage deposit return address. Foundries are the ones of the
+    /// address itself if it's an alias address, and of the aliases it controls.
+    pub async fn output_ids_for_address(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        kinds: OutputKindFilter,
+    ) -> Result<Vec<OutputId>> {
+        let bech32_address = address.convert()?;
+        let unlockable_by = [
+            QueryParameter::Address(bech32_address),
+            QueryParameter::StorageDepositReturnAddress(bech32_address),
+            QueryParameter::ExpirationReturnAddress(bech32_address),
+        ];
+        let controlled_by = [
+            QueryParameter::Governor(bech32_address),
+            QueryParameter::StateController(bech32_address),
+        ];
+
+        let (basic_responses, nft_responses, alias_responses) = futures::future::try_join3(
+            futures::future::try_join_all(
+                unlockable_by
+                    .iter()
+                    .filter(|_| kinds.basic)
+                    .map(|parameter| self.basic_output_ids([parameter.clone()])),
+            ),
+            futures::future::try_join_all(
+                unlockable_by
+                    .iter()
+                    .filter(|_| kinds.nft)
+                    .map(|parameter| self.nft_output_ids([parameter.clone()])),
+            ),
+            futures::future::try_join_all(
+                controlled_by
+                    .iter()
+                    .filter(|_| kinds.alias || kinds.foundry)
+                    .map(|parameter| self.alias_output_ids([parameter.clone()])),
+            ),
+        )
+        .await?;
+
+        let alias_output_ids = alias_responses
+            .into_iter()
+            .flat_map(|response| response.items)
+            .collect::<HashSet<_>>();
+        let mut output_ids = basic_responses
+            .into_iter()
+            .chain(nft_responses)
+            .flat_map(|response| response.items)
+            .collect::<BTreeSet<_>>();
+
+        if kinds.foundry {
+            let bech32_hrp = bech32_address.hrp();
+            let alias_ids = self
+                .try_get_outputs(&alias_output_ids.iter().copied().collect::<Vec<_>>())
+                .await?
+                .0
+                .into_iter()
+                .filter_map(|output_with_meta| match output_with_meta.output() {
+                    Output::Alias(alias_output) => {
+                        Some(alias_output.alias_id_non_null(output_with_meta.metadata().output_id()))
+                    }
+                    _ => None,
+                });
+            let mut alias_addresses = alias_ids
+                .map(|alias_id| AliasAddress::from(alias_id).to_bech32(*bech32_hrp))
+                .collect::<HashSet<_>>();
+            if bech32_address.inner().is_alias() {
+                alias_addresses.insert(bech32_address);
+            }
+
+            let foundry_responses = futures::future::try_join_all(
+                alias_addresses
+                    .into_iter()
+                    .map(|alias_address| self.foundry_output_ids([QueryParameter::AliasAddress(alias_address)])),
+            )
+            .await?;
+            output_ids.extend(foundry_responses.into_iter().flat_map(|response| response.items));
+        }
+
+        if kinds.alias {
+            output_ids.extend(alias_output_ids);
+        }
+
+        Ok(output_ids.into_iter().collect())
     }
 
     /// Reattaches blocks for provided block id. Blocks can be reattached only if they are valid and haven't been
     /// confirmed for a while.
     pub async fn reattach(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
         let metadata = self.get_block_metadata(block_id).await?;
+        if metadata.ledger_inclusion_state == Some(LedgerInclusionState::Conflicting) {
+            let block = self.get_block(block_id).await?;
+            let Some(Payload::Transaction(transaction_payload)) = block.payload() else {
+                return Err(Error::UnexpectedPayloadKind);
+            };
+            return Err(Error::TransactionConflicting {
+                transaction_id: transaction_payload.id(),
+                reason: conflict_reason(metadata.conflict_reason)?,
+            });
+        }
         if metadata.should_reattach.unwrap_or(false) {
-            self.reattach_unchecked(block_id).await
+            // The metadata is known already, so the block is reattached without requesting it again like
+            // `reattach_unchecked()` does
+            let block = self.get_block(block_id).await?;
+            self.build_and_post_block(block.payload().cloned(), PostBlockOptions::default())
+                .await
         } else {
             Err(Error::NoNeedPromoteOrReattach(block_id.to_string()))
         }
     }
 
-    /// Reattach a block without checking if it should be reattached
+    /// Reattach a block without checking if it should be reattached, a warning is logged if its transaction is
+    /// conflicting.
     pub async fn reattach_unchecked(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
         // Get the Block object by the BlockID.
         let block = self.get_block(block_id).await?;
-        let reattach_block = self.finish_block_builder(None, block.payload().cloned()).await?;
 
-        // Post the modified
-        let block_id = self.post_block_raw(&reattach_block).await?;
-        // Get block if we use remote Pow, because the node will change parents and nonce
-        let block = if self.get_local_pow().await {
-            reattach_block
-        } else {
-            self.get_block(&block_id).await?
-        };
-        Ok((block_id, block))
+        if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+            if let Ok(metadata) = self.get_block_metadata(block_id).await {
+                if metadata.ledger_inclusion_state == Some(LedgerInclusionState::Conflicting) {
+                    log::warn!(
+                        "reattaching block {block_id} with conflicting transaction {}: {:?}",
+                        transaction_payload.id(),
+                        conflict_reason(metadata.conflict_reason).unwrap_or_default()
+                    );
+                }
+            }
+        }
+
+        self.build_and_post_block(block.payload().cloned(), PostBlockOptions::default())
+            .await
+    }
+
+    /// Reattaches a block like [`Client::reattach_unchecked()`], but first checks that the inputs of its transaction
+    /// are still unspent, as the reattached block would stay conflicting otherwise. Fails with
+    /// [`Error::InputsAlreadySpent`] if another transaction spent some of them, inputs that the node doesn't know are
+    /// counted as spent, as they were pruned after being spent, and with [`Error::NoNeedPromoteOrReattach`] if the
+    /// transaction itself is included already.
+    pub async fn reattach_with_unspent_inputs(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
+        let block = self.get_block(block_id).await?;
+
+        if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+            let transaction_id = transaction_payload.id();
+            let input_ids = transaction_input_ids(&block)?;
+            // Not found inputs were spent and pruned already
+            let (inputs, mut spent_input_ids) = self.try_get_outputs(&input_ids).await?;
+
+            for input in inputs {
+                let metadata = input.metadata();
+                if metadata.is_spent() {
+                    // Spent by the transaction itself, so it's included already
+                    if metadata.transaction_id_spent() == Some(&transaction_id) {
+                        return Err(Error::NoNeedPromoteOrReattach(block_id.to_string()));
+                    }
+                    spent_input_ids.push(*metadata.output_id());
+                }
+            }
+
+            if !spent_input_ids.is_empty() {
+                return Err(Error::InputsAlreadySpent(spent_input_ids));
+            }
+        }
+
+        self.build_and_post_block(block.payload().cloned(), PostBlockOptions::default())
+            .await
     }
 
     /// Promotes a block. The method should validate if a promotion is necessary through get_block. If not, the
@@ -296,42 +517,555 @@ impl Client {
     /// Promote a block without checking if it should be promoted
     pub async fn promote_unchecked(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
         // Create a new block (zero value block) for which one tip would be the actual block.
-        let mut tips = self.get_tips().await?;
-        if let Some(tip) = tips.first_mut() {
-            *tip = *block_id;
+        self.build_and_post_block(
+            None,
+            PostBlockOptions {
+                required_parent: Some(*block_id),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Promote a block without checking if it should be promoted, with the given parents next to it instead of the
+    /// latest tips. Fails if there are more parents than a block can have. With remote PoW the node can still change
+    /// the parents. If the node rejects the parents as too old, the error is returned instead of retrying with tips.
+    pub async fn promote_with_parents(
+        &self,
+        block_id: &BlockId,
+        extra_parents: Vec<BlockId>,
+    ) -> Result<(BlockId, Block)> {
+        self.build_and_post_block(
+            None,
+            PostBlockOptions {
+                required_parent: Some(*block_id),
+                extra_parents: Some(extra_parents),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Builds a block with the payload on top of the latest tips, does PoW depending on the client configuration and
+    /// posts it. If the node rejects the block because its parents are too old, which can happen during a long local
+    /// PoW, the block is rebuilt with new tips and posted again, up to `options.max_retries` times. Blocks with
+    /// `options.extra_parents` aren't retried, as that would replace the given parents.
+    pub async fn build_and_post_block(
+        &self,
+        payload: Option<Payload>,
+        options: PostBlockOptions,
+    ) -> Result<(BlockId, Block)> {
+        let mut retries = 0;
+
+        loop {
+            let parents = match (&options.extra_parents, options.required_parent) {
+                (Some(extra_parents), required_parent) => {
+                    let mut parents = extra_parents.clone();
+                    parents.extend(required_parent);
+                    Some(Parents::from_vec(parents)?)
+                }
+                (None, Some(required_parent)) => {
+                    let mut tips = self.get_tips().await?;
+                    match tips.first_mut() {
+                        Some(tip) => *tip = required_parent,
+                        None => tips.push(required_parent),
+                    }
+                    Some(Parents::from_vec(tips)?)
+                }
+                (None, None) => None,
+            };
+            let block = self.finish_block_builder(parents, payload.clone()).await?;
+
+            match self.post_block_raw(&block).await {
+                Ok(block_id) => {
+                    // Get block if we use remote PoW, because the node will change parents and nonce.
+                    let block = if self.get_local_pow().await {
+                        block
+                    } else {
+                        self.get_block(&block_id).await?
+                    };
+                    return Ok((block_id, block));
+                }
+                Err(error)
+                    if options.extra_parents.is_none()
+                        && retries < options.max_retries
+                        && is_parents_too_old_error(&error) =>
+                {
+                    retries += 1;
+                    log::debug!(
+                        "[build_and_post_block] parents too old, retrying with new tips {retries}/{}",
+                        options.max_retries
+                    );
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Looks up the inclusion state of a transaction. The `known_block_ids` are the blocks the transaction was posted
+    /// in, they're checked if the transaction isn't included yet.
+    pub async fn lookup_transaction(
+        &self,
+        transaction_id: &TransactionId,
+        known_block_ids: &[BlockId],
+    ) -> Result<InclusionLookup> {
+        match self.get_included_block(transaction_id).await {
+            Ok(block) => return Ok(InclusionLookup::Included(block)),
+            Err(Error::Node(NodeApiError::NotFound(_))) => {}
+            Err(e) => return Err(e),
+        }
+
+        if known_block_ids.is_empty() {
+            return Ok(InclusionLookup::Unknown);
         }
 
-        let promote_block = self.finish_block_builder(Some(Parents::from_vec(tips)?), None).await?;
+        let mut pending_block_ids = Vec::new();
+
+        for block_id in known_block_ids {
+            let metadata = match self.get_block_metadata(block_id).await {
+                Ok(metadata) => metadata,
+                Err(Error::Node(NodeApiError::NotFound(_))) => continue,
+                Err(e) => return Err(e),
+            };
+
+            match metadata.ledger_inclusion_state {
+                // The transaction got included in the meantime
+                Some(LedgerInclusionState::Included) => {
+                    return Ok(InclusionLookup::Included(self.get_block(block_id).await?));
+                }
+                Some(LedgerInclusionState::Conflicting) => {
+                    return Ok(InclusionLookup::Conflicting(conflict_reason(metadata.conflict_reason)?));
+                }
+                Some(LedgerInclusionState::NoTransaction) => return Err(Error::UnexpectedPayloadKind),
+                None => pending_block_ids.push(*block_id),
+            }
+        }
 
-        let block_id = self.post_block_raw(&promote_block).await?;
-        // Get block if we use remote Pow, because the node will change parents and nonce.
-        let block = if self.get_local_pow().await {
-            promote_block
+        if pending_block_ids.is_empty() {
+            Ok(InclusionLookup::Pruned)
         } else {
-            self.get_block(&block_id).await?
-        };
-        Ok((block_id, block))
+            Ok(InclusionLookup::Pending {
+                known_block_ids: pending_block_ids,
+            })
+        }
     }
 
-    /// Returns the local time checked with the timestamp of the latest milestone, if the difference is larger than 5
-    /// minutes an error is returned to prevent locking outputs by accident for a wrong time.
+    /// Returns the sum of the minimum storage deposits required by the given outputs, independent of the amounts they
+    /// already hold.
+    pub async fn estimate_transaction_fee(&self, outputs: &[Output]) -> Result<u64> {
+        let rent_structure = self.get_rent_structure().await?;
+
+        Ok(outputs.iter().map(|output| output.rent_cost(&rent_structure)).sum())
+    }
+
+    /// Returns the local time checked with the timestamp of the latest milestone, if the difference is larger than the
+    /// time sync tolerance (5 minutes by default) an error is returned to prevent locking outputs by accident for a
+    /// wrong time. The check is skipped if it was disabled with
+    /// [`ClientBuilder::with_time_check_disabled()`](crate::client::ClientBuilder::with_time_check_disabled).
+    /// The requested milestone timestamp is reused for the time set with
+    /// [`ClientBuilder::with_time_check_cache_ttl()`](crate::client::ClientBuilder::with_time_check_cache_ttl).
     pub async fn get_time_checked(&self) -> Result<u32> {
         let current_time = unix_timestamp_now().as_secs() as u32;
 
-        let network_info = self.get_network_info().await?;
+        if *self.time_check_disabled.read().await {
+            return Ok(current_time);
+        }
 
-        if let Some(latest_ms_timestamp) = network_info.latest_milestone_timestamp {
-            // Check the local time is in the range of +-5 minutes of the node to prevent locking funds by accident
-            if !(latest_ms_timestamp - FIVE_MINUTES_IN_SECONDS..latest_ms_timestamp + FIVE_MINUTES_IN_SECONDS)
-                .contains(&current_time)
-            {
-                return Err(Error::TimeNotSynced {
-                    current_time,
-                    milestone_timestamp: latest_ms_timestamp,
-                });
-            }
+        if let Some(latest_ms_timestamp) = self.get_latest_milestone_timestamp_cached().await? {
+            check_time_synced(current_time, latest_ms_timestamp, self.get_time_sync_tolerance().await)?;
         }
 
         Ok(current_time)
     }
+
+    /// Returns the timestamp of the latest milestone, requested from the node if the cached one is older than the time
+    /// check cache ttl. If the node info can't be requested, the one of the network info is used.
+    async fn get_latest_milestone_timestamp_cached(&self) -> Result<Option<u32>> {
+        let ttl = *self.time_check_cache_ttl.read().await;
+        let mut cache = self.latest_milestone_timestamp_cache.write().await;
+
+        if let Some((requested_at, latest_ms_timestamp)) = *cache {
+            if requested_at.elapsed() < ttl {
+                return Ok(latest_ms_timestamp);
+            }
+        }
+
+        let latest_ms_timestamp = match self.get_info().await {
+            Ok(info) => info.node_info.status.latest_milestone.timestamp,
+            Err(error) => {
+                log::debug!("[get_time_checked] requesting the node info failed: {error}");
+                self.get_network_info().await?.latest_milestone_timestamp
+            }
+        };
+        *cache = Some((instant::Instant::now(), latest_ms_timestamp));
+
+        Ok(latest_ms_timestamp)
+    }
+}
+
+/// An output that can be used as input by [`Client::find_inputs_with_constraints()`].
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    input: UtxoInput,
+    address: Bech32Address,
+    amount: u64,
+}
+
+/// Selects the inputs for the amount from the candidates with the strategy, so that the constraints are satisfied.
+fn select_inputs_with_constraints(
+    candidates: &[Candidate],
+    amount: u64,
+    strategy: InputSelectionStrategy,
+    minimum_remainder: u64,
+    constraints: &FindInputsConstraints,
+) -> Result<Vec<FoundInput>> {
+    let mut address_groups = HashMap::new();
+    for (group, addresses) in constraints.disjoint_address_groups.iter().enumerate() {
+        for address in addresses {
+            if let Some(other_group) = address_groups.insert(address, group) {
+                if other_group != group {
+                    return Err(Error::AddressInMultipleGroups {
+                        address: address.to_string(),
+                        groups: vec![other_group, group],
+                    });
+                }
+            }
+        }
+    }
+
+    // Selects from the candidates with the given indexes and returns the indexes of the selected candidates
+    let select = |indexes: &[usize]| -> Result<Vec<usize>> {
+        let amounts = indexes
+            .iter()
+            .map(|index| candidates[*index].amount)
+            .collect::<Vec<_>>();
+        Ok(strategy
+            .select_amounts_without_dust(&amounts, amount, minimum_remainder, INPUT_COUNT_MAX.into())?
+            .into_iter()
+            .map(|selected| indexes[selected])
+            .collect())
+    };
+
+    let all = (0..candidates.len()).collect::<Vec<_>>();
+    let capped = match constraints.max_inputs_per_address {
+        Some(max_inputs) => {
+            let mut by_address = HashMap::<_, Vec<usize>>::new();
+            for index in &all {
+                by_address.entry(candidates[*index].address).or_default().push(*index);
+            }
+            let mut capped = by_address
+                .into_values()
+                .flat_map(|mut indexes| {
+                    indexes.sort_by(|left, right| candidates[*right].amount.cmp(&candidates[*left].amount));
+                    indexes.truncate(max_inputs);
+                    indexes
+                })
+                .collect::<Vec<_>>();
+            // Keep the order of the outputs for the strategies
+            capped.sort_unstable();
+            capped
+        }
+        None => all.clone(),
+    };
+
+    // Selects from the candidates with the given indexes, only from one of the disjoint address groups
+    let select_from_one_group = |indexes: &[usize]| -> Result<Vec<usize>> {
+        if constraints.disjoint_address_groups.is_empty() {
+            return select(indexes);
+        }
+        for group in 0..constraints.disjoint_address_groups.len() {
+            let group_indexes = indexes
+                .iter()
+                .copied()
+                .filter(|index| {
+                    address_groups
+                        .get(&candidates[*index].address)
+                        .map_or(true, |address_group| *address_group == group)
+                })
+                .collect::<Vec<_>>();
+            if let Ok(selected) = select(&group_indexes) {
+                return Ok(selected);
+            }
+        }
+        // Name the groups the amount would have to be taken from
+        let selected = select(indexes)?;
+        let groups = selected
+            .iter()
+            .filter_map(|index| address_groups.get(&candidates[*index].address).copied())
+            .collect::<BTreeSet<_>>();
+        Err(Error::DisjointAddressGroups(groups.into_iter().collect()))
+    };
+
+    let selected = match (select_from_one_group(&capped), constraints.max_inputs_per_address) {
+        (Ok(selected), _) => selected,
+        // Only report the cap if the amount could be reached without it
+        (Err(_), Some(max_inputs)) if capped.len() < all.len() && select_from_one_group(&all).is_ok() => {
+            return Err(Error::MaxInputsPerAddress(max_inputs));
+        }
+        (Err(e), _) => return Err(e),
+    };
+
+    Ok(selected
+        .into_iter()
+        .map(|index| FoundInput {
+            input: candidates[index].input,
+            address: candidates[index].address,
+        })
+        .collect())
+}
+
+/// Checks the local time is in the range of +-tolerance of the milestone timestamp to prevent locking funds by
+/// accident.
+fn check_time_synced(current_time: u32, milestone_timestamp: u32, tolerance: u32) -> Result<()> {
+    // Saturating, because the milestone timestamp can be close to 0 on freshly started private tangles
+    if !(milestone_timestamp.saturating_sub(tolerance)..=milestone_timestamp.saturating_add(tolerance))
+        .contains(&current_time)
+    {
+        return Err(Error::TimeNotSynced {
+            current_time,
+            milestone_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the output ids of the inputs of the transaction payload contained in the block.
+fn transaction_input_ids(block: &Block) -> Result<Vec<OutputId>> {
+    let inputs = match block.payload() {
+        Some(Payload::Transaction(t)) => match t.essence() {
+            TransactionEssence::Regular(e) => e.inputs(),
+        },
+        _ => return Err(Error::UnexpectedPayloadKind),
+    };
+
+    inputs
+        .iter()
+        .map(|i| match i {
+            Input::Utxo(input) => Ok(*input.output_id()),
+            Input::Treasury(_) => Err(Error::TreasuryInputUnsupported),
+        })
+        .collect()
+}
+
+/// Returns whether the node rejected a block because its parents are too old or below max depth.
+fn is_parents_too_old_error(error: &Error) -> bool {
+    match error {
+        Error::Node(NodeApiError::ResponseError { code: 400, text, .. }) => {
+            let text = text.to_lowercase();
+            text.contains("too old") || text.contains("below max depth")
+        }
+        _ => false,
+    }
+}
+
+// Decodes the conflict reason of block metadata
+fn conflict_reason(conflict_reason: Option<u8>) -> Result<ConflictReason> {
+    Ok(conflict_reason
+        .map(ConflictReason::try_from)
+        .transpose()
+        .map_err(|_| Error::UnexpectedApiResponse)?
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Hrp,
+        rand::{
+            address::rand_ed25519_address, block::rand_block_ids, payload::rand_tagged_data_payload,
+            transaction::rand_transaction_id,
+        },
+        BlockBuilder,
+    };
+
+    fn address() -> Bech32Address {
+        Bech32Address::new(Hrp::from_str_unchecked("rms"), rand_ed25519_address())
+    }
+
+    fn candidates(outputs: &[(Bech32Address, u64)]) -> Vec<Candidate> {
+        outputs
+            .iter()
+            .map(|(address, amount)| Candidate {
+                input: UtxoInput::new(rand_transaction_id(), 0).unwrap(),
+                address: *address,
+                amount: amount * 1_000_000,
+            })
+            .collect()
+    }
+
+    fn select(
+        candidates: &[Candidate],
+        amount: u64,
+        constraints: &FindInputsConstraints,
+    ) -> Result<HashMap<Bech32Address, usize>> {
+        let found = select_inputs_with_constraints(
+            candidates,
+            amount * 1_000_000,
+            InputSelectionStrategy::LargestFirst,
+            0,
+            constraints,
+        )?;
+
+        let mut inputs_per_address = HashMap::new();
+        for FoundInput { input, address } in found {
+            assert!(candidates.iter().any(|c| c.input == input && c.address == address));
+            *inputs_per_address.entry(address).or_default() += 1;
+        }
+        Ok(inputs_per_address)
+    }
+
+    #[test]
+    fn transaction_input_ids_no_transaction_payload() {
+        let parents = Parents::from_vec(rand_block_ids(2)).unwrap();
+
+        let block = BlockBuilder::new(parents.clone())
+            .with_payload(Payload::from(rand_tagged_data_payload()))
+            .finish()
+            .unwrap();
+        assert!(matches!(
+            transaction_input_ids(&block),
+            Err(Error::UnexpectedPayloadKind)
+        ));
+
+        let block = BlockBuilder::new(parents).finish().unwrap();
+        assert!(matches!(
+            transaction_input_ids(&block),
+            Err(Error::UnexpectedPayloadKind)
+        ));
+    }
+
+    #[test]
+    fn time_synced_near_zero() {
+        assert!(check_time_synced(0, 10, 300).is_ok());
+        assert!(check_time_synced(310, 10, 300).is_ok());
+        assert!(matches!(
+            check_time_synced(311, 10, 300),
+            Err(Error::TimeNotSynced {
+                current_time: 311,
+                milestone_timestamp: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn time_synced_near_max() {
+        assert!(check_time_synced(u32::MAX, u32::MAX - 10, 300).is_ok());
+        assert!(check_time_synced(u32::MAX - 310, u32::MAX - 10, 300).is_ok());
+        assert!(check_time_synced(u32::MAX - 311, u32::MAX - 10, 300).is_err());
+    }
+
+    #[test]
+    fn time_synced_max_tolerance() {
+        assert!(check_time_synced(0, u32::MAX, u32::MAX).is_ok());
+        assert!(check_time_synced(u32::MAX, 0, u32::MAX).is_ok());
+    }
+
+    #[test]
+    fn find_inputs_disjoint_address_groups() {
+        let (group_0, group_1, group_2, ungrouped) = (address(), address(), address(), address());
+        let candidates = candidates(&[
+            (group_0, 1),
+            (group_0, 2),
+            (group_1, 3),
+            (group_1, 3),
+            (group_2, 10),
+            (ungrouped, 1),
+        ]);
+        let constraints = FindInputsConstraints {
+            max_inputs_per_address: None,
+            disjoint_address_groups: vec![vec![group_0], vec![group_1], vec![group_2]],
+        };
+
+        // The first group that has enough together with the ungrouped address is used
+        assert_eq!(
+            select(&candidates, 4, &constraints).unwrap(),
+            HashMap::from([(group_0, 2), (ungrouped, 1)])
+        );
+        assert_eq!(
+            select(&candidates, 7, &constraints).unwrap(),
+            HashMap::from([(group_1, 2), (ungrouped, 1)])
+        );
+        assert_eq!(
+            select(&candidates, 8, &constraints).unwrap(),
+            HashMap::from([(group_2, 1)])
+        );
+        // Without the groups, the largest outputs of the groups 1 and 2 would be used
+        assert!(matches!(
+            select(&candidates, 15, &constraints),
+            Err(Error::DisjointAddressGroups(groups)) if groups == [1, 2]
+        ));
+        assert_eq!(
+            select(&candidates, 15, &FindInputsConstraints::default()).unwrap(),
+            HashMap::from([(group_1, 2), (group_2, 1)])
+        );
+        // Not enough funds at all
+        assert!(matches!(
+            select(&candidates, 21, &constraints),
+            Err(Error::InputSelection(_))
+        ));
+    }
+
+    #[test]
+    fn find_inputs_max_inputs_per_address() {
+        let (group_0, group_1, group_2, ungrouped) = (address(), address(), address(), address());
+        let candidates = candidates(&[
+            (group_0, 1),
+            (group_0, 2),
+            (group_1, 3),
+            (group_1, 3),
+            (group_2, 10),
+            (ungrouped, 1),
+        ]);
+        let constraints = FindInputsConstraints {
+            max_inputs_per_address: Some(1),
+            disjoint_address_groups: Vec::new(),
+        };
+
+        let inputs_per_address = select(&candidates, 16, &constraints).unwrap();
+        assert_eq!(
+            inputs_per_address,
+            HashMap::from([(group_0, 1), (group_1, 1), (group_2, 1), (ungrouped, 1)])
+        );
+        // The 20 could only be reached with all outputs
+        assert!(matches!(
+            select(&candidates, 17, &constraints),
+            Err(Error::MaxInputsPerAddress(1))
+        ));
+
+        // With the groups, only one output of group 1 can be used with the ungrouped address
+        let candidates = &candidates[..4]
+            .iter()
+            .chain(&candidates[5..])
+            .copied()
+            .collect::<Vec<_>>();
+        let constraints = FindInputsConstraints {
+            max_inputs_per_address: Some(1),
+            disjoint_address_groups: vec![vec![group_0], vec![group_1]],
+        };
+        assert_eq!(
+            select(candidates, 4, &constraints).unwrap(),
+            HashMap::from([(group_1, 1), (ungrouped, 1)])
+        );
+        // Reachable with both outputs of group 1, instead of spending group 0 and 1 together
+        assert!(matches!(
+            select(candidates, 6, &constraints),
+            Err(Error::MaxInputsPerAddress(1))
+        ));
+    }
+
+    #[test]
+    fn find_inputs_address_in_multiple_groups() {
+        let (address_0, address_1) = (address(), address());
+        let constraints = FindInputsConstraints {
+            max_inputs_per_address: None,
+            disjoint_address_groups: vec![vec![address_0], vec![address_1, address_0]],
+        };
+
+        assert!(matches!(
+            select(&candidates(&[(address_0, 1)]), 1, &constraints),
+            Err(Error::AddressInMultipleGroups { groups, .. }) if groups == [0, 1]
+        ));
+    }
 }
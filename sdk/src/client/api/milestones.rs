@@ -0,0 +1,94 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::{
+    client::{
+        constants::DEFAULT_FOLLOW_MILESTONES_INTERVAL,
+        error::{Error, Result},
+        node_api::error::Error as NodeApiError,
+        Client,
+    },
+    types::block::{payload::milestone::MilestoneIndex, Block, BlockId},
+};
+
+impl Client {
+    /// Returns a stream of the ids of all blocks referenced by the milestone with the given index, in no particular
+    /// order. The past cone of the milestone is traversed with the block metadata until blocks referenced by an older
+    /// milestone are reached.
+    pub fn block_ids_referenced_by_milestone(&self, index: u32) -> impl Stream<Item = Result<BlockId>> + Send + '_ {
+        // The queue is seeded with the parents of the milestone when the stream is first polled
+        let state: (Option<VecDeque<BlockId>>, HashSet<BlockId>) = (None, HashSet::new());
+
+        stream::try_unfold(state, move |(queue, mut visited)| async move {
+            let mut queue = match queue {
+                Some(queue) => queue,
+                None => {
+                    let milestone = self.get_milestone_by_index(index).await?;
+                    let parents = milestone.essence().parents().iter().copied().collect::<VecDeque<_>>();
+                    visited.extend(parents.iter().copied());
+                    parents
+                }
+            };
+
+            while let Some(block_id) = queue.pop_front() {
+                let metadata = match self.get_block_metadata(&block_id).await {
+                    Ok(metadata) => metadata,
+                    // Pruned blocks were referenced by an older milestone
+                    Err(Error::Node(NodeApiError::NotFound(_))) => continue,
+                    Err(e) => return Err(e),
+                };
+
+                if metadata.referenced_by_milestone_index != Some(index) {
+                    continue;
+                }
+
+                for parent in &metadata.parents {
+                    let parent = BlockId::from_str(parent)?;
+                    if visited.insert(parent) {
+                        queue.push_back(parent);
+                    }
+                }
+
+                return Ok(Some((block_id, (Some(queue), visited))));
+            }
+
+            Ok(None)
+        })
+    }
+
+    /// Returns a stream of all blocks referenced by the milestone with the given index, in no particular order. Blocks
+    /// are requested one after the other while the stream is consumed.
+    pub fn blocks_referenced_by_milestone(&self, index: u32) -> impl Stream<Item = Result<Block>> + Send + '_ {
+        self.block_ids_referenced_by_milestone(index)
+            .and_then(move |block_id| async move { self.get_block(&block_id).await })
+    }
+
+    /// Returns a stream of the confirmed milestones, starting with the given index, and the ids of the blocks they
+    /// referenced. The node is polled for new milestones and they're yielded in order without skipping any, also if
+    /// multiple milestones got confirmed between two polls.
+    pub fn follow_milestones(
+        &self,
+        from_index: u32,
+    ) -> impl Stream<Item = Result<(MilestoneIndex, Vec<BlockId>)>> + Send + '_ {
+        stream::try_unfold(from_index, move |index| async move {
+            while self.get_info().await?.node_info.status.confirmed_milestone.index < index {
+                #[cfg(target_family = "wasm")]
+                gloo_timers::future::TimeoutFuture::new((DEFAULT_FOLLOW_MILESTONES_INTERVAL * 1000) as u32).await;
+
+                #[cfg(not(target_family = "wasm"))]
+                tokio::time::sleep(std::time::Duration::from_secs(DEFAULT_FOLLOW_MILESTONES_INTERVAL)).await;
+            }
+
+            let block_ids = self.block_ids_referenced_by_milestone(index).try_collect().await?;
+
+            Ok(Some(((MilestoneIndex::new(index), block_ids), index + 1)))
+        })
+    }
+}
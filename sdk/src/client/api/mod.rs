@@ -7,8 +7,18 @@ mod address;
 mod block_builder;
 mod consolidation;
 mod high_level;
+mod milestones;
+mod retry;
 mod types;
+mod validation;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{
+    address::*,
+    block_builder::*,
+    high_level::{FindInputsConstraints, FoundInput, PartialOutputs},
+    retry::*,
+    types::*,
+    validation::{validate_block, ValidationError},
+};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
@@ -0,0 +1,165 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashSet, VecDeque};
+
+use futures::{stream, Stream};
+
+use crate::{
+    client::{
+        api::BlockStatus,
+        constants::{DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT},
+        error::{Error, Result},
+        node_api::error::Error as NodeApiError,
+        Client,
+    },
+    types::block::{
+        payload::{transaction::TransactionId, Payload},
+        Block, BlockId,
+    },
+};
+
+/// An event of [`Client::retry_until_included_stream()`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RetryEvent {
+    /// The latest attachment with the given id got promoted.
+    Promoted(BlockId),
+    /// The block got reattached in a new block.
+    Reattached(BlockId, Block),
+    /// The attachment with the given id is conflicting, another attachment could still get included.
+    Conflicting(BlockId),
+    /// The block or one of its reattachments got included. Terminates the stream.
+    Included(BlockId, Block),
+    /// The block didn't get included within the max attempts. Terminates the stream.
+    AttemptsExhausted,
+}
+
+impl RetryEvent {
+    fn is_final(&self) -> bool {
+        matches!(self, Self::Included(..) | Self::AttemptsExhausted)
+    }
+}
+
+struct RetryState {
+    // Attachments of the block to check the inclusion state of
+    block_ids: Vec<BlockId>,
+    // Attachments that are known to be conflicting, their state can't change anymore
+    conflicting_block_ids: HashSet<BlockId>,
+    attempt: u64,
+    events: VecDeque<RetryEvent>,
+    done: bool,
+}
+
+impl Client {
+    /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
+    /// milestone) and yields every promotion, reattachment and conflict as it happens. Default interval is 1 second and
+    /// max attempts is 40. The stream ends with either [`RetryEvent::Included`] or [`RetryEvent::AttemptsExhausted`].
+    pub fn retry_until_included_stream<'a>(
+        &'a self,
+        block_id: &BlockId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> impl Stream<Item = Result<RetryEvent>> + Send + 'a {
+        let interval = interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL);
+        let max_attempts = max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT);
+        let state = RetryState {
+            block_ids: vec![*block_id],
+            conflicting_block_ids: HashSet::new(),
+            attempt: 0,
+            events: VecDeque::new(),
+            done: false,
+        };
+
+        stream::try_unfold(state, move |mut state| async move {
+            loop {
+                if let Some(event) = state.events.pop_front() {
+                    state.done = event.is_final();
+                    return Ok(Some((event, state)));
+                }
+                if state.done {
+                    return Ok(None);
+                }
+                if state.attempt == max_attempts {
+                    state.events.push_back(RetryEvent::AttemptsExhausted);
+                    continue;
+                }
+                state.attempt += 1;
+
+                #[cfg(target_family = "wasm")]
+                gloo_timers::future::TimeoutFuture::new((interval * 1000).try_into().unwrap()).await;
+
+                #[cfg(not(target_family = "wasm"))]
+                tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+
+                self.retry_attempt(&mut state).await?;
+            }
+        })
+    }
+
+    /// Returns the block in which the transaction got included, regardless of which of its attachments got referenced,
+    /// or `None` if the transaction isn't included yet.
+    pub async fn find_included_attachment(&self, transaction_id: &TransactionId) -> Result<Option<Block>> {
+        match self.get_included_block(transaction_id).await {
+            Ok(block) => Ok(Some(block)),
+            Err(Error::Node(NodeApiError::NotFound(_))) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Checks the inclusion state of all attachments once and promotes or reattaches the latest one if needed.
+    async fn retry_attempt(&self, state: &mut RetryState) -> Result<()> {
+        let block_ids_len = state.block_ids.len();
+        let mut conflicting = false;
+
+        for (index, id) in state.block_ids.clone().iter().enumerate() {
+            if state.conflicting_block_ids.contains(id) {
+                conflicting = true;
+                continue;
+            }
+            // Only reattach or promote latest attachment of the block
+            let latest = index == block_ids_len - 1;
+            match self.block_status(id).await? {
+                BlockStatus::Included => {
+                    state
+                        .events
+                        .push_back(RetryEvent::Included(*id, self.get_block(id).await?));
+                    return Ok(());
+                }
+                // Only remember it as conflicting here, because another reattached block could have the included
+                // transaction
+                BlockStatus::Conflicting => {
+                    conflicting = true;
+                    state.conflicting_block_ids.insert(*id);
+                    state.events.push_back(RetryEvent::Conflicting(*id));
+                }
+                BlockStatus::NeedsPromote if latest => {
+                    self.promote_unchecked(id).await?;
+                    state.events.push_back(RetryEvent::Promoted(*id));
+                }
+                BlockStatus::NeedsReattach if latest => {
+                    let (reattached_id, reattached) = self.reattach_unchecked(id).await?;
+                    state.block_ids.push(reattached_id);
+                    state
+                        .events
+                        .push_back(RetryEvent::Reattached(reattached_id, reattached));
+                }
+                _ => {}
+            }
+        }
+
+        // After we checked all our reattached blocks, check if the transaction got reattached in another block and
+        // confirmed
+        if conflicting {
+            let block = self.get_block(&state.block_ids[0]).await?;
+            if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+                if let Some(included_block) = self.find_included_attachment(&transaction_payload.id()).await? {
+                    state
+                        .events
+                        .push_back(RetryEvent::Included(included_block.id(), included_block));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
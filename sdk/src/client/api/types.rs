@@ -5,19 +5,26 @@ use crypto::keys::slip10::Chain;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::secret::types::{InputSigningData, InputSigningDataDto},
-    types::block::{
-        address::{dto::AddressDto, Address},
-        output::{dto::OutputDto, Output},
-        payload::{
-            transaction::{
-                dto::{TransactionEssenceDto, TransactionPayloadDto},
-                TransactionEssence,
+    client::{
+        constants::DEFAULT_POST_BLOCK_MAX_RETRIES,
+        secret::types::{InputSigningData, InputSigningDataDto},
+    },
+    types::{
+        api::core::response::{BlockMetadataResponse, LedgerInclusionState},
+        block::{
+            address::{dto::AddressDto, Address, Hrp},
+            output::{dto::OutputDto, Output},
+            payload::{
+                transaction::{
+                    dto::{TransactionEssenceDto, TransactionPayloadDto},
+                    TransactionEssence,
+                },
+                TransactionPayload,
             },
-            TransactionPayload,
+            protocol::ProtocolParameters,
+            semantic::ConflictReason,
+            Block, BlockDto, BlockId, Error,
         },
-        protocol::ProtocolParameters,
-        Error,
     },
 };
 
@@ -31,6 +38,8 @@ pub struct PreparedTransactionData {
     pub inputs_data: Vec<InputSigningData>,
     /// Optional remainder output information
     pub remainder: Option<RemainderData>,
+    /// The bech32 HRP of the network, so the addresses can be displayed when signing without network access
+    pub bech32_hrp: Option<Hrp>,
 }
 
 /// PreparedTransactionData Dto
@@ -43,6 +52,9 @@ pub struct PreparedTransactionDataDto {
     pub inputs_data: Vec<InputSigningDataDto>,
     /// Optional remainder output information
     pub remainder: Option<RemainderDataDto>,
+    /// The bech32 HRP of the network
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bech32_hrp: Option<Hrp>,
 }
 
 impl From<&PreparedTransactionData> for PreparedTransactionDataDto {
@@ -51,6 +63,7 @@ impl From<&PreparedTransactionData> for PreparedTransactionDataDto {
             essence: TransactionEssenceDto::from(&value.essence),
             inputs_data: value.inputs_data.iter().map(InputSigningDataDto::from).collect(),
             remainder: value.remainder.as_ref().map(RemainderDataDto::from),
+            bech32_hrp: value.bech32_hrp,
         }
     }
 }
@@ -77,6 +90,7 @@ impl PreparedTransactionData {
                 ),
                 None => None,
             },
+            bech32_hrp: value.bech32_hrp,
         })
     }
 
@@ -97,6 +111,7 @@ impl PreparedTransactionData {
                 ),
                 None => None,
             },
+            bech32_hrp: value.bech32_hrp,
         })
     }
 }
@@ -212,3 +227,155 @@ impl From<&RemainderData> for RemainderDataDto {
         }
     }
 }
+
+/// The result of looking up the inclusion of a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InclusionLookup {
+    /// The transaction got included in the ledger with this block.
+    Included(Block),
+    /// The transaction isn't included yet, but these blocks containing it are known to the node.
+    Pending { known_block_ids: Vec<BlockId> },
+    /// The transaction is conflicting.
+    Conflicting(ConflictReason),
+    /// No block containing the transaction was provided.
+    Unknown,
+    /// None of the blocks containing the transaction are known to the node, they might have been pruned.
+    Pruned,
+}
+
+/// Dto for [`InclusionLookup`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum InclusionLookupDto {
+    /// The transaction got included in the ledger with this block.
+    Included { block: BlockDto },
+    /// The transaction isn't included yet, but these blocks containing it are known to the node.
+    #[serde(rename_all = "camelCase")]
+    Pending { known_block_ids: Vec<BlockId> },
+    /// The transaction is conflicting.
+    Conflicting { reason: ConflictReason },
+    /// No block containing the transaction was provided.
+    Unknown,
+    /// None of the blocks containing the transaction are known to the node, they might have been pruned.
+    Pruned,
+}
+
+impl From<&InclusionLookup> for InclusionLookupDto {
+    fn from(value: &InclusionLookup) -> Self {
+        match value {
+            InclusionLookup::Included(block) => Self::Included {
+                block: BlockDto::from(block),
+            },
+            InclusionLookup::Pending { known_block_ids } => Self::Pending {
+                known_block_ids: known_block_ids.clone(),
+            },
+            InclusionLookup::Conflicting(reason) => Self::Conflicting { reason: *reason },
+            InclusionLookup::Unknown => Self::Unknown,
+            InclusionLookup::Pruned => Self::Pruned,
+        }
+    }
+}
+
+/// The status of a block derived from its metadata, returned by
+/// [`Client::block_status()`](crate::client::Client::block_status).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockStatus {
+    /// The block got referenced by a milestone and its transaction, if any, is included in the ledger.
+    Included,
+    /// The block isn't referenced by a milestone yet and doesn't need to be retried.
+    Pending,
+    /// The block got referenced by a milestone, but its transaction is conflicting.
+    Conflicting,
+    /// The block should be promoted.
+    NeedsPromote,
+    /// The block should be reattached.
+    NeedsReattach,
+}
+
+impl From<&BlockMetadataResponse> for BlockStatus {
+    fn from(metadata: &BlockMetadataResponse) -> Self {
+        match metadata.ledger_inclusion_state {
+            Some(LedgerInclusionState::Included | LedgerInclusionState::NoTransaction) => Self::Included,
+            Some(LedgerInclusionState::Conflicting) => Self::Conflicting,
+            None if metadata.should_promote.unwrap_or(false) => Self::NeedsPromote,
+            None if metadata.should_reattach.unwrap_or(false) => Self::NeedsReattach,
+            None => Self::Pending,
+        }
+    }
+}
+
+/// The kinds of outputs to query with
+/// [`Client::output_ids_for_address()`](crate::client::Client::output_ids_for_address). All kinds are queried by
+/// default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputKindFilter {
+    /// Whether to query basic outputs.
+    pub basic: bool,
+    /// Whether to query alias outputs.
+    pub alias: bool,
+    /// Whether to query foundry outputs.
+    pub foundry: bool,
+    /// Whether to query nft outputs.
+    pub nft: bool,
+}
+
+impl OutputKindFilter {
+    /// Creates a filter that includes all output kinds.
+    pub fn all() -> Self {
+        Self {
+            basic: true,
+            alias: true,
+            foundry: true,
+            nft: true,
+        }
+    }
+
+    /// Creates a filter that includes no output kind.
+    pub fn none() -> Self {
+        Self {
+            basic: false,
+            alias: false,
+            foundry: false,
+            nft: false,
+        }
+    }
+}
+
+impl Default for OutputKindFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Options for [`Client::build_and_post_block()`](crate::client::Client::build_and_post_block).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostBlockOptions {
+    /// A block that is always used as one of the parents, the other parents are filled up with tips.
+    #[serde(default)]
+    pub required_parent: Option<BlockId>,
+    /// Parents that are used next to the `required_parent` instead of the tips. If the node rejects them as too old,
+    /// the error is returned instead of retrying with new tips.
+    #[serde(default)]
+    pub extra_parents: Option<Vec<BlockId>>,
+    /// How often the block is rebuilt with new tips and posted again, if the node rejects it because its parents are
+    /// too old.
+    #[serde(default = "default_post_block_max_retries")]
+    pub max_retries: usize,
+}
+
+fn default_post_block_max_retries() -> usize {
+    DEFAULT_POST_BLOCK_MAX_RETRIES
+}
+
+impl Default for PostBlockOptions {
+    fn default() -> Self {
+        Self {
+            required_parent: None,
+            extra_parents: None,
+            max_retries: DEFAULT_POST_BLOCK_MAX_RETRIES,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Local structural validation of blocks, with [`Client::validate_block()`] or offline with [`validate_block()`].
+
+use std::collections::HashMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::PackableExt;
+
+use crate::{
+    client::{Client, Result},
+    pow::score::PowScorer,
+    types::block::{
+        address::Ed25519Address,
+        input::{Input, INPUT_COUNT_RANGE},
+        output::{Output, OutputId, OUTPUT_COUNT_RANGE},
+        parent::Parents,
+        payload::{
+            transaction::{TransactionEssence, TransactionPayload},
+            Payload,
+        },
+        protocol::ProtocolParameters,
+        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        signature::Signature,
+        unlock::Unlock,
+        Block,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// The reasons why [`Client::validate_block()`] rejects a block.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ValidationError {
+    /// The number of parents is out of range.
+    #[error("invalid parents count: {0}")]
+    InvalidParentsCount(usize),
+    /// The serialized block is too long.
+    #[error("block length {length} exceeds the max length {max_length}")]
+    InvalidBlockLength {
+        /// The length of the block.
+        length: usize,
+        /// The max length of a block.
+        max_length: usize,
+    },
+    /// The PoW score of the block is below the min PoW score of the network.
+    #[error("pow score {score} is below the min pow score {min_pow_score}")]
+    PowScoreTooLow {
+        /// The PoW score of the block.
+        score: f64,
+        /// The min PoW score of the network.
+        min_pow_score: u32,
+    },
+    /// The number of inputs of the transaction is out of range.
+    #[error("invalid input count: {0}")]
+    InvalidInputCount(usize),
+    /// The number of outputs of the transaction is out of range.
+    #[error("invalid output count: {0}")]
+    InvalidOutputCount(usize),
+    /// The number of unlocks doesn't match the number of inputs.
+    #[error("unlock count {unlocks} doesn't match input count {inputs}")]
+    UnlockCountMismatch {
+        /// The number of inputs.
+        inputs: usize,
+        /// The number of unlocks.
+        unlocks: usize,
+    },
+    /// A signature unlock doesn't match its public key.
+    #[error("invalid signature in unlock {0}")]
+    InvalidSignature(usize),
+    /// An input isn't in the provided outputs.
+    #[error("input {0} not found in the provided outputs")]
+    InputNotFound(OutputId),
+    /// The semantic validation with the provided outputs failed.
+    #[error("the semantic validation failed with conflict reason: {} - {0:?}", *.0 as u8)]
+    Semantic(ConflictReason),
+}
+
+impl Client {
+    /// Validates a block locally, without posting it: the parents count, the block length, the PoW score and for a
+    /// transaction payload the input, output and unlock counts and that the signature unlocks match their public keys.
+    /// If the outputs referenced by the inputs are provided, they must all be present and the transaction is also
+    /// semantically validated against them, with the local time. Pass `None` to skip that check.
+    pub async fn validate_block(&self, block: &Block, outputs: Option<&HashMap<OutputId, Output>>) -> Result<()> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        Ok(validate_block(block, &protocol_parameters, outputs)?)
+    }
+}
+
+/// Like [`Client::validate_block()`], but with the given protocol parameters instead of the ones of the node, so it
+/// can be used offline.
+pub fn validate_block(
+    block: &Block,
+    protocol_parameters: &ProtocolParameters,
+    outputs: Option<&HashMap<OutputId, Output>>,
+) -> core::result::Result<(), ValidationError> {
+    let min_pow_score = protocol_parameters.min_pow_score();
+    if !Parents::COUNT_RANGE.contains(&(block.parents().len() as u8)) {
+        return Err(ValidationError::InvalidParentsCount(block.parents().len()));
+    }
+
+    let bytes = block.pack_to_vec();
+    if bytes.len() > Block::LENGTH_MAX {
+        return Err(ValidationError::InvalidBlockLength {
+            length: bytes.len(),
+            max_length: Block::LENGTH_MAX,
+        });
+    }
+
+    if min_pow_score != 0 {
+        let score = PowScorer::new().score(&bytes);
+        if score < min_pow_score as f64 {
+            return Err(ValidationError::PowScoreTooLow { score, min_pow_score });
+        }
+    }
+
+    if let Some(Payload::Transaction(transaction)) = block.payload() {
+        validate_transaction(transaction, outputs)?;
+    }
+
+    Ok(())
+}
+
+fn validate_transaction(
+    transaction: &TransactionPayload,
+    outputs: Option<&HashMap<OutputId, Output>>,
+) -> core::result::Result<(), ValidationError> {
+    let TransactionEssence::Regular(essence) = transaction.essence();
+    let unlocks = transaction.unlocks();
+
+    if !INPUT_COUNT_RANGE.contains(&(essence.inputs().len() as u16)) {
+        return Err(ValidationError::InvalidInputCount(essence.inputs().len()));
+    }
+    if !OUTPUT_COUNT_RANGE.contains(&(essence.outputs().len() as u16)) {
+        return Err(ValidationError::InvalidOutputCount(essence.outputs().len()));
+    }
+    if unlocks.len() != essence.inputs().len() {
+        return Err(ValidationError::UnlockCountMismatch {
+            inputs: essence.inputs().len(),
+            unlocks: unlocks.len(),
+        });
+    }
+
+    // Without the outputs, the signatures can only be checked against the addresses of their own public keys
+    let essence_hash = transaction.essence().hash();
+    for (index, unlock) in unlocks.iter().enumerate() {
+        if let Unlock::Signature(unlock) = unlock {
+            let Signature::Ed25519(signature) = unlock.signature();
+            let address = Ed25519Address::new(Blake2b256::digest(signature.public_key()).into());
+            signature
+                .is_valid(&essence_hash, &address)
+                .map_err(|_| ValidationError::InvalidSignature(index))?;
+        }
+    }
+
+    if let Some(outputs) = outputs {
+        let inputs = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .map(|output_id| {
+                outputs
+                    .get(output_id)
+                    .map(|output| (*output_id, output))
+                    .ok_or(ValidationError::InputNotFound(*output_id))
+            })
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+
+        let transaction_id = transaction.id();
+        let context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().map(|(output_id, output)| (output_id, *output)),
+            unlocks,
+            unix_timestamp_now().as_secs() as u32,
+        );
+        // Errors of the semantic validation are conflicts too
+        let conflict =
+            semantic_validation(context, &inputs, unlocks).unwrap_or(ConflictReason::SemanticValidationFailed);
+        if conflict != ConflictReason::None {
+            return Err(ValidationError::Semantic(conflict));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,306 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cache of block and block metadata responses, enabled with
+//! [`ClientBuilder::with_block_cache()`](crate::client::ClientBuilder::with_block_cache).
+
+use std::{
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use instant::Instant;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Client,
+    types::{
+        api::core::response::BlockMetadataResponse,
+        block::{Block, BlockId},
+    },
+};
+
+/// The size and the time to live of the block cache.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockCacheOptions {
+    /// Max number of blocks and of block metadata that are cached.
+    pub capacity: NonZeroUsize,
+    /// Time after which the blocks that aren't referenced by a milestone are evicted. The metadata of these blocks
+    /// isn't cached at all, as it still changes.
+    pub ttl: Duration,
+}
+
+/// Counters of the block cache.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    /// Number of requests answered from the cache.
+    pub hits: u64,
+    /// Number of requests sent to a node because the entry wasn't cached.
+    pub misses: u64,
+    /// Number of entries removed because they expired or the cache was full.
+    pub evictions: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry<T> {
+    value: T,
+    // `None` for blocks referenced by a milestone, which don't change anymore
+    expires_at: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct BlockCacheState {
+    blocks: LruCache<BlockId, CacheEntry<Block>>,
+    metadata: LruCache<BlockId, CacheEntry<BlockMetadataResponse>>,
+    stats: CacheStats,
+}
+
+/// LRU cache of the block and block metadata responses, shared by all clones of a client.
+#[derive(Debug)]
+pub(crate) struct BlockCache {
+    options: BlockCacheOptions,
+    state: Mutex<BlockCacheState>,
+}
+
+impl BlockCache {
+    pub(crate) fn new(options: BlockCacheOptions) -> Arc<Self> {
+        Arc::new(Self {
+            options,
+            state: Mutex::new(BlockCacheState {
+                blocks: LruCache::new(options.capacity),
+                metadata: LruCache::new(options.capacity),
+                stats: CacheStats::default(),
+            }),
+        })
+    }
+
+    pub(crate) fn options(&self) -> BlockCacheOptions {
+        self.options
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, BlockCacheState> {
+        // A poisoned lock only means that a previous access panicked, the entries can still be used
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.state().stats
+    }
+
+    pub(crate) fn block(&self, block_id: &BlockId) -> Option<Block> {
+        self.block_at(block_id, Instant::now())
+    }
+
+    pub(crate) fn insert_block(&self, block_id: BlockId, block: Block) {
+        self.insert_block_at(block_id, block, Instant::now())
+    }
+
+    pub(crate) fn metadata(&self, block_id: &BlockId) -> Option<BlockMetadataResponse> {
+        self.metadata_at(block_id, Instant::now())
+    }
+
+    fn block_at(&self, block_id: &BlockId, now: Instant) -> Option<Block> {
+        let mut state = self.state();
+        let state = &mut *state;
+        get(&mut state.blocks, block_id, now, &mut state.stats)
+    }
+
+    fn metadata_at(&self, block_id: &BlockId, now: Instant) -> Option<BlockMetadataResponse> {
+        let mut state = self.state();
+        let state = &mut *state;
+        get(&mut state.metadata, block_id, now, &mut state.stats)
+    }
+
+    fn insert_block_at(&self, block_id: BlockId, block: Block, now: Instant) {
+        let mut state = self.state();
+        let state = &mut *state;
+        let referenced = state
+            .metadata
+            .peek(&block_id)
+            .map_or(false, |entry| entry.value.referenced_by_milestone_index.is_some());
+        let expires_at = if referenced { None } else { Some(now + self.options.ttl) };
+
+        insert(&mut state.blocks, block_id, block, expires_at, &mut state.stats);
+    }
+
+    pub(crate) fn insert_metadata(&self, block_id: BlockId, metadata: BlockMetadataResponse) {
+        // The metadata of blocks that aren't referenced yet changes with every milestone, polling it for retries or
+        // promotions must always reach the node
+        if metadata.referenced_by_milestone_index.is_none() {
+            return;
+        }
+
+        let mut state = self.state();
+        let state = &mut *state;
+        // The block is confirmed now, so its cached entry doesn't expire anymore either
+        if let Some(entry) = state.blocks.peek_mut(&block_id) {
+            entry.expires_at = None;
+        }
+
+        insert(&mut state.metadata, block_id, metadata, None, &mut state.stats);
+    }
+}
+
+fn get<K: Hash + Eq, T: Clone>(
+    cache: &mut LruCache<K, CacheEntry<T>>,
+    key: &K,
+    now: Instant,
+    stats: &mut CacheStats,
+) -> Option<T> {
+    let expired = match cache.get(key) {
+        Some(entry) if entry.expires_at.map_or(true, |expires_at| now < expires_at) => {
+            stats.hits += 1;
+            return Some(entry.value.clone());
+        }
+        Some(_) => true,
+        None => false,
+    };
+    if expired {
+        cache.pop(key);
+        stats.evictions += 1;
+    }
+    stats.misses += 1;
+
+    None
+}
+
+fn insert<K: Hash + Eq, T>(
+    cache: &mut LruCache<K, CacheEntry<T>>,
+    key: K,
+    value: T,
+    expires_at: Option<Instant>,
+    stats: &mut CacheStats,
+) {
+    let replaced = cache.contains(&key);
+    // `push()` returns the old entry of the same key or the least recently used one if the cache is full
+    if cache.push(key, CacheEntry { value, expires_at }).is_some() && !replaced {
+        stats.evictions += 1;
+    }
+}
+
+impl Client {
+    /// Returns the hits, misses and evictions of the block cache, all zero if it isn't enabled.
+    pub async fn cache_stats(&self) -> CacheStats {
+        self.block_cache
+            .read()
+            .await
+            .as_ref()
+            .map(|block_cache| block_cache.stats())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        parent::Parents,
+        rand::block::{rand_block_id, rand_block_ids},
+        BlockBuilder,
+    };
+
+    fn block_cache(capacity: usize) -> Arc<BlockCache> {
+        BlockCache::new(BlockCacheOptions {
+            capacity: NonZeroUsize::new(capacity).unwrap(),
+            ttl: Duration::from_secs(10),
+        })
+    }
+
+    fn block() -> Block {
+        BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+            .finish()
+            .unwrap()
+    }
+
+    fn metadata(block_id: &BlockId, referenced_by_milestone_index: Option<u32>) -> BlockMetadataResponse {
+        BlockMetadataResponse {
+            block_id: block_id.to_string(),
+            parents: Vec::new(),
+            is_solid: true,
+            referenced_by_milestone_index,
+            milestone_index: None,
+            ledger_inclusion_state: None,
+            conflict_reason: None,
+            white_flag_index: None,
+            should_promote: None,
+            should_reattach: None,
+        }
+    }
+
+    #[test]
+    fn block_cache_ttl() {
+        let block_cache = block_cache(10);
+        let block_id = rand_block_id();
+        let block = block();
+        let now = Instant::now();
+
+        assert_eq!(block_cache.block_at(&block_id, now), None);
+        block_cache.insert_block_at(block_id, block.clone(), now);
+        assert_eq!(
+            block_cache.block_at(&block_id, now + Duration::from_secs(9)),
+            Some(block)
+        );
+        assert_eq!(block_cache.block_at(&block_id, now + Duration::from_secs(10)), None);
+
+        assert_eq!(
+            block_cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 2,
+                evictions: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn block_cache_referenced_blocks_dont_expire() {
+        let block_cache = block_cache(10);
+        let (block_id, unreferenced_block_id) = (rand_block_id(), rand_block_id());
+        let block = block();
+        let now = Instant::now();
+        let later = now + Duration::from_secs(3600);
+
+        block_cache.insert_block_at(block_id, block.clone(), now);
+        block_cache.insert_metadata(block_id, metadata(&block_id, Some(1)));
+        block_cache.insert_metadata(unreferenced_block_id, metadata(&unreferenced_block_id, None));
+
+        assert_eq!(block_cache.block_at(&block_id, later), Some(block));
+        assert_eq!(
+            block_cache.metadata_at(&block_id, later),
+            Some(metadata(&block_id, Some(1)))
+        );
+        // The metadata of blocks that aren't referenced yet isn't cached at all
+        assert_eq!(block_cache.metadata_at(&unreferenced_block_id, now), None);
+
+        // A block inserted after its referenced metadata doesn't expire either
+        let block = self::block();
+        block_cache.insert_block_at(block_id, block.clone(), later);
+        assert_eq!(
+            block_cache.block_at(&block_id, later + Duration::from_secs(3600)),
+            Some(block)
+        );
+    }
+
+    #[test]
+    fn block_cache_capacity() {
+        let block_cache = block_cache(2);
+        let block_ids = [rand_block_id(), rand_block_id(), rand_block_id()];
+        let now = Instant::now();
+
+        for block_id in block_ids {
+            block_cache.insert_block_at(block_id, block(), now);
+        }
+        // Replacing an entry isn't an eviction
+        block_cache.insert_block_at(block_ids[2], block(), now);
+
+        assert_eq!(block_cache.block_at(&block_ids[0], now), None);
+        assert!(block_cache.block_at(&block_ids[1], now).is_some());
+        assert!(block_cache.block_at(&block_ids[2], now).is_some());
+        assert_eq!(block_cache.stats().evictions, 1);
+    }
+}
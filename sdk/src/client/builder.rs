@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Builder of the Client Instance
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, num::NonZeroUsize, sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
@@ -11,18 +11,25 @@ use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
-        constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
+        block_cache::{BlockCache, BlockCacheOptions},
+        constants::{
+            DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIME_CHECK_CACHE_TTL,
+            DEFAULT_TIME_SYNC_TOLERANCE, DEFAULT_TIPS_INTERVAL,
+        },
         error::Result,
         node_manager::{
             builder::validate_url,
             node::{Node, NodeAuth},
+            request_signer::{RequestSigner, RequestSignerHandle},
         },
         Client,
     },
     types::block::{
+        address::Hrp,
         output::RentStructure,
         protocol::{dto::ProtocolParametersDto, ProtocolParameters},
     },
+    Url,
 };
 
 /// Struct containing network and PoW related information
@@ -44,6 +51,8 @@ pub struct NetworkInfo {
     pub tips_interval: u64,
     /// The latest cached milestone timestamp.
     pub latest_milestone_timestamp: Option<u32>,
+    /// The latest cached confirmed milestone index.
+    pub confirmed_milestone_index: Option<u32>,
 }
 
 /// Dto for the NetworkInfo
@@ -128,6 +137,27 @@ pub struct ClientBuilder {
     #[serde(default)]
     #[cfg(not(target_family = "wasm"))]
     pub pow_worker_count: Option<usize>,
+    /// Max allowed difference in seconds between the local time and the latest milestone timestamp
+    #[serde(default = "default_time_sync_tolerance")]
+    pub time_sync_tolerance: u32,
+    /// Whether the local time is used without checking it against the latest milestone timestamp
+    #[serde(default)]
+    pub time_check_disabled: bool,
+    /// Time for which the latest milestone timestamp requested for the time check is reused
+    #[serde(default = "default_time_check_cache_ttl")]
+    pub time_check_cache_ttl: Duration,
+    /// Max number of pages that are requested from an indexer route when following the cursor
+    #[serde(default)]
+    pub max_indexer_pages: Option<usize>,
+    /// Bech32 HRP used to format addresses instead of the one of the node
+    #[serde(default)]
+    pub custom_bech32_hrp: Option<Hrp>,
+    /// Whether bech32 HRPs of provided addresses are accepted without checking them against the network
+    #[serde(default)]
+    pub hrp_validation_disabled: bool,
+    /// Size and time to live of the block cache, disabled if not set
+    #[serde(default)]
+    pub block_cache: Option<BlockCacheOptions>,
 }
 
 fn default_api_timeout() -> Duration {
@@ -138,6 +168,14 @@ fn default_remote_pow_timeout() -> Duration {
     DEFAULT_REMOTE_POW_API_TIMEOUT
 }
 
+fn default_time_sync_tolerance() -> u32 {
+    DEFAULT_TIME_SYNC_TOLERANCE
+}
+
+fn default_time_check_cache_ttl() -> Duration {
+    DEFAULT_TIME_CHECK_CACHE_TTL
+}
+
 impl Default for NetworkInfo {
     fn default() -> Self {
         Self {
@@ -147,6 +185,7 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            confirmed_milestone_index: None,
         }
     }
 }
@@ -162,6 +201,13 @@ impl Default for ClientBuilder {
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
+            time_sync_tolerance: DEFAULT_TIME_SYNC_TOLERANCE,
+            time_check_disabled: false,
+            time_check_cache_ttl: DEFAULT_TIME_CHECK_CACHE_TTL,
+            max_indexer_pages: None,
+            custom_bech32_hrp: None,
+            hrp_validation_disabled: false,
+            block_cache: None,
         }
     }
 }
@@ -246,6 +292,24 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the interval in which the health of the nodes is checked with `GET /health` in the background. Nodes that
+    /// fail the check are skipped during node selection until they are healthy again, which is checked after the
+    /// [cool-down](Self::with_node_health_check_cool_down).
+    pub fn with_node_health_check_interval(mut self, node_health_check_interval: Duration) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_node_health_check_interval(node_health_check_interval);
+        self
+    }
+
+    /// Set the time after which a node that failed its health check is checked again, 60 seconds by default.
+    pub fn with_node_health_check_cool_down(mut self, node_health_check_cool_down: Duration) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_node_health_check_cool_down(node_health_check_cool_down);
+        self
+    }
+
     /// Ignores the node health status.
     /// Every node will be considered healthy and ready to use.
     pub fn with_ignore_node_health(mut self) -> Self {
@@ -253,6 +317,61 @@ impl ClientBuilder {
         self
     }
 
+    /// Set if requests should only fail over to other nodes that report to be healthy, using `GET /health`, after a
+    /// connection or server error. Every node is tried at most once before the error is returned.
+    pub fn with_auto_failover(mut self, enabled: bool) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_auto_failover(enabled);
+        self
+    }
+
+    /// Set if request counts, error counts and latencies of the nodes should be recorded, they can be read with
+    /// [`Client::metrics()`](crate::client::Client::metrics).
+    pub fn with_metrics_enabled(mut self, enabled: bool) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_metrics_enabled(enabled);
+        self
+    }
+
+    /// Set the username and password sent with HTTP basic auth to nodes that don't have their own auth.
+    pub fn with_basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_basic_auth(username, password);
+        self
+    }
+
+    /// Set the JWT sent as bearer token to nodes that don't have their own auth, it takes precedence over the basic
+    /// auth.
+    pub fn with_jwt_token(mut self, token: &str) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_jwt_token(token);
+        self
+    }
+
+    /// Set the url from which a new JWT is requested when a node rejects the current one with 401, the request is then
+    /// sent once more. The url is requested with the basic auth if set and has to respond with `{"jwt":"<token>"}`.
+    pub fn with_jwt_refresh_url(mut self, url: Url) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_jwt_refresh_url(url)?;
+        Ok(self)
+    }
+
+    /// Set a request signer that adds headers to every request to the nodes, after the auth headers, for example a
+    /// signature required by a gateway. A signer error aborts the request. The signer isn't serialized with the
+    /// builder.
+    pub fn with_request_signer(mut self, request_signer: Arc<dyn RequestSigner>) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_request_signer(RequestSignerHandle(request_signer));
+        self
+    }
+
+    /// Pins the TLS certificate of the nodes: only nodes that present exactly this DER encoded certificate are
+    /// accepted, instead of verifying the certificate chain. MQTT connections aren't affected. Certificate pinning
+    /// isn't supported by the TLS of the browser, so building the client fails on wasm with
+    /// [`Error::TlsPinningUnsupported`](crate::client::Error).
+    #[cfg(feature = "tls-pinning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls-pinning")))]
+    pub fn with_tls_certificate_pinning(mut self, cert_der: Vec<u8>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_tls_certificate_pinning(cert_der);
+        self
+    }
+
     /// Set if quorum should be used or not
     pub fn with_quorum(mut self, quorum: bool) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_quorum(quorum);
@@ -317,6 +436,57 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the max allowed difference in seconds between the local time and the latest milestone timestamp, default
+    /// is 300 seconds (5 minutes).
+    pub fn with_time_sync_tolerance(mut self, seconds: u32) -> Self {
+        self.time_sync_tolerance = seconds;
+        self
+    }
+
+    /// Disables the check of the local time against the timestamp of the latest milestone, for offline signing where
+    /// no node is reachable.
+    pub fn with_time_check_disabled(mut self) -> Self {
+        self.time_check_disabled = true;
+        self
+    }
+
+    /// Sets the time for which the latest milestone timestamp requested for the time check is reused, so repeated
+    /// checks, for example during input selection, don't request the node info every time. Default is 10 seconds.
+    pub fn with_time_check_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.time_check_cache_ttl = ttl;
+        self
+    }
+
+    /// Sets the max number of pages that are requested from an indexer route when following the cursor, no limit by
+    /// default. An error is returned if more pages would be needed.
+    pub fn with_max_indexer_pages(mut self, max_pages: impl Into<Option<usize>>) -> Self {
+        self.max_indexer_pages = max_pages.into();
+        self
+    }
+
+    /// Sets a bech32 HRP that is used to format addresses instead of the one of the node, for private tangles with
+    /// their own address format. Provided addresses are validated against the HRP of the node and the custom one,
+    /// unless [`ClientBuilder::with_hrp_validation_disabled()`] is set.
+    pub fn with_custom_bech32_hrp(mut self, bech32_hrp: impl Into<Option<Hrp>>) -> Self {
+        self.custom_bech32_hrp = bech32_hrp.into();
+        self
+    }
+
+    /// Disables the check of the bech32 HRP of provided addresses against the network, addresses with any HRP are
+    /// accepted.
+    pub fn with_hrp_validation_disabled(mut self) -> Self {
+        self.hrp_validation_disabled = true;
+        self
+    }
+
+    /// Caches up to `capacity` blocks and block metadata returned by [`Client::get_block()`] and
+    /// [`Client::get_block_metadata()`], so they aren't requested again. Entries are evicted after `ttl`, unless the
+    /// block is referenced by a milestone. A capacity of 0 disables the cache.
+    pub fn with_block_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.block_cache = NonZeroUsize::new(capacity).map(|capacity| BlockCacheOptions { capacity, ttl });
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -330,6 +500,7 @@ impl ClientBuilder {
         use tokio::sync::RwLock;
 
         let node_sync_interval = self.node_manager_builder.node_sync_interval;
+        let node_health_check_interval = self.node_manager_builder.node_health_check_interval;
         let ignore_node_health = self.node_manager_builder.ignore_node_health;
         let nodes = self
             .node_manager_builder
@@ -343,11 +514,19 @@ impl ClientBuilder {
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
         let client_inner = Arc::new(ClientInner {
-            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
             pow_worker_count: RwLock::new(self.pow_worker_count),
+            time_sync_tolerance: RwLock::new(self.time_sync_tolerance),
+            time_check_disabled: RwLock::new(self.time_check_disabled),
+            time_check_cache_ttl: RwLock::new(self.time_check_cache_ttl),
+            latest_milestone_timestamp_cache: Default::default(),
+            max_indexer_pages: RwLock::new(self.max_indexer_pages),
+            custom_bech32_hrp: RwLock::new(self.custom_bech32_hrp),
+            hrp_validation_disabled: RwLock::new(self.hrp_validation_disabled),
+            block_cache: RwLock::new(self.block_cache.map(BlockCache::new)),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
                 client: Default::default(),
@@ -359,8 +538,18 @@ impl ClientBuilder {
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
-        let client_clone = client_inner.clone();
 
+        let health_check_handle = node_health_check_interval.map(|node_health_check_interval| {
+            let client_clone = client_inner.clone();
+            let nodes = nodes.clone();
+            tokio::spawn(async move {
+                client_clone
+                    .start_health_check_process(nodes, node_health_check_interval)
+                    .await
+            })
+        });
+
+        let client_clone = client_inner.clone();
         let sync_handle = tokio::spawn(async move {
             client_clone
                 .start_sync_process(nodes, node_sync_interval, ignore_node_health)
@@ -370,6 +559,7 @@ impl ClientBuilder {
         let client = Client {
             inner: client_inner,
             _sync_handle: Arc::new(RwLock::new(super::SyncHandle(Some(sync_handle)))),
+            _health_check_handle: Arc::new(RwLock::new(super::SyncHandle(health_check_handle))),
         };
 
         Ok(client)
@@ -385,10 +575,18 @@ impl ClientBuilder {
 
         let client = Client {
             inner: Arc::new(ClientInner {
-                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                time_sync_tolerance: RwLock::new(self.time_sync_tolerance),
+                time_check_disabled: RwLock::new(self.time_check_disabled),
+                time_check_cache_ttl: RwLock::new(self.time_check_cache_ttl),
+                latest_milestone_timestamp_cache: Default::default(),
+                max_indexer_pages: RwLock::new(self.max_indexer_pages),
+                custom_bech32_hrp: RwLock::new(self.custom_bech32_hrp),
+                hrp_validation_disabled: RwLock::new(self.hrp_validation_disabled),
+                block_cache: RwLock::new(self.block_cache.map(BlockCache::new)),
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
@@ -413,6 +611,18 @@ impl ClientBuilder {
             remote_pow_timeout: client.get_remote_pow_timeout().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
+            time_sync_tolerance: client.get_time_sync_tolerance().await,
+            time_check_disabled: *client.time_check_disabled.read().await,
+            time_check_cache_ttl: *client.time_check_cache_ttl.read().await,
+            max_indexer_pages: client.get_max_indexer_pages().await,
+            custom_bech32_hrp: *client.custom_bech32_hrp.read().await,
+            hrp_validation_disabled: *client.hrp_validation_disabled.read().await,
+            block_cache: client
+                .block_cache
+                .read()
+                .await
+                .as_ref()
+                .map(|block_cache| block_cache.options()),
         }
     }
 }
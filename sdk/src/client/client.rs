@@ -17,6 +17,7 @@ use {
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
+        block_cache::BlockCache,
         builder::{ClientBuilder, NetworkInfo},
         error::Result,
         node_manager::NodeManager,
@@ -31,6 +32,8 @@ pub struct Client {
     pub(crate) inner: Arc<ClientInner>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) _sync_handle: Arc<RwLock<SyncHandle>>,
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) _health_check_handle: Arc<RwLock<SyncHandle>>,
 }
 
 impl core::ops::Deref for Client {
@@ -52,6 +55,22 @@ pub struct ClientInner {
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// Max allowed difference in seconds between the local time and the latest milestone timestamp.
+    pub(crate) time_sync_tolerance: RwLock<u32>,
+    /// Whether the local time isn't checked against the latest milestone timestamp.
+    pub(crate) time_check_disabled: RwLock<bool>,
+    /// Time for which the latest milestone timestamp of the time check is reused.
+    pub(crate) time_check_cache_ttl: RwLock<Duration>,
+    /// Latest milestone timestamp of the time check and when it was requested.
+    pub(crate) latest_milestone_timestamp_cache: RwLock<Option<(instant::Instant, Option<u32>)>>,
+    /// Max number of pages that are requested from an indexer route.
+    pub(crate) max_indexer_pages: RwLock<Option<usize>>,
+    /// Bech32 HRP used to format addresses instead of the one of the node.
+    pub(crate) custom_bech32_hrp: RwLock<Option<Hrp>>,
+    /// Whether provided bech32 HRPs aren't checked against the network.
+    pub(crate) hrp_validation_disabled: RwLock<bool>,
+    /// Cache of block and block metadata responses.
+    pub(crate) block_cache: RwLock<Option<Arc<BlockCache>>>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
 }
@@ -116,6 +135,7 @@ impl ClientInner {
             let info = self.get_info().await?.node_info;
             let mut client_network_info = self.network_info.write().await;
             client_network_info.protocol_parameters = info.protocol.try_into()?;
+            client_network_info.confirmed_milestone_index = Some(info.status.confirmed_milestone.index);
 
             *LAST_SYNC.lock().unwrap() = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
@@ -145,6 +165,14 @@ impl ClientInner {
 
     /// Gets the bech32 HRP of the node we're connecting to.
     pub async fn get_bech32_hrp(&self) -> Result<Hrp> {
+        match *self.custom_bech32_hrp.read().await {
+            Some(bech32_hrp) => Ok(bech32_hrp),
+            None => self.get_network_bech32_hrp().await,
+        }
+    }
+
+    /// Gets the bech32 HRP declared by the node we're connecting to, ignoring a custom bech32 HRP.
+    pub async fn get_network_bech32_hrp(&self) -> Result<Hrp> {
         Ok(*self.get_network_info().await?.protocol_parameters.bech32_hrp())
     }
 
@@ -186,14 +214,30 @@ impl ClientInner {
         *self.remote_pow_timeout.read().await
     }
 
+    /// returns the max allowed difference in seconds between the local time and the latest milestone timestamp
+    pub async fn get_time_sync_tolerance(&self) -> u32 {
+        *self.time_sync_tolerance.read().await
+    }
+
+    /// returns the max number of pages that are requested from an indexer route
+    pub async fn get_max_indexer_pages(&self) -> Option<usize> {
+        *self.max_indexer_pages.read().await
+    }
+
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
         self.network_info.read().await.fallback_to_local_pow
     }
 
-    /// Validates if a bech32 HRP matches the one from the connected network.
+    /// Validates if a bech32 HRP matches the one from the connected network or the custom bech32 HRP. Always succeeds
+    /// if the HRP validation is disabled.
     pub async fn bech32_hrp_matches(&self, bech32_hrp: &Hrp) -> Result<()> {
-        let expected = self.get_bech32_hrp().await?;
+        if *self.hrp_validation_disabled.read().await
+            || self.custom_bech32_hrp.read().await.as_ref() == Some(bech32_hrp)
+        {
+            return Ok(());
+        }
+        let expected = self.get_network_bech32_hrp().await?;
         if bech32_hrp != &expected {
             return Err(Error::Bech32HrpMismatch {
                 provided: bech32_hrp.to_string(),
@@ -12,18 +12,26 @@ pub(crate) const DEFAULT_API_TIMEOUT: Duration = Duration::from_secs(15);
 pub(crate) const DEFAULT_REMOTE_POW_API_TIMEOUT: Duration = Duration::from_secs(100);
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+/// Default number of retries with new tips when a posted block is rejected because its parents are too old
+pub(crate) const DEFAULT_POST_BLOCK_MAX_RETRIES: usize = 2;
+/// Interval in seconds in which the node is polled for newly confirmed milestones to follow
+pub(crate) const DEFAULT_FOLLOW_MILESTONES_INTERVAL: u64 = 2;
 /// Interval in seconds when new tips will be requested during PoW, so the final block always will be attached to a
 /// new part of the Tangle
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+/// Default time after which a node that failed its health check is checked again
+pub(crate) const DEFAULT_NODE_HEALTH_CHECK_COOL_DOWN: Duration = Duration::from_secs(60);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
-/// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
-pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
+/// Default max allowed difference between the local time and latest milestone time, 5 minutes in seconds
+pub(crate) const DEFAULT_TIME_SYNC_TOLERANCE: u32 = 300;
+/// Default time for which the latest milestone timestamp requested for the time check is reused
+pub(crate) const DEFAULT_TIME_CHECK_CACHE_TTL: Duration = Duration::from_secs(10);
 /// Delay for caching a node info response in WASM runtime
 #[cfg(target_family = "wasm")]
 pub(crate) const CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS: u32 = 60;
@@ -13,7 +13,7 @@ use serde::{
 
 use crate::{
     client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
-    types::block::semantic::ConflictReason,
+    types::block::{output::OutputId, payload::transaction::TransactionId, semantic::ConflictReason},
 };
 
 /// Type alias of `Result` in iota-client
@@ -22,6 +22,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// Error type of the iota client crate.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// An address is part of multiple disjoint address groups
+    #[error("address {address} is part of the disjoint address groups {groups:?}")]
+    AddressInMultipleGroups {
+        /// The address.
+        address: String,
+        /// The indexes of the groups containing the address.
+        groups: Vec<usize>,
+    },
     /// Block dtos error
     #[error("{0}")]
     ApiTypes(#[from] crate::types::api::core::error::Error),
@@ -39,12 +47,18 @@ pub enum Error {
     /// Block types error
     #[error("{0}")]
     Block(#[from] crate::types::block::Error),
+    /// Local block validation error
+    #[error("{0}")]
+    BlockValidation(#[from] crate::client::api::ValidationError),
     /// The wallet account has enough funds, but split on too many outputs
     #[error("the wallet account has enough funds, but split on too many outputs: {0}, max. is 128, consolidate them")]
     ConsolidationRequired(usize),
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
+    /// The amount could only be reached with inputs of multiple disjoint address groups
+    #[error("the amount can only be reached with inputs of the disjoint address groups {0:?}")]
+    DisjointAddressGroups(Vec<usize>),
     /// Address not found
     #[error("address: {address} not found in range: {range}")]
     InputAddressNotFound {
@@ -53,6 +67,9 @@ pub enum Error {
         /// The range in which the address was not found.
         range: String,
     },
+    /// Inputs of a transaction to reattach were already spent by another transaction
+    #[error("inputs {0:?} were already spent by another transaction")]
+    InputsAlreadySpent(Vec<OutputId>),
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
@@ -81,18 +98,30 @@ pub enum Error {
     /// JSON error
     #[error("{0}")]
     Json(#[from] serde_json::Error),
+    /// The max number of indexer pages was reached before all results were requested
+    #[error("max number of indexer pages reached: {0}")]
+    MaxIndexerPagesReached(usize),
+    /// The amount could only be reached with more inputs of an address than allowed
+    #[error("the amount can't be reached with at most {0} inputs per address")]
+    MaxInputsPerAddress(usize),
     /// Missing required parameters
     #[error("must provide required parameter: {0}")]
     MissingParameter(&'static str),
     /// Error on API request
     #[error("node error: {0}")]
     Node(#[from] crate::client::node_api::error::Error),
+    /// None of the known blocks of a transaction is known to the node, so there is nothing to retry
+    #[error("no known attachment of transaction {0}")]
+    NoKnownAttachment(TransactionId),
     /// The block doesn't need to be promoted or reattached
     #[error("block ID `{0}` doesn't need to be promoted or reattached")]
     NoNeedPromoteOrReattach(String),
     /// Requested output id not found for this type
     #[error("No output found for {0}")]
     NoOutput(String),
+    /// The watch-only secret manager has no public key for the address index
+    #[error("watch-only secret manager has no public key for address index {0}")]
+    MissingWatchOnlyPublicKey(u32),
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
@@ -144,12 +173,34 @@ pub enum Error {
         /// The timestamp of the latest milestone.
         milestone_timestamp: u32,
     },
+    /// TLS certificate pinning isn't supported by the TLS of the browser.
+    #[cfg(all(feature = "tls-pinning", target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls-pinning")))]
+    #[error("TLS certificate pinning isn't supported on wasm")]
+    TlsPinningUnsupported,
+    /// The transaction contains a treasury input, which isn't supported.
+    #[error("treasury inputs are not supported")]
+    TreasuryInputUnsupported,
     /// The semantic validation of a transaction failed.
     #[error("the semantic validation of a transaction failed with conflict reason: {} - {0:?}", *.0 as u8)]
     TransactionSemantic(ConflictReason),
+    /// The transaction of a block to reattach is conflicting.
+    #[error("transaction {transaction_id} is conflicting with reason: {} - {reason:?}", *.reason as u8)]
+    TransactionConflicting {
+        /// The id of the conflicting transaction.
+        transaction_id: TransactionId,
+        /// The reason of the conflict.
+        reason: ConflictReason,
+    },
     /// Unexpected API response error
     #[error("unexpected API response")]
     UnexpectedApiResponse,
+    /// The block doesn't contain the expected transaction payload.
+    #[error("unexpected payload kind, expected a transaction payload")]
+    UnexpectedPayloadKind,
+    /// A BIP-44 path prefix with a purpose other than 44, which isn't supported for signing.
+    #[error("unsupported BIP-44 purpose {0}, only 44 is supported")]
+    UnsupportedBip44Purpose(u32),
     /// An indexer API request contains a query parameter not supported by the endpoint.
     #[error("an indexer API request contains a query parameter not supported by the endpoint: {0}.")]
     UnsupportedQueryParameter(QueryParameter),
@@ -165,6 +216,12 @@ pub enum Error {
     /// URL validation error
     #[error("{0}")]
     UrlValidation(String),
+    /// The watch-only secret manager can't be used for signing
+    #[error("watch-only secret manager can't be used for signing")]
+    WatchOnly,
+    /// The watch-only secret manager only has the public keys of the account with the index 0
+    #[error("watch-only secret manager has no public keys for account index {0}")]
+    WatchOnlyAccountIndex(u32),
     /// Input selection error.
     #[error("{0}")]
     InputSelection(#[from] InputSelectionError),
@@ -195,6 +252,12 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     #[error("{0}")]
     Stronghold(#[from] crate::client::stronghold::Error),
+
+    /// WebSocket error
+    #[cfg(feature = "websocket")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "websocket")))]
+    #[error("{0}")]
+    WebSocket(#[from] crate::client::node_api::ws::Error),
 }
 
 // Serialize type with Display error
@@ -8,7 +8,7 @@ pub mod routes;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::constants::MAX_PARALLEL_API_REQUESTS;
 use crate::{
-    client::{Client, Result},
+    client::{node_api::error::Error as NodeApiError, Client, Error, Result},
     types::block::output::{dto::OutputMetadataDto, OutputId, OutputWithMetadata},
 };
 
@@ -38,35 +38,55 @@ impl Client {
         Ok(outputs)
     }
 
-    /// Request outputs by their output ID in parallel, ignoring failed requests
-    /// Useful to get data about spent outputs, that might not be pruned yet
-    pub async fn get_outputs_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
+    /// Request outputs by their output ID in parallel, returning the found outputs and the ids of the outputs that
+    /// were not found, for example because they were pruned. Other errors are returned as usual.
+    pub async fn try_get_outputs(&self, output_ids: &[OutputId]) -> Result<(Vec<OutputWithMetadata>, Vec<OutputId>)> {
         #[cfg(target_family = "wasm")]
-        let outputs = futures::future::join_all(output_ids.iter().map(|id| self.get_output(id)))
-            .await
-            .into_iter()
-            .filter_map(Result::ok)
-            .collect();
+        let results = futures::future::try_join_all(output_ids.iter().map(|id| self.try_get_output(id))).await?;
 
         #[cfg(not(target_family = "wasm"))]
-        let outputs =
+        let results =
             futures::future::try_join_all(output_ids.chunks(MAX_PARALLEL_API_REQUESTS).map(|output_ids_chunk| {
                 let client = self.clone();
                 let output_ids_chunk = output_ids_chunk.to_vec();
-                tokio::spawn(async move {
-                    futures::future::join_all(output_ids_chunk.iter().map(|id| client.get_output(id)))
-                        .await
-                        .into_iter()
-                        .filter_map(Result::ok)
-                        .collect::<Vec<_>>()
-                })
+                async move {
+                    tokio::spawn(async move {
+                        futures::future::try_join_all(output_ids_chunk.iter().map(|id| client.try_get_output(id))).await
+                    })
+                    .await?
+                }
             }))
             .await?
             .into_iter()
             .flatten()
-            .collect();
+            .collect::<Vec<_>>();
 
-        Ok(outputs)
+        let mut outputs = Vec::new();
+        let mut not_found = Vec::new();
+
+        for result in results {
+            match result {
+                Ok(output) => outputs.push(output),
+                Err(output_id) => not_found.push(output_id),
+            }
+        }
+
+        Ok((outputs, not_found))
+    }
+
+    /// Request an output by its output ID, returning the output ID as inner error if it was not found.
+    async fn try_get_output(&self, output_id: &OutputId) -> Result<std::result::Result<OutputWithMetadata, OutputId>> {
+        match self.get_output(output_id).await {
+            Ok(output) => Ok(Ok(output)),
+            Err(Error::Node(NodeApiError::NotFound(_))) => Ok(Err(*output_id)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Request outputs by their output ID in parallel, ignoring the outputs that were not found, other errors are
+    /// returned. Useful to get data about spent outputs, that might not be pruned yet
+    pub async fn get_outputs_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
+        Ok(self.try_get_outputs(output_ids).await?.0)
     }
 
     /// Requests metadata for outputs by their output ID in parallel, ignoring failed requests
@@ -5,6 +5,7 @@
 
 use std::str::FromStr;
 
+use bytes::Bytes;
 use packable::PackableExt;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -12,14 +13,17 @@ use url::Url;
 use crate::{
     client::{
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+        },
         Client, ClientInner, Error, Result,
     },
     types::{
         api::core::response::{
-            BlockMetadataResponse, BlockResponse, InfoResponse, MilestoneResponse, OutputWithMetadataResponse,
-            PeerResponse, ReceiptResponse, ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse,
-            TreasuryResponse, UtxoChangesResponse,
+            BlockMetadataResponse, InfoResponse, MilestoneResponse, OutputWithMetadataResponse, PeerResponse,
+            ReceiptResponse, ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse, TreasuryResponse,
+            UtxoChangesResponse,
         },
         block::{
             output::{dto::OutputMetadataDto, Output, OutputId, OutputMetadata, OutputWithMetadata},
@@ -183,12 +187,15 @@ impl ClientInner {
             self.get_remote_pow_timeout().await
         };
 
+        // Packed once, the bytes are shared between the requests to the different nodes
+        let block_bytes = Bytes::from(block.pack_to_vec());
+
         // fallback to local Pow if remote Pow fails
         let resp = match self
             .node_manager
             .read()
             .await
-            .post_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec(), local_pow)
+            .post_request_bytes::<SubmitBlockResponse>(path, timeout, block_bytes, local_pow)
             .await
         {
             Ok(res) => res,
@@ -215,7 +222,7 @@ impl ClientInner {
                 self.node_manager
                     .read()
                     .await
-                    .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
+                    .post_request_bytes(path, timeout, Bytes::from(block_with_local_pow.pack_to_vec()), true)
                     .await?
             }
             Err(e) => return Err(e),
@@ -224,22 +231,30 @@ impl ClientInner {
         Ok(BlockId::from_str(&resp.block_id)?)
     }
 
-    /// Finds a block by its BlockId. This method returns the given block object.
+    /// Finds a block by its BlockId. This method returns the given block object, from the cache if enabled with
+    /// [`ClientBuilder::with_block_cache()`](crate::client::ClientBuilder::with_block_cache).
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block(&self, block_id: &BlockId) -> Result<Block> {
+        let block_cache = self.block_cache.read().await.clone();
+        if let Some(block) = block_cache.as_ref().and_then(|block_cache| block_cache.block(block_id)) {
+            return Ok(block);
+        }
+
         let path = &format!("api/core/v2/blocks/{block_id}");
 
-        let resp = self
+        let dto = self
             .node_manager
             .read()
             .await
-            .get_request::<BlockResponse>(path, None, self.get_timeout().await, false, true)
+            .get_request::<BlockDto>(path, None, self.get_timeout().await, false, true)
             .await?;
 
-        match resp {
-            BlockResponse::Json(dto) => Ok(Block::try_from_dto(dto, &self.get_protocol_parameters().await?)?),
-            BlockResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
+        let block = Block::try_from_dto(dto, &self.get_protocol_parameters().await?)?;
+        if let Some(block_cache) = block_cache {
+            block_cache.insert_block(*block_id, block.clone());
         }
+
+        Ok(block)
     }
 
     /// Finds a block by its BlockId. This method returns the given block raw data.
@@ -254,16 +269,31 @@ impl ClientInner {
             .await
     }
 
-    /// Returns the metadata of a block.
+    /// Returns the metadata of a block, from the cache if enabled with
+    /// [`ClientBuilder::with_block_cache()`](crate::client::ClientBuilder::with_block_cache).
     /// GET /api/core/v2/blocks/{BlockId}/metadata
     pub async fn get_block_metadata(&self, block_id: &BlockId) -> Result<BlockMetadataResponse> {
+        let block_cache = self.block_cache.read().await.clone();
+        if let Some(metadata) = block_cache
+            .as_ref()
+            .and_then(|block_cache| block_cache.metadata(block_id))
+        {
+            return Ok(metadata);
+        }
+
         let path = &format!("api/core/v2/blocks/{block_id}/metadata");
 
-        self.node_manager
+        let metadata: BlockMetadataResponse = self
+            .node_manager
             .read()
             .await
             .get_request(path, None, self.get_timeout().await, true, true)
-            .await
+            .await?;
+        if let Some(block_cache) = block_cache {
+            block_cache.insert_metadata(*block_id, metadata.clone());
+        }
+
+        Ok(metadata)
     }
 
     // UTXO routes.
@@ -359,17 +389,14 @@ impl ClientInner {
     pub async fn get_included_block(&self, transaction_id: &TransactionId) -> Result<Block> {
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block");
 
-        let resp = self
+        let dto = self
             .node_manager
             .read()
             .await
-            .get_request::<BlockResponse>(path, None, self.get_timeout().await, true, true)
+            .get_request::<BlockDto>(path, None, self.get_timeout().await, true, true)
             .await?;
 
-        match resp {
-            BlockResponse::Json(dto) => Ok(Block::try_from_dto(dto, &self.get_protocol_parameters().await?)?),
-            BlockResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
-        }
+        Ok(Block::try_from_dto(dto, &self.get_protocol_parameters().await?)?)
     }
 
     /// Returns the block, as raw bytes, that was included in the ledger for a given TransactionId.
@@ -528,6 +555,15 @@ impl ClientInner {
 impl Client {
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        Self::get_node_info_with_http_client(&HttpClient::new(DEFAULT_USER_AGENT.to_string()), url, auth).await
+    }
+
+    // Requests the node info with the given http client, so the client auth and request signer are used
+    pub(crate) async fn get_node_info_with_http_client(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+    ) -> Result<InfoResponse> {
         let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
@@ -540,19 +576,18 @@ impl Client {
         let path = "api/core/v2/info";
         url.set_path(path);
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp: InfoResponse = http_client
+            .get(
+                Node {
+                    url,
+                    auth,
+                    disabled: false,
+                },
+                DEFAULT_API_TIMEOUT,
+            )
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
@@ -23,7 +23,23 @@ pub enum Error {
         /// The url of the API.
         url: String,
     },
+    /// The response of a node couldn't be deserialized
+    #[error("failed to deserialize the response at {path}: {error}, URL: {url}")]
+    ResponseDeserialization {
+        /// The JSON pointer to the value that couldn't be deserialized.
+        path: String,
+        /// The deserialization error.
+        error: String,
+        /// The url of the API, containing the node and the route.
+        url: String,
+    },
+    /// Rw lock failed.
+    #[error("rw lock failed")]
+    PoisonError,
     /// None of our nodes have remote Pow enabled
     #[error("No node available for remote Pow")]
     UnavailablePow,
+    /// The request signer failed or returned an invalid header
+    #[error("request signing failed: {0}")]
+    RequestSigning(String),
 }
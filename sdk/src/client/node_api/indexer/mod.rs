@@ -8,13 +8,15 @@ pub mod routes;
 
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
-    client::{ClientInner, Result},
+    client::{ClientInner, Error, Result},
     types::api::plugins::indexer::OutputIdsResponse,
 };
 
 impl ClientInner {
     /// Get all output ids for a provided URL route and query parameters.
     /// If a `QueryParameter::Cursor(_)` is provided, only a single page will be queried.
+    /// Otherwise the cursor is followed until all pages are queried, or the max number of indexer pages is reached, in
+    /// which case an error is returned.
     pub async fn get_output_ids(
         &self,
         route: &str,
@@ -30,6 +32,12 @@ impl ClientInner {
 
         // Return early with only a single page if a `QueryParameter::Cursor(_)` is provided.
         let return_early = query_parameters.contains(QueryParameter::Cursor(String::new()).kind());
+        let max_pages = self.get_max_indexer_pages().await;
+        // Addresses formatted with a custom HRP need to be converted to the HRP of the node
+        if self.custom_bech32_hrp.read().await.is_some() {
+            query_parameters.set_bech32_hrp(self.get_network_bech32_hrp().await?);
+        }
+        let mut pages = 0;
 
         while let Some(cursor) = {
             let output_ids_response = self
@@ -55,6 +63,12 @@ impl ClientInner {
 
             &merged_output_ids_response.cursor
         } {
+            pages += 1;
+            if let Some(max_pages) = max_pages {
+                if pages >= max_pages {
+                    return Err(Error::MaxIndexerPagesReached(max_pages));
+                }
+            }
             query_parameters.replace(QueryParameter::Cursor(cursor.to_string()));
         }
 
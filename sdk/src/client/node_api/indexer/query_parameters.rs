@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{Error, Result},
-    types::block::address::Bech32Address,
+    types::block::address::{Bech32Address, Hrp},
 };
 
 // https://github.com/gohornet/hornet/blob/bb1271be9f3a638f6acdeb6de74eab64515f27f1/plugins/indexer/v1/routes.go#L54
@@ -56,6 +56,25 @@ impl QueryParameters {
         self.0.iter().any(f)
     }
 
+    /// Sets the bech32 HRP of all address parameters, so they can be understood by a node with another HRP.
+    pub(crate) fn set_bech32_hrp(&mut self, bech32_hrp: Hrp) {
+        for query_parameter in &mut self.0 {
+            match query_parameter {
+                QueryParameter::Address(address)
+                | QueryParameter::AliasAddress(address)
+                | QueryParameter::ExpirationReturnAddress(address)
+                | QueryParameter::Governor(address)
+                | QueryParameter::Issuer(address)
+                | QueryParameter::Sender(address)
+                | QueryParameter::StateController(address)
+                | QueryParameter::StorageDepositReturnAddress(address) => {
+                    *address = Bech32Address::new(bech32_hrp, *address.inner());
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Converts parameters to a single String.
     pub fn to_query_string(&self) -> Option<String> {
         if self.0.is_empty() {
@@ -0,0 +1,203 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! IOTA node WebSocket API, the MQTT topics of a node received as a [`Stream`].
+//!
+//! Only available for non-WASM targets for now, `wss` urls require the `tls` feature.
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use crypto::utils;
+use futures::{stream, Stream, StreamExt};
+use packable::PackableExt;
+use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, Outgoing, QoS, SubscribeFilter, Transport};
+
+use crate::{
+    client::{Client, Result},
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{address::Bech32Address, Block, ConvertTo},
+    },
+    Url,
+};
+
+/// WebSocket related errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Client error.
+    #[error("client error {0}")]
+    Client(#[from] rumqttc::ClientError),
+    /// Connection error.
+    #[error("connection error {0}")]
+    Connection(#[from] rumqttc::ConnectionError),
+    /// Crypto error.
+    #[error("crypto error {0}")]
+    Crypto(#[from] crypto::Error),
+    /// Unsupported url scheme.
+    #[error("unsupported url scheme {0}")]
+    UnsupportedScheme(String),
+}
+
+const BLOCKS_TOPIC: &str = "blocks";
+const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+// Room for the MQTT header of a publish packet containing a block
+const MAX_PACKET_SIZE: usize = Block::LENGTH_MAX + 1024;
+
+impl Client {
+    /// Subscribes to the new blocks of a node over a WebSocket connection, like `ws://localhost:14265/api/mqtt/v1`.
+    ///
+    /// Returns once the connection is established, then the stream reconnects with an exponential backoff whenever it
+    /// gets lost. Dropping the stream closes the connection.
+    pub async fn subscribe_blocks(&self, node_ws_url: Url) -> Result<impl Stream<Item = Result<Block>> + Send> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let subscription = Subscription::connect(node_ws_url, vec![BLOCKS_TOPIC.to_string()]).await?;
+
+        Ok(subscription
+            .into_stream()
+            .map(move |payload| -> Result<Block> { Ok(Block::unpack_verified(&*payload?, &protocol_parameters)?) }))
+    }
+
+    /// Subscribes to the outputs with an unlock condition to an address over a WebSocket connection, see
+    /// [`Client::subscribe_blocks()`]. The stream receives the created outputs and again the same outputs, with spent
+    /// metadata, once they get spent.
+    pub async fn subscribe_output_updates(
+        &self,
+        node_ws_url: Url,
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<impl Stream<Item = Result<OutputWithMetadataResponse>> + Send> {
+        let address = address.convert()?;
+        self.bech32_hrp_matches(address.hrp()).await?;
+        // `+` matches all kinds of unlock conditions
+        let topic = format!("outputs/unlock/+/{address}");
+        let subscription = Subscription::connect(node_ws_url, vec![format!("{topic}/spent"), topic]).await?;
+
+        Ok(subscription
+            .into_stream()
+            .map(|payload| -> Result<OutputWithMetadataResponse> { Ok(serde_json::from_slice(&payload?)?) }))
+    }
+}
+
+/// A connection to a node that is subscribed to topics with the same kind of payload.
+struct Subscription {
+    client: AsyncClient,
+    // Only `None` once dropped
+    event_loop: Option<EventLoop>,
+    topics: Vec<String>,
+    reconnect_delay: Duration,
+    subscribed: bool,
+}
+
+impl Subscription {
+    async fn connect(node_ws_url: Url, topics: Vec<String>) -> Result<Self> {
+        let (transport, port) = match node_ws_url.scheme() {
+            "ws" => (Transport::ws(), node_ws_url.port().unwrap_or(80)),
+            #[cfg(feature = "tls")]
+            "wss" => (Transport::wss_with_default_config(), node_ws_url.port().unwrap_or(443)),
+            scheme => return Err(Error::UnsupportedScheme(scheme.to_string()).into()),
+        };
+
+        let mut entropy = [0u8; 8];
+        utils::rand::fill(&mut entropy).map_err(Error::from)?;
+        let mut mqtt_options = MqttOptions::new(
+            format!("iotasdk{}", prefix_hex::encode(entropy)),
+            node_ws_url.as_str(),
+            port,
+        );
+        mqtt_options
+            .set_transport(transport)
+            .set_max_packet_size(MAX_PACKET_SIZE, MAX_PACKET_SIZE);
+
+        let (client, mut event_loop) = AsyncClient::new(mqtt_options, 10);
+        // Only return the stream once the node accepted the connection
+        loop {
+            if let Event::Incoming(Incoming::ConnAck(_)) = event_loop.poll().await.map_err(Error::from)? {
+                break;
+            }
+        }
+
+        let mut subscription = Self {
+            client,
+            event_loop: Some(event_loop),
+            topics,
+            reconnect_delay: MIN_RECONNECT_DELAY,
+            subscribed: false,
+        };
+        subscription.subscribe().await?;
+
+        Ok(subscription)
+    }
+
+    fn into_stream(self) -> impl Stream<Item = Result<Bytes>> + Send {
+        stream::unfold(self, |mut subscription| async move {
+            let payload = subscription.next_payload().await;
+            Some((payload, subscription))
+        })
+    }
+
+    async fn subscribe(&mut self) -> Result<()> {
+        self.client
+            .subscribe_many(
+                self.topics
+                    .iter()
+                    .map(|topic| SubscribeFilter::new(topic.clone(), QoS::AtLeastOnce)),
+            )
+            .await
+            .map_err(Error::from)?;
+        self.subscribed = true;
+
+        Ok(())
+    }
+
+    /// Returns the payload of the next publish packet, without the topic.
+    async fn next_payload(&mut self) -> Result<Bytes> {
+        loop {
+            let event = self
+                .event_loop
+                .as_mut()
+                .expect("event loop is only taken on drop")
+                .poll()
+                .await;
+
+            match event {
+                Ok(Event::Incoming(Incoming::ConnAck(_))) => {
+                    self.reconnect_delay = MIN_RECONNECT_DELAY;
+                    // The subscription is lost with the connection
+                    if !self.subscribed {
+                        self.subscribe().await?;
+                    }
+                }
+                // All topics of a connection have the same kind of payload
+                Ok(Event::Incoming(Incoming::Publish(publish))) => return Ok(publish.payload),
+                Ok(_) => {}
+                Err(e) => {
+                    log::debug!("[{:?}] reconnecting in {:?}: {e}", self.topics, self.reconnect_delay);
+                    self.subscribed = false;
+                    // The next poll of the event loop reconnects
+                    tokio::time::sleep(self.reconnect_delay).await;
+                    self.reconnect_delay = (self.reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        // Send the disconnect packet on the current runtime so the node closes the connection cleanly, otherwise the
+        // socket is just dropped
+        if let (Some(mut event_loop), Ok(runtime)) = (self.event_loop.take(), tokio::runtime::Handle::try_current()) {
+            let client = self.client.clone();
+            runtime.spawn(async move {
+                if client.disconnect().await.is_ok() {
+                    while let Ok(event) = event_loop.poll().await {
+                        if let Event::Outgoing(Outgoing::Disconnect) = event {
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
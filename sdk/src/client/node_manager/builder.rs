@@ -14,11 +14,15 @@ use url::Url;
 
 use crate::{
     client::{
-        constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
+        constants::{
+            DEFAULT_MIN_QUORUM_SIZE, DEFAULT_NODE_HEALTH_CHECK_COOL_DOWN, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT,
+            NODE_SYNC_INTERVAL,
+        },
         error::{Error, Result},
         node_manager::{
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
+            request_signer::RequestSignerHandle,
             NodeManager,
         },
     },
@@ -41,10 +45,19 @@ pub struct NodeManagerBuilder {
     /// If the node health should be ignored
     #[serde(default)]
     pub ignore_node_health: bool,
+    /// If other nodes should only be tried after a connection or server error if they report to be healthy
+    #[serde(default)]
+    pub auto_failover: bool,
     /// Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::client::NetworkInfo)
     /// gets updated
     #[serde(default = "default_node_sync_interval")]
     pub node_sync_interval: Duration,
+    /// Interval in which the health of the nodes is checked, disabled if `None`
+    #[serde(default)]
+    pub node_health_check_interval: Option<Duration>,
+    /// Time after which a node that failed its health check is checked again
+    #[serde(default = "default_node_health_check_cool_down")]
+    pub node_health_check_cool_down: Duration,
     /// If node quorum is enabled. Will compare the responses from multiple nodes and only returns the response if
     /// `quorum_threshold`% of the nodes return the same one
     #[serde(default)]
@@ -58,6 +71,23 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// If request counts, error counts and latencies of the nodes are recorded
+    #[serde(default)]
+    pub metrics_enabled: bool,
+    /// The auth for nodes that don't have their own
+    #[serde(default)]
+    pub auth: Option<NodeAuth>,
+    /// Url from which a new JWT is requested with a GET request when a node responds with 401, it has to respond
+    /// with `{"jwt":"<token>"}`
+    #[serde(default)]
+    pub jwt_refresh_url: Option<Url>,
+    /// Adds headers to every request, it can't be serialized
+    #[serde(skip)]
+    pub(crate) request_signer: Option<RequestSignerHandle>,
+    /// The DER encoded TLS certificate that nodes have to present, instead of a valid certificate chain
+    #[cfg(feature = "tls-pinning")]
+    #[serde(default)]
+    pub tls_certificate_pinning: Option<Vec<u8>>,
 }
 
 fn default_user_agent() -> String {
@@ -68,6 +98,10 @@ fn default_node_sync_interval() -> Duration {
     NODE_SYNC_INTERVAL
 }
 
+fn default_node_health_check_cool_down() -> Duration {
+    DEFAULT_NODE_HEALTH_CHECK_COOL_DOWN
+}
+
 fn default_min_quorum_size() -> usize {
     DEFAULT_MIN_QUORUM_SIZE
 }
@@ -198,6 +232,21 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_node_health_check_interval(mut self, node_health_check_interval: Duration) -> Self {
+        self.node_health_check_interval = Some(node_health_check_interval);
+        self
+    }
+
+    pub(crate) fn with_node_health_check_cool_down(mut self, node_health_check_cool_down: Duration) -> Self {
+        self.node_health_check_cool_down = node_health_check_cool_down;
+        self
+    }
+
+    pub(crate) fn with_auto_failover(mut self, enabled: bool) -> Self {
+        self.auto_failover = enabled;
+        self
+    }
+
     pub(crate) fn with_quorum(mut self, quorum: bool) -> Self {
         self.quorum = quorum;
         self
@@ -218,8 +267,64 @@ impl NodeManagerBuilder {
         self
     }
 
-    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
-        NodeManager {
+    pub(crate) fn with_metrics_enabled(mut self, enabled: bool) -> Self {
+        self.metrics_enabled = enabled;
+        self
+    }
+
+    pub(crate) fn with_basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.auth
+            .get_or_insert(NodeAuth {
+                jwt: None,
+                basic_auth_name_pwd: None,
+            })
+            .basic_auth_name_pwd = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    pub(crate) fn with_jwt_token(mut self, token: &str) -> Self {
+        self.auth
+            .get_or_insert(NodeAuth {
+                jwt: None,
+                basic_auth_name_pwd: None,
+            })
+            .jwt = Some(token.to_string());
+        self
+    }
+
+    pub(crate) fn with_jwt_refresh_url(mut self, url: Url) -> Result<Self> {
+        self.jwt_refresh_url = Some(validate_url(url)?);
+        Ok(self)
+    }
+
+    pub(crate) fn with_request_signer(mut self, request_signer: RequestSignerHandle) -> Self {
+        self.request_signer = Some(request_signer);
+        self
+    }
+
+    #[cfg(feature = "tls-pinning")]
+    pub(crate) fn with_tls_certificate_pinning(mut self, cert_der: Vec<u8>) -> Self {
+        self.tls_certificate_pinning = Some(cert_der);
+        self
+    }
+
+    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> Result<NodeManager> {
+        #[allow(unused_mut)]
+        let mut http_client = HttpClient::new(self.user_agent)
+            .with_metrics(self.metrics_enabled.then(Default::default))
+            .with_auth(self.auth, self.jwt_refresh_url)
+            .with_request_signer(self.request_signer);
+        #[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+        if let Some(certificate) = self.tls_certificate_pinning {
+            http_client = http_client.with_pinned_certificate(certificate)?;
+        }
+        // Connecting without the pinning the user asked for would be less secure than expected
+        #[cfg(all(feature = "tls-pinning", target_family = "wasm"))]
+        if self.tls_certificate_pinning.is_some() {
+            return Err(crate::client::Error::TlsPinningUnsupported);
+        }
+
+        Ok(NodeManager {
             primary_node: self.primary_node.map(|node| node.into()),
             primary_pow_node: self.primary_pow_node.map(|node| node.into()),
             nodes: self.nodes.into_iter().map(|node| node.into()).collect(),
@@ -227,13 +332,17 @@ impl NodeManagerBuilder {
                 .permanodes
                 .map(|nodes| nodes.into_iter().map(|node| node.into()).collect()),
             ignore_node_health: self.ignore_node_health,
+            auto_failover: self.auto_failover,
             node_sync_interval: self.node_sync_interval,
+            node_health_check_interval: self.node_health_check_interval,
+            node_health_check_cool_down: self.node_health_check_cool_down,
+            node_health: Default::default(),
             healthy_nodes: RwLock::new(healthy_nodes),
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
-        }
+            http_client,
+        })
     }
 }
 
@@ -245,11 +354,20 @@ impl Default for NodeManagerBuilder {
             nodes: HashSet::new(),
             permanodes: None,
             ignore_node_health: false,
+            auto_failover: false,
             node_sync_interval: NODE_SYNC_INTERVAL,
+            node_health_check_interval: None,
+            node_health_check_cool_down: DEFAULT_NODE_HEALTH_CHECK_COOL_DOWN,
             quorum: false,
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            metrics_enabled: false,
+            auth: None,
+            jwt_refresh_url: None,
+            request_signer: None,
+            #[cfg(feature = "tls-pinning")]
+            tls_certificate_pinning: None,
         }
     }
 }
@@ -273,11 +391,23 @@ impl From<&NodeManager> for NodeManagerBuilder {
                 .as_ref()
                 .map(|p| p.iter().cloned().map(NodeDto::Node).collect()),
             ignore_node_health: value.ignore_node_health,
+            auto_failover: value.auto_failover,
             node_sync_interval: value.node_sync_interval,
+            node_health_check_interval: value.node_health_check_interval,
+            node_health_check_cool_down: value.node_health_check_cool_down,
             quorum: value.quorum,
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
             user_agent: value.http_client.user_agent.clone(),
+            metrics_enabled: value.http_client.metrics.is_some(),
+            auth: value.http_client.auth(),
+            jwt_refresh_url: value.http_client.jwt_refresh_url.clone(),
+            request_signer: value.http_client.request_signer.clone(),
+            #[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+            tls_certificate_pinning: value.http_client.pinned_certificate.clone(),
+            // A node manager with a pinned certificate can't be built on wasm
+            #[cfg(all(feature = "tls-pinning", target_family = "wasm"))]
+            tls_certificate_pinning: None,
         }
     }
 }
@@ -3,15 +3,27 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
-use std::time::Duration;
+use std::{
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
 
-use reqwest::RequestBuilder;
-use serde::de::DeserializeOwned;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    RequestBuilder,
+};
+use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::Value;
+use serde_path_to_error::Segment;
 
 use crate::client::{
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::{
+        metrics::MetricsStore,
+        node::{Node, NodeAuth},
+        request_signer::{RequestParts, RequestSignerHandle},
+    },
 };
 pub(crate) struct Response(reqwest::Response);
 
@@ -21,7 +33,10 @@ impl Response {
     }
 
     pub(crate) async fn into_json<T: DeserializeOwned>(self) -> Result<T> {
-        self.0.json().await.map_err(Into::into)
+        let url = self.0.url().to_string();
+        let bytes = self.0.bytes().await?;
+
+        deserialize_json(&bytes, url)
     }
 
     #[cfg(not(target_family = "wasm"))]
@@ -34,10 +49,52 @@ impl Response {
     }
 }
 
+/// Deserializes a JSON response, returning the JSON pointer to the value that couldn't be deserialized on failure.
+pub(crate) fn deserialize_json<T: DeserializeOwned>(json: &[u8], url: String) -> Result<T> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(json);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let path = error
+            .path()
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Seq { index } => Some(index.to_string()),
+                Segment::Map { key } => Some(key.replace('~', "~0").replace('/', "~1")),
+                Segment::Enum { .. } | Segment::Unknown => None,
+            })
+            .fold(String::new(), |path, segment| format!("{path}/{segment}"));
+
+        Error::ResponseDeserialization {
+            path,
+            error: error.into_inner().to_string(),
+            url,
+        }
+    })
+}
+
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: reqwest::Client,
     pub(crate) user_agent: String,
+    pub(crate) metrics: Option<Arc<MetricsStore>>,
+    /// Username and password for nodes without their own auth.
+    pub(crate) basic_auth: Option<(String, String)>,
+    /// JWT for nodes without their own auth, replaced when it's refreshed.
+    pub(crate) jwt: Arc<RwLock<Option<String>>>,
+    /// Url from which a new JWT is requested after a node responded with 401.
+    pub(crate) jwt_refresh_url: Option<url::Url>,
+    /// Held while the JWT is refreshed, so requests rejected at the same time only refresh it once.
+    jwt_refresh_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Adds headers to every request after the auth.
+    pub(crate) request_signer: Option<RequestSignerHandle>,
+    #[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+    pub(crate) pinned_certificate: Option<Vec<u8>>,
+}
+
+/// The response of a JWT refresh url.
+#[derive(Deserialize)]
+struct JwtResponse {
+    jwt: String,
 }
 
 impl HttpClient {
@@ -45,7 +102,58 @@ impl HttpClient {
         Self {
             client: reqwest::Client::new(),
             user_agent,
+            metrics: None,
+            basic_auth: None,
+            jwt: Default::default(),
+            jwt_refresh_url: None,
+            jwt_refresh_lock: Default::default(),
+            request_signer: None,
+            #[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+            pinned_certificate: None,
+        }
+    }
+
+    /// Sets the auth that is used for nodes without their own auth.
+    pub(crate) fn with_auth(mut self, auth: Option<NodeAuth>, jwt_refresh_url: Option<url::Url>) -> Self {
+        if let Some(auth) = auth {
+            self.basic_auth = auth.basic_auth_name_pwd;
+            self.jwt = Arc::new(RwLock::new(auth.jwt));
         }
+        self.jwt_refresh_url = jwt_refresh_url;
+        self
+    }
+
+    pub(crate) fn with_request_signer(mut self, request_signer: Option<RequestSignerHandle>) -> Self {
+        self.request_signer = request_signer;
+        self
+    }
+
+    /// Returns the auth used for nodes without their own auth, with the current JWT.
+    pub(crate) fn auth(&self) -> Option<NodeAuth> {
+        // The JWT is only replaced as a whole, so it's still valid if the lock was poisoned
+        let jwt = self.jwt.read().unwrap_or_else(PoisonError::into_inner).clone();
+        (jwt.is_some() || self.basic_auth.is_some()).then(|| NodeAuth {
+            jwt,
+            basic_auth_name_pwd: self.basic_auth.clone(),
+        })
+    }
+
+    /// Only accepts TLS connections to nodes that present exactly the DER encoded certificate.
+    #[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+    pub(crate) fn with_pinned_certificate(mut self, certificate: Vec<u8>) -> Result<Self> {
+        let tls_config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(Arc::new(PinnedCertificateVerifier(certificate.clone())))
+            .with_no_client_auth();
+
+        self.client = reqwest::Client::builder().use_preconfigured_tls(tls_config).build()?;
+        self.pinned_certificate = Some(certificate);
+        Ok(self)
+    }
+
+    pub(crate) fn with_metrics(mut self, metrics: Option<Arc<MetricsStore>>) -> Self {
+        self.metrics = metrics;
+        self
     }
 
     async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
@@ -73,14 +181,10 @@ impl HttpClient {
         }
     }
 
-    fn build_request(&self, request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
+    fn build_request(&self, request_builder: RequestBuilder, _timeout: Duration) -> RequestBuilder {
+        #[allow(unused_mut)]
         let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
 
-        if let Some(node_auth) = &node.auth {
-            if let Some(jwt) = &node_auth.jwt {
-                request_builder = request_builder.bearer_auth(jwt);
-            }
-        }
         #[cfg(not(target_family = "wasm"))]
         {
             request_builder = request_builder.timeout(_timeout);
@@ -88,39 +192,190 @@ impl HttpClient {
         request_builder
     }
 
+    // Adds the auth of the node, or the one of the client if the node has none. The JWT takes precedence over the
+    // username and password.
+    fn authenticate(&self, mut request_builder: RequestBuilder, node: &Node) -> Result<RequestBuilder> {
+        match &node.auth {
+            Some(node_auth) => {
+                if let Some(jwt) = &node_auth.jwt {
+                    request_builder = request_builder.bearer_auth(jwt);
+                }
+            }
+            None => {
+                if let Some(jwt) = self.jwt()? {
+                    request_builder = request_builder.bearer_auth(jwt);
+                } else if let Some((name, password)) = &self.basic_auth {
+                    request_builder = request_builder.basic_auth(name, Some(password));
+                }
+            }
+        }
+        Ok(request_builder)
+    }
+
+    // Returns the current JWT of the client
+    fn jwt(&self) -> Result<Option<String>> {
+        Ok(self.jwt.read().map_err(|_| Error::PoisonError)?.clone())
+    }
+
+    // Adds the auth and the headers of the request signer, then sends the request
+    async fn execute(&self, request_builder: RequestBuilder, node: &Node) -> Result<Response> {
+        let mut request = self.authenticate(request_builder, node)?.build()?;
+
+        if let Some(request_signer) = &self.request_signer {
+            let headers = request_signer
+                .0
+                .sign(RequestParts {
+                    method: request.method().as_str(),
+                    url: request.url(),
+                    body: request.body().and_then(|body| body.as_bytes()).unwrap_or_default(),
+                })
+                .map_err(|e| Error::RequestSigning(e.to_string()))?;
+            for (name, value) in headers {
+                let name = HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|_| Error::RequestSigning(format!("invalid header name {name}")))?;
+                let value = HeaderValue::from_str(&value)
+                    .map_err(|_| Error::RequestSigning(format!("invalid value for header {name}")))?;
+                request.headers_mut().insert(name, value);
+            }
+        }
+
+        Self::parse_response(self.client.execute(request).await?, &node.url).await
+    }
+
+    // Requests a new JWT from the refresh url, with the username and password if set. Nothing is requested if the
+    // rejected JWT was already replaced by a refresh for another request.
+    async fn refresh_jwt(&self, refresh_url: &url::Url, rejected_jwt: Option<String>) -> Result<()> {
+        let _refresh_guard = self.jwt_refresh_lock.lock().await;
+        if self.jwt()? != rejected_jwt {
+            return Ok(());
+        }
+
+        let mut request_builder = self
+            .client
+            .get(refresh_url.clone())
+            .header(reqwest::header::USER_AGENT, &self.user_agent);
+        if let Some((name, password)) = &self.basic_auth {
+            request_builder = request_builder.basic_auth(name, Some(password));
+        }
+        let response = Self::parse_response(request_builder.send().await?, refresh_url).await?;
+        let JwtResponse { jwt } = response.into_json().await?;
+        *self.jwt.write().map_err(|_| Error::PoisonError)? = Some(jwt);
+        Ok(())
+    }
+
+    // Sends the request with auth and records its metrics if enabled. If the node rejects the JWT of the client and a
+    // refresh url is set, the JWT is refreshed and the request is sent once more.
+    async fn send(&self, request_builder: RequestBuilder, node: &Node) -> Result<Response> {
+        let url = &node.url;
+        let retry = match &self.jwt_refresh_url {
+            Some(refresh_url) if node.auth.is_none() => request_builder
+                .try_clone()
+                .map(|request_builder| (request_builder, refresh_url)),
+            _ => None,
+        };
+        // The JWT that is sent, read before the request so a refresh in the meantime is noticed
+        let sent_jwt = match retry {
+            Some(_) => self.jwt()?,
+            None => None,
+        };
+
+        let start_time = instant::Instant::now();
+        let mut res = self.execute(request_builder, node).await;
+        let unauthorized = matches!(res, Err(Error::ResponseError { code: 401, .. }));
+        if let (true, Some((request_builder, refresh_url))) = (unauthorized, retry) {
+            log::debug!("[send] refreshing the JWT for {url}");
+            res = match self.refresh_jwt(refresh_url, sent_jwt).await {
+                Ok(()) => self.execute(request_builder, node).await,
+                Err(e) => Err(e),
+            };
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.record(url, start_time.elapsed(), res.is_ok());
+        }
+        res
+    }
+
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
         let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
+        request_builder = self.build_request(request_builder, timeout);
         let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
-        log::debug!(
-            "GET: {:?} ms for {} {}",
-            start_time.elapsed().as_millis(),
-            resp.status(),
-            node.url
-        );
-        Self::parse_response(resp, &node.url).await
+        let res = self.send(request_builder, &node).await;
+        log::debug!("GET: {:?} ms for {}", start_time.elapsed().as_millis(), node.url);
+        res
     }
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
         let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
+        request_builder = self.build_request(request_builder, timeout);
         request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+        self.send(request_builder, &node).await
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        request_builder = self.build_request(request_builder, timeout);
+        self.send(request_builder.json(&json), &node).await
     }
 
-    pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
+    pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: Bytes) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
+        request_builder = self.build_request(request_builder, timeout);
         request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        self.send(request_builder.body(body), &node).await
+    }
+}
+
+/// Accepts a server certificate only if it's the pinned one, the certificate chain isn't checked.
+#[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+struct PinnedCertificateVerifier(Vec<u8>);
+
+#[cfg(all(feature = "tls-pinning", not(target_family = "wasm")))]
+impl rustls::client::ServerCertVerifier for PinnedCertificateVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::Certificate,
+        _intermediates: &[rustls::Certificate],
+        _server_name: &rustls::ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: std::time::SystemTime,
+    ) -> std::result::Result<rustls::client::ServerCertVerified, rustls::Error> {
+        if end_entity.0 == self.0 {
+            Ok(rustls::client::ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::General(
+                "the certificate of the node doesn't match the pinned certificate".to_string(),
+            ))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tls-pinning", not(target_family = "wasm")))]
+mod tests {
+    use rustls::client::ServerCertVerifier;
+
+    use super::*;
+
+    fn verify(verifier: &PinnedCertificateVerifier, certificate: &[u8]) -> bool {
+        verifier
+            .verify_server_cert(
+                &rustls::Certificate(certificate.to_vec()),
+                &[],
+                &rustls::ServerName::try_from("node.example").unwrap(),
+                &mut std::iter::empty(),
+                &[],
+                std::time::SystemTime::now(),
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn pinned_certificate_verifier() {
+        let verifier = PinnedCertificateVerifier(vec![1, 2, 3]);
+
+        assert!(verify(&verifier, &[1, 2, 3]));
+        assert!(!verify(&verifier, &[1, 2, 4]));
+        assert!(!verify(&verifier, &[]));
     }
 }
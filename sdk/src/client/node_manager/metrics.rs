@@ -0,0 +1,134 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Request metrics of the nodes, enabled with
+//! [`ClientBuilder::with_metrics_enabled()`](crate::client::ClientBuilder::with_metrics_enabled).
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{client::Client, Url};
+
+/// Max number of latency samples that are kept per node.
+const LATENCY_WINDOW_SIZE: usize = 1000;
+
+#[derive(Debug, Default)]
+struct NodeMetrics {
+    request_count: u64,
+    error_count: u64,
+    // Latencies of the last requests in milliseconds, the oldest first
+    latencies: VecDeque<u64>,
+}
+
+/// Request counts, error counts and latencies, by node url without path and query.
+#[derive(Debug, Default)]
+pub struct MetricsStore {
+    nodes: Mutex<HashMap<Url, NodeMetrics>>,
+}
+
+impl MetricsStore {
+    pub(crate) fn record(&self, url: &Url, latency: Duration, success: bool) {
+        let url = node_url(url);
+        // A poisoned lock only means that a previous recording panicked, the metrics can still be used
+        let mut nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        let metrics = nodes.entry(url).or_default();
+
+        metrics.request_count += 1;
+        if !success {
+            metrics.error_count += 1;
+        }
+        if metrics.latencies.len() == LATENCY_WINDOW_SIZE {
+            metrics.latencies.pop_front();
+        }
+        metrics
+            .latencies
+            .push_back(u64::try_from(latency.as_millis()).unwrap_or(u64::MAX));
+    }
+
+    fn with_node<T>(&self, url: &Url, f: impl FnOnce(&NodeMetrics) -> T) -> Option<T> {
+        self.nodes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&node_url(url))
+            .map(f)
+    }
+
+    /// Returns the urls of the nodes that received requests.
+    pub fn urls(&self) -> Vec<Url> {
+        self.nodes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the number of requests sent to a node.
+    pub fn request_count(&self, url: &Url) -> u64 {
+        self.with_node(url, |metrics| metrics.request_count).unwrap_or_default()
+    }
+
+    /// Returns the number of requests to a node that failed or didn't return a successful status.
+    pub fn error_count(&self, url: &Url) -> u64 {
+        self.with_node(url, |metrics| metrics.error_count).unwrap_or_default()
+    }
+
+    /// Returns the 95th percentile of the latencies of the last 1000 requests to a node in milliseconds, `None` if
+    /// no request was sent to it.
+    pub fn p95_latency_ms(&self, url: &Url) -> Option<u64> {
+        self.with_node(url, |metrics| {
+            let mut latencies = metrics.latencies.iter().copied().collect::<Vec<_>>();
+            latencies.sort_unstable();
+            // Nearest rank
+            let rank = (latencies.len() * 95 + 100 - 1) / 100;
+            latencies.get(rank.checked_sub(1)?).copied()
+        })
+        .flatten()
+    }
+}
+
+// Requests are sent to the url of the node with a different path, the metrics are grouped by the node
+fn node_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_path("");
+    url.set_query(None);
+    url
+}
+
+impl Client {
+    /// Returns the request metrics of the nodes, `None` if they aren't enabled.
+    pub async fn metrics(&self) -> Option<Arc<MetricsStore>> {
+        self.node_manager.read().await.http_client.metrics.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record() {
+        let store = MetricsStore::default();
+        let url = Url::parse("http://localhost:14265/").unwrap();
+        assert_eq!(store.p95_latency_ms(&url), None);
+
+        for latency in 1..=100 {
+            let request_url = url.join("api/core/v2/info").unwrap();
+            store.record(&request_url, Duration::from_millis(latency), latency % 10 != 0);
+        }
+        assert_eq!(store.urls(), [url.clone()]);
+        assert_eq!(store.request_count(&url), 100);
+        assert_eq!(store.error_count(&url), 10);
+        assert_eq!(store.p95_latency_ms(&url), Some(95));
+
+        // Only the last samples are used for the latency
+        for _ in 0..LATENCY_WINDOW_SIZE {
+            store.record(&url, Duration::from_millis(3), true);
+        }
+        assert_eq!(store.request_count(&url), 1100);
+        assert_eq!(store.p95_latency_ms(&url), Some(3));
+    }
+}
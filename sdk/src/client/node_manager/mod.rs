@@ -5,8 +5,11 @@
 
 pub mod builder;
 pub(crate) mod http_client;
+pub mod metrics;
 /// Structs for nodes
 pub mod node;
+pub mod node_pool;
+pub mod request_signer;
 pub(crate) mod syncing;
 
 use std::{
@@ -15,15 +18,23 @@ use std::{
     time::Duration,
 };
 
+use bytes::Bytes;
 use serde_json::Value;
+use url::{Origin, Url};
 
-use self::{http_client::HttpClient, node::Node};
+use self::{
+    http_client::HttpClient,
+    node::Node,
+    node_pool::{NodeHealth, NodeHealthStatuses},
+};
 use crate::{
     client::{
         error::{Error, Result},
+        node_api::error::Error as NodeApiError,
         node_manager::builder::NodeManagerBuilder,
     },
     types::api::core::response::InfoResponse,
+    utils::unix_timestamp_now,
 };
 
 // The node manager takes care of selecting node(s) for requests until a result is returned or if quorum is enabled it
@@ -34,7 +45,11 @@ pub struct NodeManager {
     pub(crate) nodes: HashSet<Node>,
     permanodes: Option<HashSet<Node>>,
     pub(crate) ignore_node_health: bool,
+    auto_failover: bool,
     node_sync_interval: Duration,
+    pub(crate) node_health_check_interval: Option<Duration>,
+    pub(crate) node_health_check_cool_down: Duration,
+    pub(crate) node_health: NodeHealthStatuses,
     pub(crate) healthy_nodes: RwLock<HashMap<Node, InfoResponse>>,
     quorum: bool,
     min_quorum_size: usize,
@@ -50,7 +65,11 @@ impl std::fmt::Debug for NodeManager {
         d.field("nodes", &self.nodes);
         d.field("permanodes", &self.permanodes);
         d.field("ignore_node_health", &self.ignore_node_health);
+        d.field("auto_failover", &self.auto_failover);
         d.field("node_sync_interval", &self.node_sync_interval);
+        d.field("node_health_check_interval", &self.node_health_check_interval);
+        d.field("node_health_check_cool_down", &self.node_health_check_cool_down);
+        d.field("node_health", &self.node_health);
         d.field("healthy_nodes", &self.healthy_nodes);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
@@ -138,6 +157,17 @@ impl NodeManager {
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
 
+        // remove nodes that failed their last health check, until they can be checked again after the cool-down
+        {
+            let now = unix_timestamp_now().as_secs();
+            let node_health = self.node_health.read().map_err(|_| crate::client::Error::PoisonError)?;
+            nodes_with_modified_url.retain(|n| {
+                node_health.get(&n.url).map_or(true, |health| {
+                    health.is_healthy || now >= health.last_checked + self.node_health_check_cool_down.as_secs()
+                })
+            });
+        }
+
         if nodes_with_modified_url.is_empty() {
             if use_pow_nodes {
                 return Err(crate::client::Error::Node(
@@ -224,6 +254,9 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
+                if self.skip_failover_node(&node, error.as_ref(), timeout).await {
+                    continue;
+                }
                 match self.http_client.get(node.clone(), timeout).await {
                     Ok(res) => {
                         // Handle node_info extra because we also want to return the url
@@ -275,7 +308,7 @@ impl NodeManager {
             // with query we ignore quorum because the nodes can store a different amount of history
             || query.is_some()
         {
-            Ok(serde_json::from_str(&res.0)?)
+            Ok(http_client::deserialize_json(res.0.as_bytes(), path.to_string())?)
         } else {
             Err(Error::QuorumThresholdError {
                 quorum_size: res.1,
@@ -297,6 +330,9 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
+            if self.skip_failover_node(&node, error.as_ref(), timeout).await {
+                continue;
+            }
             match self.http_client.get_bytes(node, timeout).await {
                 Ok(res) => {
                     match res.into_bytes().await {
@@ -318,7 +354,7 @@ impl NodeManager {
         &self,
         path: &str,
         timeout: Duration,
-        body: &[u8],
+        body: Bytes,
         local_pow: bool,
     ) -> Result<T> {
         // primary_pow_node should only be used for post request with remote PoW
@@ -326,7 +362,10 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_bytes(node, timeout, body).await {
+            if self.skip_failover_node(&node, error.as_ref(), timeout).await {
+                continue;
+            }
+            match self.http_client.post_bytes(node, timeout, body.clone()).await {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -355,6 +394,9 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
+            if self.skip_failover_node(&node, error.as_ref(), timeout).await {
+                continue;
+            }
             match self.http_client.post_json(node, timeout, json.clone()).await {
                 Ok(res) => {
                     match res.into_json::<T>().await {
@@ -371,4 +413,74 @@ impl NodeManager {
         // Each node will throw an error or return Ok()
         Err(error.unwrap())
     }
+
+    // With auto failover, a node is only tried after a connection or server error of the previous one if it reports to
+    // be healthy. A health state that isn't older than the cool-down, or the node sync, is reused, otherwise the node
+    // is checked with `GET /health` and the result is stored for the next requests.
+    async fn skip_failover_node(&self, node: &Node, error: Option<&Error>, timeout: Duration) -> bool {
+        let failover = matches!(
+            error,
+            Some(Error::Node(NodeApiError::Reqwest(_)))
+                | Some(Error::Node(NodeApiError::ResponseError { code: 500.., .. }))
+        );
+        if !self.auto_failover || !failover {
+            return false;
+        }
+
+        // The url of the node has the path of the request, the health states are stored by the configured url
+        let origin = node.url.origin();
+        let now = unix_timestamp_now().as_secs();
+        let known_health = self.node_health.read().ok().and_then(|node_health| {
+            node_health
+                .iter()
+                .find(|(url, health)| {
+                    url.origin() == origin && now < health.last_checked + self.node_health_check_cool_down.as_secs()
+                })
+                .map(|(_, health)| health.is_healthy)
+        });
+        let synced = || {
+            !self.ignore_node_health
+                && self.healthy_nodes.read().map_or(false, |healthy_nodes| {
+                    healthy_nodes.keys().any(|n| n.url.origin() == origin)
+                })
+        };
+
+        let healthy = match known_health {
+            Some(healthy) => healthy,
+            None if synced() => true,
+            None => {
+                let mut health_node = node.clone();
+                health_node.url.set_path("health");
+                health_node.url.set_query(None);
+                let healthy = self.http_client.get(health_node, timeout).await.is_ok();
+                if let Some(url) = self.configured_url(&origin) {
+                    if let Ok(mut node_health) = self.node_health.write() {
+                        node_health.insert(
+                            url,
+                            NodeHealth {
+                                is_healthy: healthy,
+                                last_checked: now,
+                            },
+                        );
+                    }
+                }
+                healthy
+            }
+        };
+        if !healthy {
+            log::debug!("skipping unhealthy node {}", node.url);
+        }
+        !healthy
+    }
+
+    // Returns the configured url of a node, as used for its health state
+    fn configured_url(&self, origin: &Origin) -> Option<Url> {
+        self.primary_node
+            .iter()
+            .chain(self.primary_pow_node.iter())
+            .chain(self.nodes.iter())
+            .chain(self.permanodes.iter().flatten())
+            .find(|node| &node.url.origin() == origin)
+            .map(|node| node.url.clone())
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Periodic health checks of the nodes in the pool, enabled with
+//! [`ClientBuilder::with_node_health_check_interval()`](crate::client::ClientBuilder::with_node_health_check_interval).
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_family = "wasm"))]
+use {
+    super::Node,
+    crate::client::ClientInner,
+    crate::utils::unix_timestamp_now,
+    std::{collections::HashSet, time::Duration},
+    tokio::time::sleep,
+};
+
+use crate::{
+    client::{Client, Error, Result},
+    Url,
+};
+
+/// The health statuses of the nodes, by their url.
+pub(crate) type NodeHealthStatuses = Arc<RwLock<HashMap<Url, NodeHealth>>>;
+
+/// The health of a node, as determined by the last health check.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    /// If the node responded successfully to `GET /health`. Unhealthy nodes are skipped during node selection.
+    pub is_healthy: bool,
+    /// The unix timestamp in seconds of the last health check.
+    pub last_checked: u64,
+}
+
+impl Client {
+    /// Returns the health statuses of the nodes, as determined by the periodic health checks. Nodes that weren't
+    /// checked yet aren't included.
+    pub async fn node_health_statuses(&self) -> Result<HashMap<Url, NodeHealth>> {
+        Ok(self
+            .node_manager
+            .read()
+            .await
+            .node_health
+            .read()
+            .map_err(|_| Error::PoisonError)?
+            .clone())
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl ClientInner {
+    /// Checks the health of the nodes per node_health_check_interval, unhealthy nodes are only checked again after
+    /// node_health_check_cool_down.
+    pub(crate) async fn start_health_check_process(&self, nodes: HashSet<Node>, node_health_check_interval: Duration) {
+        loop {
+            if let Err(e) = self.check_node_health(&nodes).await {
+                log::warn!("Checking node health failed: {e}");
+            }
+            sleep(node_health_check_interval).await;
+        }
+    }
+
+    pub(crate) async fn check_node_health(&self, nodes: &HashSet<Node>) -> Result<()> {
+        log::debug!("check_node_health");
+        let (http_client, node_health, cool_down) = {
+            let node_manager = self.node_manager.read().await;
+            (
+                node_manager.http_client.clone(),
+                node_manager.node_health.clone(),
+                node_manager.node_health_check_cool_down,
+            )
+        };
+        let timeout = self.get_timeout().await;
+
+        for node in nodes {
+            let now = unix_timestamp_now().as_secs();
+            let check_due = node_health
+                .read()
+                .map_err(|_| Error::PoisonError)?
+                .get(&node.url)
+                .map_or(true, |health| {
+                    health.is_healthy || now >= health.last_checked + cool_down.as_secs()
+                });
+            if !check_due {
+                continue;
+            }
+
+            let mut health_node = node.clone();
+            health_node.url.set_path("health");
+            health_node.url.set_query(None);
+            let is_healthy = http_client.get(health_node, timeout).await.is_ok();
+            if !is_healthy {
+                log::debug!("{} is not healthy", node.url);
+            }
+
+            node_health.write().map_err(|_| Error::PoisonError)?.insert(
+                node.url.clone(),
+                NodeHealth {
+                    is_healthy,
+                    last_checked: now,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing of the requests to nodes that are behind a gateway requiring it, set with
+//! [`ClientBuilder::with_request_signer()`](crate::client::ClientBuilder::with_request_signer).
+
+use std::sync::Arc;
+
+use crate::Url;
+
+/// The parts of a request to a node that can be signed.
+#[derive(Clone, Copy, Debug)]
+pub struct RequestParts<'a> {
+    /// The HTTP method, like `GET`.
+    pub method: &'a str,
+    /// The url with the path and query.
+    pub url: &'a Url,
+    /// The body, empty if the request has none.
+    pub body: &'a [u8],
+}
+
+/// Returns extra headers for every request to a node, after the auth headers are applied. It's called again for
+/// retried requests and for every node that is tried.
+pub trait RequestSigner: Send + Sync {
+    /// Returns the headers to add to the request, an error aborts the request with
+    /// [`Error::RequestSigning`](crate::client::node_api::error::Error::RequestSigning).
+    fn sign(
+        &self,
+        request: RequestParts<'_>,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Shared request signer, compared by identity so the builders containing it can still be compared.
+#[derive(Clone)]
+pub(crate) struct RequestSignerHandle(pub(crate) Arc<dyn RequestSigner>);
+
+impl std::fmt::Debug for RequestSignerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestSigner")
+    }
+}
+
+impl PartialEq for RequestSignerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for RequestSignerHandle {}
+
+/// The header with the timestamp in seconds that is part of the signature of the [`HmacRequestSigner`].
+#[cfg(feature = "hmac-request-signer")]
+pub const HMAC_TIMESTAMP_HEADER: &str = "X-Timestamp";
+/// The header with the hex encoded signature of the [`HmacRequestSigner`].
+#[cfg(feature = "hmac-request-signer")]
+pub const HMAC_SIGNATURE_HEADER: &str = "X-Signature";
+
+/// A [`RequestSigner`] that signs the method, the path with the query, a timestamp and the body with HMAC-SHA256.
+///
+/// The signed message is `{method}\n{path}\n{timestamp}\n{body}`, the timestamp in seconds is added as
+/// [`HMAC_TIMESTAMP_HEADER`] and the hex encoded signature as [`HMAC_SIGNATURE_HEADER`].
+#[cfg(feature = "hmac-request-signer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac-request-signer")))]
+pub struct HmacRequestSigner {
+    key: zeroize::Zeroizing<Vec<u8>>,
+}
+
+#[cfg(feature = "hmac-request-signer")]
+impl HmacRequestSigner {
+    /// Creates a signer with the shared key of the gateway.
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: zeroize::Zeroizing::new(key.into()),
+        }
+    }
+
+    fn signature(&self, request: RequestParts<'_>, timestamp: u64) -> String {
+        let path = match request.url.query() {
+            Some(query) => format!("{}?{query}", request.url.path()),
+            None => request.url.path().to_string(),
+        };
+        let mut message = format!("{}\n{path}\n{timestamp}\n", request.method).into_bytes();
+        message.extend_from_slice(request.body);
+
+        let mut mac = [0; 32];
+        crypto::macs::hmac::HMAC_SHA256(&message, &self.key, &mut mac);
+        prefix_hex::encode(mac)
+    }
+}
+
+#[cfg(feature = "hmac-request-signer")]
+impl RequestSigner for HmacRequestSigner {
+    fn sign(
+        &self,
+        request: RequestParts<'_>,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error + Send + Sync>> {
+        let timestamp = crate::utils::unix_timestamp_now().as_secs();
+
+        Ok(vec![
+            (HMAC_TIMESTAMP_HEADER.to_string(), timestamp.to_string()),
+            (HMAC_SIGNATURE_HEADER.to_string(), self.signature(request, timestamp)),
+        ])
+    }
+}
+
+#[cfg(all(test, feature = "hmac-request-signer"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hmac_signature() {
+        let url = "http://localhost:14265/api/core/v2/blocks".parse().unwrap();
+        let request = RequestParts {
+            method: "POST",
+            url: &url,
+            body: br#"{"protocolVersion":2}"#,
+        };
+
+        assert_eq!(
+            HmacRequestSigner::new("secret").signature(request, 1_700_000_000),
+            "0x107860cdb544226f7e88ceb33a3ae829a2797c63a2b81c51dc92c18014c344e7"
+        );
+    }
+}
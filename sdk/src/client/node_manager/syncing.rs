@@ -67,10 +67,12 @@ impl ClientInner {
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        // Use the http client of the node manager, so nodes without their own auth get the one of the client
+        let http_client = self.node_manager.read().await.http_client.clone();
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match Client::get_node_info_with_http_client(&http_client, node.url.as_ref(), node.auth.clone()).await {
                 Ok(info) => {
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(&info.protocol.network_name) {
@@ -105,6 +107,7 @@ impl ClientInner {
                 let mut network_info = self.network_info.write().await;
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
+                network_info.confirmed_milestone_index = Some(info.status.confirmed_milestone.index);
                 network_info.protocol_parameters = ProtocolParameters::try_from(info.protocol.clone())?;
             }
 
@@ -130,6 +133,7 @@ impl Client {
     #[cfg(not(target_family = "wasm"))]
     pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::wallet::Result<()> {
         let node_sync_interval = node_manager.node_sync_interval;
+        let node_health_check_interval = node_manager.node_health_check_interval;
         let ignore_node_health = node_manager.ignore_node_health;
         let nodes = node_manager
             .primary_node
@@ -141,6 +145,18 @@ impl Client {
         *self.node_manager.write().await = node_manager;
 
         self.sync_nodes(&nodes, ignore_node_health).await?;
+
+        let health_check_handle = node_health_check_interval.map(|node_health_check_interval| {
+            let client = self.clone();
+            let nodes = nodes.clone();
+            tokio::spawn(async move {
+                client
+                    .start_health_check_process(nodes, node_health_check_interval)
+                    .await
+            })
+        });
+        *self._health_check_handle.write().await = crate::client::SyncHandle(health_check_handle);
+
         let client = self.clone();
 
         let sync_handle = tokio::spawn(async move {
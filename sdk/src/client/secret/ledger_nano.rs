@@ -51,6 +51,9 @@ pub enum Error {
     /// Ledger Essence Too Large
     #[error("ledger essence too large")]
     EssenceTooLarge,
+    /// The essence can only be blind signed, but blind signing isn't enabled on the device
+    #[error("blind signing required, enable it in the settings of the app on the ledger")]
+    BlindSigningRequired,
     /// Ledger transport error
     #[error("ledger transport error")]
     MiscError,
@@ -77,6 +80,11 @@ pub enum Error {
     NoAvailableInputsProvided,
 }
 
+/// Flag of the app config that is set if the device is locked.
+const APP_CONFIG_FLAG_LOCKED: u8 = 1 << 0;
+/// Flag of the app config that is set if blind signing is enabled in the settings of the app.
+const APP_CONFIG_FLAG_BLIND_SIGNING_ENABLED: u8 = 1 << 1;
+
 impl From<crate::types::block::Error> for Error {
     fn from(error: crate::types::block::Error) -> Self {
         Self::Block(Box::new(error))
@@ -90,6 +98,7 @@ impl From<crate::types::block::Error> for Error {
 // LedgerDeviceNotFound: No usable Ledger device was found
 // LedgerMiscError: Everything else.
 // LedgerEssenceTooLarge: Essence with bip32 input indices need more space then the internal buffer is big
+// LedgerBlindSigningRequired: Is returned before signing, if the essence needs blind signing but it isn't enabled
 #[cfg(feature = "ledger_nano")]
 impl From<iota_ledger_nano::api::errors::APIError> for Error {
     fn from(error: iota_ledger_nano::api::errors::APIError) -> Self {
@@ -272,12 +281,22 @@ impl SignTransactionEssence for LedgerSecretManager {
         // lock the mutex to prevent multiple simultaneous requests to a ledger
         let lock = self.mutex.lock().await;
 
+        // Read the app config before the ledger is opened for signing, since both open a transport to the device. If
+        // the config can't be read, the device reports the error itself when signing.
+        let blind_signing_enabled = get_app_config(&self.transport_type())
+            .map(|config| config.flags & APP_CONFIG_FLAG_BLIND_SIGNING_ENABLED != 0)
+            .ok();
+
         let ledger = get_ledger(coin_type, bip32_account, self.is_simulator)?;
         let blind_signing = needs_blind_signing(prepared_transaction, ledger.get_buffer_size());
 
         // if essence + bip32 input indices are larger than the buffer size or the essence contains
         // features / types that are not supported blind signing will be needed
         if blind_signing {
+            // Fail before the device prompts, if it would reject the blind signing anyway
+            if blind_signing_enabled == Some(false) {
+                return Err(Error::BlindSigningRequired);
+            }
             // prepare signing
             log::debug!("[LEDGER] prepare_blind_signing");
             log::debug!("[LEDGER] {:?} {:?}", input_bip32_indices, essence_hash);
@@ -411,16 +430,20 @@ impl LedgerSecretManager {
         }
     }
 
+    fn transport_type(&self) -> TransportTypes {
+        if self.is_simulator {
+            TransportTypes::TCP
+        } else {
+            TransportTypes::NativeHID
+        }
+    }
+
     /// Get Ledger hardware status.
     pub async fn get_ledger_nano_status(&self) -> LedgerNanoStatus {
         log::debug!("get_ledger_nano_status");
         // lock the mutex
         let _lock = self.mutex.lock().await;
-        let transport_type = if self.is_simulator {
-            TransportTypes::TCP
-        } else {
-            TransportTypes::NativeHID
-        };
+        let transport_type = self.transport_type();
 
         log::debug!("get_opened_app");
         let app = match get_opened_app(&transport_type) {
@@ -437,9 +460,9 @@ impl LedgerSecretManager {
                 (
                     true,
                     // locked flag
-                    Some(config.flags & (1 << 0) != 0),
+                    Some(config.flags & APP_CONFIG_FLAG_LOCKED != 0),
                     // blind signing enabled flag
-                    config.flags & (1 << 1) != 0,
+                    config.flags & APP_CONFIG_FLAG_BLIND_SIGNING_ENABLED != 0,
                     LedgerDeviceType::try_from(config.device).ok(),
                 )
             });
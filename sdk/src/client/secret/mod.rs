@@ -16,6 +16,8 @@ pub mod placeholder;
 pub mod stronghold;
 /// Signing related types
 pub mod types;
+/// Module for the WatchOnlySecretManager
+pub mod watch_only;
 
 #[cfg(feature = "stronghold")]
 use std::time::Duration;
@@ -34,7 +36,9 @@ use self::ledger_nano::LedgerSecretManager;
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
 pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
-use self::{mnemonic::MnemonicSecretManager, placeholder::PlaceholderSecretManager};
+use self::{
+    mnemonic::MnemonicSecretManager, placeholder::PlaceholderSecretManager, watch_only::WatchOnlySecretManager,
+};
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
@@ -135,6 +139,9 @@ pub enum SecretManager {
     /// Secret manager that's just a placeholder, so it can be provided to an online wallet, but can't be used for
     /// signing.
     Placeholder(PlaceholderSecretManager),
+
+    /// Secret manager that only has the public keys of the public addresses, so an account can be synced but not sign.
+    WatchOnly(WatchOnlySecretManager),
 }
 
 impl std::fmt::Debug for SecretManager {
@@ -146,6 +153,10 @@ impl std::fmt::Debug for SecretManager {
             Self::LedgerNano(_) => f.debug_tuple("LedgerNano").field(&"...").finish(),
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
             Self::Placeholder(_) => f.debug_struct("Placeholder").finish(),
+            Self::WatchOnly(secret_manager) => f
+                .debug_tuple("WatchOnly")
+                .field(&secret_manager.hex_public_keys())
+                .finish(),
         }
     }
 }
@@ -180,6 +191,9 @@ pub enum SecretManagerDto {
     /// Placeholder
     #[serde(alias = "placeholder")]
     Placeholder,
+    /// Watch-only, with the hex encoded public keys of the public addresses by key index
+    #[serde(alias = "watchOnly")]
+    WatchOnly(Vec<String>),
 }
 
 impl TryFrom<SecretManagerDto> for SecretManager {
@@ -213,6 +227,10 @@ impl TryFrom<SecretManagerDto> for SecretManager {
             }
 
             SecretManagerDto::Placeholder => Self::Placeholder(PlaceholderSecretManager),
+
+            SecretManagerDto::WatchOnly(public_keys) => {
+                Self::WatchOnly(WatchOnlySecretManager::try_from_hex_public_keys(&public_keys)?)
+            }
         })
     }
 }
@@ -240,6 +258,7 @@ impl From<&SecretManager> for SecretManagerDto {
             // to know the type
             SecretManager::Mnemonic(_mnemonic) => Self::Mnemonic("...".to_string().into()),
             SecretManager::Placeholder(_) => Self::Placeholder,
+            SecretManager::WatchOnly(secret_manager) => Self::WatchOnly(secret_manager.hex_public_keys()),
         }
     }
 }
@@ -274,6 +293,11 @@ impl SecretManage for SecretManager {
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            Self::WatchOnly(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
         }
     }
 
@@ -303,6 +327,11 @@ impl SecretManage for SecretManager {
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            Self::WatchOnly(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
         }
     }
 
@@ -314,6 +343,7 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Placeholder(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::WatchOnly(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
         }
     }
 
@@ -329,6 +359,7 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_evm(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_evm(msg, chain).await,
             Self::Placeholder(secret_manager) => secret_manager.sign_evm(msg, chain).await,
+            Self::WatchOnly(secret_manager) => secret_manager.sign_evm(msg, chain).await,
         }
     }
 }
@@ -359,6 +390,11 @@ impl SignTransactionEssence for SecretManager {
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            Self::WatchOnly(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
         }
     }
 }
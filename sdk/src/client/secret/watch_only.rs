@@ -0,0 +1,127 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`WatchOnlySecretManager`].
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+    signatures::{
+        ed25519,
+        secp256k1_ecdsa::{self, EvmAddress},
+    },
+};
+
+use super::{GenerateAddressOptions, SecretManage, SignTransactionEssence};
+use crate::{
+    client::{secret::PreparedTransactionData, Error},
+    types::block::{address::Ed25519Address, signature::Ed25519Signature, unlock::Unlocks},
+};
+
+/// Secret manager that only knows the public keys of the public addresses of an account, so the account can be synced
+/// without the private keys being on the same machine. The public keys belong to the account with the index 0 of the
+/// coin type they were derived with, the public key at position `i` to the address with the key index `i`. Generating
+/// addresses of other accounts fails with [`Error::WatchOnlyAccountIndex`] and signing with [`Error::WatchOnly`].
+pub struct WatchOnlySecretManager {
+    public_keys: Vec<ed25519::PublicKey>,
+}
+
+impl WatchOnlySecretManager {
+    /// Creates a watch-only secret manager with the public keys of the public addresses, by key index.
+    pub fn new(public_keys: Vec<ed25519::PublicKey>) -> Self {
+        Self { public_keys }
+    }
+
+    /// Creates a watch-only secret manager from hex encoded public keys.
+    pub fn try_from_hex_public_keys(public_keys: &[String]) -> Result<Self, Error> {
+        Ok(Self::new(
+            public_keys
+                .iter()
+                .map(|public_key| {
+                    ed25519::PublicKey::try_from_bytes(prefix_hex::decode(public_key)?).map_err(Error::from)
+                })
+                .collect::<Result<_, Error>>()?,
+        ))
+    }
+
+    /// The public keys of the public addresses, by key index.
+    pub fn public_keys(&self) -> &[ed25519::PublicKey] {
+        &self.public_keys
+    }
+
+    /// The hex encoded public keys of the public addresses, by key index.
+    pub fn hex_public_keys(&self) -> Vec<String> {
+        self.public_keys
+            .iter()
+            .map(|public_key| prefix_hex::encode(public_key.to_bytes()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SecretManage for WatchOnlySecretManager {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        _coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        // The public keys would otherwise be used for the addresses of every account
+        if account_index != 0 {
+            return Err(Error::WatchOnlyAccountIndex(account_index));
+        }
+        // Internal addresses are only needed for remainders, which can't be sent anyway
+        if options.into().map_or(false, |options| options.internal) {
+            return Err(Error::WatchOnly);
+        }
+
+        address_indexes
+            .map(|address_index| {
+                let public_key = self
+                    .public_keys
+                    .get(address_index as usize)
+                    .ok_or(Error::MissingWatchOnlyPublicKey(address_index))?;
+                Ok(Ed25519Address::new(Blake2b256::digest(public_key.to_bytes()).into()))
+            })
+            .collect()
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::WatchOnly)
+    }
+
+    async fn sign_ed25519(&self, _msg: &[u8], _chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
+        Err(Error::WatchOnly)
+    }
+
+    async fn sign_evm(
+        &self,
+        _msg: &[u8],
+        _chain: &Chain,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::Signature), Self::Error> {
+        Err(Error::WatchOnly)
+    }
+}
+
+#[async_trait]
+impl SignTransactionEssence for WatchOnlySecretManager {
+    async fn sign_transaction_essence(
+        &self,
+        _prepared_transaction_data: &PreparedTransactionData,
+        _time: Option<u32>,
+    ) -> Result<Unlocks, <Self as SecretManage>::Error> {
+        Err(Error::WatchOnly)
+    }
+}
@@ -25,9 +25,9 @@
 //! They can also be set later on [`StrongholdAdapter`] using [`set_password()`], [`set_timeout()`], etc.
 //!
 //! With [`set_timeout()`], an automatic task can be spawned in the background to purge the key from memory using
-//! [zeroize] after the `timeout` duration. It's used to reduce the attack vector. When the key is cleared from the
-//! memory, Stronghold will be unloaded from the memory too. If no `snapshot_path` has been set at this point, then
-//! secrets stored in Stronghold will be dropped and lost.
+//! [zeroize] once it wasn't used for signing or address generation during the `timeout` duration. It's used to reduce
+//! the attack vector. When the key is cleared from the memory, Stronghold will be unloaded from the memory too. If no
+//! `snapshot_path` has been set at this point, then secrets stored in Stronghold will be dropped and lost.
 //!
 //! Nevertheless, Stronghold is memory-based, so it's not required to use a snapshot file on the disk. Without a
 //! snapshot path set, [`StrongholdAdapter`] will run purely in memory. If a snapshot path is set, then
@@ -62,7 +62,7 @@ use derive_builder::Builder;
 use iota_stronghold::{KeyProvider, SnapshotPath, Stronghold};
 use log::{debug, error, warn};
 use tokio::{
-    sync::{Mutex, MutexGuard},
+    sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard},
     task::JoinHandle,
 };
 use zeroize::Zeroizing;
@@ -95,7 +95,8 @@ pub struct StrongholdAdapter {
     /// An interval of time, after which `key` will be cleared from the memory.
     ///
     /// This is an extra security measure to further prevent attacks. If a timeout is set, then upon a `key` is set, a
-    /// timer will be spawned in the background to clear ([zeroize]) the key after `timeout`.
+    /// timer will be spawned in the background to clear ([zeroize]) the key after `timeout`. The timer is restarted
+    /// every time the key is used, a zero timeout disables it.
     ///
     /// If a [`StrongholdAdapter`] is destroyed (dropped), then the timer will stop too.
     #[builder(setter(strip_option))]
@@ -108,6 +109,10 @@ pub struct StrongholdAdapter {
     #[builder(setter(custom))]
     timeout_task: Arc<Mutex<Option<JoinHandle<()>>>>,
 
+    /// Held by the operations using the key, so the key clearing task doesn't clear the key in the middle of one.
+    #[builder(setter(skip))]
+    key_in_use: Arc<RwLock<()>>,
+
     /// The path to a Stronghold snapshot file.
     #[builder(setter(skip))]
     pub snapshot_path: PathBuf,
@@ -186,9 +191,12 @@ impl StrongholdAdapterBuilder {
         let has_key_provider = self.key_provider.is_some();
         let key_provider = Arc::new(Mutex::new(self.key_provider));
         let stronghold = Arc::new(Mutex::new(stronghold));
+        let key_in_use = Arc::new(RwLock::new(()));
+        // A zero timeout disables the key clearing
+        let timeout = self.timeout.flatten().filter(|timeout| !timeout.is_zero());
 
         // If both `key` and `timeout` are set, then we spawn the task and keep its join handle.
-        if let (true, Some(Some(timeout))) = (has_key_provider, self.timeout) {
+        if let (true, Some(timeout)) = (has_key_provider, timeout) {
             let timeout_task = Arc::new(Mutex::new(None));
 
             // The key clearing task, with the data it owns.
@@ -200,11 +208,13 @@ impl StrongholdAdapterBuilder {
             // itself is a `fn`. There is also a small delay from the return of this function to the task actually being
             // spawned and set in the `struct`.
             let stronghold_clone = stronghold.clone();
+            let key_in_use = key_in_use.clone();
             tokio::spawn(async move {
                 *task_self.lock().await = Some(tokio::spawn(task_key_clear(
                     task_self.clone(), // LHS moves task_self
                     stronghold_clone,
                     key_provider,
+                    key_in_use,
                     timeout,
                 )));
             });
@@ -217,8 +227,9 @@ impl StrongholdAdapterBuilder {
         Ok(StrongholdAdapter {
             stronghold,
             key_provider,
-            timeout: self.timeout.unwrap_or(None),
+            timeout,
             timeout_task: self.timeout_task.unwrap_or_else(|| Arc::new(Mutex::new(None))),
+            key_in_use,
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
         })
     }
@@ -263,39 +274,46 @@ impl StrongholdAdapter {
         drop(key_provider_guard);
 
         // If a timeout is set, spawn a task to clear the key after the timeout.
-        if let Some(timeout) = self.timeout {
-            // If there has been a spawned task, stop it and re-spawn one.
-            if let Some(timeout_task) = self.timeout_task.lock().await.take() {
-                timeout_task.abort();
-            }
-
-            // The key clearing task, with the data it owns.
-            let task_self = self.timeout_task.clone();
-            let key_provider = self.key_provider.clone();
-
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
-                task_self,
-                self.stronghold.clone(),
-                key_provider,
-                timeout,
-            )));
-        }
+        self.reset_key_clearing_task().await;
 
         Ok(())
     }
 
     /// Change the password of the currently loaded Stronghold.
     ///
-    /// If a snapshot path has been set, then it'll be rewritten with the newly set password.
+    /// A wrong `current_password` fails with [`Error::InvalidPassword`], also if the key was cleared already. Without a
+    /// snapshot, the current password can only be checked against the loaded key, so it fails with
+    /// [`Error::KeyCleared`] if no password was set or the key was cleared. If a snapshot path has been set, then
+    /// it'll be rewritten with the newly set password, the old snapshot is only replaced once the new one is written
+    /// completely. The passwords are zeroized when they're dropped.
     ///
     /// The secrets (e.g. mnemonic) stored in the Stronghold vault will be preserved, but the data saved via the
     /// [`StorageAdapter`] interface won't - they'll stay encrypted with the old password. To re-encrypt these
     /// data, provide a list of keys in `keys_to_re_encrypt`, as we have no way to list and iterate over every
     /// key-value in the Stronghold store - we'll attempt on the ones provided instead. Set it to `None` to skip
     /// re-encryption.
-    pub async fn change_password(&self, new_password: impl Into<Password> + Send) -> Result<(), Error> {
+    pub async fn change_password(
+        &self,
+        current_password: impl Into<Password> + Send,
+        new_password: impl Into<Password> + Send,
+    ) -> Result<(), Error> {
+        let current_password = current_password.into();
         let new_password = new_password.into();
 
+        // Once the key is cleared, the already loaded client accepts any password, so the current one is checked
+        // against the snapshot with a new Stronghold. Without a snapshot, `set_password()` checks it against the loaded
+        // key, there is nothing to check it against if there is none.
+        if self.snapshot_path.exists() {
+            check_or_create_snapshot(
+                &Stronghold::default(),
+                &self::common::key_provider_from_password(current_password.clone()),
+                &SnapshotPath::from_path(&self.snapshot_path),
+            )?;
+        } else if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+        self.set_password(current_password).await?;
+
         // Stop the key clearing task to prevent the key from being abruptly cleared (largely).
         if let Some(timeout_task) = self.timeout_task.lock().await.take() {
             timeout_task.abort();
@@ -328,18 +346,7 @@ impl StrongholdAdapter {
                     error!("an error occurred during the re-encryption of Stronghold Store: {err}");
 
                     // Recover: restart the key clearing task
-                    if let Some(timeout) = self.timeout {
-                        // The key clearing task, with the data it owns.
-                        let task_self = self.timeout_task.clone();
-                        let key_provider = self.key_provider.clone();
-
-                        *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
-                            task_self,
-                            self.stronghold.clone(),
-                            key_provider,
-                            timeout,
-                        )));
-                    }
+                    self.reset_key_clearing_task().await;
 
                     return Err(err);
                 }
@@ -371,18 +378,7 @@ impl StrongholdAdapter {
                 self.read_stronghold_snapshot().await?;
 
                 // Recover: restart key clearing task
-                if let Some(timeout) = self.timeout {
-                    // The key clearing task, with the data it owns.
-                    let task_self = self.timeout_task.clone();
-                    let key_provider = self.key_provider.clone();
-
-                    *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
-                        task_self,
-                        self.stronghold.clone(),
-                        key_provider,
-                        timeout,
-                    )));
-                }
+                self.reset_key_clearing_task().await;
 
                 return Err(err);
             }
@@ -392,18 +388,7 @@ impl StrongholdAdapter {
         self.write_stronghold_snapshot(None).await?;
 
         // Restart the key clearing task.
-        if let Some(timeout) = self.timeout {
-            // The key clearing task, with the data it owns.
-            let task_self = self.timeout_task.clone();
-            let key_provider = self.key_provider.clone();
-
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
-                task_self,
-                self.stronghold.clone(),
-                key_provider,
-                timeout,
-            )));
-        }
+        self.reset_key_clearing_task().await;
 
         Ok(())
     }
@@ -425,7 +410,8 @@ impl StrongholdAdapter {
             }
         }
 
-        // Purge the key, setting it to None then.
+        // Purge the key, setting it to None then, once no operation uses it anymore.
+        let _key_in_use = self.key_in_use.write().await;
         self.key_provider.lock().await.take();
         debug!("cleared stronghold key");
     }
@@ -443,29 +429,54 @@ impl StrongholdAdapter {
     ///
     /// The key won't be cleared.
     pub async fn set_timeout(&mut self, new_timeout: Option<Duration>) {
-        // In any case we terminate the current task (if there is) first.
-        if let Some(timeout_task) = self.timeout_task.lock().await.take() {
-            timeout_task.abort();
-        }
+        // Keep the new timeout, a zero timeout disables the key clearing.
+        self.timeout = new_timeout.filter(|timeout| !timeout.is_zero());
 
-        // Keep the new timeout.
-        self.timeout = new_timeout;
+        // The current task (if there is) is terminated in any case. If a new timeout is set and the key is still in the
+        // memory, a new task is spawned; otherwise we do nothing.
+        self.reset_key_clearing_task().await;
+    }
 
-        // If a new timeout is set and the key is still in the memory, spawn a new task; otherwise we do nothing.
-        if let (Some(_), Some(timeout)) = (self.key_provider.lock().await.as_ref(), self.timeout) {
-            // The key clearing task, with the data it owns.
-            let task_self = self.timeout_task.clone();
-            let key_provider = self.key_provider.clone();
+    /// Set the interval without signing or address generation after which the key is cleared, see [`set_timeout()`].
+    /// Every use of the key restarts the interval, [`Duration::ZERO`] disables the clearing.
+    ///
+    /// [`set_timeout()`]: Self::set_timeout()
+    pub async fn set_password_clear_interval(&mut self, interval: Duration) {
+        self.set_timeout(Some(interval)).await;
+    }
 
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
-                task_self,
+    /// Aborts the key clearing task and spawns a new one if a timeout is set and the key is available, so the key is
+    /// cleared after `timeout` without activity.
+    async fn reset_key_clearing_task(&self) {
+        let mut timeout_task = self.timeout_task.lock().await;
+        if let Some(task) = timeout_task.take() {
+            task.abort();
+        }
+
+        if let (Some(timeout), true) = (self.timeout, self.is_key_available().await) {
+            // The key clearing task, with the data it owns.
+            *timeout_task = Some(tokio::spawn(task_key_clear(
+                self.timeout_task.clone(),
                 self.stronghold.clone(),
-                key_provider,
+                self.key_provider.clone(),
+                self.key_in_use.clone(),
                 timeout,
             )));
         }
     }
 
+    /// Checks that the key is available for an operation using it and restarts the key clearing task. The key isn't
+    /// cleared by the task until the returned guard is dropped.
+    pub(crate) async fn use_key(&self) -> Result<RwLockReadGuard<'_, ()>, Error> {
+        let key_in_use = self.key_in_use.read().await;
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+        self.reset_key_clearing_task().await;
+
+        Ok(key_in_use)
+    }
+
     /// Restart the key clearing task.
     ///
     /// This is equivalent to calling `set_timeout()` with the currently set `timeout`.
@@ -509,10 +520,17 @@ impl StrongholdAdapter {
             return Err(Error::KeyCleared);
         };
 
-        self.stronghold.lock().await.commit_with_keyprovider(
-            &SnapshotPath::from_path(snapshot_path.unwrap_or(&self.snapshot_path)),
-            key_provider,
-        )?;
+        // Write to a temporary file first, so an interrupted write doesn't corrupt the existing snapshot
+        let snapshot_path = snapshot_path.unwrap_or(&self.snapshot_path);
+        let mut temporary_path = snapshot_path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+        let temporary_path = PathBuf::from(temporary_path);
+
+        self.stronghold
+            .lock()
+            .await
+            .commit_with_keyprovider(&SnapshotPath::from_path(&temporary_path), key_provider)?;
+        std::fs::rename(&temporary_path, snapshot_path)?;
 
         Ok(())
     }
@@ -547,10 +565,13 @@ async fn task_key_clear(
     task_self: Arc<Mutex<Option<JoinHandle<()>>>>,
     stronghold: Arc<Mutex<Stronghold>>,
     key_provider: Arc<Mutex<Option<KeyProvider>>>,
+    key_in_use: Arc<RwLock<()>>,
     timeout: Duration,
 ) {
     tokio::time::sleep(timeout).await;
 
+    // Wait for the operations using the key to finish
+    let _key_in_use = key_in_use.write().await;
     debug!("StrongholdAdapter is purging the key");
     key_provider.lock().await.take();
 
@@ -565,7 +586,13 @@ async fn task_key_clear(
 mod tests {
     use std::fs;
 
+    use crypto::keys::slip10::Chain;
+
     use super::*;
+    use crate::client::{
+        constants::{HD_WALLET_TYPE, IOTA_COIN_TYPE},
+        secret::SecretManage,
+    };
 
     #[tokio::test]
     async fn test_clear_key() {
@@ -630,4 +657,89 @@ mod tests {
 
         fs::remove_file(stronghold_path).unwrap();
     }
+
+    #[tokio::test]
+    async fn stronghold_change_password() {
+        let stronghold_path = "stronghold_change_password.stronghold";
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        adapter
+            .store_mnemonic(
+                "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally".to_owned(),
+            )
+            .await
+            .unwrap();
+        let chain = Chain::from_u32_hardened([HD_WALLET_TYPE, IOTA_COIN_TYPE, 0, 0, 0]);
+        let signature = adapter.sign_ed25519(&[0; 32], &chain).await.unwrap();
+
+        // A wrong current password is rejected, also after the key got cleared
+        adapter.clear_key().await;
+        assert!(matches!(
+            adapter
+                .change_password("other_password".to_owned(), "new_password".to_owned())
+                .await,
+            Err(Error::InvalidPassword)
+        ));
+        assert!(
+            StrongholdAdapter::builder()
+                .password("drowssap".to_owned())
+                .build(stronghold_path)
+                .is_ok()
+        );
+
+        adapter
+            .change_password("drowssap".to_owned(), "new_password".to_owned())
+            .await
+            .unwrap();
+        assert!(!Path::new("stronghold_change_password.stronghold.tmp").exists());
+        assert!(matches!(
+            StrongholdAdapter::builder()
+                .password("drowssap".to_owned())
+                .build(stronghold_path),
+            Err(Error::InvalidPassword)
+        ));
+        // The mnemonic can still be used to sign, with the loaded adapter and when the snapshot is loaded again with
+        // the new password
+        assert_eq!(adapter.sign_ed25519(&[0; 32], &chain).await.unwrap(), signature);
+        let reloaded_adapter = StrongholdAdapter::builder()
+            .password("new_password".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        assert_eq!(
+            reloaded_adapter.sign_ed25519(&[0; 32], &chain).await.unwrap(),
+            signature
+        );
+
+        fs::remove_file(stronghold_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn stronghold_change_password_without_snapshot() {
+        let stronghold_path = "stronghold_change_password_without_snapshot.stronghold";
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        fs::remove_file(stronghold_path).unwrap();
+
+        // The current password is checked against the loaded key
+        assert!(matches!(
+            adapter
+                .change_password("other_password".to_owned(), "new_password".to_owned())
+                .await,
+            Err(Error::InvalidPassword)
+        ));
+
+        // Without the key there is nothing to check the current password against
+        adapter.clear_key().await;
+        assert!(matches!(
+            adapter
+                .change_password("other_password".to_owned(), "new_password".to_owned())
+                .await,
+            Err(Error::KeyCleared)
+        ));
+        assert!(!Path::new(stronghold_path).exists());
+    }
 }
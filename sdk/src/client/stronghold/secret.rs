@@ -45,9 +45,8 @@ impl SecretManage for StrongholdAdapter {
         // it doesn't make sense when it comes to our user (signing transactions / generating addresses without a key).
         // Thus, we put an extra guard here to prevent this methods from being invoked when our cached key has
         // been cleared.
-        if !self.is_key_available().await {
-            return Err(Error::KeyCleared);
-        }
+        // The guard also keeps the key from being cleared until the method returns.
+        let _key_in_use = self.use_key().await?;
 
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
@@ -109,9 +108,8 @@ impl SecretManage for StrongholdAdapter {
         // it doesn't make sense when it comes to our user (signing transactions / generating addresses without a key).
         // Thus, we put an extra guard here to prevent this methods from being invoked when our cached key has
         // been cleared.
-        if !self.is_key_available().await {
-            return Err(Error::KeyCleared);
-        }
+        // The guard also keeps the key from being cleared until the method returns.
+        let _key_in_use = self.use_key().await?;
 
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
@@ -161,9 +159,8 @@ impl SecretManage for StrongholdAdapter {
         // it doesn't make sense when it comes to our user (signing transactions / generating addresses without a key).
         // Thus, we put an extra guard here to prevent this methods from being invoked when our cached key has
         // been cleared.
-        if !self.is_key_available().await {
-            return Err(Error::KeyCleared);
-        }
+        // The guard also keeps the key from being cleared until the method returns.
+        let _key_in_use = self.use_key().await?;
 
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
@@ -206,9 +203,8 @@ impl SecretManage for StrongholdAdapter {
         // it doesn't make sense when it comes to our user (signing transactions / generating addresses without a key).
         // Thus, we put an extra guard here to prevent this methods from being invoked when our cached key has
         // been cleared.
-        if !self.is_key_available().await {
-            return Err(Error::KeyCleared);
-        }
+        // The guard also keeps the key from being cleared until the method returns.
+        let _key_in_use = self.use_key().await?;
 
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
@@ -364,10 +360,8 @@ impl StrongholdAdapter {
 
     /// Store a mnemonic into the Stronghold vault.
     pub async fn store_mnemonic(&self, mut mnemonic: String) -> Result<(), Error> {
-        // The key needs to be supplied first.
-        if self.key_provider.lock().await.is_none() {
-            return Err(Error::KeyCleared);
-        };
+        // The key needs to be supplied first, it isn't cleared until the mnemonic is stored.
+        let _key_in_use = self.use_key().await?;
 
         // Stronghold arguments.
         let output = Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH);
@@ -403,7 +397,7 @@ impl StrongholdAdapter {
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{path::Path, time::Duration};
 
     use super::*;
     use crate::{
@@ -519,4 +513,53 @@ mod tests {
         // Remove garbage after test, but don't care about the result
         std::fs::remove_file(stronghold_path).ok();
     }
+
+    #[tokio::test]
+    async fn test_key_cleared_after_inactivity() {
+        // The clock only moves when it's advanced, so the timing doesn't depend on the speed of the machine
+        tokio::time::pause();
+        let stronghold_path = "test_key_cleared_after_inactivity.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = String::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally",
+        );
+        let mut stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .timeout(Duration::from_millis(300))
+            .build(stronghold_path)
+            .unwrap();
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+        let chain = Chain::from_u32_hardened([HD_WALLET_TYPE, IOTA_COIN_TYPE, 0, 0, 0]);
+
+        // Every signature restarts the interval
+        for _ in 0..4 {
+            tokio::time::advance(Duration::from_millis(150)).await;
+            stronghold_adapter.sign_ed25519(&[0; 32], &chain).await.unwrap();
+        }
+        assert!(stronghold_adapter.is_key_available().await);
+
+        tokio::time::advance(Duration::from_millis(300)).await;
+        // Let the clearing task run after its timer fired
+        tokio::task::yield_now().await;
+        assert!(!stronghold_adapter.is_key_available().await);
+        assert!(matches!(
+            stronghold_adapter.sign_ed25519(&[0; 32], &chain).await,
+            Err(Error::KeyCleared)
+        ));
+
+        // After setting the password again it works again
+        stronghold_adapter.set_password("drowssap".to_owned()).await.unwrap();
+        stronghold_adapter.sign_ed25519(&[0; 32], &chain).await.unwrap();
+
+        // A zero interval disables the clearing
+        stronghold_adapter.set_password_clear_interval(Duration::ZERO).await;
+        assert_eq!(stronghold_adapter.get_timeout(), None);
+        tokio::time::advance(Duration::from_millis(500)).await;
+        tokio::task::yield_now().await;
+        stronghold_adapter.sign_ed25519(&[0; 32], &chain).await.unwrap();
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
 }
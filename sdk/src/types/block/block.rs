@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloc::vec::Vec;
-use core::ops::Deref;
+use core::{convert::Infallible, ops::Deref};
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
+#[cfg(feature = "std")]
+use packable::packer::IoPacker;
 use packable::{
     error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
     packer::Packer,
@@ -64,33 +66,40 @@ impl BlockBuilder {
         self
     }
 
-    fn _finish(self) -> Result<(Block, Vec<u8>), Error> {
+    fn _finish(self) -> Result<Block, Error> {
         verify_payload(self.payload.as_ref())?;
 
-        let block = Block {
+        Ok(Block {
             protocol_version: self.protocol_version.unwrap_or(PROTOCOL_VERSION),
             parents: self.parents,
             payload: self.payload,
             nonce: self.nonce.unwrap_or(Self::DEFAULT_NONCE),
-        };
-
-        let block_bytes = block.pack_to_vec();
+        })
+    }
 
-        if block_bytes.len() > Block::LENGTH_MAX {
-            return Err(Error::InvalidBlockLength(block_bytes.len()));
+    fn verify_length(block_length: usize) -> Result<(), Error> {
+        if block_length > Block::LENGTH_MAX {
+            return Err(Error::InvalidBlockLength(block_length));
         }
 
-        Ok((block, block_bytes))
+        Ok(())
     }
 
     /// Finishes the [`BlockBuilder`] into a [`Block`].
     pub fn finish(self) -> Result<Block, Error> {
-        self._finish().map(|res| res.0)
+        let block = self._finish()?;
+        // Only the length is needed here, the block doesn't have to be packed
+        Self::verify_length(block.packed_len())?;
+
+        Ok(block)
     }
 
     /// Finishes the [`BlockBuilder`] into a [`Block`], computing the nonce with a given provider.
     pub fn finish_nonce<F: Fn(&[u8]) -> Option<u64>>(self, nonce_provider: F) -> Result<Block, Error> {
-        let (mut block, block_bytes) = self._finish()?;
+        let mut block = self._finish()?;
+        // Packed once, the same bytes are used for the length check and the PoW
+        let block_bytes = block.pack_to_vec();
+        Self::verify_length(block_bytes.len())?;
 
         block.nonce = nonce_provider(&block_bytes[..block_bytes.len() - core::mem::size_of::<u64>()])
             .ok_or(Error::NonceNotFound)?;
@@ -152,7 +161,19 @@ impl Block {
     /// Computes the identifier of the block.
     #[inline(always)]
     pub fn id(&self) -> BlockId {
-        BlockId::new(Blake2b256::digest(self.pack_to_vec()).into())
+        let mut hasher = HashPacker(Blake2b256::new());
+        // Hashing the bytes can't fail
+        if let Err(e) = self.pack(&mut hasher) {
+            match e {}
+        }
+
+        BlockId::new(hasher.0.finalize().into())
+    }
+
+    /// Packs the [`Block`] into a writer, without allocating an intermediate buffer.
+    #[cfg(feature = "std")]
+    pub fn pack_into(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.pack(&mut IoPacker::new(writer))
     }
 
     /// Consumes the [`Block`], and returns ownership over its [`Parents`].
@@ -239,6 +260,18 @@ impl Packable for Block {
     }
 }
 
+/// A [`Packer`] that hashes the bytes instead of storing them.
+struct HashPacker(Blake2b256);
+
+impl Packer for HashPacker {
+    type Error = Infallible;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.update(bytes.as_ref());
+        Ok(())
+    }
+}
+
 fn verify_payload(payload: Option<&Payload>) -> Result<(), Error> {
     if !matches!(
         payload,
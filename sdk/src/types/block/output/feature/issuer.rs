@@ -3,7 +3,7 @@
 
 use derive_more::From;
 
-use crate::types::block::address::Address;
+use crate::types::block::address::{Address, AliasAddress, Ed25519Address, NftAddress};
 
 /// Identifies the validated issuer of the UTXO state machine.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, From, packable::Packable)]
@@ -25,18 +25,66 @@ impl IssuerFeature {
     pub fn address(&self) -> &Address {
         &self.0
     }
+
+    /// Returns the issuer address as an [`Ed25519Address`], if it is one.
+    /// Unlike [`Address::as_ed25519()`], this doesn't panic on other addresses.
+    pub fn as_ed25519(&self) -> Option<&Ed25519Address> {
+        match &self.0 {
+            Address::Ed25519(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    /// Returns the issuer address as an [`AliasAddress`], if it is one.
+    /// Unlike [`Address::as_alias()`], this doesn't panic on other addresses.
+    pub fn as_alias(&self) -> Option<&AliasAddress> {
+        match &self.0 {
+            Address::Alias(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    /// Returns the issuer address as an [`NftAddress`], if it is one.
+    /// Unlike [`Address::as_nft()`], this doesn't panic on other addresses.
+    pub fn as_nft(&self) -> Option<&NftAddress> {
+        match &self.0 {
+            Address::Nft(address) => Some(address),
+            _ => None,
+        }
+    }
 }
 
 #[allow(missing_docs)]
 pub mod dto {
-    use serde::{Deserialize, Serialize};
+    use alloc::format;
+
+    use serde::{Deserialize, Deserializer, Serialize};
 
+    use super::IssuerFeature;
     use crate::types::block::address::dto::AddressDto;
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
     pub struct IssuerFeatureDto {
-        #[serde(rename = "type")]
+        /// Defaults to [`IssuerFeature::KIND`] if absent, as it can't be anything else.
+        #[serde(rename = "type", default = "kind", deserialize_with = "deserialize_kind")]
         pub kind: u8,
         pub address: AddressDto,
     }
+
+    fn kind() -> u8 {
+        IssuerFeature::KIND
+    }
+
+    fn deserialize_kind<'de, D: Deserializer<'de>>(d: D) -> Result<u8, D::Error> {
+        let kind = u8::deserialize(d)?;
+
+        if kind != IssuerFeature::KIND {
+            return Err(serde::de::Error::custom(format!(
+                "invalid issuer feature type {kind}, expected {}",
+                IssuerFeature::KIND
+            )));
+        }
+
+        Ok(kind)
+    }
 }
@@ -15,7 +15,11 @@ use packable::{bounded::BoundedU8, prefix::BoxedSlicePrefix, Packable};
 
 pub use self::{issuer::IssuerFeature, metadata::MetadataFeature, sender::SenderFeature, tag::TagFeature};
 pub(crate) use self::{metadata::MetadataFeatureLength, tag::TagFeatureLength};
-use crate::types::block::{create_bitflags, Error};
+use crate::types::block::{
+    create_bitflags,
+    output::{AliasOutput, BasicOutput, FoundryOutput, NftOutput, TreasuryOutput},
+    Error,
+};
 
 ///
 #[derive(Clone, Eq, PartialEq, Hash, From, Packable)]
@@ -264,6 +268,26 @@ impl Features {
             None
         }
     }
+
+    /// Verifies that the features are allowed in an output of the given kind, either as its features or, if
+    /// `immutable` is true, as its immutable features. E.g. an [`IssuerFeature`] is only allowed in the immutable
+    /// features of alias and NFT outputs.
+    /// Building an output already performs this check, this allows to catch unallowed features before.
+    pub fn verify_allowed_in_output(&self, output_kind: u8, immutable: bool) -> Result<(), Error> {
+        let allowed_features = match (output_kind, immutable) {
+            (BasicOutput::KIND, false) => BasicOutput::ALLOWED_FEATURES,
+            (AliasOutput::KIND, false) => AliasOutput::ALLOWED_FEATURES,
+            (AliasOutput::KIND, true) => AliasOutput::ALLOWED_IMMUTABLE_FEATURES,
+            (FoundryOutput::KIND, false) => FoundryOutput::ALLOWED_FEATURES,
+            (FoundryOutput::KIND, true) => FoundryOutput::ALLOWED_IMMUTABLE_FEATURES,
+            (NftOutput::KIND, false) => NftOutput::ALLOWED_FEATURES,
+            (NftOutput::KIND, true) => NftOutput::ALLOWED_IMMUTABLE_FEATURES,
+            (BasicOutput::KIND | TreasuryOutput::KIND, _) => FeatureFlags::empty(),
+            _ => return Err(Error::InvalidOutputKind(output_kind)),
+        };
+
+        verify_allowed_features(self, allowed_features)
+    }
 }
 
 #[inline]
@@ -304,6 +328,106 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn issuer_address_variants() {
+        use crate::types::block::{
+            address::{AliasAddress, Ed25519Address, NftAddress},
+            output::{AliasId, NftId},
+        };
+
+        let ed25519 = IssuerFeature::new(Ed25519Address::new([1; 32]));
+        assert_eq!(ed25519.as_ed25519(), Some(&Ed25519Address::new([1; 32])));
+        assert_eq!(ed25519.as_alias(), None);
+        assert_eq!(ed25519.as_nft(), None);
+
+        let alias = IssuerFeature::new(AliasAddress::new(AliasId::new([2; 32])));
+        assert_eq!(alias.as_ed25519(), None);
+        assert_eq!(alias.as_alias(), Some(&AliasAddress::new(AliasId::new([2; 32]))));
+        assert_eq!(alias.as_nft(), None);
+
+        let nft = IssuerFeature::new(NftAddress::new(NftId::new([3; 32])));
+        assert_eq!(nft.as_ed25519(), None);
+        assert_eq!(nft.as_alias(), None);
+        assert_eq!(nft.as_nft(), Some(&NftAddress::new(NftId::new([3; 32]))));
+    }
+
+    #[test]
+    fn issuer_feature_dto_kind() {
+        use crate::types::block::{address::dto::AddressDto, output::feature::dto::IssuerFeatureDto};
+
+        let address = serde_json::json!({
+            "type": 0,
+            "pubKeyHash": "0x0000000000000000000000000000000000000000000000000000000000000000"
+        });
+        let expected = IssuerFeatureDto {
+            kind: IssuerFeature::KIND,
+            address: serde_json::from_value::<AddressDto>(address.clone()).unwrap(),
+        };
+
+        let with_kind = serde_json::json!({ "type": 1, "address": address });
+        assert_eq!(serde_json::from_value::<IssuerFeatureDto>(with_kind).unwrap(), expected);
+
+        let without_kind = serde_json::json!({ "address": address });
+        assert_eq!(
+            serde_json::from_value::<IssuerFeatureDto>(without_kind).unwrap(),
+            expected
+        );
+
+        let wrong_kind = serde_json::json!({ "type": 0, "address": address });
+        let error = serde_json::from_value::<IssuerFeatureDto>(wrong_kind).unwrap_err();
+        assert_eq!(error.to_string(), "invalid issuer feature type 0, expected 1");
+    }
+
+    #[test]
+    fn verify_allowed_in_output() {
+        use crate::types::block::address::{Address, Ed25519Address};
+
+        let address = Address::from(Ed25519Address::new([0; 32]));
+        let issuer = Features::from_vec(vec![Feature::Issuer(IssuerFeature::new(address))]).unwrap();
+        let sender_and_issuer = Features::from_vec(vec![
+            Feature::Sender(SenderFeature::new(address)),
+            Feature::Issuer(IssuerFeature::new(address)),
+        ])
+        .unwrap();
+
+        assert!(issuer.verify_allowed_in_output(AliasOutput::KIND, true).is_ok());
+        assert!(issuer.verify_allowed_in_output(NftOutput::KIND, true).is_ok());
+        assert!(
+            Features::from_vec(Vec::new())
+                .unwrap()
+                .verify_allowed_in_output(BasicOutput::KIND, true)
+                .is_ok()
+        );
+
+        for (output_kind, immutable) in [
+            (BasicOutput::KIND, false),
+            (BasicOutput::KIND, true),
+            (AliasOutput::KIND, false),
+            (FoundryOutput::KIND, true),
+            (NftOutput::KIND, false),
+            (TreasuryOutput::KIND, false),
+        ] {
+            assert_eq!(
+                issuer.verify_allowed_in_output(output_kind, immutable),
+                Err(Error::UnallowedFeature {
+                    index: 0,
+                    kind: IssuerFeature::KIND
+                })
+            );
+        }
+        assert_eq!(
+            sender_and_issuer.verify_allowed_in_output(BasicOutput::KIND, false),
+            Err(Error::UnallowedFeature {
+                index: 1,
+                kind: IssuerFeature::KIND
+            })
+        );
+        assert_eq!(
+            issuer.verify_allowed_in_output(42, true),
+            Err(Error::InvalidOutputKind(42))
+        );
+    }
 }
 
 #[allow(missing_docs)]
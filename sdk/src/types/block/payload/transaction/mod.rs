@@ -56,6 +56,25 @@ impl TransactionPayload {
     }
 }
 
+#[cfg(feature = "rand")]
+impl TransactionPayload {
+    /// Returns a mock of this type with the given essence of a single input, which is unlocked by a dummy signature.
+    pub fn rand_mock(essence: TransactionEssence) -> Self {
+        use crate::types::block::{
+            signature::{Ed25519Signature, Signature},
+            unlock::{SignatureUnlock, Unlock},
+        };
+
+        let signature = Signature::Ed25519(Ed25519Signature::new([0; 32], [0; 64]));
+
+        Self::new(
+            essence,
+            Unlocks::new([Unlock::Signature(SignatureUnlock::from(signature))]).unwrap(),
+        )
+        .unwrap()
+    }
+}
+
 impl Packable for TransactionPayload {
     type UnpackError = Error;
     type UnpackVisitor = ProtocolParameters;
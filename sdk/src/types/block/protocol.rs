@@ -47,6 +47,109 @@ impl Borrow<()> for ProtocolParameters {
 
 impl Default for ProtocolParameters {
     fn default() -> Self {
+        Self::mainnet_defaults()
+    }
+}
+
+/// Builder for a [`ProtocolParameters`], starting from the [`ProtocolParameters::mainnet_defaults()`] or from the
+/// parameters it's converted from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct ProtocolParametersBuilder {
+    protocol_version: u8,
+    network_name: String,
+    bech32_hrp: Hrp,
+    min_pow_score: u32,
+    below_max_depth: u8,
+    rent_structure: RentStructure,
+    token_supply: u64,
+}
+
+impl Default for ProtocolParametersBuilder {
+    fn default() -> Self {
+        ProtocolParameters::mainnet_defaults().into()
+    }
+}
+
+impl From<ProtocolParameters> for ProtocolParametersBuilder {
+    fn from(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            protocol_version: protocol_parameters.protocol_version,
+            network_name: protocol_parameters.network_name().into(),
+            bech32_hrp: protocol_parameters.bech32_hrp,
+            min_pow_score: protocol_parameters.min_pow_score,
+            below_max_depth: protocol_parameters.below_max_depth,
+            rent_structure: protocol_parameters.rent_structure,
+            token_supply: protocol_parameters.token_supply,
+        }
+    }
+}
+
+impl ProtocolParametersBuilder {
+    /// Returns a new [`ProtocolParametersBuilder`] with the mainnet defaults.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the protocol version.
+    pub fn protocol_version(mut self, protocol_version: u8) -> Self {
+        self.protocol_version = protocol_version;
+        self
+    }
+
+    /// Sets the network name, which also determines the network ID.
+    pub fn network_name(mut self, network_name: impl Into<String>) -> Self {
+        self.network_name = network_name.into();
+        self
+    }
+
+    /// Sets the bech32 HRP.
+    pub fn bech32_hrp(mut self, bech32_hrp: Hrp) -> Self {
+        self.bech32_hrp = bech32_hrp;
+        self
+    }
+
+    /// Sets the minimum PoW score, 0 disables PoW.
+    pub fn min_pow_score(mut self, min_pow_score: u32) -> Self {
+        self.min_pow_score = min_pow_score;
+        self
+    }
+
+    /// Sets the below max depth.
+    pub fn below_max_depth(mut self, below_max_depth: u8) -> Self {
+        self.below_max_depth = below_max_depth;
+        self
+    }
+
+    /// Sets the rent structure.
+    pub fn rent_structure(mut self, rent_structure: RentStructure) -> Self {
+        self.rent_structure = rent_structure;
+        self
+    }
+
+    /// Sets the token supply.
+    pub fn token_supply(mut self, token_supply: u64) -> Self {
+        self.token_supply = token_supply;
+        self
+    }
+
+    /// Returns the built [`ProtocolParameters`], fails if the network name is too long.
+    pub fn finish(self) -> Result<ProtocolParameters, Error> {
+        ProtocolParameters::new(
+            self.protocol_version,
+            self.network_name,
+            self.bech32_hrp,
+            self.min_pow_score,
+            self.below_max_depth,
+            self.rent_structure,
+            self.token_supply,
+        )
+    }
+}
+
+impl ProtocolParameters {
+    /// Returns the parameters of the Shimmer mainnet, which are also the [`Default`].
+    pub fn mainnet_defaults() -> Self {
         // PANIC: These values are known to be correct.
         Self::new(
             PROTOCOL_VERSION,
@@ -59,9 +162,27 @@ impl Default for ProtocolParameters {
         )
         .unwrap()
     }
-}
 
-impl ProtocolParameters {
+    /// Returns the parameters of the Shimmer testnet.
+    pub fn testnet_defaults() -> Self {
+        // PANIC: These values are known to be correct.
+        Self::new(
+            PROTOCOL_VERSION,
+            String::from("testnet"),
+            "rms",
+            1500,
+            15,
+            RentStructure::default(),
+            1_813_620_509_061_365,
+        )
+        .unwrap()
+    }
+
+    /// Returns a builder for a [`ProtocolParameters`], to set only some of the parameters in tests or offline.
+    pub fn builder() -> ProtocolParametersBuilder {
+        ProtocolParametersBuilder::new()
+    }
+
     /// Creates a new [`ProtocolParameters`].
     pub fn new(
         protocol_version: u8,
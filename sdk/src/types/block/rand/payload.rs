@@ -3,7 +3,7 @@
 
 use crate::types::block::{
     payload::{
-        milestone::{MilestoneEssence, MilestoneOptions, MilestonePayload},
+        milestone::{MilestoneEssence, MilestoneOption, MilestoneOptions, MilestonePayload, ReceiptMilestoneOption},
         tagged_data::TaggedDataPayload,
         treasury_transaction::TreasuryTransactionPayload,
         Payload,
@@ -15,6 +15,7 @@ use crate::types::block::{
         number::{rand_number, rand_number_range},
         output::rand_treasury_output,
         parents::rand_parents,
+        receipt::rand_migrated_funds_entry,
     },
     signature::{Ed25519Signature, Signature},
 };
@@ -55,6 +56,39 @@ pub fn rand_milestone_payload(protocol_version: u8) -> MilestonePayload {
     MilestonePayload::new(essence, signatures).unwrap()
 }
 
+/// Generates a random milestone payload with a receipt of two migrated funds entries.
+pub fn rand_milestone_payload_with_receipt(protocol_version: u8, token_supply: u64) -> MilestonePayload {
+    let receipt = ReceiptMilestoneOption::new(
+        rand_milestone_index(),
+        true,
+        [
+            rand_migrated_funds_entry(token_supply / 2),
+            rand_migrated_funds_entry(token_supply / 2),
+        ],
+        rand_treasury_transaction_payload(token_supply),
+        token_supply,
+    )
+    .unwrap();
+    let essence = MilestoneEssence::new(
+        rand_milestone_index(),
+        rand_number(),
+        protocol_version,
+        rand_milestone_id(),
+        rand_parents(),
+        rand_merkle_root(),
+        rand_merkle_root(),
+        rand_bytes(32),
+        MilestoneOptions::from_vec(vec![MilestoneOption::Receipt(receipt)]).unwrap(),
+    )
+    .unwrap();
+    let signatures = [Signature::from(Ed25519Signature::new(
+        rand_bytes_array(),
+        rand_bytes_array(),
+    ))];
+
+    MilestonePayload::new(essence, signatures).unwrap()
+}
+
 /// Generates a random payload for a block.
 pub fn rand_payload_for_block() -> Payload {
     // TODO complete
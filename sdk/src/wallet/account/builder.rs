@@ -9,7 +9,10 @@ use crate::{
     client::{api::GetAddressesOptions, secret::SecretManager},
     types::block::address::{Bech32Address, Hrp},
     wallet::{
-        account::{types::AccountAddress, Account, AccountDetails},
+        account::{
+            constants::DEFAULT_ADDRESS_GAP_LIMIT, operations::changes::RecordMilestones, output_index::OutputIndex,
+            types::AccountAddress, Account, AccountDetails,
+        },
         Error, Wallet,
     },
 };
@@ -147,7 +150,14 @@ impl AccountBuilder {
             pending_transactions: HashSet::new(),
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
+            legacy_migrations: HashMap::new(),
             native_token_foundries: HashMap::new(),
+            output_index: OutputIndex::default(),
+            last_processed_milestone: None,
+            record_milestones: RecordMilestones::default(),
+            remainder_address_uses: HashMap::new(),
+            address_gap_limit: DEFAULT_ADDRESS_GAP_LIMIT,
+            address_labels: HashMap::new(),
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
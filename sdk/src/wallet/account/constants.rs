@@ -8,6 +8,14 @@ pub(crate) const DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 100;
 #[cfg(feature = "ledger_nano")]
 pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 
+/// Number of consecutive public addresses without outputs that are kept generated and synced after the last used one,
+/// by default only the already generated addresses are synced
+pub const DEFAULT_ADDRESS_GAP_LIMIT: u32 = 0;
+
+/// Number of milestones for which the milestone indexes of changed records are kept, about a week with milestones every
+/// five seconds
+pub(crate) const RECORD_MILESTONES_WINDOW: u32 = 120_960;
+
 /// Amount of API request that can be sent in parallel during syncing
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
@@ -17,3 +25,7 @@ pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+/// Amount of outputs that are claimed in a single transaction, only the half of the max input count, because every
+/// claimed output could require a storage deposit return output and additional inputs could be needed
+pub(crate) const CLAIM_OUTPUTS_BATCH_SIZE: usize = crate::types::block::input::INPUT_COUNT_MAX as usize / 2;
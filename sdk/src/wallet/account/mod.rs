@@ -7,41 +7,51 @@ pub(crate) mod builder;
 pub(crate) mod constants;
 /// The account operations like address generation, syncing and creating transactions.
 pub(crate) mod operations;
+/// Indexes of the unspent outputs for fast lookups.
+pub(crate) mod output_index;
 /// Types used in an account and returned from methods.
 pub mod types;
 /// Methods to update the account state.
 pub(crate) mod update;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     ops::Deref,
     str::FromStr,
     sync::Arc,
 };
 
 use getset::{Getters, Setters};
+use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction,
+use self::{
+    operations::changes::RecordMilestones,
+    output_index::OutputIndex,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        Balance, LegacyMigration, OutputData, Transaction,
+    },
 };
 pub use self::{
+    constants::DEFAULT_ADDRESS_GAP_LIMIT,
     operations::{
+        changes::{AccountChange, AccountChanges},
         output_claiming::OutputsToClaim,
         syncing::{
-            options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
+            options::{AccountSyncOptions, AddressReuseCallback, AliasSyncOptions, NftSyncOptions},
             SyncOptions,
         },
         transaction::{
             high_level::{
+                compose::TransactionComposer,
                 create_alias::{CreateAliasParams, CreateAliasParamsDto},
                 minting::{
-                    mint_native_token::{
-                        MintNativeTokenParams, MintNativeTokenParamsDto, MintTokenTransactionDto,
+                    create_native_token::{
+                        CreateNativeTokenParams, CreateNativeTokenParamsDto, MintTokenTransactionDto,
                         PreparedMintTokenTransactionDto,
                     },
                     mint_nfts::{MintNftParams, MintNftParamsDto},
@@ -50,8 +60,11 @@ pub use self::{
             prepare_output::{
                 Assets, Features, OutputParams, OutputParamsDto, ReturnStrategy, StorageDeposit, Unlocks,
             },
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            RemainderAddressRotation, RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
         },
+        token_overview::TokenOverview,
+        transaction_history::{ExportFormat, TransactionPage, TransactionSort},
+        verify_integrity::{IntegrityIssue, IntegrityReport},
     },
     types::OutputDataDto,
 };
@@ -61,15 +74,16 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
-            output::{AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
+            address::{Address, Bech32Address},
+            output::{AliasId, ChainId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
                 TransactionPayload,
             },
-            BlockId,
+            BlockId, ConvertTo,
         },
     },
-    wallet::{account::types::InclusionState, Result},
+    wallet::{account::types::InclusionState, Error, Result},
 };
 
 /// Options to filter outputs
@@ -90,6 +104,38 @@ pub struct FilterOptions {
     pub nft_ids: Option<HashSet<NftId>>,
 }
 
+impl FilterOptions {
+    fn chain_ids(&self) -> impl Iterator<Item = ChainId> + '_ {
+        self.alias_ids
+            .iter()
+            .flatten()
+            .map(|alias_id| ChainId::from(*alias_id))
+            .chain(
+                self.foundry_ids
+                    .iter()
+                    .flatten()
+                    .map(|foundry_id| ChainId::from(*foundry_id)),
+            )
+            .chain(self.nft_ids.iter().flatten().map(|nft_id| ChainId::from(*nft_id)))
+    }
+
+    fn matches_output_type(&self, output: &Output) -> bool {
+        self.output_types
+            .as_ref()
+            .map_or(true, |output_types| output_types.contains(&output.kind()))
+    }
+
+    fn matches_booked_timestamp(&self, output: &OutputData) -> bool {
+        let timestamp = output.metadata.milestone_timestamp_booked();
+
+        self.lower_bound_booked_timestamp
+            .map_or(true, |lower_bound| timestamp >= lower_bound)
+            && self
+                .upper_bound_booked_timestamp
+                .map_or(true, |upper_bound| timestamp <= upper_bound)
+    }
+}
+
 /// Details of an account.
 #[derive(Clone, Debug, Eq, PartialEq, Getters, Setters, Serialize, Deserialize)]
 #[getset(get = "pub")]
@@ -134,9 +180,43 @@ pub struct AccountDetails {
     /// set, because another node might still have them.
     #[serde(default)]
     inaccessible_incoming_transactions: HashSet<TransactionId>,
+    /// Funds migrated from the legacy network to the account, by the id of the output that was created for them.
+    /// These outputs have no incoming transaction, they were created by the receipt of a milestone.
+    #[serde(default)]
+    legacy_migrations: HashMap<OutputId, LegacyMigration>,
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Indexes of the unspent outputs, kept in sync with `unspent_outputs`
+    // missing for accounts stored by older versions, in which case it's rebuilt when the account is loaded
+    #[serde(default)]
+    #[getset(skip)]
+    output_index: OutputIndex,
+    /// The highest milestone index that was fully processed by syncing
+    #[serde(default)]
+    #[getset(skip)]
+    pub(crate) last_processed_milestone: Option<u32>,
+    /// Milestone indexes of the syncs in which outputs and transactions changed
+    // missing for records stored by older versions, which are then included conservatively in the changes
+    #[serde(default)]
+    #[getset(skip)]
+    pub(crate) record_milestones: RecordMilestones,
+    /// Number of remainders that were sent to internal addresses, by key index
+    #[serde(default)]
+    #[getset(skip)]
+    pub(crate) remainder_address_uses: HashMap<u32, u32>,
+    /// Number of consecutive public addresses without outputs that are synced after the last used one
+    #[serde(default = "default_address_gap_limit")]
+    #[getset(skip)]
+    pub(crate) address_gap_limit: u32,
+    /// Labels of addresses of the account, by bech32 address
+    #[serde(default)]
+    #[getset(skip)]
+    pub(crate) address_labels: HashMap<String, String>,
+}
+
+fn default_address_gap_limit() -> u32 {
+    DEFAULT_ADDRESS_GAP_LIMIT
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -154,6 +234,7 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    pub(crate) remainder_address_rotation: Mutex<RemainderAddressRotation>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -167,7 +248,12 @@ impl Deref for Account {
 
 impl Account {
     /// Create a new Account with an AccountDetails
-    pub(crate) async fn new(details: AccountDetails, wallet: Arc<WalletInner>) -> Result<Self> {
+    pub(crate) async fn new(mut details: AccountDetails, wallet: Arc<WalletInner>) -> Result<Self> {
+        // Accounts stored by older versions have no or an incomplete index
+        if details.output_index.len() != details.unspent_outputs.len() {
+            details.output_index = OutputIndex::new(details.unspent_outputs.values());
+        }
+
         #[cfg(feature = "storage")]
         let default_sync_options = wallet
             .storage_manager
@@ -179,12 +265,36 @@ impl Account {
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
 
+        #[cfg(feature = "storage")]
+        let remainder_address_rotation = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_remainder_address_rotation(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let remainder_address_rotation = Default::default();
+
+        // Restored so the `MIN_SYNC_INTERVAL` also applies to the first sync after a restart
+        #[cfg(feature = "storage")]
+        let last_synced = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_last_synced(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let last_synced = Default::default();
+
         Ok(Self {
             wallet,
             inner: Arc::new(AccountInner {
                 details: RwLock::new(details),
-                last_synced: Default::default(),
+                last_synced: Mutex::new(last_synced),
                 default_sync_options: Mutex::new(default_sync_options),
+                remainder_address_rotation: Mutex::new(remainder_address_rotation),
             }),
         })
     }
@@ -198,14 +308,21 @@ impl Account {
     /// from the account, if it isn't in the account try to get it from the node
     pub async fn get_foundry_output(&self, native_token_id: TokenId) -> Result<Output> {
         let foundry_id = FoundryId::from(native_token_id);
+        let account_details = self.details().await;
 
-        for output_data in self.details().await.outputs().values() {
+        if let Some(output_data) = account_details.unspent_chain_output(ChainId::from(foundry_id)) {
+            return Ok(output_data.output.clone());
+        }
+
+        // The foundry could also have been spent already, its immutable data is still the same
+        for output_data in account_details.outputs().values() {
             if let Output::Foundry(foundry_output) = &output_data.output {
                 if foundry_output.id() == foundry_id {
                     return Ok(output_data.output.clone());
                 }
             }
         }
+        drop(account_details);
 
         // Foundry was not found in the account, try to get it from the node
         let foundry_output_id = self.client().foundry_output_id(foundry_id).await?;
@@ -255,6 +372,12 @@ impl AccountInner {
         self.details().await.alias.clone()
     }
 
+    /// Get the number of consecutive public addresses without outputs that are synced after the last used one, see
+    /// [`Account::set_address_gap_limit()`]
+    pub async fn address_gap_limit(&self) -> u32 {
+        self.details().await.address_gap_limit
+    }
+
     /// Get the [`OutputData`] of an output stored in the account
     pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
         self.details().await.outputs().get(output_id).cloned()
@@ -283,6 +406,23 @@ impl AccountInner {
         Ok(all_addresses.to_vec())
     }
 
+    /// Returns the address of the account with the given label, see [`Account::label_address()`]
+    pub async fn address_by_label(&self, label: &str) -> Result<Option<AccountAddress>> {
+        let account_details = self.details().await;
+        let address = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .find(|address| {
+                account_details
+                    .address_labels
+                    .get(&address.address.to_string())
+                    .map_or(false, |address_label| address_label == label)
+            })
+            .cloned();
+        Ok(address)
+    }
+
     /// Returns all public addresses of the account
     pub(crate) async fn public_addresses(&self) -> Vec<AccountAddress> {
         self.details().await.public_addresses().to_vec()
@@ -301,61 +441,17 @@ impl AccountInner {
         let filter = filter.into();
 
         if let Some(filter) = filter {
-            let mut filtered_outputs = Vec::new();
-
-            for output in outputs {
-                match &output.output {
-                    Output::Alias(alias) => {
-                        if let Some(alias_ids) = &filter.alias_ids {
-                            let alias_id = alias.alias_id_non_null(&output.output_id);
-                            if alias_ids.contains(&alias_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Foundry(foundry) => {
-                        if let Some(foundry_ids) = &filter.foundry_ids {
-                            let foundry_id = foundry.id();
-                            if foundry_ids.contains(&foundry_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Nft(nft) => {
-                        if let Some(nft_ids) = &filter.nft_ids {
-                            let nft_id = nft.nft_id_non_null(&output.output_id);
-                            if nft_ids.contains(&nft_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-
-                if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-
-                if let Some(output_types) = &filter.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-
-                filtered_outputs.push(output.clone());
-            }
-
-            Ok(filtered_outputs)
+            let chain_ids = filter.chain_ids().collect::<HashSet<_>>();
+
+            Ok(outputs
+                .filter(|output| {
+                    output
+                        .chain_id()
+                        .map_or(false, |chain_id| chain_ids.contains(&chain_id))
+                        || (filter.matches_output_type(&output.output) && filter.matches_booked_timestamp(output))
+                })
+                .cloned()
+                .collect())
         } else {
             Ok(outputs.cloned().collect())
         }
@@ -363,42 +459,78 @@ impl AccountInner {
 
     /// Returns outputs of the account
     pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
+        // Spent outputs aren't indexed, so all outputs are filtered
         self.filter_outputs(self.details().await.outputs.values(), filter)
     }
 
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
+        let account_details = self.details().await;
+
+        Ok(match filter.into() {
+            Some(filter) => account_details.filter_unspent_outputs(&filter).cloned().collect(),
+            None => account_details.unspent_outputs.values().cloned().collect(),
+        })
     }
 
     /// Gets the unspent alias output matching the given ID.
     pub async fn unspent_alias_output(&self, alias_id: &AliasId) -> Result<Option<OutputData>> {
-        self.unspent_outputs(FilterOptions {
-            alias_ids: Some([*alias_id].into()),
-            ..Default::default()
-        })
-        .await
-        .map(|res| res.get(0).cloned())
+        Ok(self
+            .details()
+            .await
+            .unspent_chain_output(ChainId::from(*alias_id))
+            .cloned())
     }
 
     /// Gets the unspent foundry output matching the given ID.
     pub async fn unspent_foundry_output(&self, foundry_id: &FoundryId) -> Result<Option<OutputData>> {
-        self.unspent_outputs(FilterOptions {
-            foundry_ids: Some([*foundry_id].into()),
-            ..Default::default()
-        })
-        .await
-        .map(|res| res.get(0).cloned())
+        Ok(self
+            .details()
+            .await
+            .unspent_chain_output(ChainId::from(*foundry_id))
+            .cloned())
     }
 
     /// Gets the unspent nft output matching the given ID.
     pub async fn unspent_nft_output(&self, nft_id: &NftId) -> Result<Option<OutputData>> {
-        self.unspent_outputs(FilterOptions {
-            nft_ids: Some([*nft_id].into()),
-            ..Default::default()
-        })
-        .await
-        .map(|res| res.get(0).cloned())
+        Ok(self
+            .details()
+            .await
+            .unspent_chain_output(ChainId::from(*nft_id))
+            .cloned())
+    }
+
+    /// Returns the unspent outputs that have the given address in one of their unlock conditions.
+    pub async fn unspent_outputs_with_address(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<Vec<OutputData>> {
+        let address = address.convert()?;
+        Ok(self
+            .details()
+            .await
+            .unspent_outputs_with_address(address.inner())
+            .cloned()
+            .collect())
+    }
+
+    /// Returns the unspent outputs that hold the given native token.
+    pub async fn unspent_outputs_with_native_token(&self, token_id: &TokenId) -> Vec<OutputData> {
+        self.details()
+            .await
+            .unspent_outputs_with_native_token(token_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the amounts of all native tokens held by the unspent outputs of the account, summed by token ID.
+    pub async fn native_token_balances(&self) -> Result<BTreeMap<TokenId, U256>> {
+        self.details().await.native_token_balances()
+    }
+
+    /// Returns the amount of a native token held by the unspent outputs of the account.
+    pub async fn native_token_balance(&self, token_id: &TokenId) -> Result<U256> {
+        self.details().await.native_token_balance(token_id)
     }
 
     /// Returns all incoming transactions of the account
@@ -406,6 +538,11 @@ impl AccountInner {
         self.details().await.incoming_transactions.values().cloned().collect()
     }
 
+    /// Returns the funds that were migrated from the legacy network to the account
+    pub async fn legacy_migrations(&self) -> Vec<LegacyMigration> {
+        self.details().await.legacy_migrations.values().cloned().collect()
+    }
+
     /// Returns all transactions of the account
     pub async fn transactions(&self) -> Vec<Transaction> {
         self.details().await.transactions.values().cloned().collect()
@@ -426,10 +563,122 @@ impl AccountInner {
     }
 }
 
+impl AccountDetails {
+    /// Returns the unspent outputs that match the filter, looked up in the output index instead of checking all unspent
+    /// outputs where possible.
+    pub(crate) fn filter_unspent_outputs<'a>(
+        &'a self,
+        filter: &FilterOptions,
+    ) -> impl Iterator<Item = &'a OutputData> + 'a {
+        // The outputs of the given chains are returned regardless of the other filters
+        let mut output_ids = filter
+            .chain_ids()
+            .filter_map(|chain_id| self.output_index.chain_output_id(&chain_id))
+            .copied()
+            .collect::<HashSet<_>>();
+
+        let matches_booked_timestamp = |output_id: &&OutputId| {
+            self.unspent_outputs
+                .get(*output_id)
+                .map_or(false, |output| filter.matches_booked_timestamp(output))
+        };
+        match &filter.output_types {
+            Some(output_types) => output_ids.extend(
+                output_types
+                    .iter()
+                    .flat_map(|kind| self.output_index.output_ids_with_kind(*kind))
+                    .filter(matches_booked_timestamp),
+            ),
+            None => output_ids.extend(self.unspent_outputs.keys().filter(matches_booked_timestamp)),
+        }
+
+        output_ids
+            .into_iter()
+            .filter_map(|output_id| self.unspent_outputs.get(&output_id))
+    }
+
+    /// Inserts an unspent output and indexes it.
+    pub(crate) fn insert_unspent_output(&mut self, output_data: OutputData) {
+        if let Some(previous) = self.unspent_outputs.get(&output_data.output_id) {
+            self.output_index.remove(previous);
+        }
+        self.output_index.insert(&output_data);
+        self.unspent_outputs.insert(output_data.output_id, output_data);
+    }
+
+    /// Removes an unspent output and its index entries.
+    pub(crate) fn remove_unspent_output(&mut self, output_id: &OutputId) -> Option<OutputData> {
+        let output_data = self.unspent_outputs.remove(output_id)?;
+        self.output_index.remove(&output_data);
+        Some(output_data)
+    }
+
+    /// Returns the unspent output of a chain.
+    pub(crate) fn unspent_chain_output(&self, chain_id: ChainId) -> Option<&OutputData> {
+        self.output_index
+            .chain_output_id(&chain_id)
+            .and_then(|output_id| self.unspent_outputs.get(output_id))
+    }
+
+    /// Returns the unspent outputs that have the address in one of their unlock conditions.
+    pub(crate) fn unspent_outputs_with_address<'a>(
+        &'a self,
+        address: &Address,
+    ) -> impl Iterator<Item = &'a OutputData> + 'a {
+        self.output_index
+            .output_ids_with_address(address)
+            .filter_map(|output_id| self.unspent_outputs.get(output_id))
+    }
+
+    /// Returns the unspent outputs that hold the native token.
+    pub(crate) fn unspent_outputs_with_native_token<'a>(
+        &'a self,
+        token_id: &TokenId,
+    ) -> impl Iterator<Item = &'a OutputData> + 'a {
+        self.output_index
+            .output_ids_with_native_token(token_id)
+            .filter_map(|output_id| self.unspent_outputs.get(output_id))
+    }
+
+    /// Returns the amounts of all native tokens of the unspent outputs, summed by token ID.
+    pub(crate) fn native_token_balances(&self) -> Result<BTreeMap<TokenId, U256>> {
+        let mut balances = BTreeMap::<TokenId, U256>::new();
+
+        for native_token in self
+            .unspent_outputs
+            .values()
+            .filter_map(|output_data| output_data.output.native_tokens())
+            .flat_map(|native_tokens| native_tokens.iter())
+        {
+            let token_id = *native_token.token_id();
+            let balance = balances.entry(token_id).or_default();
+            *balance = balance
+                .checked_add(native_token.amount())
+                .ok_or(Error::NativeTokenOverflow { token_id })?;
+        }
+
+        Ok(balances)
+    }
+
+    /// Returns the amount of a native token of the unspent outputs.
+    pub(crate) fn native_token_balance(&self, token_id: &TokenId) -> Result<U256> {
+        self.unspent_outputs_with_native_token(token_id)
+            .filter_map(|output_data| output_data.output.native_tokens()?.get(token_id))
+            .try_fold(U256::zero(), |balance, native_token| {
+                balance
+                    .checked_add(native_token.amount())
+                    .ok_or(Error::NativeTokenOverflow { token_id: *token_id })
+            })
+    }
+}
+
+/// Builds an incoming transaction, the `milestone_timestamp_booked` of its outputs is the time it got confirmed, which
+/// is also known if its inputs are already pruned.
 pub(crate) fn build_transaction_from_payload_and_inputs(
     tx_id: TransactionId,
     tx_payload: TransactionPayload,
     inputs: Vec<OutputWithMetadataResponse>,
+    milestone_timestamp_booked: u32,
 ) -> crate::wallet::Result<Transaction> {
     let TransactionEssence::Regular(tx_essence) = &tx_payload.essence();
     Ok(Transaction {
@@ -438,10 +687,7 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
             .first()
             .and_then(|i| BlockId::from_str(&i.metadata.block_id).ok()),
         inclusion_state: InclusionState::Confirmed,
-        timestamp: inputs
-            .first()
-            .and_then(|i| i.metadata.milestone_timestamp_spent.map(|t| t as u128 * 1000))
-            .unwrap_or_else(|| crate::utils::unix_timestamp_now().as_millis()),
+        timestamp: milestone_timestamp_booked as u128 * 1000,
         transaction_id: tx_id,
         network_id: tx_essence.network_id(),
         incoming: true,
@@ -543,12 +789,73 @@ fn serialize() {
         pending_transactions: HashSet::new(),
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
+        legacy_migrations: HashMap::new(),
         native_token_foundries: HashMap::new(),
+        output_index: OutputIndex::default(),
+        last_processed_milestone: None,
+        record_milestones: RecordMilestones::default(),
+        remainder_address_uses: HashMap::new(),
+        address_gap_limit: DEFAULT_ADDRESS_GAP_LIMIT,
+        address_labels: HashMap::new(),
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
 }
 
+#[test]
+fn native_token_balances() {
+    use crate::types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken},
+        protocol::protocol_parameters,
+        rand::{address::rand_address, bytes::rand_bytes_array},
+    };
+
+    let token_supply = protocol_parameters().token_supply();
+    let address = rand_address();
+    let token_id_1 = TokenId::from(rand_bytes_array());
+    let token_id_2 = TokenId::from(rand_bytes_array());
+    let output_data = |native_tokens: Vec<(TokenId, U256)>| {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .with_native_tokens(
+                native_tokens
+                    .into_iter()
+                    .map(|(token_id, amount)| NativeToken::new(token_id, amount).unwrap()),
+            )
+            .finish_output(token_supply)
+            .unwrap();
+        OutputData::rand_mock(output, address)
+    };
+
+    let mut account = AccountDetails::mock();
+    assert!(account.native_token_balances().unwrap().is_empty());
+    assert_eq!(account.native_token_balance(&token_id_1).unwrap(), U256::zero());
+
+    account.insert_unspent_output(output_data(vec![(token_id_1, 10.into()), (token_id_2, 1.into())]));
+    account.insert_unspent_output(output_data(vec![(token_id_1, 20.into())]));
+    account.insert_unspent_output(output_data(Vec::new()));
+
+    assert_eq!(
+        account.native_token_balances().unwrap(),
+        BTreeMap::from([(token_id_1, U256::from(30)), (token_id_2, U256::from(1))])
+    );
+    assert_eq!(account.native_token_balance(&token_id_1).unwrap(), U256::from(30));
+    assert_eq!(account.native_token_balance(&token_id_2).unwrap(), U256::from(1));
+
+    // Summing the amounts of the outputs overflows
+    account.insert_unspent_output(output_data(vec![(token_id_2, U256::MAX)]));
+
+    assert_eq!(account.native_token_balance(&token_id_1).unwrap(), U256::from(30));
+    assert!(matches!(
+        account.native_token_balance(&token_id_2),
+        Err(Error::NativeTokenOverflow { token_id }) if token_id == token_id_2
+    ));
+    assert!(matches!(
+        account.native_token_balances(),
+        Err(Error::NativeTokenOverflow { token_id }) if token_id == token_id_2
+    ));
+}
+
 #[cfg(test)]
 impl AccountDetails {
     /// Returns a mock of this type with the following values:
@@ -578,7 +885,14 @@ impl AccountDetails {
             pending_transactions: HashSet::new(),
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
+            legacy_migrations: HashMap::new(),
             native_token_foundries: HashMap::new(),
+            output_index: OutputIndex::default(),
+            last_processed_milestone: None,
+            record_milestones: RecordMilestones::default(),
+            remainder_address_uses: HashMap::new(),
+            address_gap_limit: DEFAULT_ADDRESS_GAP_LIMIT,
+            address_labels: HashMap::new(),
         }
     }
 }
@@ -5,8 +5,11 @@
 use crate::wallet::events::types::{AddressData, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage, SecretManager},
-    types::block::address::Bech32Address,
-    wallet::account::{types::address::AccountAddress, Account},
+    types::block::{
+        address::Bech32Address,
+        payload::{transaction::TransactionEssence, TransactionPayload},
+    },
+    wallet::account::{types::address::AccountAddress, Account, AccountDetails, RemainderAddressRotation},
 };
 
 impl Account {
@@ -140,6 +143,16 @@ impl Account {
                     .await?
             }
             SecretManager::Placeholder(_) => Vec::new(),
+            SecretManager::WatchOnly(watch_only) => {
+                watch_only
+                    .generate_ed25519_addresses(
+                        account_details.coin_type,
+                        account_details.index,
+                        address_range,
+                        Some(options),
+                    )
+                    .await?
+            }
         };
 
         drop(account_details);
@@ -172,4 +185,212 @@ impl Account {
 
         Ok(result)
     }
+
+    /// Sets the policy for choosing the change address of
+    /// [`RemainderValueStrategy::ChangeAddress`](crate::wallet::account::RemainderValueStrategy::ChangeAddress).
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_remainder_address_rotation(
+        &self,
+        rotation: RemainderAddressRotation,
+    ) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_remainder_address_rotation(index, &rotation).await?;
+        }
+
+        *self.remainder_address_rotation.lock().await = rotation;
+        Ok(())
+    }
+
+    /// Returns the policy for choosing the change address of
+    /// [`RemainderValueStrategy::ChangeAddress`](crate::wallet::account::RemainderValueStrategy::ChangeAddress).
+    pub async fn remainder_address_rotation(&self) -> RemainderAddressRotation {
+        *self.remainder_address_rotation.lock().await
+    }
+
+    /// Returns the internal address the next remainder to a change address will be sent to, and reserves it by
+    /// generating it if required. Uses are only counted once a transaction is submitted, so the address stays reserved
+    /// until then.
+    pub async fn peek_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
+        self.remainder_change_address().await
+    }
+
+    // Returns the change address for a remainder according to the rotation policy, generating a new internal address
+    // if required. The use is counted by `count_remainder_address_uses()` when the transaction is submitted.
+    pub(crate) async fn remainder_change_address(&self) -> crate::wallet::Result<AccountAddress> {
+        let rotation = self.remainder_address_rotation().await;
+        if let Some(address) = reusable_remainder_address(&*self.details().await, rotation) {
+            return Ok(address.clone());
+        }
+
+        // Generated addresses are stored in the account, so syncing includes them
+        self.generate_remainder_address().await
+    }
+}
+
+// Counts a use for every internal address that receives an output of a submitted transaction, since remainders to a
+// change address are sent to them
+pub(crate) fn count_remainder_address_uses(account_details: &mut AccountDetails, payload: &TransactionPayload) {
+    let TransactionEssence::Regular(essence) = payload.essence();
+    let key_indexes = account_details
+        .internal_addresses
+        .iter()
+        .filter(|address| {
+            essence.outputs().iter().any(|output| {
+                output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.address())
+                    .map_or(false, |unlock_condition| {
+                        unlock_condition.address() == address.address.inner()
+                    })
+            })
+        })
+        .map(|address| address.key_index)
+        .collect::<Vec<_>>();
+
+    for key_index in key_indexes {
+        *account_details.remainder_address_uses.entry(key_index).or_default() += 1;
+    }
+}
+
+// Returns the latest internal address if it can receive another remainder according to the rotation policy
+fn reusable_remainder_address(
+    account_details: &AccountDetails,
+    rotation: RemainderAddressRotation,
+) -> Option<&AccountAddress> {
+    let address = account_details
+        .internal_addresses
+        .iter()
+        .max_by_key(|address| address.key_index)?;
+    let uses = account_details
+        .remainder_address_uses
+        .get(&address.key_index)
+        .copied()
+        .unwrap_or_default();
+
+    // Used addresses without counted remainders received them before the uses were counted
+    rotation.max_uses().map_or(Some(address), |max_uses| {
+        (uses < max_uses && (uses > 0 || !address.used)).then_some(address)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        input::{Input, UtxoInput},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutput, InputsCommitment, Output},
+        payload::transaction::RegularTransactionEssence,
+        protocol::protocol_parameters,
+        rand::{address::rand_ed25519_address, transaction::rand_transaction_id},
+    };
+
+    fn internal_address(account_details: &AccountDetails, key_index: u32, used: bool) -> AccountAddress {
+        AccountAddress {
+            address: Bech32Address::new(
+                *account_details.public_addresses[0].address.hrp(),
+                rand_ed25519_address(),
+            ),
+            key_index,
+            internal: true,
+            used,
+        }
+    }
+
+    fn transaction_to(address: &AccountAddress) -> TransactionPayload {
+        let protocol_parameters = protocol_parameters();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs([Input::Utxo(UtxoInput::new(rand_transaction_id(), 0).unwrap())])
+                .with_outputs([Output::Basic(
+                    BasicOutput::build_with_amount(1_000_000)
+                        .add_unlock_condition(AddressUnlockCondition::new(address.address.inner))
+                        .finish(protocol_parameters.token_supply())
+                        .unwrap(),
+                )])
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+
+        TransactionPayload::rand_mock(essence)
+    }
+
+    #[test]
+    fn reusable_remainder_address_rotation() {
+        let mut account_details = AccountDetails::mock();
+        let rotations = [
+            RemainderAddressRotation::Reuse,
+            RemainderAddressRotation::AlwaysNew,
+            RemainderAddressRotation::NewAfterNUses(3),
+        ];
+
+        // Without internal addresses, one has to be generated
+        for rotation in rotations {
+            assert_eq!(reusable_remainder_address(&account_details, rotation), None);
+        }
+
+        let old = internal_address(&account_details, 0, true);
+        let latest = internal_address(&account_details, 1, false);
+        account_details.internal_addresses = vec![latest.clone(), old.clone()];
+
+        // An unused address can receive a remainder with every policy
+        for rotation in rotations {
+            assert_eq!(reusable_remainder_address(&account_details, rotation), Some(&latest));
+        }
+
+        count_remainder_address_uses(&mut account_details, &transaction_to(&latest));
+        count_remainder_address_uses(&mut account_details, &transaction_to(&latest));
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::Reuse),
+            Some(&latest)
+        );
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::AlwaysNew),
+            None
+        );
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::NewAfterNUses(3)),
+            Some(&latest)
+        );
+        count_remainder_address_uses(&mut account_details, &transaction_to(&latest));
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::NewAfterNUses(3)),
+            None
+        );
+        // Only the address that received the outputs is counted
+        assert_eq!(account_details.remainder_address_uses.get(&latest.key_index), Some(&3));
+        assert_eq!(account_details.remainder_address_uses.get(&old.key_index), None);
+
+        // Used addresses without counted uses received remainders before the uses were counted
+        let used = internal_address(&account_details, 2, true);
+        account_details.internal_addresses.push(used.clone());
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::Reuse),
+            Some(&used)
+        );
+        assert_eq!(
+            reusable_remainder_address(&account_details, RemainderAddressRotation::NewAfterNUses(3)),
+            None
+        );
+    }
+
+    #[test]
+    fn remainder_address_rotation_serde() {
+        for (rotation, json) in [
+            (RemainderAddressRotation::Reuse, r#"{"policy":"Reuse"}"#),
+            (RemainderAddressRotation::AlwaysNew, r#"{"policy":"AlwaysNew"}"#),
+            (
+                RemainderAddressRotation::NewAfterNUses(5),
+                r#"{"policy":"NewAfterNUses","value":5}"#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&rotation).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<RemainderAddressRotation>(json).unwrap(),
+                rotation
+            );
+        }
+    }
 }
@@ -1,12 +1,14 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
+
 use primitive_types::U256;
 
 use crate::{
     types::block::{
         address::Bech32Address,
-        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        output::{unlock_condition::UnlockCondition, ChainId, FoundryId, NativeTokensBuilder, Output, Rent},
         ConvertTo,
     },
     wallet::{
@@ -63,6 +65,9 @@ impl Account {
         let mut total_rent_amount = 0;
         let mut total_native_tokens = NativeTokensBuilder::default();
 
+        // Only needed for outputs with additional unlock conditions, so it's computed once when the first one is found
+        let mut claim_context = None;
+
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
 
@@ -100,8 +105,9 @@ impl Account {
                             // Add native tokens
                             total_native_tokens.add_native_tokens(output.native_tokens().clone())?;
 
-                            let alias_id = output.alias_id_non_null(output_id);
-                            balance.aliases.push(alias_id);
+                            if let Some(ChainId::Alias(alias_id)) = data.chain_id() {
+                                balance.aliases.push(alias_id);
+                            }
                         }
                         Output::Foundry(output) => {
                             // Add amount
@@ -125,8 +131,7 @@ impl Account {
                                 .as_ref()
                             {
                                 // add nft_id for nft outputs
-                                if let Output::Nft(output) = &output {
-                                    let nft_id = output.nft_id_non_null(output_id);
+                                if let Some(ChainId::Nft(nft_id)) = data.chain_id() {
                                     balance.nfts.push(nft_id);
                                 }
 
@@ -159,12 +164,20 @@ impl Account {
                                 // if we have multiple unlock conditions for basic or nft outputs, then we might can't
                                 // spend the balance at the moment or in the future
 
-                                let account_addresses = self.addresses().await?;
-                                let local_time = self.client().get_time_checked().await?;
-                                let output_can_be_unlocked_now = self
-                                    .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
-                                    .await?
-                                    .contains(output_id);
+                                if claim_context.is_none() {
+                                    claim_context = Some((
+                                        self.addresses().await?,
+                                        self.client().get_time_checked().await?,
+                                        self.claimable_outputs(OutputsToClaim::All)
+                                            .await?
+                                            .into_iter()
+                                            .collect::<HashSet<_>>(),
+                                    ));
+                                }
+                                let (account_addresses, local_time, unlockable_outputs) =
+                                    claim_context.as_ref().expect("claim context was just set");
+                                let local_time = *local_time;
+                                let output_can_be_unlocked_now = unlockable_outputs.contains(output_id);
 
                                 // For outputs that are expired or have a timelock unlock condition, but no expiration
                                 // unlock condition and we then can unlock them, then
@@ -207,8 +220,7 @@ impl Account {
                                             );
 
                                         // add nft_id for nft outputs
-                                        if let Output::Nft(output) = &output {
-                                            let nft_id = output.nft_id_non_null(output_id);
+                                        if let Some(ChainId::Nft(nft_id)) = data.chain_id() {
                                             balance.nfts.push(nft_id);
                                         }
 
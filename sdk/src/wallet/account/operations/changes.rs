@@ -0,0 +1,330 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{output::OutputId, payload::transaction::TransactionId},
+    wallet::account::{
+        constants::RECORD_MILESTONES_WINDOW,
+        types::{InclusionState, OutputData, Transaction},
+        Account, AccountDetails,
+    },
+};
+
+/// The milestone indexes of the syncs in which records of the account changed.
+// Records stored by older versions or whose milestone index got pruned have no milestone index
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordMilestones {
+    /// Milestone indexes of the syncs in which outputs were added to the account.
+    pub(crate) created_outputs: HashMap<OutputId, u32>,
+    /// Milestone indexes of the syncs in which outputs were spent.
+    pub(crate) spent_outputs: HashMap<OutputId, u32>,
+    /// Milestone indexes of the syncs in which the inclusion state of transactions changed.
+    pub(crate) transactions: HashMap<TransactionId, u32>,
+    /// Records without milestone index changed before this milestone index, `None` if it's unknown when they changed.
+    #[serde(default)]
+    pub(crate) untracked_before: Option<u32>,
+}
+
+impl RecordMilestones {
+    /// Returns whether records changed in the sync up to the milestone.
+    pub(crate) fn contains(&self, milestone_index: u32) -> bool {
+        self.created_outputs
+            .values()
+            .chain(self.spent_outputs.values())
+            .chain(self.transactions.values())
+            .any(|index| *index == milestone_index)
+    }
+
+    /// Removes the milestone indexes older than [`RECORD_MILESTONES_WINDOW`] milestones before the given one.
+    pub(crate) fn prune(&mut self, milestone_index: u32) {
+        let Some(oldest_index) = milestone_index.checked_sub(RECORD_MILESTONES_WINDOW) else {
+            return;
+        };
+        self.created_outputs.retain(|_, index| *index >= oldest_index);
+        self.spent_outputs.retain(|_, index| *index >= oldest_index);
+        self.transactions.retain(|_, index| *index >= oldest_index);
+        self.untracked_before = self.untracked_before.max(Some(oldest_index));
+    }
+}
+
+/// A changed record of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChange<T> {
+    /// The changed record.
+    pub record: T,
+    /// The milestone index of the sync that processed the change, `None` if it's unknown.
+    pub milestone_index: Option<u32>,
+    /// If the milestone index of the change is unknown, because the record was stored by an older version or its
+    /// milestone index is older than the recorded window. Such records are included if they could have changed at or
+    /// after the requested milestone, even though they might have changed before it.
+    pub unknown_milestone: bool,
+}
+
+impl<T> AccountChange<T> {
+    fn new(record: T, milestone_index: Option<u32>) -> Self {
+        Self {
+            record,
+            milestone_index,
+            unknown_milestone: milestone_index.is_none(),
+        }
+    }
+}
+
+/// The changes of an account since a milestone, returned from [`Account::changes_since()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChanges {
+    /// Outputs that were added to the account.
+    pub created_outputs: Vec<AccountChange<OutputData>>,
+    /// Outputs that got spent.
+    pub spent_outputs: Vec<AccountChange<OutputData>>,
+    /// Transactions whose inclusion state changed.
+    pub transactions: Vec<AccountChange<Transaction>>,
+    /// The milestone index up to which the account was processed, to be used as cursor for the next call.
+    pub last_processed_milestone: Option<u32>,
+}
+
+impl Account {
+    /// Returns the highest milestone index that was fully processed by syncing the account, `None` if the account
+    /// wasn't synced yet.
+    pub async fn last_processed_milestone(&self) -> Option<u32> {
+        self.details().await.last_processed_milestone
+    }
+
+    /// Returns the outputs that were created or spent and the transactions whose inclusion state changed at or after
+    /// the given milestone index, as processed by syncing. Only the local data is used, the account isn't synced.
+    /// [`AccountChanges::last_processed_milestone`] can be stored and used for the next call to only get the new
+    /// changes. The milestone indexes are only kept for about a week of milestones, records that changed before
+    /// are returned with [`AccountChange::unknown_milestone`] for earlier milestones.
+    pub async fn changes_since(&self, milestone_index: u32) -> AccountChanges {
+        self.details().await.changes_since(milestone_index)
+    }
+}
+
+impl AccountDetails {
+    pub(crate) fn changes_since(&self, milestone_index: u32) -> AccountChanges {
+        // Returns the milestone index of a change to include, records without milestone index are included if they
+        // could have changed at or after the milestone
+        let changed = |record_milestone: Option<&u32>| match record_milestone {
+            Some(index) => (*index >= milestone_index).then_some(Some(*index)),
+            None => self
+                .record_milestones
+                .untracked_before
+                .map_or(true, |untracked_before| milestone_index < untracked_before)
+                .then_some(None),
+        };
+
+        let mut changes = AccountChanges {
+            last_processed_milestone: self.last_processed_milestone,
+            ..Default::default()
+        };
+
+        for output_data in self.outputs.values() {
+            if let Some(index) = changed(self.record_milestones.created_outputs.get(&output_data.output_id)) {
+                changes
+                    .created_outputs
+                    .push(AccountChange::new(output_data.clone(), index));
+            }
+            if output_data.is_spent {
+                if let Some(index) = changed(self.record_milestones.spent_outputs.get(&output_data.output_id)) {
+                    changes
+                        .spent_outputs
+                        .push(AccountChange::new(output_data.clone(), index));
+                }
+            }
+        }
+
+        for (transaction_id, transaction) in &self.transactions {
+            let record_milestone = self.record_milestones.transactions.get(transaction_id);
+            // Pending transactions without milestone info didn't change their inclusion state yet
+            if record_milestone.is_none() && transaction.inclusion_state == InclusionState::Pending {
+                continue;
+            }
+            if let Some(index) = changed(record_milestone) {
+                changes
+                    .transactions
+                    .push(AccountChange::new(transaction.clone(), index));
+            }
+        }
+
+        changes
+            .created_outputs
+            .sort_by_key(|change| (change.milestone_index, change.record.output_id));
+        changes
+            .spent_outputs
+            .sort_by_key(|change| (change.milestone_index, change.record.output_id));
+        changes
+            .transactions
+            .sort_by_key(|change| (change.milestone_index, change.record.timestamp));
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        input::{Input, UtxoInput},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, InputsCommitment},
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        rand::{address::rand_address, output::rand_output_id, transaction::rand_transaction_id},
+    };
+
+    fn output_data(is_spent: bool) -> OutputData {
+        let address = rand_address();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        OutputData {
+            is_spent,
+            ..OutputData::rand_mock(output, address)
+        }
+    }
+
+    fn transaction(inclusion_state: InclusionState) -> Transaction {
+        let protocol_parameters = protocol_parameters();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs([Input::Utxo(UtxoInput::new(rand_transaction_id(), 0).unwrap())])
+                .add_output(output_data(false).output)
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+        let payload = TransactionPayload::rand_mock(essence);
+
+        Transaction {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            network_id: protocol_parameters.network_id(),
+            timestamp: 0,
+            inclusion_state,
+            incoming: false,
+            note: None,
+            inputs: Vec::new(),
+        }
+    }
+
+    fn summary(changes: &[AccountChange<OutputData>]) -> Vec<(OutputId, Option<u32>, bool)> {
+        changes
+            .iter()
+            .map(|change| {
+                (
+                    change.record.output_id,
+                    change.milestone_index,
+                    change.unknown_milestone,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn changes_since() {
+        let mut account_details = AccountDetails::mock();
+        let old = output_data(false);
+        let new = output_data(false);
+        let spent = output_data(true);
+        let legacy = output_data(true);
+        for output_data in [&old, &new, &spent, &legacy] {
+            account_details
+                .outputs
+                .insert(output_data.output_id, output_data.clone());
+        }
+
+        let pending = transaction(InclusionState::Pending);
+        let confirmed = transaction(InclusionState::Confirmed);
+        let legacy_confirmed = transaction(InclusionState::Confirmed);
+        account_details.transactions = [&pending, &confirmed, &legacy_confirmed]
+            .into_iter()
+            .map(|transaction| (transaction.transaction_id, transaction.clone()))
+            .collect();
+
+        account_details.last_processed_milestone = Some(20);
+        account_details.record_milestones = RecordMilestones {
+            created_outputs: HashMap::from([(old.output_id, 5), (new.output_id, 15), (spent.output_id, 5)]),
+            spent_outputs: HashMap::from([(spent.output_id, 20)]),
+            transactions: HashMap::from([(confirmed.transaction_id, 12)]),
+            untracked_before: None,
+        };
+
+        let changes = account_details.changes_since(10);
+        assert_eq!(changes.last_processed_milestone, Some(20));
+        assert_eq!(
+            summary(&changes.created_outputs),
+            [(legacy.output_id, None, true), (new.output_id, Some(15), false)]
+        );
+        assert_eq!(
+            summary(&changes.spent_outputs),
+            [(legacy.output_id, None, true), (spent.output_id, Some(20), false)]
+        );
+        // The pending transaction without milestone info is skipped, the confirmed one without is included
+        assert_eq!(
+            changes
+                .transactions
+                .iter()
+                .map(|change| (change.record.transaction_id, change.milestone_index))
+                .collect::<Vec<_>>(),
+            [
+                (legacy_confirmed.transaction_id, None),
+                (confirmed.transaction_id, Some(12))
+            ]
+        );
+
+        // Only records without milestone info are left
+        let changes_since_latest = account_details.changes_since(21);
+        assert_eq!(
+            summary(&changes_since_latest.created_outputs),
+            [(legacy.output_id, None, true)]
+        );
+        assert_eq!(
+            summary(&changes_since_latest.spent_outputs),
+            [(legacy.output_id, None, true)]
+        );
+        assert_eq!(changes_since_latest.transactions.len(), 1);
+
+        let json = serde_json::to_string(&changes).unwrap();
+        assert_eq!(serde_json::from_str::<AccountChanges>(&json).unwrap(), changes);
+
+        // Records without milestone index are known to have changed before the first processed milestone
+        account_details.record_milestones.untracked_before = Some(10);
+        let changes = account_details.changes_since(10);
+        assert_eq!(summary(&changes.created_outputs), [(new.output_id, Some(15), false)]);
+        assert_eq!(summary(&changes.spent_outputs), [(spent.output_id, Some(20), false)]);
+        assert_eq!(changes.transactions.len(), 1);
+        assert_eq!(account_details.changes_since(9).created_outputs.len(), 2);
+    }
+
+    #[test]
+    fn prune_record_milestones() {
+        let (old, new) = (rand_output_id(), rand_output_id());
+        let mut record_milestones = RecordMilestones {
+            created_outputs: HashMap::from([(old, 5), (new, 15)]),
+            spent_outputs: HashMap::from([(old, 15)]),
+            ..Default::default()
+        };
+        assert!(record_milestones.contains(15));
+        assert!(!record_milestones.contains(6));
+
+        // Nothing is older than the window
+        record_milestones.prune(RECORD_MILESTONES_WINDOW);
+        assert_eq!(record_milestones.created_outputs.len(), 2);
+        assert_eq!(record_milestones.untracked_before, Some(0));
+
+        record_milestones.prune(RECORD_MILESTONES_WINDOW + 10);
+        assert_eq!(record_milestones.created_outputs, HashMap::from([(new, 15)]));
+        assert_eq!(record_milestones.spent_outputs, HashMap::from([(old, 15)]));
+        assert_eq!(record_milestones.untracked_before, Some(10));
+    }
+}
@@ -5,6 +5,8 @@
 pub(crate) mod address_generation;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module to get the changes of the account since a milestone
+pub(crate) mod changes;
 /// Helper functions
 pub(crate) mod helpers;
 /// The module for claiming of outputs with
@@ -20,7 +22,15 @@ pub(crate) mod output_finder;
 pub(crate) mod participation;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
+/// The module to export and restore snapshots of the account state
+pub(crate) mod snapshot;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
+/// The module to get the supply of a native token and how much of it is held by the account
+pub(crate) mod token_overview;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module to export the transaction history
+pub(crate) mod transaction_history;
+/// The module to verify the stored account data against the node
+pub(crate) mod verify_integrity;
@@ -15,8 +15,8 @@ use crate::{
         },
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_now, types::Transaction, Account, OutputData,
-        TransactionOptions,
+        constants::CLAIM_OUTPUTS_BATCH_SIZE, operations::helpers::time::can_output_be_unlocked_now,
+        types::Transaction, Account, AccountDetails, OutputData, TransactionOptions,
     },
 };
 
@@ -35,91 +35,22 @@ impl Account {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition),
     /// [`StorageDepositReturnUnlockCondition`] or
     /// [`TimelockUnlockCondition`](crate::types::block::output::unlock_condition::TimelockUnlockCondition) and can be
-    /// unlocked now and also get basic outputs with only an [`AddressUnlockCondition`] unlock condition, for
-    /// additional inputs
+    /// unlocked now, outputs that expired and can only be unlocked by the sender anymore are excluded. They can be
+    /// claimed with [`Account::claim_outputs()`].
+    pub async fn claimable_outputs(&self, outputs_to_claim: OutputsToClaim) -> crate::wallet::Result<Vec<OutputId>> {
+        log::debug!("[OUTPUT_CLAIMING] claimable_outputs");
+        let local_time = self.client().get_time_checked().await?;
+
+        self.details().await.claimable_outputs(outputs_to_claim, local_time)
+    }
+
+    /// Get basic and nft outputs that can be claimed now, see [`Account::claimable_outputs()`].
+    #[deprecated(note = "renamed to `claimable_outputs`")]
     pub async fn get_unlockable_outputs_with_additional_unlock_conditions(
         &self,
         outputs_to_claim: OutputsToClaim,
     ) -> crate::wallet::Result<Vec<OutputId>> {
-        log::debug!("[OUTPUT_CLAIMING] get_unlockable_outputs_with_additional_unlock_conditions");
-        let account_details = self.details().await;
-
-        let local_time = self.client().get_time_checked().await?;
-
-        // Get outputs for the claim
-        let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
-        for (output_id, output_data) in account_details
-            .unspent_outputs
-            .iter()
-            .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
-        {
-            // Don't use outputs that are locked for other transactions
-            if !account_details.locked_outputs.contains(output_id) && account_details.outputs.contains_key(output_id) {
-                if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
-                    // If there is a single [UnlockCondition], then it's an
-                    // [AddressUnlockCondition] and we own it already without
-                    // further restrictions
-                    if unlock_conditions.len() != 1
-                        && can_output_be_unlocked_now(
-                            // We use the addresses with unspent outputs, because other addresses of the
-                            // account without unspent outputs can't be related to this output
-                            &account_details.addresses_with_unspent_outputs,
-                            // outputs controlled by an alias or nft are currently not considered
-                            &[],
-                            output_data,
-                            local_time,
-                            // Not relevant without alias addresses
-                            None,
-                        )?
-                    {
-                        match outputs_to_claim {
-                            OutputsToClaim::MicroTransactions => {
-                                if let Some(sdr) = unlock_conditions.storage_deposit_return() {
-                                    // If expired, it's not a micro transaction anymore
-                                    if unlock_conditions.is_expired(local_time) {
-                                        continue;
-                                    }
-                                    // Only micro transaction if not the same
-                                    if sdr.amount() != output_data.output.amount() {
-                                        output_ids_to_claim.insert(output_data.output_id);
-                                    }
-                                }
-                            }
-                            OutputsToClaim::NativeTokens => {
-                                if !output_data.output.native_tokens().map(|n| n.is_empty()).unwrap_or(true) {
-                                    output_ids_to_claim.insert(output_data.output_id);
-                                }
-                            }
-                            OutputsToClaim::Nfts => {
-                                if output_data.output.is_nft() {
-                                    output_ids_to_claim.insert(output_data.output_id);
-                                }
-                            }
-                            OutputsToClaim::Amount => {
-                                let mut claimable_amount = output_data.output.amount();
-                                if !unlock_conditions.is_expired(local_time) {
-                                    claimable_amount -= unlock_conditions
-                                        .storage_deposit_return()
-                                        .map(|s| s.amount())
-                                        .unwrap_or_default()
-                                };
-                                if claimable_amount > 0 {
-                                    output_ids_to_claim.insert(output_data.output_id);
-                                }
-                            }
-                            OutputsToClaim::All => {
-                                output_ids_to_claim.insert(output_data.output_id);
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        log::debug!(
-            "[OUTPUT_CLAIMING] available outputs to claim: {}",
-            output_ids_to_claim.len()
-        );
-        Ok(output_ids_to_claim.into_iter().collect())
+        self.claimable_outputs(outputs_to_claim).await
     }
 
     /// Get basic outputs that have only one unlock condition which is [AddressUnlockCondition], so they can be used as
@@ -158,7 +89,8 @@ impl Account {
     }
 
     /// Try to claim basic or nft outputs that have additional unlock conditions to their [AddressUnlockCondition]
-    /// from [`Account::get_unlockable_outputs_with_additional_unlock_conditions()`].
+    /// from [`Account::claimable_outputs()`]. If the storage deposit return unlock condition of an output isn't
+    /// expired, the return amount is sent back to its return address.
     pub async fn claim_outputs<I: IntoIterator<Item = OutputId> + Send>(
         &self,
         output_ids_to_claim: I,
@@ -188,6 +120,37 @@ impl Account {
             })
     }
 
+    /// Claims the outputs like [`Account::claim_outputs()`], but with multiple transactions if there are too many
+    /// outputs for a single one. If a transaction fails after others were already sent,
+    /// [`Error::ClaimingOutputsFailed`](crate::wallet::Error::ClaimingOutputsFailed) is returned with the sent
+    /// transactions.
+    pub async fn claim_outputs_in_batches<I: IntoIterator<Item = OutputId> + Send>(
+        &self,
+        output_ids_to_claim: I,
+    ) -> crate::wallet::Result<Vec<Transaction>>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[OUTPUT_CLAIMING] claim_outputs_in_batches");
+        let output_ids_to_claim = output_ids_to_claim.into_iter().collect::<Vec<_>>();
+
+        let mut transactions = Vec::new();
+        for output_ids_chunk in output_ids_to_claim.chunks(CLAIM_OUTPUTS_BATCH_SIZE) {
+            // The inputs of the previous transactions are locked, so they aren't used as additional inputs again
+            match self.claim_outputs(output_ids_chunk.to_vec()).await {
+                Ok(transaction) => transactions.push(transaction),
+                Err(error) if transactions.is_empty() => return Err(error),
+                Err(error) => {
+                    return Err(crate::wallet::Error::ClaimingOutputsFailed {
+                        transactions,
+                        error: Box::new(error),
+                    });
+                }
+            }
+        }
+        Ok(transactions)
+    }
+
     /// Try to claim basic outputs that have additional unlock conditions to their [AddressUnlockCondition].
     pub(crate) async fn claim_outputs_internal<I: IntoIterator<Item = OutputId> + Send>(
         &self,
@@ -399,6 +362,99 @@ impl Account {
     }
 }
 
+impl AccountDetails {
+    /// Returns the ids of the basic and nft outputs with additional unlock conditions that can be unlocked at the
+    /// given time, filtered by what should be claimed.
+    pub(crate) fn claimable_outputs(
+        &self,
+        outputs_to_claim: OutputsToClaim,
+        local_time: u32,
+    ) -> crate::wallet::Result<Vec<OutputId>> {
+        // Only outputs with an address of the account in their unlock conditions can be unlocked by it, so only these
+        // are looked up in the output index
+        let candidate_output_ids = self
+            .addresses_with_unspent_outputs
+            .iter()
+            .flat_map(|address| self.output_index.output_ids_with_address(address.address.inner()))
+            .collect::<HashSet<_>>();
+
+        // Get outputs for the claim
+        let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
+        for (output_id, output_data) in candidate_output_ids
+            .into_iter()
+            .filter_map(|output_id| Some((output_id, self.unspent_outputs.get(output_id)?)))
+            .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
+        {
+            // Don't use outputs that are locked for other transactions
+            if !self.locked_outputs.contains(output_id) && self.outputs.contains_key(output_id) {
+                if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
+                    // If there is a single [UnlockCondition], then it's an
+                    // [AddressUnlockCondition] and we own it already without
+                    // further restrictions
+                    if unlock_conditions.len() != 1
+                        && can_output_be_unlocked_now(
+                            // We use the addresses with unspent outputs, because other addresses of the
+                            // account without unspent outputs can't be related to this output
+                            &self.addresses_with_unspent_outputs,
+                            // outputs controlled by an alias or nft are currently not considered
+                            &[],
+                            output_data,
+                            local_time,
+                            // Not relevant without alias addresses
+                            None,
+                        )?
+                    {
+                        match outputs_to_claim {
+                            OutputsToClaim::MicroTransactions => {
+                                if let Some(sdr) = unlock_conditions.storage_deposit_return() {
+                                    // If expired, it's not a micro transaction anymore
+                                    if unlock_conditions.is_expired(local_time) {
+                                        continue;
+                                    }
+                                    // Only micro transaction if not the same
+                                    if sdr.amount() != output_data.output.amount() {
+                                        output_ids_to_claim.insert(output_data.output_id);
+                                    }
+                                }
+                            }
+                            OutputsToClaim::NativeTokens => {
+                                if !output_data.output.native_tokens().map(|n| n.is_empty()).unwrap_or(true) {
+                                    output_ids_to_claim.insert(output_data.output_id);
+                                }
+                            }
+                            OutputsToClaim::Nfts => {
+                                if output_data.output.is_nft() {
+                                    output_ids_to_claim.insert(output_data.output_id);
+                                }
+                            }
+                            OutputsToClaim::Amount => {
+                                let mut claimable_amount = output_data.output.amount();
+                                if !unlock_conditions.is_expired(local_time) {
+                                    claimable_amount -= unlock_conditions
+                                        .storage_deposit_return()
+                                        .map(|s| s.amount())
+                                        .unwrap_or_default()
+                                };
+                                if claimable_amount > 0 {
+                                    output_ids_to_claim.insert(output_data.output_id);
+                                }
+                            }
+                            OutputsToClaim::All => {
+                                output_ids_to_claim.insert(output_data.output_id);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        log::debug!(
+            "[OUTPUT_CLAIMING] available outputs to claim: {}",
+            output_ids_to_claim.len()
+        );
+        Ok(output_ids_to_claim.into_iter().collect())
+    }
+}
+
 /// Get the `StorageDepositReturnUnlockCondition`, if not expired
 pub(crate) fn sdr_not_expired(output: &Output, current_time: u32) -> Option<&StorageDepositReturnUnlockCondition> {
     output.unlock_conditions().and_then(|unlock_conditions| {
@@ -424,3 +480,212 @@ pub(crate) fn get_new_native_token_count(
     native_tokens_count.add_native_tokens(native_tokens.clone())?;
     Ok(native_tokens_count.len())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            output::{
+                unlock_condition::{ExpirationUnlockCondition, TimelockUnlockCondition, UnlockCondition},
+                NativeToken, NftId, TokenId,
+            },
+            protocol::protocol_parameters,
+            rand::{address::rand_address, bytes::rand_bytes_array},
+        },
+        wallet::account::types::AddressWithUnspentOutputs,
+    };
+
+    const LOCAL_TIME: u32 = 100;
+    const EXPIRED: u32 = 50;
+    const NOT_EXPIRED: u32 = 200;
+
+    // Returns the account and its address that can unlock outputs
+    fn account_details() -> (AccountDetails, Address) {
+        let mut account_details = AccountDetails::mock();
+        let account_address = account_details.public_addresses[0].address.clone();
+        let address = *account_address.inner();
+        account_details.addresses_with_unspent_outputs = vec![AddressWithUnspentOutputs {
+            address: account_address,
+            key_index: 0,
+            internal: false,
+            output_ids: Vec::new(),
+        }];
+        (account_details, address)
+    }
+
+    fn insert_output(
+        account_details: &mut AccountDetails,
+        address: Address,
+        unlock_conditions: Vec<UnlockCondition>,
+        native_token: Option<TokenId>,
+    ) -> OutputId {
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(address));
+        for unlock_condition in unlock_conditions {
+            builder = builder.add_unlock_condition(unlock_condition);
+        }
+        if let Some(token_id) = native_token {
+            builder = builder.add_native_token(NativeToken::new(token_id, 10.into()).unwrap());
+        }
+        let output = builder.finish_output(protocol_parameters().token_supply()).unwrap();
+        insert_output_data(account_details, output, address)
+    }
+
+    fn insert_output_data(account_details: &mut AccountDetails, output: Output, address: Address) -> OutputId {
+        let output_data = OutputData::rand_mock(output, address);
+        let output_id = output_data.output_id;
+        account_details.outputs.insert(output_id, output_data.clone());
+        account_details.insert_unspent_output(output_data);
+        output_id
+    }
+
+    fn claimable(account_details: &AccountDetails, outputs_to_claim: OutputsToClaim) -> HashSet<OutputId> {
+        account_details
+            .claimable_outputs(outputs_to_claim, LOCAL_TIME)
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn claimable_outputs_unlock_conditions() {
+        let token_supply = protocol_parameters().token_supply();
+        let (mut account_details, address) = account_details();
+        let sender = rand_address();
+
+        let address_only = insert_output(&mut account_details, address, Vec::new(), None);
+        let micro_transaction = insert_output(
+            &mut account_details,
+            address,
+            vec![
+                StorageDepositReturnUnlockCondition::new(sender, 500_000, token_supply)
+                    .unwrap()
+                    .into(),
+                ExpirationUnlockCondition::new(sender, NOT_EXPIRED).unwrap().into(),
+            ],
+            None,
+        );
+        // The whole amount has to be returned
+        let full_return = insert_output(
+            &mut account_details,
+            address,
+            vec![
+                StorageDepositReturnUnlockCondition::new(sender, 1_000_000, token_supply)
+                    .unwrap()
+                    .into(),
+            ],
+            None,
+        );
+        let not_expired = insert_output(
+            &mut account_details,
+            address,
+            vec![ExpirationUnlockCondition::new(sender, NOT_EXPIRED).unwrap().into()],
+            None,
+        );
+        // Can only be unlocked by the sender anymore
+        let expired_to_sender = insert_output(
+            &mut account_details,
+            address,
+            vec![ExpirationUnlockCondition::new(sender, EXPIRED).unwrap().into()],
+            None,
+        );
+        // Sent by the account and returned to it, the return amount doesn't have to be sent back
+        let expired_to_account = insert_output(
+            &mut account_details,
+            sender,
+            vec![
+                StorageDepositReturnUnlockCondition::new(address, 1_000_000, token_supply)
+                    .unwrap()
+                    .into(),
+                ExpirationUnlockCondition::new(address, EXPIRED).unwrap().into(),
+            ],
+            None,
+        );
+        let timelocked = insert_output(
+            &mut account_details,
+            address,
+            vec![TimelockUnlockCondition::new(NOT_EXPIRED).unwrap().into()],
+            None,
+        );
+        let timelock_passed = insert_output(
+            &mut account_details,
+            address,
+            vec![TimelockUnlockCondition::new(EXPIRED).unwrap().into()],
+            None,
+        );
+        let native_tokens = insert_output(
+            &mut account_details,
+            address,
+            vec![
+                StorageDepositReturnUnlockCondition::new(sender, 1_000_000, token_supply)
+                    .unwrap()
+                    .into(),
+            ],
+            Some(TokenId::new(rand_bytes_array())),
+        );
+        let nft = insert_output_data(
+            &mut account_details,
+            NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(ExpirationUnlockCondition::new(sender, NOT_EXPIRED).unwrap())
+                .finish_output(token_supply)
+                .unwrap(),
+            address,
+        );
+
+        let all = claimable(&account_details, OutputsToClaim::All);
+        assert_eq!(
+            all,
+            HashSet::from([
+                micro_transaction,
+                full_return,
+                not_expired,
+                expired_to_account,
+                timelock_passed,
+                native_tokens,
+                nft
+            ])
+        );
+        for excluded in [address_only, expired_to_sender, timelocked] {
+            assert!(!all.contains(&excluded));
+        }
+
+        assert_eq!(
+            claimable(&account_details, OutputsToClaim::MicroTransactions),
+            HashSet::from([micro_transaction])
+        );
+        assert_eq!(
+            claimable(&account_details, OutputsToClaim::Amount),
+            HashSet::from([micro_transaction, not_expired, expired_to_account, timelock_passed, nft])
+        );
+        assert_eq!(
+            claimable(&account_details, OutputsToClaim::NativeTokens),
+            HashSet::from([native_tokens])
+        );
+        assert_eq!(claimable(&account_details, OutputsToClaim::Nfts), HashSet::from([nft]));
+    }
+
+    #[test]
+    fn claimable_outputs_locked() {
+        let (mut account_details, address) = account_details();
+        let output_id = insert_output(
+            &mut account_details,
+            address,
+            vec![
+                ExpirationUnlockCondition::new(rand_address(), NOT_EXPIRED)
+                    .unwrap()
+                    .into(),
+            ],
+            None,
+        );
+        assert_eq!(
+            claimable(&account_details, OutputsToClaim::All),
+            HashSet::from([output_id])
+        );
+
+        // Outputs used as input of another transaction can't be claimed
+        account_details.locked_outputs.insert(output_id);
+        assert!(claimable(&account_details, OutputsToClaim::All).is_empty());
+    }
+}
@@ -4,7 +4,7 @@
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::SecretManager;
 use crate::{
-    client::api::PreparedTransactionData,
+    client::api::{input_selection::SelectionPreview, PreparedTransactionData},
     types::block::{
         input::INPUT_COUNT_MAX,
         output::{
@@ -24,6 +24,8 @@ const MIN_OUTPUT_SIZE_IN_ESSENCE: usize = 46;
 
 #[cfg(feature = "ledger_nano")]
 use crate::wallet::account::constants::DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SyncProgressEvent, WalletEvent};
 use crate::wallet::{
     account::{
         constants::DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD,
@@ -86,6 +88,33 @@ impl Account {
         Ok(consolidation_tx)
     }
 
+    // Consolidates the outputs at the end of a sync if the default threshold is reached
+    pub(crate) async fn consolidate_outputs_after_sync(&self) -> Result<()> {
+        let prepared_transaction = match self.prepare_consolidate_outputs(false, None).await {
+            Ok(prepared_transaction) => prepared_transaction,
+            Err(crate::wallet::Error::NoOutputsToConsolidate { .. }) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+
+        #[cfg(feature = "events")]
+        self.emit(
+            self.details().await.index,
+            WalletEvent::SyncProgress(SyncProgressEvent::ConsolidationStarted {
+                outputs: prepared_transaction.inputs_data.len(),
+            }),
+        )
+        .await;
+
+        let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
+        log::debug!(
+            "[SYNC] consolidation transaction created: block_id: {:?} tx_id: {:?}",
+            consolidation_tx.block_id,
+            consolidation_tx.transaction_id
+        );
+
+        Ok(())
+    }
+
     /// Function to prepare the transaction for
     /// [Account.consolidate_outputs()](crate::account::Account.consolidate_outputs)
     pub async fn prepare_consolidate_outputs(
@@ -93,6 +122,35 @@ impl Account {
         force: bool,
         output_consolidation_threshold: Option<usize>,
     ) -> Result<PreparedTransactionData> {
+        let (consolidation_output, options) = self
+            .consolidation_transaction(force, output_consolidation_threshold)
+            .await?;
+
+        self.prepare_transaction(consolidation_output, options).await
+    }
+
+    /// Previews which outputs [`Account::consolidate_outputs()`] would consolidate and the output it would create,
+    /// without locking the outputs or submitting anything. Fails like it with
+    /// [`Error::NoOutputsToConsolidate`](crate::wallet::Error::NoOutputsToConsolidate) if nothing would be
+    /// consolidated.
+    pub async fn preview_consolidate_outputs(
+        &self,
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+    ) -> Result<SelectionPreview> {
+        let (consolidation_output, options) = self
+            .consolidation_transaction(force, output_consolidation_threshold)
+            .await?;
+
+        self.preview_transaction(consolidation_output, options).await
+    }
+
+    // Returns the output and the options with the inputs of the consolidation transaction
+    async fn consolidation_transaction(
+        &self,
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+    ) -> Result<([Output; 1], TransactionOptions)> {
         log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
@@ -189,11 +247,11 @@ impl Account {
             .with_native_tokens(total_native_tokens.finish()?)
             .finish_output(token_supply)?];
 
-        let options = Some(TransactionOptions {
+        let options = TransactionOptions {
             custom_inputs: Some(custom_inputs),
             ..Default::default()
-        });
+        };
 
-        self.prepare_transaction(consolidation_output, options).await
+        Ok((consolidation_output, options))
     }
 }
@@ -5,15 +5,77 @@ use std::cmp;
 
 use crate::{
     client::secret::GenerateAddressOptions,
-    wallet::account::{operations::syncing::SyncOptions, types::AddressWithUnspentOutputs, Account},
+    wallet::account::{
+        operations::syncing::SyncOptions,
+        types::{AddressWithUnspentOutputs, Balance},
+        Account,
+    },
 };
 
 impl Account {
+    /// Searches the public and internal addresses of the account for unspent outputs beyond the default address gap,
+    /// like after restoring a mnemonic, and syncs the account afterwards.
+    /// `address_gap_limit`: The number of consecutive empty addresses after the last address with unspent outputs,
+    /// the addresses are generated and searched in chunks of this size until a chunk doesn't have new outputs.
+    /// `address_start_index`: The index of the public and internal addresses to start the search from, by default the
+    /// first ones.
+    /// Afterwards the account has `address_gap_limit` addresses after the last one with outputs, so outputs sent to
+    /// them are found by a normal sync. The already known addresses are always kept.
+    pub async fn search_addresses_with_outputs(
+        &self,
+        address_gap_limit: u32,
+        address_start_index: Option<u32>,
+    ) -> crate::wallet::Result<Balance> {
+        let mut sync_options = self.default_sync_options().await;
+        if let Some(address_start_index) = address_start_index {
+            sync_options.address_start_index = address_start_index;
+            sync_options.address_start_index_internal = address_start_index;
+        }
+        self.search_addresses_with_sync_options(address_gap_limit, Some(sync_options))
+            .await?;
+
+        // The search removed the addresses after the last one with outputs again, so the gap has to be generated
+        let (public_addresses_to_generate, internal_addresses_to_generate) = {
+            let account_details = self.details().await;
+            let highest_index_with_outputs = |internal: bool| {
+                account_details
+                    .addresses_with_unspent_outputs()
+                    .iter()
+                    .filter(|address| address.internal == internal)
+                    .map(|address| address.key_index)
+                    .max()
+            };
+            (
+                addresses_to_fill_gap(
+                    account_details.public_addresses.last().map(|a| a.key_index),
+                    highest_index_with_outputs(false),
+                    address_gap_limit,
+                ),
+                addresses_to_fill_gap(
+                    account_details.internal_addresses.last().map(|a| a.key_index),
+                    highest_index_with_outputs(true),
+                    address_gap_limit,
+                ),
+            )
+        };
+        self.generate_ed25519_addresses(public_addresses_to_generate, None)
+            .await?;
+        self.generate_ed25519_addresses(internal_addresses_to_generate, Some(GenerateAddressOptions::internal()))
+            .await?;
+
+        // The search only synced the new addresses
+        self.sync(Some(SyncOptions {
+            force_syncing: true,
+            ..self.default_sync_options().await
+        }))
+        .await
+    }
+
     /// Search addresses with unspent outputs
     /// `address_gap_limit`: The number of addresses to search for, after the last address with unspent outputs
     /// Addresses that got crated during this operation and have a higher key_index than the latest one with outputs,
     /// will be removed again, to keep the account size smaller
-    pub(crate) async fn search_addresses_with_outputs(
+    pub(crate) async fn search_addresses_with_sync_options(
         &self,
         mut address_gap_limit: u32,
         sync_options: Option<SyncOptions>,
@@ -279,3 +341,20 @@ impl Account {
             };
     }
 }
+
+/// Returns the number of addresses that need to be generated after the highest known index, so there are
+/// `address_gap_limit` addresses after the highest index with outputs.
+fn addresses_to_fill_gap(
+    highest_known_index: Option<u32>,
+    highest_index_with_outputs: Option<u32>,
+    address_gap_limit: u32,
+) -> u32 {
+    let Some(highest_index_with_outputs) = highest_index_with_outputs else {
+        return 0;
+    };
+    let gap_end_index = highest_index_with_outputs.saturating_add(address_gap_limit);
+    match highest_known_index {
+        Some(highest_known_index) => gap_end_index.saturating_sub(highest_known_index),
+        None => gap_end_index + 1,
+    }
+}
@@ -2,19 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{
-        api::core::response::LedgerInclusionState,
-        block::{
-            payload::{transaction::TransactionId, Payload},
-            Block, BlockId,
-        },
+    types::block::{
+        payload::{transaction::TransactionId, Payload},
+        Block, BlockId,
     },
     wallet::account::{types::InclusionState, Account},
 };
 
-const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
-const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
-
 impl Account {
     /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
     /// milestone). This function is re-exported from the client library and default interval is as defined there.
@@ -32,7 +26,11 @@ impl Account {
     }
 
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
-    /// included (referenced by a milestone). Returns the included block id.
+    /// included (referenced by a milestone), with [`Client::retry_transaction_until_included()`] and the stored block
+    /// id of the transaction. Returns the included block id. A conflicting transaction is marked as such and fails with
+    /// [`crate::client::Error::TransactionConflicting`], unless another attachment of it got included in the meantime.
+    ///
+    /// [`Client::retry_transaction_until_included()`]: crate::client::Client::retry_transaction_until_included()
     pub async fn retry_transaction_until_included(
         &self,
         transaction_id: &TransactionId,
@@ -70,56 +68,20 @@ impl Account {
                     .id(),
             };
 
-            // Attachments of the Block to check inclusion state
-            let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-                let duration =
-                    std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
-
-                #[cfg(target_family = "wasm")]
-                gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
-
-                #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
-
-                // Check inclusion state for each attachment
-                let block_ids_len = block_ids.len();
-                let mut conflicting = false;
-                for (index, block_id_) in block_ids.clone().iter().enumerate() {
-                    let block_metadata = self.client().get_block_metadata(block_id_).await?;
-                    if let Some(inclusion_state) = block_metadata.ledger_inclusion_state {
-                        match inclusion_state {
-                            LedgerInclusionState::Included | LedgerInclusionState::NoTransaction => {
-                                return Ok(*block_id_);
-                            }
-                            // only set it as conflicting here and don't return, because another reattached block could
-                            // have the included transaction
-                            LedgerInclusionState::Conflicting => conflicting = true,
-                        };
-                    }
-                    // Only reattach or promote latest attachment of the block
-                    if index == block_ids_len - 1 {
-                        if block_metadata.should_promote.unwrap_or(false) {
-                            // Safe to unwrap since we iterate over it
-                            self.client().promote_unchecked(block_ids.last().unwrap()).await?;
-                        } else if block_metadata.should_reattach.unwrap_or(false) {
-                            let reattached_block = self
-                                .client()
-                                .block()
-                                .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                                .await?;
-                            block_ids.push(reattached_block.id());
-                        }
+            match self
+                .client()
+                .retry_transaction_until_included(transaction_id, &[block_id], interval, max_attempts)
+                .await
+            {
+                Ok((included_block_id, _)) => Ok(included_block_id),
+                Err(e @ crate::client::Error::TransactionConflicting { .. }) => {
+                    match self.mark_transaction_conflicting(transaction_id).await? {
+                        Some(included_block_id) => Ok(included_block_id),
+                        None => Err(e.into()),
                     }
                 }
-                // After we checked all our reattached blocks, check if the transaction got reattached in another block
-                // and confirmed
-                if conflicting {
-                    let included_block = self.client().get_included_block(transaction_id).await?;
-                    return Ok(included_block.id());
-                }
+                Err(e) => Err(e.into()),
             }
-            Err(crate::client::Error::TangleInclusion(block_id.to_string()).into())
         } else {
             Err(crate::wallet::Error::TransactionNotFound(*transaction_id))
         }
@@ -0,0 +1,121 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{
+    account::{output_index::OutputIndex, Account, AccountDetails},
+    encryption, Error, Result,
+};
+
+/// The version of the snapshot format, increased with every incompatible change.
+const SNAPSHOT_VERSION: u8 = 1;
+const SNAPSHOT_UNENCRYPTED: u8 = 0;
+const SNAPSHOT_ENCRYPTED: u8 = 1;
+const SALT_LENGTH: usize = 32;
+const PBKDF2_ROUNDS: u32 = 100_000;
+
+/// The portable state of an account.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SnapshotDto {
+    /// The version of the SDK that created the snapshot.
+    sdk_version: String,
+    /// The account with all its addresses, outputs and transactions.
+    account: AccountDetails,
+}
+
+impl Account {
+    /// Exports the full state of the account to a portable snapshot that can be restored with
+    /// [`Account::restore_from_snapshot()`], also on another machine. If a password is provided, the snapshot is
+    /// encrypted with AES-256-GCM using a key derived from it.
+    ///
+    /// The snapshot is laid out as `version || encryption flag || data`, with `data` being the JSON of the account
+    /// state or, if encrypted, `salt || nonce || tag || ciphertext`.
+    pub async fn snapshot(&self, password: Option<&str>) -> Result<Vec<u8>> {
+        log::debug!("[snapshot]");
+        let snapshot = SnapshotDto {
+            sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+            account: self.details().await.clone(),
+        };
+        let json = serde_json::to_vec(&snapshot)?;
+
+        let mut bytes = vec![SNAPSHOT_VERSION];
+        match password {
+            Some(password) => {
+                let mut salt = [0u8; SALT_LENGTH];
+                crypto::utils::rand::fill(&mut salt)?;
+
+                bytes.push(SNAPSHOT_ENCRYPTED);
+                bytes.extend_from_slice(&salt);
+                bytes.extend(encryption::encrypt(&encryption_key(password, &salt), &json)?);
+            }
+            None => {
+                bytes.push(SNAPSHOT_UNENCRYPTED);
+                bytes.extend(json);
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    /// Replaces the state of the account with the one of a snapshot created by [`Account::snapshot()`] and saves it.
+    /// The snapshot must be of an account with the same index and coin type.
+    pub async fn restore_from_snapshot(&self, snapshot: &[u8], password: Option<&str>) -> Result<()> {
+        log::debug!("[restore_from_snapshot]");
+        let [version, encryption, data @ ..] = snapshot else {
+            return Err(Error::InvalidSnapshot("missing header"));
+        };
+
+        if *version > SNAPSHOT_VERSION {
+            return Err(Error::SnapshotVersionMismatch {
+                found: *version,
+                supported: SNAPSHOT_VERSION,
+            });
+        }
+
+        let SnapshotDto {
+            sdk_version,
+            mut account,
+        } = match *encryption {
+            SNAPSHOT_UNENCRYPTED => serde_json::from_slice(data)?,
+            SNAPSHOT_ENCRYPTED => {
+                let password = password.ok_or(Error::MissingParameter("password"))?;
+                if data.len() < SALT_LENGTH {
+                    return Err(Error::InvalidSnapshot("missing salt"));
+                }
+                let (salt, encrypted) = data.split_at(SALT_LENGTH);
+                serde_json::from_slice(&encryption::decrypt(&encryption_key(password, salt), encrypted)?)?
+            }
+            _ => return Err(Error::InvalidSnapshot("unknown encryption")),
+        };
+        log::debug!("[restore_from_snapshot] snapshot created with SDK version {sdk_version}");
+
+        let mut account_details = self.details_mut().await;
+        if account.index() != account_details.index() || account.coin_type() != account_details.coin_type() {
+            return Err(Error::InvalidSnapshot("account index or coin type mismatch"));
+        }
+        // Don't rely on the stored index being consistent with the outputs
+        account.output_index = OutputIndex::new(account.unspent_outputs.values());
+        *account_details = account;
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+}
+
+fn encryption_key(password: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    crypto::keys::pbkdf::PBKDF2_HMAC_SHA512(
+        password.as_bytes(),
+        salt,
+        // PANIC: the rounds are not zero.
+        NonZeroU32::new(PBKDF2_ROUNDS).unwrap(),
+        &mut key,
+    );
+    key
+}
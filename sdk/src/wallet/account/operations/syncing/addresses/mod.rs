@@ -6,7 +6,10 @@ mod outputs;
 
 use std::collections::HashSet;
 
-use crate::wallet::account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account};
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account},
+};
 
 impl Account {
     /// Get the addresses that should be synced with the current known unspent output ids
@@ -18,6 +21,10 @@ impl Account {
     ) -> crate::wallet::Result<Vec<AddressWithUnspentOutputs>> {
         log::debug!("[SYNC] get_addresses_to_sync");
 
+        if options.addresses.is_empty() {
+            self.generate_gap_addresses().await?;
+        }
+
         let mut addresses_before_syncing = self.addresses().await?;
 
         // If custom addresses are provided check if they are in the account and only use them
@@ -68,4 +75,35 @@ impl Account {
 
         Ok(addresses_with_old_output_ids)
     }
+
+    /// Generates the public addresses that are missing to have `address_gap_limit` addresses after the last used one
+    /// and returns them.
+    pub(crate) async fn generate_gap_addresses(&self) -> crate::wallet::Result<Vec<Bech32Address>> {
+        let account_details = self.details().await;
+        let gap_end = account_details
+            .public_addresses
+            .iter()
+            .filter(|address| address.used)
+            .map(|address| address.key_index + 1)
+            .max()
+            .unwrap_or_default()
+            + account_details.address_gap_limit;
+        let generated = account_details.public_addresses.len() as u32;
+        drop(account_details);
+
+        if gap_end <= generated {
+            return Ok(Vec::new());
+        }
+        log::debug!(
+            "[SYNC] generating {} addresses for the address gap",
+            gap_end - generated
+        );
+
+        Ok(self
+            .generate_ed25519_addresses(gap_end - generated, None)
+            .await?
+            .into_iter()
+            .map(|address| address.address)
+            .collect())
+    }
 }
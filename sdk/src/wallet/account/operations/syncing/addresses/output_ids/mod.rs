@@ -1,24 +1,22 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-mod alias_foundry;
 mod basic;
-mod nft;
 
-use std::collections::HashSet;
-
-#[cfg(not(target_family = "wasm"))]
-use futures::FutureExt;
+use futures::StreamExt;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SyncProgressEvent, WalletEvent};
 use crate::{
+    client::api::OutputKindFilter,
     types::block::{
         address::{Address, Bech32Address},
         output::OutputId,
     },
-    wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+    wallet::{
+        account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account},
+        task,
     },
 };
 
@@ -40,111 +38,21 @@ impl Account {
             return Ok(output_ids);
         }
 
-        #[cfg(target_family = "wasm")]
-        let mut results = Vec::new();
-
-        #[cfg(not(target_family = "wasm"))]
-        let mut tasks = Vec::new();
-
-        if (address.is_ed25519() && sync_options.account.basic_outputs)
-            || (address.is_nft() && sync_options.nft.basic_outputs)
-            || (address.is_alias() && sync_options.alias.basic_outputs)
-        {
-            // basic outputs
-            #[cfg(target_family = "wasm")]
-            {
-                results.push(
-                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address)
-                        .await,
-                )
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                tasks.push(
-                    async move {
-                        let account = self.clone();
-                        tokio::spawn(async move {
-                            account
-                                .get_basic_output_ids_with_any_unlock_condition(bech32_address)
-                                .await
-                        })
-                        .await
-                    }
-                    .boxed(),
-                );
-            }
-        }
-
-        if (address.is_ed25519() && sync_options.account.nft_outputs)
-            || (address.is_nft() && sync_options.nft.nft_outputs)
-            || (address.is_alias() && sync_options.alias.nft_outputs)
-        {
-            // nfts
-            #[cfg(target_family = "wasm")]
-            {
-                results.push(self.get_nft_output_ids_with_any_unlock_condition(bech32_address).await)
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                tasks.push(
-                    async move {
-                        let account = self.clone();
-                        tokio::spawn(async move {
-                            account
-                                .get_nft_output_ids_with_any_unlock_condition(bech32_address)
-                                .await
-                        })
-                        .await
-                    }
-                    .boxed(),
-                );
-            }
-        }
-
-        if (address.is_ed25519() && sync_options.account.alias_outputs)
+        let alias_outputs = (address.is_ed25519() && sync_options.account.alias_outputs)
             || (address.is_nft() && sync_options.nft.alias_outputs)
-            || (address.is_alias() && sync_options.alias.alias_outputs)
-        {
-            // aliases and foundries
-            #[cfg(target_family = "wasm")]
-            {
-                results.push(
-                    self.get_alias_and_foundry_output_ids(bech32_address, sync_options)
-                        .await,
-                )
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                tasks.push(
-                    async move {
-                        let sync_options = sync_options.clone();
-                        let account = self.clone();
-                        tokio::spawn(async move {
-                            account
-                                .get_alias_and_foundry_output_ids(bech32_address, &sync_options)
-                                .await
-                        })
-                        .await
-                    }
-                    .boxed(),
-                );
-            }
-        }
-
-        #[cfg(not(target_family = "wasm"))]
-        let results = futures::future::try_join_all(tasks).await?;
-
-        // Get all results
-        let mut output_ids = HashSet::new();
-        for res in results {
-            let found_output_ids = res?;
-            output_ids.extend(found_output_ids.into_iter());
-        }
-
-        Ok(output_ids.into_iter().collect())
+            || (address.is_alias() && sync_options.alias.alias_outputs);
+        let kinds = OutputKindFilter {
+            basic: (address.is_ed25519() && sync_options.account.basic_outputs)
+                || (address.is_nft() && sync_options.nft.basic_outputs)
+                || (address.is_alias() && sync_options.alias.basic_outputs),
+            alias: alias_outputs,
+            foundry: alias_outputs && sync_options.alias.foundry_outputs,
+            nft: (address.is_ed25519() && sync_options.account.nft_outputs)
+                || (address.is_nft() && sync_options.nft.nft_outputs)
+                || (address.is_alias() && sync_options.alias.nft_outputs),
+        };
+
+        Ok(self.client().output_ids_for_address(bech32_address, kinds).await?)
     }
 
     /// Get the current output ids for provided addresses and only returns addresses that have unspent outputs and
@@ -160,59 +68,54 @@ impl Account {
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
         let mut spent_or_not_anymore_synced_outputs = Vec::new();
-        // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
-            .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
-        {
-            let results;
-            #[cfg(target_family = "wasm")]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
-                    tasks.push(crate::wallet::Result::Ok((address, output_ids)));
+        #[cfg(feature = "events")]
+        let (account_index, total, mut synced) = (self.details().await.index, addresses_with_unspent_outputs.len(), 0);
+        // The order of the results doesn't matter, the addresses are looked up by their key index afterwards
+        let mut results = futures::stream::iter(addresses_with_unspent_outputs)
+            .map(|address| {
+                let account = self.clone();
+                let sync_options = options.clone();
+                async move {
+                    task::spawn(async move {
+                        let output_ids = account
+                            .get_output_ids_for_address(address.address.inner, &sync_options)
+                            .await?;
+                        crate::wallet::Result::Ok((address, output_ids))
+                    })
+                    .await?
                 }
-                results = tasks;
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let account = self.clone();
-                    let sync_options = options.clone();
-                    tasks.push(async move {
-                        tokio::spawn(async move {
-                            let output_ids = account
-                                .get_output_ids_for_address(address.address.inner, &sync_options)
-                                .await?;
-                            crate::wallet::Result::Ok((address, output_ids))
-                        })
-                        .await
-                    });
+            })
+            .buffer_unordered(options.max_parallel_requests.max(1));
+
+        while let Some(res) = results.next().await {
+            let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
+            // only return addresses with outputs
+            if !output_ids.is_empty() {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                for output_id in address.output_ids {
+                    if !output_ids.contains(&output_id) {
+                        spent_or_not_anymore_synced_outputs.push(output_id);
+                    }
                 }
-
-                results = futures::future::try_join_all(tasks).await?;
+                address.output_ids = output_ids;
+                addresses_with_outputs.push(address);
+            } else {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
             }
 
-            for res in results {
-                let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
-                // only return addresses with outputs
-                if !output_ids.is_empty() {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    for output_id in address.output_ids {
-                        if !output_ids.contains(&output_id) {
-                            spent_or_not_anymore_synced_outputs.push(output_id);
-                        }
-                    }
-                    address.output_ids = output_ids;
-                    addresses_with_outputs.push(address);
-                } else {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
+            #[cfg(feature = "events")]
+            {
+                synced += 1;
+                // Only emit an event for every batch of parallel requests
+                if synced % options.max_parallel_requests.max(1) == 0 || synced == total {
+                    self.emit(
+                        account_index,
+                        WalletEvent::SyncProgress(SyncProgressEvent::AddressesSynced { synced, total }),
+                    )
+                    .await;
                 }
             }
         }
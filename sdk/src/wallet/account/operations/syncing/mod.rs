@@ -9,11 +9,15 @@ pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
+use futures::StreamExt;
+
 pub use self::options::SyncOptions;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SyncProgressEvent, WalletEvent};
 use crate::{
     types::block::{
         address::{Address, AliasAddress, NftAddress, ToBech32Ext},
-        output::{dto::OutputMetadataDto, FoundryId, Output, OutputId},
+        output::{dto::OutputMetadataDto, ChainId, FoundryId, OutputId},
     },
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
@@ -55,9 +59,31 @@ impl Account {
 
         // Prevent syncing the account multiple times simultaneously
         let time_now = crate::utils::unix_timestamp_now().as_millis();
-        let mut last_synced = self.last_synced.lock().await;
-        log::debug!("[SYNC] last time synced before {}ms", time_now - *last_synced);
-        if !options.force_syncing && time_now - *last_synced < MIN_SYNC_INTERVAL {
+        let mut last_synced = match self.last_synced.try_lock() {
+            Ok(last_synced) => last_synced,
+            Err(_) if options.wait_for_ongoing_sync => {
+                log::debug!("[SYNC] waiting for the ongoing sync");
+                let last_synced = self.last_synced.lock().await;
+                // Use the result of the ongoing sync if it succeeded, instead of syncing again
+                if *last_synced >= time_now {
+                    return self.balance().await;
+                }
+                last_synced
+            }
+            Err(_) => {
+                log::debug!("[SYNC] another sync is ongoing, only calculating balance");
+                let mut balance = self.balance().await?;
+                balance.sync_in_progress = true;
+                return Ok(balance);
+            }
+        };
+        // The restored last synced time can be in the future after a clock change or if the storage was copied from
+        // another machine, that doesn't prevent syncing
+        log::debug!("[SYNC] last time synced before {}ms", time_now.saturating_sub(*last_synced));
+        if !options.force_syncing
+            && *last_synced <= time_now
+            && time_now.saturating_sub(*last_synced) < MIN_SYNC_INTERVAL
+        {
             log::debug!(
                 "[SYNC] synced within the latest {} ms, only calculating balance",
                 MIN_SYNC_INTERVAL
@@ -67,28 +93,77 @@ impl Account {
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        // All data requested afterwards includes at least the changes up to this milestone, the cached index from the
+        // node syncing is older than the current one, so it's also a valid lower bound
+        let milestone_index = match self.client().get_network_info().await?.confirmed_milestone_index {
+            Some(milestone_index) => milestone_index,
+            None => {
+                self.client()
+                    .get_info()
+                    .await?
+                    .node_info
+                    .status
+                    .confirmed_milestone
+                    .index
+            }
+        };
+
+        self.sync_internal(&options, milestone_index).await?;
+
+        // Addresses that received outputs can need more addresses to keep the gap, only these are synced then
+        if options.addresses.is_empty() {
+            loop {
+                let gap_addresses = self.generate_gap_addresses().await?;
+                if gap_addresses.is_empty() {
+                    break;
+                }
+                let gap_options = SyncOptions {
+                    addresses: gap_addresses,
+                    ..options.clone()
+                };
+                self.sync_internal(&gap_options, milestone_index).await?;
+            }
+        }
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
-            let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
+            let confirmed_tx_with_unknown_output = self.sync_pending_transactions(milestone_index).await?;
+            #[cfg(feature = "events")]
+            self.emit(
+                self.details().await.index,
+                WalletEvent::SyncProgress(SyncProgressEvent::PendingTransactionsSynced),
+            )
+            .await;
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(&options, milestone_index).await?;
             }
         };
 
+        self.update_last_processed_milestone(milestone_index).await?;
+
+        if options.consolidate_outputs {
+            self.consolidate_outputs_after_sync().await?;
+        }
+
         let balance = self.balance().await?;
         // Update last_synced mutex
         let time_now = crate::utils::unix_timestamp_now().as_millis();
         *last_synced = time_now;
+        #[cfg(feature = "storage")]
+        self.wallet
+            .storage_manager
+            .read()
+            .await
+            .set_last_synced(self.details().await.index, time_now)
+            .await?;
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
         Ok(balance)
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    async fn sync_internal(&self, options: &SyncOptions, milestone_index: u32) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
 
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
@@ -100,6 +175,15 @@ impl Account {
             Vec<OutputData>,
         ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
 
+        #[cfg(feature = "events")]
+        self.emit(
+            self.details().await.index,
+            WalletEvent::SyncProgress(SyncProgressEvent::OutputsFetched {
+                count: outputs_data.len(),
+            }),
+        )
+        .await;
+
         // Request possible spent outputs
         log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
         let spent_or_unsynced_output_metadata_responses = self
@@ -119,7 +203,12 @@ impl Account {
         if options.sync_incoming_transactions {
             let transaction_ids = outputs_data
                 .iter()
-                .map(|output| *output.output_id.transaction_id())
+                .map(|output| {
+                    (
+                        *output.output_id.transaction_id(),
+                        output.metadata.milestone_timestamp_booked(),
+                    )
+                })
                 .collect();
             // Request and store transaction payload for newly received unspent outputs
             self.request_incoming_transaction_data(transaction_ids).await?;
@@ -146,6 +235,7 @@ impl Account {
             outputs_data,
             spent_or_unsynced_output_metadata_map,
             options,
+            Some(milestone_index),
         )
         .await
     }
@@ -173,7 +263,7 @@ impl Account {
                 spent_or_not_synced_output_ids = spent_or_not_synced_output_ids_inner;
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_unspent_outputs_inner, outputs_data_inner) = self
-                    .get_outputs_from_address_output_ids(addresses_with_output_ids)
+                    .get_outputs_from_address_output_ids(options, addresses_with_output_ids)
                     .await?;
                 addresses_with_unspent_outputs = addresses_with_unspent_outputs_inner;
                 outputs_data.extend(outputs_data_inner.clone().into_iter());
@@ -181,8 +271,16 @@ impl Account {
             } else {
                 let bech32_hrp = self.client().get_bech32_hrp().await?;
                 let mut new_outputs_data = Vec::new();
-                for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses {
-                    let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
+                let mut results = futures::stream::iter(new_alias_and_nft_addresses)
+                    .map(|(alias_or_nft_address, ed25519_address)| async move {
+                        let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
+                        let output_responses = self.get_outputs(output_ids).await?;
+                        crate::wallet::Result::Ok((ed25519_address, output_responses))
+                    })
+                    .buffer_unordered(options.max_parallel_requests.max(1));
+
+                while let Some(res) = results.next().await {
+                    let (ed25519_address, new_outputs_data_inner) = res?;
 
                     // Update address with unspent outputs
                     let address_with_unspent_outputs = addresses_with_unspent_outputs
@@ -191,9 +289,11 @@ impl Account {
                         .ok_or_else(|| {
                             crate::wallet::Error::AddressNotFoundInAccount(ed25519_address.to_bech32(bech32_hrp))
                         })?;
-                    address_with_unspent_outputs.output_ids.extend(output_ids.clone());
-
-                    let new_outputs_data_inner = self.get_outputs(output_ids).await?;
+                    address_with_unspent_outputs.output_ids.extend(
+                        new_outputs_data_inner
+                            .iter()
+                            .map(|output| *output.metadata().output_id()),
+                    );
 
                     let outputs_data_inner = self
                         .output_response_to_output_data(new_outputs_data_inner, address_with_unspent_outputs)
@@ -209,16 +309,13 @@ impl Account {
             new_alias_and_nft_addresses = HashMap::new();
             // Add new alias and nft addresses
             for output_data in new_outputs_data.iter() {
-                match &output_data.output {
-                    Output::Alias(alias_output) => {
-                        let alias_address = AliasAddress::from(alias_output.alias_id_non_null(&output_data.output_id));
-
-                        new_alias_and_nft_addresses.insert(Address::Alias(alias_address), output_data.address);
+                match output_data.chain_id() {
+                    Some(ChainId::Alias(alias_id)) => {
+                        new_alias_and_nft_addresses
+                            .insert(Address::Alias(AliasAddress::from(alias_id)), output_data.address);
                     }
-                    Output::Nft(nft_output) => {
-                        let nft_address = NftAddress::from(nft_output.nft_id_non_null(&output_data.output_id));
-
-                        new_alias_and_nft_addresses.insert(Address::Nft(nft_address), output_data.address);
+                    Some(ChainId::Nft(nft_id)) => {
+                        new_alias_and_nft_addresses.insert(Address::Nft(NftAddress::from(nft_id)), output_data.address);
                     }
                     _ => {}
                 }
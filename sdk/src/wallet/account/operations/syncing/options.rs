@@ -1,9 +1,14 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::address::Bech32Address;
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::account::{constants::PARALLEL_REQUESTS_AMOUNT, types::address::AddressWithUnspentOutputs},
+};
 
 const DEFAULT_ADDRESS_START_INDEX: u32 = 0;
 const DEFAULT_FORCE_SYNCING: bool = false;
@@ -11,6 +16,7 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_WAIT_FOR_ONGOING_SYNC: bool = true;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -56,6 +62,57 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Max number of addresses that are synced at the same time. Every address sends several requests to the node, one
+    /// for each synced output kind and its outputs, so more requests than this can be in flight.
+    #[serde(default = "default_max_parallel_requests")]
+    pub max_parallel_requests: usize,
+    /// If another sync of the account is ongoing, wait until it's finished and return its balance. Otherwise the
+    /// current balance is returned immediately, with `sync_in_progress` set.
+    #[serde(default = "default_wait_for_ongoing_sync")]
+    pub wait_for_ongoing_sync: bool,
+    /// Consolidate the outputs after syncing if their amount reached the default consolidation threshold, like
+    /// [`Account::consolidate_outputs()`](crate::wallet::Account::consolidate_outputs) without `force`.
+    #[serde(default)]
+    pub consolidate_outputs: bool,
+    /// Called for every address that was already used before the sync and received new outputs. Reusing addresses
+    /// weakens the privacy of the account. Not serialized, `None` by default.
+    #[serde(skip)]
+    pub on_address_reuse: Option<AddressReuseCallback>,
+}
+
+/// Callback for [`SyncOptions::on_address_reuse`], compared by identity so the sync options can still be compared.
+#[derive(Clone)]
+pub struct AddressReuseCallback(Arc<dyn Fn(&AddressWithUnspentOutputs) + Send + Sync>);
+
+impl AddressReuseCallback {
+    /// Creates a callback that gets the reused address with all its unspent output ids.
+    pub fn new(callback: impl Fn(&AddressWithUnspentOutputs) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn call(&self, address: &AddressWithUnspentOutputs) {
+        (self.0)(address)
+    }
+}
+
+impl std::fmt::Debug for AddressReuseCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AddressReuseCallback")
+    }
+}
+
+impl PartialEq for AddressReuseCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for AddressReuseCallback {}
+
+impl std::hash::Hash for AddressReuseCallback {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const () as usize).hash(state)
+    }
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,6 +139,14 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_max_parallel_requests() -> usize {
+    PARALLEL_REQUESTS_AMOUNT
+}
+
+fn default_wait_for_ongoing_sync() -> bool {
+    DEFAULT_WAIT_FOR_ONGOING_SYNC
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -96,6 +161,10 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            max_parallel_requests: default_max_parallel_requests(),
+            wait_for_ongoing_sync: default_wait_for_ongoing_sync(),
+            consolidate_outputs: false,
+            on_address_reuse: None,
         }
     }
 }
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use crypto::keys::slip10::Chain;
 use instant::Instant;
 
@@ -12,17 +14,30 @@ use crate::{
             input::Input,
             output::{OutputId, OutputWithMetadata},
             payload::{
+                milestone::MilestoneId,
                 transaction::{TransactionEssence, TransactionId},
                 Payload, TransactionPayload,
             },
         },
     },
     wallet::{
-        account::{build_transaction_from_payload_and_inputs, types::OutputData, Account, AddressWithUnspentOutputs},
+        account::{
+            build_transaction_from_payload_and_inputs,
+            types::{LegacyMigration, OutputData, Transaction},
+            Account, AddressWithUnspentOutputs,
+        },
         task,
     },
 };
 
+/// The data found for the transaction id of a received output.
+enum IncomingTransactionData {
+    Transaction(Box<Transaction>),
+    // The transaction id is the id of a milestone with a receipt
+    LegacyMigrations(Vec<LegacyMigration>),
+    Inaccessible,
+}
+
 impl Account {
     /// Convert OutputWithMetadataResponse to OutputData with the network_id added
     pub(crate) async fn output_response_to_output_data(
@@ -86,7 +101,7 @@ impl Account {
                 // set unspent
                 Some(output_data) => {
                     output_data.is_spent = false;
-                    unspent_outputs.push((output_id, output_data.clone()));
+                    unspent_outputs.push(output_data.clone());
                     outputs.push(OutputWithMetadata::new(
                         output_data.output.clone(),
                         output_data.metadata.clone(),
@@ -97,14 +112,20 @@ impl Account {
         }
         // known output is unspent, so insert it to the unspent outputs again, because if it was an
         // alias/nft/foundry output it could have been removed when syncing without them
-        for (output_id, output_data) in unspent_outputs {
-            account_details.unspent_outputs.insert(output_id, output_data);
+        for output_data in unspent_outputs {
+            account_details.insert_unspent_output(output_data);
         }
 
         drop(account_details);
 
         if !unknown_outputs.is_empty() {
-            outputs.extend(self.client().get_outputs(&unknown_outputs).await?);
+            let (found_outputs, not_found_output_ids) = self.client().try_get_outputs(&unknown_outputs).await?;
+            // Outputs that aren't available anymore, for example because they got pruned, shouldn't fail the whole
+            // sync, they're just not added to the account
+            if !not_found_output_ids.is_empty() {
+                log::debug!("[SYNC] outputs not found: {not_found_output_ids:?}");
+            }
+            outputs.extend(found_outputs);
         }
 
         log::debug!(
@@ -120,18 +141,23 @@ impl Account {
     // returned
     pub(crate) async fn request_incoming_transaction_data(
         &self,
-        mut transaction_ids: Vec<TransactionId>,
+        mut transaction_ids: HashMap<TransactionId, u32>,
     ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] request_incoming_transaction_data");
 
         let account_details = self.details().await;
-        transaction_ids.retain(|transaction_id| {
+        transaction_ids.retain(|transaction_id, _| {
             !(account_details.transactions.contains_key(transaction_id)
                 || account_details.incoming_transactions.contains_key(transaction_id)
                 || account_details
                     .inaccessible_incoming_transactions
-                    .contains(transaction_id))
+                    .contains(transaction_id)
+                || account_details
+                    .legacy_migrations
+                    .keys()
+                    .any(|output_id| output_id.transaction_id() == transaction_id))
         });
+        let transaction_ids = transaction_ids.into_iter().collect::<Vec<_>>();
 
         // Limit parallel requests to 100, to avoid timeouts
         let results =
@@ -139,8 +165,8 @@ impl Account {
                 let client = self.client().clone();
                 async move {
                     task::spawn(async move {
-                        futures::future::try_join_all(transaction_ids.iter().map(|transaction_id| async {
-                            let transaction_id = *transaction_id;
+                        futures::future::try_join_all(transaction_ids.iter().map(|(transaction_id, timestamp)| async {
+                            let (transaction_id, timestamp) = (*transaction_id, *timestamp);
                             match client.get_included_block(&transaction_id).await {
                                 Ok(block) => {
                                     if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
@@ -155,15 +181,32 @@ impl Account {
                                             transaction_id,
                                             *transaction_payload.clone(),
                                             inputs_response,
+                                            timestamp,
                                         )?;
 
-                                        Ok((transaction_id, Some(transaction)))
+                                        Ok((
+                                            transaction_id,
+                                            IncomingTransactionData::Transaction(Box::new(transaction)),
+                                        ))
                                     } else {
-                                        Ok((transaction_id, None))
+                                        Ok((transaction_id, IncomingTransactionData::Inaccessible))
                                     }
                                 }
                                 Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
-                                    Ok((transaction_id, None))
+                                    // Outputs of migrated funds have the id of the milestone with the receipt as
+                                    // transaction id
+                                    match client.get_milestone_by_id(&MilestoneId::new(*transaction_id)).await {
+                                        Ok(milestone) => Ok((
+                                            transaction_id,
+                                            IncomingTransactionData::LegacyMigrations(LegacyMigration::from_milestone(
+                                                &milestone,
+                                            )),
+                                        )),
+                                        Err(crate::client::Error::Node(
+                                            crate::client::node_api::error::Error::NotFound(_),
+                                        )) => Ok((transaction_id, IncomingTransactionData::Inaccessible)),
+                                        Err(e) => Err(crate::wallet::Error::Client(e.into())),
+                                    }
                                 }
                                 Err(e) => Err(crate::wallet::Error::Client(e.into())),
                             }
@@ -177,11 +220,27 @@ impl Account {
 
         // Update account with new transactions
         let mut account_details = self.details_mut().await;
-        for (transaction_id, txn) in results.into_iter().flatten() {
-            if let Some(transaction) = txn {
+        for (transaction_id, data) in results.into_iter().flatten() {
+            let migrations = match data {
+                IncomingTransactionData::Transaction(transaction) => {
+                    account_details
+                        .incoming_transactions
+                        .insert(transaction_id, *transaction);
+                    continue;
+                }
+                IncomingTransactionData::LegacyMigrations(migrations) => migrations,
+                IncomingTransactionData::Inaccessible => Vec::new(),
+            };
+            // Only the funds migrated to the account, the receipt also lists the ones of others
+            let migrations = migrations
+                .into_iter()
+                .filter(|migration| account_details.outputs.contains_key(&migration.output_id))
+                .collect::<Vec<_>>();
+            if !migrations.is_empty() {
+                log::debug!("[SYNC] adding legacy migrations of milestone {transaction_id}");
                 account_details
-                    .incoming_transactions
-                    .insert(transaction_id, transaction);
+                    .legacy_migrations
+                    .extend(migrations.into_iter().map(|migration| (migration.output_id, migration)));
             } else {
                 log::debug!("[SYNC] adding {transaction_id} to inaccessible_incoming_transactions");
                 // Save transactions that weren't found by the node to avoid requesting them endlessly.
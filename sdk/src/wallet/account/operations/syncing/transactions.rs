@@ -1,13 +1,9 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::str::FromStr;
-
 use crate::{
-    types::{
-        api::core::response::LedgerInclusionState,
-        block::{input::Input, output::OutputId, payload::transaction::TransactionEssence, BlockId},
-    },
+    client::api::InclusionLookup,
+    types::block::{input::Input, output::OutputId, payload::transaction::TransactionEssence, BlockId},
     utils::unix_timestamp_now,
     wallet::account::{
         types::{InclusionState, Transaction},
@@ -25,7 +21,7 @@ impl Account {
     /// output ids that don't need to be locked anymore
     /// Return true if a transaction got confirmed for which we don't have an output already, based on this outputs will
     /// be synced again
-    pub(crate) async fn sync_pending_transactions(&self) -> crate::wallet::Result<bool> {
+    pub(crate) async fn sync_pending_transactions(&self, milestone_index: u32) -> crate::wallet::Result<bool> {
         log::debug!("[SYNC] sync pending transactions");
         let account_details = self.details().await;
 
@@ -98,76 +94,36 @@ impl Account {
             }
 
             if let Some(block_id) = transaction.block_id {
-                match self.client().get_block_metadata(&block_id).await {
-                    Ok(metadata) => {
-                        if let Some(inclusion_state) = metadata.ledger_inclusion_state {
-                            match inclusion_state {
-                                LedgerInclusionState::Included => {
-                                    log::debug!(
-                                        "[SYNC] confirmed transaction {transaction_id} in block {}",
-                                        metadata.block_id
-                                    );
-                                    confirmed_unknown_output = true;
-                                    updated_transaction_and_outputs(
-                                        transaction,
-                                        Some(BlockId::from_str(&metadata.block_id)?),
-                                        InclusionState::Confirmed,
-                                        &mut updated_transactions,
-                                        &mut spent_output_ids,
-                                    );
-                                }
-                                LedgerInclusionState::Conflicting => {
-                                    // try to get the included block, because maybe only this attachment is
-                                    // conflicting because it got confirmed in another block
-                                    if let Ok(included_block) =
-                                        self.client().get_included_block(&transaction.payload.id()).await
-                                    {
-                                        confirmed_unknown_output = true;
-                                        updated_transaction_and_outputs(
-                                            transaction,
-                                            Some(included_block.id()),
-                                            // block metadata was Conflicting, but it's confirmed in another attachment
-                                            InclusionState::Confirmed,
-                                            &mut updated_transactions,
-                                            &mut spent_output_ids,
-                                        );
-                                    } else {
-                                        log::debug!("[SYNC] conflicting transaction {transaction_id}");
-                                        updated_transaction_and_outputs(
-                                            transaction,
-                                            None,
-                                            InclusionState::Conflicting,
-                                            &mut updated_transactions,
-                                            &mut spent_output_ids,
-                                        );
-                                    }
-                                }
-                                LedgerInclusionState::NoTransaction => {
-                                    unreachable!(
-                                        "We should only get the metadata for blocks with a transaction payload"
-                                    )
-                                }
-                            }
-                        } else {
-                            // no need to reattach if one input got spent
-                            if input_got_spent {
-                                process_transaction_with_unknown_state(
-                                    &account_details,
-                                    transaction,
-                                    &mut updated_transactions,
-                                    &mut output_ids_to_unlock,
-                                )?;
-                            } else {
-                                let time_now = unix_timestamp_now().as_millis();
-                                // Reattach if older than 30 seconds
-                                if transaction.timestamp + 30000 < time_now {
-                                    // only reattach if inputs are still unspent
-                                    transactions_to_reattach.push(transaction);
-                                }
-                            }
-                        }
+                match self
+                    .client()
+                    .lookup_transaction(&transaction.transaction_id, &[block_id])
+                    .await?
+                {
+                    InclusionLookup::Included(included_block) => {
+                        log::debug!(
+                            "[SYNC] confirmed transaction {transaction_id} in block {}",
+                            included_block.id()
+                        );
+                        confirmed_unknown_output = true;
+                        updated_transaction_and_outputs(
+                            transaction,
+                            Some(included_block.id()),
+                            InclusionState::Confirmed,
+                            &mut updated_transactions,
+                            &mut spent_output_ids,
+                        );
+                    }
+                    InclusionLookup::Conflicting(reason) => {
+                        log::debug!("[SYNC] conflicting transaction {transaction_id}: {reason:?}");
+                        updated_transaction_and_outputs(
+                            transaction,
+                            None,
+                            InclusionState::Conflicting,
+                            &mut updated_transactions,
+                            &mut spent_output_ids,
+                        );
                     }
-                    Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                    InclusionLookup::Pending { .. } | InclusionLookup::Pruned | InclusionLookup::Unknown => {
                         // no need to reattach if one input got spent
                         if input_got_spent {
                             process_transaction_with_unknown_state(
@@ -185,7 +141,6 @@ impl Account {
                             }
                         }
                     }
-                    Err(e) => return Err(e.into()),
                 }
             } else {
                 // transaction wasn't submitted yet, so we have to send it again
@@ -207,8 +162,13 @@ impl Account {
         }
 
         // updates account with balances, output ids, outputs
-        self.update_account_with_transactions(updated_transactions, spent_output_ids, output_ids_to_unlock)
-            .await?;
+        self.update_account_with_transactions(
+            updated_transactions,
+            spent_output_ids,
+            output_ids_to_unlock,
+            milestone_index,
+        )
+        .await?;
 
         Ok(confirmed_unknown_output)
     }
@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{ChainId, FoundryId, FoundryOutput, Output, TokenId, TokenScheme},
+    wallet::account::{Account, AccountDetails},
+};
+
+/// The supply of a native token and how much of it is held by an account, returned from
+/// [`Account::token_overview()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenOverview {
+    /// The id of the native token.
+    pub token_id: TokenId,
+    /// The id of the foundry that issues the native token.
+    pub foundry_id: FoundryId,
+    /// The amount of minted tokens of the foundry.
+    pub minted_tokens: U256,
+    /// The amount of melted tokens of the foundry.
+    pub melted_tokens: U256,
+    /// The maximum supply of the foundry.
+    pub maximum_supply: U256,
+    /// The circulating supply, the minted tokens minus the melted ones.
+    pub circulating_supply: U256,
+    /// The part of the circulating supply held by the unspent outputs of the account.
+    pub held_by_account: U256,
+    /// The part of the circulating supply held by others.
+    pub held_externally: U256,
+    /// If the foundry output is an unspent output of the account.
+    pub controls_foundry: bool,
+    /// If the alias output controlling the foundry is an unspent output of the account.
+    pub controls_alias: bool,
+}
+
+impl Account {
+    /// Returns the supply of a native token and how much of the circulating supply is held by the account. The foundry
+    /// output is taken from the unspent outputs of the account if it controls it, also when it's owned by one of its
+    /// aliases, otherwise it's requested from the node.
+    pub async fn token_overview(&self, token_id: &TokenId) -> crate::wallet::Result<TokenOverview> {
+        log::debug!("[token_overview]");
+        let foundry_id = FoundryId::from(*token_id);
+
+        let owned_foundry_output = self
+            .details()
+            .await
+            .unspent_chain_output(ChainId::from(foundry_id))
+            .map(|output_data| output_data.output.clone());
+        let foundry_output = match owned_foundry_output {
+            Some(output) => output,
+            None => {
+                let output_id = self.client().foundry_output_id(foundry_id).await?;
+                self.client().get_output(&output_id).await?.into_output()
+            }
+        };
+
+        if let Output::Foundry(foundry_output) = foundry_output {
+            self.details().await.token_overview(&foundry_output)
+        } else {
+            Err(crate::wallet::Error::InvalidOutputKind(
+                foundry_output.kind().to_string(),
+            ))
+        }
+    }
+}
+
+impl AccountDetails {
+    /// Returns the supply of the native token of the foundry and how much of it is held by the unspent outputs.
+    pub(crate) fn token_overview(&self, foundry_output: &FoundryOutput) -> crate::wallet::Result<TokenOverview> {
+        let token_id = foundry_output.token_id();
+        let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+        let circulating_supply = token_scheme.circulating_supply();
+        let held_by_account = self.native_token_balance(&token_id)?;
+
+        Ok(TokenOverview {
+            token_id,
+            foundry_id: foundry_output.id(),
+            minted_tokens: token_scheme.minted_tokens(),
+            melted_tokens: token_scheme.melted_tokens(),
+            maximum_supply: token_scheme.maximum_supply(),
+            circulating_supply,
+            held_by_account,
+            // Stale account data could hold more than what's circulating
+            held_externally: circulating_supply.saturating_sub(held_by_account),
+            controls_foundry: self.unspent_chain_output(ChainId::from(foundry_output.id())).is_some(),
+            controls_alias: self
+                .unspent_chain_output(ChainId::from(*foundry_output.alias_address().alias_id()))
+                .is_some(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, AliasAddress},
+            output::{
+                unlock_condition::{
+                    AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                    StateControllerAddressUnlockCondition,
+                },
+                AliasOutputBuilder, BasicOutputBuilder, FoundryOutputBuilder, NativeToken, SimpleTokenScheme,
+            },
+            protocol::protocol_parameters,
+            rand::{address::rand_address, output::rand_alias_id},
+        },
+        wallet::account::types::OutputData,
+    };
+
+    fn output_data(output: Output, address: Address) -> OutputData {
+        OutputData::rand_mock(output, address)
+    }
+
+    fn foundry_output(alias_address: AliasAddress) -> FoundryOutput {
+        FoundryOutputBuilder::new_with_amount(
+            1_000_000,
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(100.into(), 10.into(), 1_000.into()).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(alias_address))
+        .finish(protocol_parameters().token_supply())
+        .unwrap()
+    }
+
+    fn basic_output_with_tokens(address: Address, token_id: TokenId, amount: u64) -> Output {
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_native_token(NativeToken::new(token_id, amount.into()).unwrap())
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    #[test]
+    fn token_overview_external_foundry() {
+        let mut account_details = AccountDetails::mock();
+        let address = rand_address();
+        // The foundry output as returned by the indexer, the account only holds some of the tokens
+        let foundry_output = foundry_output(AliasAddress::from(rand_alias_id()));
+        let token_id = foundry_output.token_id();
+
+        account_details.insert_unspent_output(output_data(basic_output_with_tokens(address, token_id, 30), address));
+        account_details.insert_unspent_output(output_data(basic_output_with_tokens(address, token_id, 20), address));
+
+        assert_eq!(
+            account_details.token_overview(&foundry_output).unwrap(),
+            TokenOverview {
+                token_id,
+                foundry_id: foundry_output.id(),
+                minted_tokens: 100.into(),
+                melted_tokens: 10.into(),
+                maximum_supply: 1_000.into(),
+                circulating_supply: 90.into(),
+                held_by_account: 50.into(),
+                held_externally: 40.into(),
+                controls_foundry: false,
+                controls_alias: false,
+            }
+        );
+    }
+
+    #[test]
+    fn token_overview_foundry_owned_by_alias() {
+        let token_supply = protocol_parameters().token_supply();
+        let mut account_details = AccountDetails::mock();
+        let address = rand_address();
+        let alias_id = rand_alias_id();
+        let alias_address = AliasAddress::from(alias_id);
+        let foundry_output = foundry_output(alias_address);
+        let token_id = foundry_output.token_id();
+
+        account_details.insert_unspent_output(output_data(
+            AliasOutputBuilder::new_with_amount(1_000_000, alias_id)
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+                .finish_output(token_supply)
+                .unwrap(),
+            address,
+        ));
+        account_details.insert_unspent_output(output_data(
+            Output::Foundry(foundry_output.clone()),
+            Address::Alias(alias_address),
+        ));
+        account_details.insert_unspent_output(output_data(basic_output_with_tokens(address, token_id, 60), address));
+
+        let overview = account_details.token_overview(&foundry_output).unwrap();
+        assert!(overview.controls_foundry);
+        assert!(overview.controls_alias);
+        assert_eq!(overview.held_by_account, 60.into());
+        assert_eq!(overview.held_externally, 30.into());
+
+        serde_json::from_str::<TokenOverview>(&serde_json::to_string(&overview).unwrap()).unwrap();
+    }
+}
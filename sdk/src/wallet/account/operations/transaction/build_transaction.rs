@@ -67,6 +67,7 @@ impl Account {
             essence,
             inputs_data: inputs_for_signing,
             remainder: selected_transaction_data.remainder,
+            bech32_hrp: Some(*protocol_parameters.bech32_hrp()),
         };
 
         log::debug!(
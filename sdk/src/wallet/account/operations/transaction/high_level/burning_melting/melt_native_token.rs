@@ -6,7 +6,8 @@ use primitive_types::U256;
 use crate::{
     client::api::PreparedTransactionData,
     types::block::output::{
-        AliasId, AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
+        AliasId, AliasOutputBuilder, ChainId, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId,
+        TokenScheme,
     },
     wallet::{
         account::{operations::transaction::Transaction, types::OutputData, Account, TransactionOptions},
@@ -18,27 +19,47 @@ impl Account {
     /// Function to melt native tokens. This happens with the foundry output which minted them, by increasing it's
     /// `melted_tokens` field. This should be preferred over burning, because after burning, the foundry can never be
     /// destroyed anymore.
-    pub async fn decrease_native_token_supply(
+    pub async fn melt_native_token(
         &self,
         token_id: TokenId,
         melt_amount: U256,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
-        let prepared_transaction = self
-            .prepare_decrease_native_token_supply(token_id, melt_amount, options)
-            .await?;
+        let prepared_transaction = self.prepare_melt_native_token(token_id, melt_amount, options).await?;
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
-    /// Function to prepare the transaction for
-    /// [Account.decrease_native_token_supply()](crate::account::Account.decrease_native_token_supply)
+    /// Previous name of [`Account::melt_native_token()`].
+    #[deprecated(note = "renamed to `melt_native_token`")]
+    pub async fn decrease_native_token_supply(
+        &self,
+        token_id: TokenId,
+        melt_amount: U256,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.melt_native_token(token_id, melt_amount, options).await
+    }
+
+    /// Previous name of [`Account::prepare_melt_native_token()`].
+    #[deprecated(note = "renamed to `prepare_melt_native_token`")]
     pub async fn prepare_decrease_native_token_supply(
         &self,
         token_id: TokenId,
         melt_amount: U256,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
-        log::debug!("[TRANSACTION] prepare_decrease_native_token_supply");
+        self.prepare_melt_native_token(token_id, melt_amount, options).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.melt_native_token()](crate::account::Account.melt_native_token)
+    pub async fn prepare_melt_native_token(
+        &self,
+        token_id: TokenId,
+        melt_amount: U256,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_melt_native_token");
 
         let foundry_id = FoundryId::from(token_id);
         let alias_id = *foundry_id.alias_address().alias_id();
@@ -83,34 +104,16 @@ impl Account {
         alias_id: AliasId,
         foundry_id: FoundryId,
     ) -> crate::wallet::Result<(OutputData, OutputData)> {
-        let mut existing_alias_output_data = None;
-        let mut existing_foundry_output = None;
-
-        for (output_id, output_data) in self.details().await.unspent_outputs().iter() {
-            match &output_data.output {
-                Output::Alias(output) => {
-                    if output.alias_id_non_null(output_id) == alias_id {
-                        existing_alias_output_data = Some(output_data.clone());
-                    }
-                }
-                Output::Foundry(output) => {
-                    if output.id() == foundry_id {
-                        existing_foundry_output = Some(output_data.clone());
-                    }
-                }
-                // Not interested in these outputs here
-                Output::Treasury(_) | Output::Basic(_) | Output::Nft(_) => {}
-            }
-
-            if existing_alias_output_data.is_some() && existing_foundry_output.is_some() {
-                break;
-            }
-        }
+        let account_details = self.details().await;
 
-        let existing_alias_output_data = existing_alias_output_data
+        let existing_alias_output_data = account_details
+            .unspent_chain_output(ChainId::from(alias_id))
+            .cloned()
             .ok_or_else(|| Error::BurningOrMeltingFailed("required alias output for foundry not found".to_string()))?;
 
-        let existing_foundry_output_data = existing_foundry_output
+        let existing_foundry_output_data = account_details
+            .unspent_chain_output(ChainId::from(foundry_id))
+            .cloned()
             .ok_or_else(|| Error::BurningOrMeltingFailed("required foundry output not found".to_string()))?;
 
         Ok((existing_alias_output_data, existing_foundry_output_data))
@@ -1,15 +1,18 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use primitive_types::U256;
+
 use crate::{
     client::api::{input_selection::Burn, PreparedTransactionData},
+    types::block::output::{AliasId, FoundryId, NativeToken, NftId, TokenId},
     wallet::{
         account::{types::Transaction, TransactionOptions},
         Account,
     },
 };
 
-pub(crate) mod decrease_native_token_supply;
+pub(crate) mod melt_native_token;
 
 impl Account {
     /// A generic `burn()` function that can be used to burn native tokens, nfts, foundries and aliases.
@@ -26,6 +29,44 @@ impl Account {
         self.sign_and_submit_transaction(prepared).await
     }
 
+    /// Burns an nft output controlled by the account, its storage deposit is sent to the account.
+    pub async fn burn_nft(
+        &self,
+        nft_id: NftId,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.burn(nft_id, options).await
+    }
+
+    /// Burns an amount of native tokens of the account, see [`Account::burn()`] for the difference with melting.
+    pub async fn burn_native_token(
+        &self,
+        token_id: TokenId,
+        amount: U256,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.burn(NativeToken::new(token_id, amount)?, options).await
+    }
+
+    /// Destroys an alias output controlled by the account, its storage deposit is sent to the account.
+    pub async fn destroy_alias(
+        &self,
+        alias_id: AliasId,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.burn(alias_id, options).await
+    }
+
+    /// Destroys a foundry output controlled by the account, its storage deposit is sent to the account. All its
+    /// native tokens need to be melted before.
+    pub async fn destroy_foundry(
+        &self,
+        foundry_id: FoundryId,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.burn(foundry_id, options).await
+    }
+
     /// A generic `prepare_burn()` function that can be used to prepare the burn of native tokens, nfts, foundries and
     /// aliases.
     ///
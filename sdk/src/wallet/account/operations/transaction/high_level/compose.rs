@@ -0,0 +1,202 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::api::{input_selection::Burn, PreparedTransactionData},
+    types::block::{
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::AddressUnlockCondition, ChainId, NativeTokensBuilder, NftOutputBuilder, Output, OutputId,
+            OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::{
+        account::{operations::transaction::high_level::send_amount::SendAmountParams, Account, TransactionOptions},
+        Error,
+    },
+};
+
+/// Composes several account operations into a single transaction, created with [`Account::compose()`].
+///
+/// All claims, sends and burns are merged into the requirements of a single input selection run.
+/// ```ignore
+/// let prepared_transaction = account
+///     .compose()
+///     .claim(output_ids_to_claim)
+///     .send([SendAmountParams::new(address, 1_000_000)?])
+///     .burn(Burn::new().add_nft(nft_id))
+///     .finish(None)
+///     .await?;
+/// let transaction = account.sign_and_submit_transaction(prepared_transaction).await?;
+/// ```
+#[must_use]
+pub struct TransactionComposer<'a> {
+    account: &'a Account,
+    claim: Vec<OutputId>,
+    send: Vec<SendAmountParams>,
+    burn: Vec<Burn>,
+}
+
+impl Account {
+    /// Starts composing a transaction out of several operations, see [`TransactionComposer`].
+    pub fn compose(&self) -> TransactionComposer<'_> {
+        TransactionComposer {
+            account: self,
+            claim: Vec::new(),
+            send: Vec::new(),
+            burn: Vec::new(),
+        }
+    }
+}
+
+impl<'a> TransactionComposer<'a> {
+    /// Claims the given basic or nft outputs, like [`Account::claim_outputs()`].
+    pub fn claim(mut self, output_ids: impl IntoIterator<Item = OutputId>) -> Self {
+        self.claim.extend(output_ids);
+        self
+    }
+
+    /// Sends the given amounts, like [`Account::send_amount()`].
+    pub fn send(mut self, params: impl IntoIterator<Item = SendAmountParams>) -> Self {
+        self.send.extend(params);
+        self
+    }
+
+    /// Burns the given aliases, nfts, foundries or native tokens, like [`Account::burn()`].
+    pub fn burn(mut self, burn: impl Into<Burn>) -> Self {
+        self.burn.push(burn.into());
+        self
+    }
+
+    /// Merges all operations and prepares a single transaction for them. Custom inputs, mandatory inputs and a burn
+    /// from the options are merged with the ones of the operations.
+    pub async fn finish(
+        self,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] compose");
+        let mut options = options.into().unwrap_or_default();
+        let token_supply = self.account.client().get_token_supply().await?;
+
+        let mut burns = options.burn.take().into_iter().chain(self.burn);
+        let burn = match burns.next() {
+            Some(mut burn) => {
+                burn.try_extend(burns)?;
+                Some(burn)
+            }
+            None => None,
+        };
+        let mut outputs = self.account.send_amount_outputs(self.send, Some(&options)).await?;
+
+        let mut claimed = Vec::new();
+        // Native tokens of the claimed inputs and of all outputs, including the remainder.
+        let mut input_native_tokens = NativeTokensBuilder::new();
+        let mut output_native_tokens = NativeTokensBuilder::new();
+        if !self.claim.is_empty() {
+            let account_details = self.account.details().await;
+            let claim_address = account_details
+                .public_addresses
+                .first()
+                .ok_or(Error::FailedToGetRemainder)?
+                .address
+                .inner;
+            for output_id in self.claim {
+                if claimed.contains(&output_id) {
+                    continue;
+                }
+                claimed.push(output_id);
+                let output_data = account_details
+                    .unspent_outputs
+                    .get(&output_id)
+                    .filter(|_| !account_details.locked_outputs.contains(&output_id))
+                    .ok_or_else(|| Error::CustomInput(format!("output {output_id} can't be claimed")))?;
+
+                match &output_data.output {
+                    Output::Basic(basic_output) => {
+                        input_native_tokens.add_native_tokens(basic_output.native_tokens().clone())?;
+                        // Native tokens of claimed basic outputs all end up in the remainder.
+                        output_native_tokens.add_native_tokens(basic_output.native_tokens().clone())?;
+                    }
+                    Output::Nft(nft_output) => {
+                        let nft_id = nft_output.nft_id_non_null(&output_id);
+                        if burn.as_ref().map_or(false, |burn| burn.nfts.contains(&nft_id)) {
+                            return Err(Error::ConflictingRequirements(format!(
+                                "nft {nft_id} of output {output_id} is claimed and burned"
+                            )));
+                        }
+                        input_native_tokens.add_native_tokens(nft_output.native_tokens().clone())?;
+                        // Storage deposit returns of the claimed outputs are created by the input selection.
+                        outputs.push(
+                            NftOutputBuilder::from(nft_output)
+                                .with_nft_id(nft_id)
+                                .with_unlock_conditions([AddressUnlockCondition::new(claim_address)])
+                                .finish_output(token_supply)?,
+                        );
+                    }
+                    _ => {
+                        return Err(Error::CustomInput(format!(
+                            "output {output_id} can't be claimed, only basic and nft outputs are supported"
+                        )));
+                    }
+                }
+            }
+        }
+
+        for output in &outputs {
+            if let Some(native_tokens) = output.native_tokens() {
+                output_native_tokens.add_native_tokens(native_tokens.clone())?;
+            }
+        }
+        input_native_tokens.finish()?;
+        output_native_tokens.finish()?;
+
+        // Inputs that are known upfront: the claimed outputs, the custom or mandatory inputs and the outputs to burn.
+        let mut inputs = claimed.iter().copied().collect::<HashSet<_>>();
+        inputs.extend(options.custom_inputs.iter().chain(&options.mandatory_inputs).flatten());
+        if let Some(burn) = &burn {
+            let account_details = self.account.details().await;
+            let chain_ids = burn
+                .aliases
+                .iter()
+                .map(|alias_id| ChainId::from(*alias_id))
+                .chain(burn.nfts.iter().map(|nft_id| ChainId::from(*nft_id)))
+                .chain(burn.foundries.iter().map(|foundry_id| ChainId::from(*foundry_id)));
+            for chain_id in chain_ids {
+                if let Some(output_data) = account_details.unspent_chain_output(chain_id) {
+                    inputs.insert(output_data.output_id);
+                }
+            }
+        }
+        if inputs.len() > INPUT_COUNT_MAX as usize {
+            return Err(Error::TooManyInputs {
+                input_count: inputs.len(),
+                input_count_max: INPUT_COUNT_MAX,
+            });
+        }
+
+        if outputs.len() > OUTPUT_COUNT_MAX as usize {
+            return Err(Error::ConflictingRequirements(format!(
+                "{} outputs are required, but at most {OUTPUT_COUNT_MAX} are allowed",
+                outputs.len()
+            )));
+        }
+
+        if !claimed.is_empty() {
+            // Custom inputs are the only inputs used, so the claimed outputs have to be added to them if set.
+            let inputs = match options.custom_inputs.as_mut() {
+                Some(custom_inputs) => custom_inputs,
+                None => options.mandatory_inputs.get_or_insert_with(Vec::new),
+            };
+            for output_id in claimed {
+                if !inputs.contains(&output_id) {
+                    inputs.push(output_id);
+                }
+            }
+        }
+        options.burn = burn;
+
+        self.account.prepare_transaction(outputs, options).await
+    }
+}
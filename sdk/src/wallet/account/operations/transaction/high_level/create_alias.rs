@@ -10,7 +10,7 @@ use crate::{
         output::{
             feature::MetadataFeature,
             unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
-            AliasId, AliasOutputBuilder, Output,
+            AliasId, AliasOutputBuilder, ChainId,
         },
         Error,
     },
@@ -158,26 +158,23 @@ impl Account {
     /// Get an existing alias output
     pub(crate) async fn get_alias_output(&self, alias_id: Option<AliasId>) -> Option<(AliasId, OutputData)> {
         log::debug!("[get_alias_output]");
-        self.details()
-            .await
-            .unspent_outputs()
-            .values()
-            .find_map(|output_data| match &output_data.output {
-                Output::Alias(alias_output) => {
-                    let output_alias_id = alias_output.alias_id_non_null(&output_data.output_id);
+        let account_details = self.details().await;
 
-                    alias_id.map_or_else(
-                        || Some((output_alias_id, output_data.clone())),
-                        |alias_id| {
-                            if output_alias_id == alias_id {
-                                Some((output_alias_id, output_data.clone()))
-                            } else {
-                                None
-                            }
-                        },
-                    )
-                }
-                _ => None,
-            })
+        alias_id.map_or_else(
+            || {
+                account_details
+                    .unspent_outputs()
+                    .values()
+                    .find_map(|output_data| match output_data.chain_id() {
+                        Some(ChainId::Alias(output_alias_id)) => Some((output_alias_id, output_data.clone())),
+                        _ => None,
+                    })
+            },
+            |alias_id| {
+                account_details
+                    .unspent_chain_output(ChainId::from(alias_id))
+                    .map(|output_data| (alias_id, output_data.clone()))
+            },
+        )
     }
 }
@@ -197,11 +197,11 @@ impl Account {
         I::IntoIter: Send,
     {
         log::debug!("[TRANSACTION] prepare_mint_nfts");
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
         let account_addresses = self.addresses().await?;
-        let mut outputs = Vec::new();
+        let mut nft_builders = Vec::new();
+        let mut bech32_hrps = Vec::new();
 
+        // Build the outputs first, so invalid params fail before anything is requested from the node
         for MintNftParams {
             address,
             sender,
@@ -211,11 +211,15 @@ impl Account {
             immutable_metadata,
         } in params
         {
+            bech32_hrps.extend(
+                [&address, &sender, &issuer]
+                    .into_iter()
+                    .flatten()
+                    .map(|address| *address.hrp()),
+            );
+
             let address = match address {
-                Some(address) => {
-                    self.client().bech32_hrp_matches(address.hrp()).await?;
-                    address
-                }
+                Some(address) => address,
                 // todo other error message
                 None => {
                     account_addresses
@@ -225,8 +229,8 @@ impl Account {
                 }
             };
 
-            // NftId needs to be set to 0 for the creation
-            let mut nft_builder = NftOutputBuilder::new_with_minimum_storage_deposit(rent_structure, NftId::null())
+            // NftId needs to be set to 0 for the creation, the amount is set to the minimum storage deposit afterwards
+            let mut nft_builder = NftOutputBuilder::new_with_amount(0, NftId::null())
                 // Address which will own the nft
                 .add_unlock_condition(AddressUnlockCondition::new(address));
 
@@ -250,9 +254,24 @@ impl Account {
                 nft_builder = nft_builder.add_immutable_feature(MetadataFeature::new(immutable_metadata)?);
             }
 
-            outputs.push(nft_builder.finish_output(token_supply)?);
+            nft_builders.push(nft_builder);
         }
 
+        for bech32_hrp in &bech32_hrps {
+            self.client().bech32_hrp_matches(bech32_hrp).await?;
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let outputs = nft_builders
+            .into_iter()
+            .map(|nft_builder| {
+                nft_builder
+                    .with_minimum_storage_deposit(rent_structure)
+                    .finish_output(token_supply)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         self.prepare_transaction(outputs, options).await
     }
 }
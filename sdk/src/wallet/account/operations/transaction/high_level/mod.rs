@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burning_melting;
+pub(crate) mod compose;
 pub(crate) mod create_alias;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
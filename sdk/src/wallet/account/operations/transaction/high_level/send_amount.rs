@@ -12,7 +12,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder,
+            BasicOutputBuilder, Output, RentStructure,
         },
         ConvertTo,
     },
@@ -22,7 +22,7 @@ use crate::{
             operations::transaction::{
                 high_level::minimum_storage_deposit::minimum_storage_deposit_basic_native_tokens, Transaction,
             },
-            Account, TransactionOptions,
+            Account, ReturnStrategy, TransactionOptions,
         },
         Error,
     },
@@ -48,6 +48,12 @@ pub struct SendAmountParams {
     /// expiration is needed but not provided, it will default to one day.
     #[getset(get = "pub")]
     expiration: Option<u32>,
+    /// What to do if the amount is below the minimum storage deposit: with [`ReturnStrategy::Return`], the default,
+    /// the missing storage deposit is added and returned to the return address, which requires
+    /// [`TransactionOptions::allow_micro_amount`]. With [`ReturnStrategy::Gift`] the recipient gets the minimum
+    /// storage deposit instead of the amount.
+    #[getset(get = "pub")]
+    return_strategy: Option<ReturnStrategy>,
 }
 
 impl SendAmountParams {
@@ -57,6 +63,7 @@ impl SendAmountParams {
             amount,
             return_address: None,
             expiration: None,
+            return_strategy: None,
         })
     }
 
@@ -77,6 +84,11 @@ impl SendAmountParams {
         self.expiration = expiration.into();
         self
     }
+
+    pub fn with_return_strategy(mut self, return_strategy: impl Into<Option<ReturnStrategy>>) -> Self {
+        self.return_strategy = return_strategy.into();
+        self
+    }
 }
 
 impl Account {
@@ -120,6 +132,21 @@ impl Account {
     {
         log::debug!("[TRANSACTION] prepare_send_amount");
         let options = options.into();
+        let outputs = self.send_amount_outputs(params, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Builds the outputs for the given [`SendAmountParams`], adding storage deposit returns and expirations when
+    /// required.
+    pub(crate) async fn send_amount_outputs<I: IntoIterator<Item = SendAmountParams> + Send>(
+        &self,
+        params: I,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -127,81 +154,208 @@ impl Account {
         let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
 
         let local_time = self.client().get_time_checked().await?;
+        let allow_micro_amount = options.map(|o| o.allow_micro_amount).unwrap_or_default();
 
         let mut outputs = Vec::new();
-        for SendAmountParams {
-            address,
-            amount,
-            return_address,
-            expiration,
-        } in params
-        {
-            self.client().bech32_hrp_matches(address.hrp()).await?;
-            let return_address = return_address
-                .map(|return_address| {
-                    if return_address.hrp() != address.hrp() {
-                        Err(crate::client::Error::Bech32HrpMismatch {
-                            provided: return_address.hrp().to_string(),
-                            expected: address.hrp().to_string(),
-                        })?;
-                    }
-                    Ok::<_, Error>(return_address)
-                })
-                .transpose()?
-                .unwrap_or(default_return_address.address);
-
-            // Get the minimum required amount for an output assuming it does not need a storage deposit.
-            let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
-                .add_unlock_condition(AddressUnlockCondition::new(address))
-                .finish_output(token_supply)?;
-
-            if amount >= output.amount() {
-                outputs.push(
-                    BasicOutputBuilder::from(output.as_basic())
-                        .with_amount(amount)
-                        .finish_output(token_supply)?,
-                )
-            } else {
-                let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
-
-                // Since it does need a storage deposit, calculate how much that should be
-                let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
-                    &rent_structure,
-                    address.inner(),
-                    return_address.inner(),
-                    None,
-                    token_supply,
-                )?;
-
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
-                    return Err(Error::InsufficientFunds {
-                        available: amount,
-                        required: amount + storage_deposit_amount,
-                    });
-                }
-
-                outputs.push(
-                    // Add address_and_amount.amount+storage_deposit_amount, so receiver can get
-                    // address_and_amount.amount
-                    BasicOutputBuilder::from(output.as_basic())
-                        .with_amount(amount + storage_deposit_amount)
-                        .add_unlock_condition(
-                            // We send the storage_deposit_amount back to the sender, so only the additional amount is
-                            // sent
-                            StorageDepositReturnUnlockCondition::new(
-                                return_address,
-                                storage_deposit_amount,
-                                token_supply,
-                            )?,
-                        )
-                        .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
-                        .finish_output(token_supply)?,
-                )
+        let mut gifts = false;
+        for params in params {
+            self.client().bech32_hrp_matches(params.address.hrp()).await?;
+            gifts |= params.return_strategy == Some(ReturnStrategy::Gift);
+            outputs.push(send_amount_output(
+                params,
+                &default_return_address.address,
+                allow_micro_amount,
+                local_time,
+                rent_structure,
+                token_supply,
+            )?);
+        }
+
+        // Fail before input selection if the storage deposits that are gifted can't be afforded
+        if gifts {
+            let available = self.balance().await?.base_coin().available();
+            let required = outputs.iter().map(Output::amount).sum::<u64>();
+            if required > available {
+                return Err(Error::InsufficientFunds { available, required });
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
+    }
+}
+
+/// Builds the output for [`SendAmountParams`], adding a storage deposit return and an expiration or gifting the storage
+/// deposit if the amount is below the minimum storage deposit.
+fn send_amount_output(
+    params: SendAmountParams,
+    default_return_address: &Bech32Address,
+    allow_micro_amount: bool,
+    local_time: u32,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> crate::wallet::Result<Output> {
+    let SendAmountParams {
+        address,
+        amount,
+        return_address,
+        expiration,
+        return_strategy,
+    } = params;
+
+    let return_address = return_address
+        .map(|return_address| {
+            if return_address.hrp() != address.hrp() {
+                Err(crate::client::Error::Bech32HrpMismatch {
+                    provided: return_address.hrp().to_string(),
+                    expected: address.hrp().to_string(),
+                })?;
+            }
+            Ok::<_, Error>(return_address)
+        })
+        .transpose()?
+        .unwrap_or(*default_return_address);
+
+    // Get the minimum required amount for an output assuming it does not need a storage deposit.
+    let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(token_supply)?;
+
+    if amount >= output.amount() {
+        return Ok(BasicOutputBuilder::from(output.as_basic())
+            .with_amount(amount)
+            .finish_output(token_supply)?);
+    }
+
+    // The recipient gets the minimum storage deposit
+    if return_strategy.unwrap_or_default() == ReturnStrategy::Gift {
+        return Ok(output);
+    }
+
+    let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
+        local_time + expiration_time
+    });
+
+    // Since it does need a storage deposit, calculate how much that should be
+    let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
+        &rent_structure,
+        address.inner(),
+        return_address.inner(),
+        None,
+        token_supply,
+    )?;
+
+    if !allow_micro_amount {
+        return Err(Error::InsufficientFunds {
+            available: amount,
+            required: amount + storage_deposit_amount,
+        });
+    }
+
+    // Add address_and_amount.amount+storage_deposit_amount, so receiver can get
+    // address_and_amount.amount
+    Ok(BasicOutputBuilder::from(output.as_basic())
+        .with_amount(amount + storage_deposit_amount)
+        .add_unlock_condition(
+            // We send the storage_deposit_amount back to the sender, so only the additional amount is
+            // sent
+            StorageDepositReturnUnlockCondition::new(return_address, storage_deposit_amount, token_supply)?,
+        )
+        .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
+        .finish_output(token_supply)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{output::Rent, protocol::protocol_parameters, rand::address::rand_address};
+
+    fn send(
+        amount: u64,
+        return_strategy: Option<ReturnStrategy>,
+        allow_micro_amount: bool,
+    ) -> crate::wallet::Result<Output> {
+        let protocol_parameters = protocol_parameters();
+        let sender = Bech32Address::try_new("rms", rand_address()).unwrap();
+        let recipient = Bech32Address::try_new("rms", rand_address()).unwrap();
+
+        send_amount_output(
+            SendAmountParams::new(recipient, amount)?.with_return_strategy(return_strategy),
+            &sender,
+            allow_micro_amount,
+            0,
+            *protocol_parameters.rent_structure(),
+            protocol_parameters.token_supply(),
+        )
+    }
+
+    fn minimum_amount() -> u64 {
+        let protocol_parameters = protocol_parameters();
+        BasicOutputBuilder::new_with_minimum_storage_deposit(*protocol_parameters.rent_structure())
+            .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+            .finish_output(protocol_parameters.token_supply())
+            .unwrap()
+            .amount()
+    }
+
+    #[test]
+    fn send_amount_output_minimum_amount() {
+        let protocol_parameters = protocol_parameters();
+        let amount = minimum_amount();
+
+        let output = send(amount, None, false).unwrap();
+        assert_eq!(output.amount(), amount);
+        assert!(output.unlock_conditions().unwrap().storage_deposit_return().is_none());
+        output
+            .verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn send_amount_output_micro_amount_return() {
+        let protocol_parameters = protocol_parameters();
+
+        for amount in [1, minimum_amount() - 1] {
+            assert!(matches!(
+                send(amount, None, false),
+                Err(Error::InsufficientFunds { available, .. }) if available == amount
+            ));
+
+            let output = send(amount, Some(ReturnStrategy::Return), true).unwrap();
+            let unlock_conditions = output.unlock_conditions().unwrap();
+            let storage_deposit_return = unlock_conditions.storage_deposit_return().unwrap();
+            // The recipient gets the amount, the storage deposit is returned
+            assert_eq!(output.amount() - storage_deposit_return.amount(), amount);
+            assert_eq!(
+                storage_deposit_return.amount(),
+                output.rent_cost(protocol_parameters.rent_structure())
+            );
+            assert!(unlock_conditions.expiration().is_some());
+            output
+                .verify_storage_deposit(
+                    *protocol_parameters.rent_structure(),
+                    protocol_parameters.token_supply(),
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn send_amount_output_micro_amount_gift() {
+        let protocol_parameters = protocol_parameters();
+
+        for amount in [1, minimum_amount() - 1] {
+            let output = send(amount, Some(ReturnStrategy::Gift), false).unwrap();
+            assert_eq!(output.amount(), minimum_amount());
+            assert_eq!(output.unlock_conditions().unwrap().len(), 1);
+            output
+                .verify_storage_deposit(
+                    *protocol_parameters.rent_structure(),
+                    protocol_parameters.token_supply(),
+                )
+                .unwrap();
+        }
     }
 }
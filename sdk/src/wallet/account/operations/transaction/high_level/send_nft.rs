@@ -85,7 +85,6 @@ impl Account {
     {
         log::debug!("[TRANSACTION] prepare_send_nft");
 
-        let unspent_outputs = self.unspent_outputs(None).await?;
         let token_supply = self.client().get_token_supply().await?;
 
         let mut outputs = Vec::new();
@@ -94,13 +93,7 @@ impl Account {
             self.client().bech32_hrp_matches(address.hrp()).await?;
 
             // Find nft output from the inputs
-            if let Some(nft_output_data) = unspent_outputs.iter().find(|o| {
-                if let Output::Nft(nft_output) = &o.output {
-                    nft_id == nft_output.nft_id_non_null(&o.output_id)
-                } else {
-                    false
-                }
-            }) {
+            if let Some(nft_output_data) = self.unspent_nft_output(&nft_id).await? {
                 if let Output::Nft(nft_output) = &nft_output_data.output {
                     // Set the nft id and new address unlock condition
                     let nft_builder = NftOutputBuilder::from(nft_output)
@@ -7,15 +7,22 @@ use std::collections::{hash_map::Values, HashSet};
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{is_alias_transition, Burn, InputSelection, Selected},
+        api::input_selection::{
+            is_alias_transition, Burn, InputSelection, InputSelectionStrategy, Selected, SelectionPreview,
+        },
         secret::types::InputSigningData,
     },
     types::block::{
         address::Address,
+        input::INPUT_COUNT_MAX,
         output::{Output, OutputId},
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
+        operations::{
+            helpers::time::can_output_be_unlocked_forever_from_now_on,
+            transaction::{RemainderValueStrategy, TransactionOptions},
+        },
+        Account, AccountDetails, OutputData,
     },
 };
 
@@ -28,6 +35,7 @@ impl Account {
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        strategy: InputSelectionStrategy,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -35,7 +43,6 @@ impl Account {
         let voting_output = self.get_voting_output().await?;
         // lock so the same inputs can't be selected in multiple transactions
         let mut account_details = self.details_mut().await;
-        let protocol_parameters = self.client().get_protocol_parameters().await?;
 
         #[cfg(feature = "events")]
         self.emit(
@@ -44,6 +51,147 @@ impl Account {
         )
         .await;
 
+        let selected_transaction_data = match self
+            .input_selection(
+                &account_details,
+                outputs,
+                custom_inputs,
+                mandatory_inputs,
+                remainder_address,
+                burn,
+                strategy,
+                #[cfg(feature = "participation")]
+                voting_output,
+            )
+            .await?
+            .select()
+        {
+            Ok(r) => r,
+            // TODO this error doesn't exist with the new ISA
+            // Err(crate::client::Error::ConsolidationRequired(output_count)) => {
+            //     #[cfg(feature = "events")]
+            //     self.event_emitter
+            //         .lock()
+            //         .await
+            //         .emit(account.index, WalletEvent::ConsolidationRequired);
+            //     return Err(crate::wallet::Error::ConsolidationRequired {
+            //         output_count,
+            //         output_count_max: INPUT_COUNT_MAX,
+            //     });
+            // }
+            Err(e) => return Err(e.into()),
+        };
+
+        // lock outputs so they don't get used by another transaction
+        for output in &selected_transaction_data.inputs {
+            log::debug!("[TRANSACTION] locking: {}", output.output_id());
+            account_details.locked_outputs.insert(*output.output_id());
+        }
+
+        Ok(selected_transaction_data)
+    }
+
+    /// Previews the input selection for a transaction, without locking any outputs
+    pub(crate) async fn preview_inputs(
+        &self,
+        outputs: Vec<Output>,
+        custom_inputs: Option<HashSet<OutputId>>,
+        mandatory_inputs: Option<HashSet<OutputId>>,
+        remainder_address: Option<Address>,
+        burn: Option<&Burn>,
+        strategy: InputSelectionStrategy,
+    ) -> crate::wallet::Result<SelectionPreview> {
+        log::debug!("[TRANSACTION] preview_inputs");
+        // Voting output needs to be requested before to prevent a deadlock
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let account_details = self.details().await;
+
+        Ok(self
+            .input_selection(
+                &account_details,
+                outputs,
+                custom_inputs,
+                mandatory_inputs,
+                remainder_address,
+                burn,
+                strategy,
+                #[cfg(feature = "participation")]
+                voting_output,
+            )
+            .await?
+            .preview()?)
+    }
+
+    /// Returns the input to add for [`RemainderValueStrategy::SweepIntoExisting`]: the smallest unlocked basic output
+    /// with only an address unlock condition that the transaction doesn't consume anyway, if it has a remainder and
+    /// room for another input.
+    pub(crate) async fn sweep_input(
+        &self,
+        outputs: &[Output],
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Option<OutputId>> {
+        let options = match options {
+            Some(options) => options,
+            None => return Ok(None),
+        };
+        // Only the custom inputs can be used if there are some
+        if !matches!(
+            options.remainder_value_strategy,
+            RemainderValueStrategy::SweepIntoExisting
+        ) || options.custom_inputs.is_some()
+        {
+            return Ok(None);
+        }
+        log::debug!("[TRANSACTION] sweep_input");
+
+        let preview = self
+            .preview_inputs(
+                outputs.to_vec(),
+                None,
+                options
+                    .mandatory_inputs
+                    .as_ref()
+                    .map(|inputs| HashSet::from_iter(inputs.clone())),
+                None,
+                options.burn.as_ref(),
+                options.input_selection_strategy,
+            )
+            .await?;
+
+        // Without a remainder the swept amount would need a new output
+        if preview.remainder.is_none() || preview.inputs.len() >= INPUT_COUNT_MAX as usize {
+            return Ok(None);
+        }
+
+        let account_details = self.details().await;
+
+        Ok(account_details
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                !account_details.locked_outputs.contains(&output_data.output_id)
+                    && !preview.inputs.contains(&output_data.output_id)
+                    && matches!(&output_data.output, Output::Basic(basic) if basic.simple_deposit_address().is_some())
+            })
+            .min_by_key(|output_data| (output_data.output.amount(), output_data.output_id))
+            .map(|output_data| output_data.output_id))
+    }
+
+    // Creates the input selection from the unspent outputs of the account that aren't locked
+    #[allow(clippy::too_many_arguments)]
+    async fn input_selection(
+        &self,
+        account_details: &AccountDetails,
+        outputs: Vec<Output>,
+        custom_inputs: Option<HashSet<OutputId>>,
+        mandatory_inputs: Option<HashSet<OutputId>>,
+        remainder_address: Option<Address>,
+        burn: Option<&Burn>,
+        strategy: InputSelectionStrategy,
+        #[cfg(feature = "participation")] voting_output: Option<OutputData>,
+    ) -> crate::wallet::Result<InputSelection> {
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
         let current_time = self.client().get_time_checked().await?;
         #[allow(unused_mut)]
         let mut forbidden_inputs = account_details.locked_outputs.clone();
@@ -69,7 +217,7 @@ impl Account {
         // Filter inputs to not include inputs that require additional outputs for storage deposit return or could be
         // still locked.
         let available_outputs_signing_data = filter_inputs(
-            &account_details,
+            account_details,
             account_details.unspent_outputs.values(),
             current_time,
             &outputs,
@@ -80,91 +228,28 @@ impl Account {
 
         // if custom inputs are provided we should only use them (validate if we have the outputs in this account and
         // that the amount is enough)
-        if let Some(custom_inputs) = custom_inputs {
-            // Check that no input got already locked
-            for input in custom_inputs.iter() {
-                if account_details.locked_outputs.contains(input) {
-                    return Err(crate::wallet::Error::CustomInput(format!(
-                        "provided custom input {input} is already used in another transaction",
-                    )));
-                }
-            }
-
-            let mut input_selection = InputSelection::new(
-                available_outputs_signing_data,
-                outputs,
-                addresses,
-                protocol_parameters.clone(),
-            )
-            .required_inputs(custom_inputs)
-            .forbidden_inputs(forbidden_inputs);
-
-            if let Some(address) = remainder_address {
-                input_selection = input_selection.remainder_address(address);
-            }
+        let required_inputs = custom_inputs.or(mandatory_inputs);
 
-            if let Some(burn) = burn {
-                input_selection = input_selection.burn(burn.clone());
-            }
-
-            let selected_transaction_data = input_selection.select()?;
-
-            // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
-            }
-
-            return Ok(selected_transaction_data);
-        } else if let Some(mandatory_inputs) = mandatory_inputs {
+        if let Some(required_inputs) = &required_inputs {
             // Check that no input got already locked
-            for input in mandatory_inputs.iter() {
+            for input in required_inputs.iter() {
                 if account_details.locked_outputs.contains(input) {
                     return Err(crate::wallet::Error::CustomInput(format!(
                         "provided custom input {input} is already used in another transaction",
                     )));
                 }
             }
+        }
 
-            let mut input_selection = InputSelection::new(
-                available_outputs_signing_data,
-                outputs,
-                addresses,
-                protocol_parameters.clone(),
-            )
-            .required_inputs(mandatory_inputs)
-            .forbidden_inputs(forbidden_inputs);
-
-            if let Some(address) = remainder_address {
-                input_selection = input_selection.remainder_address(address);
-            }
-
-            if let Some(burn) = burn {
-                input_selection = input_selection.burn(burn.clone());
-            }
-
-            let selected_transaction_data = input_selection.select()?;
-
-            // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
-            }
-
-            // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
-            }
+        let mut input_selection =
+            InputSelection::new(available_outputs_signing_data, outputs, addresses, protocol_parameters)
+                .forbidden_inputs(forbidden_inputs)
+                .strategy(strategy);
 
-            return Ok(selected_transaction_data);
+        if let Some(required_inputs) = required_inputs {
+            input_selection = input_selection.required_inputs(required_inputs);
         }
 
-        let mut input_selection = InputSelection::new(
-            available_outputs_signing_data,
-            outputs,
-            addresses,
-            protocol_parameters.clone(),
-        )
-        .forbidden_inputs(forbidden_inputs);
-
         if let Some(address) = remainder_address {
             input_selection = input_selection.remainder_address(address);
         }
@@ -173,30 +258,7 @@ impl Account {
             input_selection = input_selection.burn(burn.clone());
         }
 
-        let selected_transaction_data = match input_selection.select() {
-            Ok(r) => r,
-            // TODO this error doesn't exist with the new ISA
-            // Err(crate::client::Error::ConsolidationRequired(output_count)) => {
-            //     #[cfg(feature = "events")]
-            //     self.event_emitter
-            //         .lock()
-            //         .await
-            //         .emit(account.index, WalletEvent::ConsolidationRequired);
-            //     return Err(crate::wallet::Error::ConsolidationRequired {
-            //         output_count,
-            //         output_count_max: INPUT_COUNT_MAX,
-            //     });
-            // }
-            Err(e) => return Err(e.into()),
-        };
-
-        // lock outputs so they don't get used by another transaction
-        for output in &selected_transaction_data.inputs {
-            log::debug!("[TRANSACTION] locking: {}", output.output_id());
-            account_details.locked_outputs.insert(*output.output_id());
-        }
-
-        Ok(selected_transaction_data)
+        Ok(input_selection)
     }
 }
 
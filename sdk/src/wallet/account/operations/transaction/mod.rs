@@ -10,7 +10,7 @@ mod prepare_transaction;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::options::{RemainderAddressRotation, RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -29,6 +29,7 @@ use crate::{
         },
     },
     wallet::account::{
+        operations::address_generation::count_remainder_address_uses,
         types::{InclusionState, Transaction},
         Account,
     },
@@ -101,11 +102,11 @@ impl Account {
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
 
-        let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
+        let signed_transaction_data = match self.sign_transaction(&prepared_transaction_data).await {
             Ok(res) => res,
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                self.unlock_inputs(&prepared_transaction_data).await?;
                 return Err(err);
             }
         };
@@ -113,7 +114,8 @@ impl Account {
         self.submit_and_store_transaction(signed_transaction_data).await
     }
 
-    /// Validate the transaction, submit it to a node and store it in the account
+    /// Validate the transaction, submit it to a node and store it in the account, the inputs signed offline with
+    /// [`Account::sign_transaction()`] don't need to be locked in this account
     pub async fn submit_and_store_transaction(
         &self,
         signed_transaction_data: SignedTransactionData,
@@ -138,7 +140,8 @@ impl Account {
                 signed_transaction_data.transaction_payload
             );
             // unlock outputs so they are available for a new transaction
-            self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+            self.unlock_input_signing_data(&signed_transaction_data.inputs_data)
+                .await?;
             return Err(Error::TransactionSemantic(conflict).into());
         }
 
@@ -184,6 +187,8 @@ impl Account {
 
         account_details.transactions.insert(transaction_id, transaction.clone());
         account_details.pending_transactions.insert(transaction_id);
+        // The transaction will be resent during syncing if submitting the block failed, so its remainder counts already
+        count_remainder_address_uses(&mut account_details, &transaction.payload);
         #[cfg(feature = "storage")]
         {
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
@@ -193,8 +198,18 @@ impl Account {
         Ok(transaction)
     }
 
+    /// Unlocks the inputs of a prepared transaction that won't be signed or submitted, so they can be used for a new
+    /// transaction. The inputs are locked by [`Account::prepare_transaction()`].
+    pub async fn unlock_inputs(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<()> {
+        self.unlock_input_signing_data(&prepared_transaction_data.inputs_data)
+            .await
+    }
+
     // unlock outputs
-    async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    async fn unlock_input_signing_data(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
         for input_signing_data in inputs {
             let output_id = input_signing_data.output_id();
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, InputSelectionStrategy},
     types::block::{
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
@@ -31,6 +31,9 @@ pub struct TransactionOptions {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// The strategy to select inputs for the amount of the outputs.
+    #[serde(default)]
+    pub input_selection_strategy: InputSelectionStrategy,
 }
 
 impl TransactionOptions {
@@ -44,6 +47,7 @@ impl TransactionOptions {
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            input_selection_strategy: value.input_selection_strategy,
         })
     }
 }
@@ -66,6 +70,9 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// The strategy to select inputs for the amount of the outputs.
+    #[serde(default)]
+    pub input_selection_strategy: InputSelectionStrategy,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -79,6 +86,10 @@ pub enum RemainderValueStrategy {
     ChangeAddress,
     /// Move the remainder value to any specified address.
     CustomAddress(AccountAddress),
+    /// Keep the remainder value on the source address, together with the amount of the smallest basic output of the
+    /// account without native tokens, features or other unlock conditions, which is added as input so the number of
+    /// outputs shrinks by one.
+    SweepIntoExisting,
 }
 
 impl Default for RemainderValueStrategy {
@@ -86,3 +97,27 @@ impl Default for RemainderValueStrategy {
         Self::ReuseAddress
     }
 }
+
+/// The policy of an account for choosing the change address of [`RemainderValueStrategy::ChangeAddress`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "policy", content = "value")]
+pub enum RemainderAddressRotation {
+    /// Keep using the latest internal address, only generate one if there is none.
+    Reuse,
+    /// Generate a new internal address for every remainder.
+    #[default]
+    AlwaysNew,
+    /// Generate a new internal address after the latest one received the given number of remainders.
+    NewAfterNUses(u32),
+}
+
+impl RemainderAddressRotation {
+    // The number of remainders an internal address can receive, `None` if unlimited
+    pub(crate) fn max_uses(&self) -> Option<u32> {
+        match self {
+            Self::Reuse => None,
+            Self::AlwaysNew => Some(1),
+            Self::NewAfterNUses(uses) => Some((*uses).max(1)),
+        }
+    }
+}
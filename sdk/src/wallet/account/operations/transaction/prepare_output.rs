@@ -15,11 +15,11 @@ use crate::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
                 TimelockUnlockCondition,
             },
-            BasicOutputBuilder, NativeToken, NftId, NftOutput, NftOutputBuilder, Output, Rent,
+            BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, Output, Rent,
         },
         Error,
     },
-    wallet::account::{operations::transaction::RemainderValueStrategy, Account, FilterOptions, TransactionOptions},
+    wallet::account::{operations::transaction::RemainderValueStrategy, Account, TransactionOptions},
 };
 
 impl Account {
@@ -190,23 +190,11 @@ impl Account {
 
         let token_supply = self.client().get_token_supply().await?;
         let rent_structure = self.client().get_rent_structure().await?;
-        let unspent_nft_outputs = self
-            .unspent_outputs(Some(FilterOptions {
-                output_types: Some(vec![NftOutput::KIND]),
-                ..Default::default()
-            }))
-            .await?;
 
         // Find nft output from the inputs
-        let mut first_output_builder = if let Some(nft_output_data) = unspent_nft_outputs.iter().find(|o| {
-            if let Output::Nft(nft_output) = &o.output {
-                nft_id == nft_output.nft_id_non_null(&o.output_id)
-            } else {
-                false
-            }
-        }) {
+        let mut first_output_builder = if let Some(nft_output_data) = self.unspent_nft_output(&nft_id).await? {
             if let Output::Nft(nft_output) = &nft_output_data.output {
-                NftOutputBuilder::from(nft_output).with_nft_id(nft_output.nft_id_non_null(&nft_output_data.output_id))
+                NftOutputBuilder::from(nft_output).with_nft_id(nft_id)
             } else {
                 unreachable!("We checked before if it's an nft output")
             }
@@ -360,12 +348,12 @@ impl Account {
         let remainder_address = match &transaction_options {
             Some(options) => {
                 match &options.remainder_value_strategy {
-                    RemainderValueStrategy::ReuseAddress => {
+                    RemainderValueStrategy::ReuseAddress | RemainderValueStrategy::SweepIntoExisting => {
                         // select_inputs will select an address from the inputs if it's none
                         None
                     }
                     RemainderValueStrategy::ChangeAddress => {
-                        let remainder_address = self.generate_remainder_address().await?;
+                        let remainder_address = self.remainder_change_address().await?;
                         Some(remainder_address.address().inner)
                     }
                     RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
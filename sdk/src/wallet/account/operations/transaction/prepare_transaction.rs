@@ -9,10 +9,10 @@ use packable::bounded::TryIntoBoundedU16Error;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::api::PreparedTransactionData,
+    client::api::{input_selection::SelectionPreview, PreparedTransactionData},
     types::block::{
         input::INPUT_COUNT_RANGE,
-        output::{Output, OUTPUT_COUNT_RANGE},
+        output::{Output, OutputId, OUTPUT_COUNT_RANGE},
     },
     wallet::account::{
         operations::transaction::{RemainderValueStrategy, TransactionOptions},
@@ -21,7 +21,10 @@ use crate::{
 };
 
 impl Account {
-    /// Get inputs and build the transaction essence
+    /// Get inputs and build the transaction essence, without signing it. The selected inputs are locked until the
+    /// transaction is submitted with [`Account::submit_and_store_transaction()`] or they're unlocked with
+    /// [`Account::unlock_inputs()`]. The returned data contains everything needed to sign it with
+    /// [`Account::sign_transaction()`] without network access.
     pub async fn prepare_transaction(
         &self,
         outputs: impl Into<Vec<Output>> + Send,
@@ -70,12 +73,12 @@ impl Account {
         let remainder_address = match &options {
             Some(options) => {
                 match &options.remainder_value_strategy {
-                    RemainderValueStrategy::ReuseAddress => {
+                    RemainderValueStrategy::ReuseAddress | RemainderValueStrategy::SweepIntoExisting => {
                         // select_inputs will select an address from the inputs if it's none
                         None
                     }
                     RemainderValueStrategy::ChangeAddress => {
-                        let remainder_address = self.generate_remainder_address().await?;
+                        let remainder_address = self.remainder_change_address().await?;
                         #[cfg(feature = "events")]
                         {
                             let account_index = self.details().await.index;
@@ -97,6 +100,9 @@ impl Account {
             None => None,
         };
 
+        let sweep_input = self.sweep_input(&outputs, options.as_ref()).await?;
+        let mandatory_inputs = with_sweep_input(options.as_ref(), sweep_input);
+
         let selected_transaction_data = self
             .select_inputs(
                 outputs,
@@ -104,12 +110,13 @@ impl Account {
                     .as_ref()
                     .and_then(|options| options.custom_inputs.as_ref())
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
-                options
-                    .as_ref()
-                    .and_then(|options| options.mandatory_inputs.as_ref())
-                    .map(|inputs| HashSet::from_iter(inputs.clone())),
+                mandatory_inputs,
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.input_selection_strategy)
+                    .unwrap_or_default(),
             )
             .await?;
 
@@ -131,4 +138,65 @@ impl Account {
         );
         Ok(prepared_transaction_data)
     }
+
+    /// Previews what a transaction with the outputs and options would consume, burn and create, without building the
+    /// essence or locking any outputs. With [`RemainderValueStrategy::ChangeAddress`] no new remainder address is
+    /// generated for the preview, the remainder is shown on an address of the inputs instead.
+    pub async fn preview_transaction(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<SelectionPreview> {
+        log::debug!("[TRANSACTION] preview_transaction");
+        let options = options.into();
+        let outputs = outputs.into();
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        for output in &outputs {
+            output.verify_storage_deposit(rent_structure, token_supply)?;
+        }
+
+        let remainder_address = options
+            .as_ref()
+            .and_then(|options| match &options.remainder_value_strategy {
+                RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                RemainderValueStrategy::ReuseAddress
+                | RemainderValueStrategy::ChangeAddress
+                | RemainderValueStrategy::SweepIntoExisting => None,
+            });
+        let sweep_input = self.sweep_input(&outputs, options.as_ref()).await?;
+        let mandatory_inputs = with_sweep_input(options.as_ref(), sweep_input);
+
+        let mut preview = self
+            .preview_inputs(
+                outputs,
+                options
+                    .as_ref()
+                    .and_then(|options| options.custom_inputs.as_ref())
+                    .map(|inputs| HashSet::from_iter(inputs.clone())),
+                mandatory_inputs,
+                remainder_address,
+                options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.input_selection_strategy)
+                    .unwrap_or_default(),
+            )
+            .await?;
+        preview.swept_input = sweep_input;
+
+        Ok(preview)
+    }
+}
+
+// The mandatory inputs of the options, with the input to sweep into the remainder
+fn with_sweep_input(options: Option<&TransactionOptions>, sweep_input: Option<OutputId>) -> Option<HashSet<OutputId>> {
+    let mut mandatory_inputs = options
+        .and_then(|options| options.mandatory_inputs.as_ref())
+        .map(|inputs| HashSet::from_iter(inputs.clone()));
+    if let Some(sweep_input) = sweep_input {
+        mandatory_inputs.get_or_insert_with(HashSet::new).insert(sweep_input);
+    }
+    mandatory_inputs
 }
@@ -18,12 +18,23 @@ use crate::{
 };
 
 impl Account {
-    /// Function to sign a transaction essence
+    /// Previous name of [`Account::sign_transaction()`].
+    #[deprecated(note = "renamed to `sign_transaction`")]
     pub async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
     ) -> crate::wallet::Result<SignedTransactionData> {
-        log::debug!("[TRANSACTION] sign_transaction_essence");
+        self.sign_transaction(prepared_transaction_data).await
+    }
+
+    /// Signs a transaction prepared with [`Account::prepare_transaction()`], only the secret manager is used, so it can
+    /// be done on a machine without network access. The inputs stay locked if signing fails, use
+    /// [`Account::unlock_inputs()`] if the transaction is abandoned.
+    pub async fn sign_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<SignedTransactionData> {
+        log::debug!("[TRANSACTION] sign_transaction");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
         #[cfg(feature = "events")]
         self.emit(
@@ -56,21 +67,13 @@ impl Account {
             }
         }
 
-        let unlocks = match self
+        let unlocks = self
             .wallet
             .secret_manager
             .read()
             .await
             .sign_transaction_essence(prepared_transaction_data, None)
-            .await
-        {
-            Ok(res) => res,
-            Err(err) => {
-                // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
-                return Err(err.into());
-            }
-        };
+            .await?;
         let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
 
         log::debug!("[TRANSACTION] signed transaction: {:?}", transaction_payload);
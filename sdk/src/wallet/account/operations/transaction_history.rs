@@ -0,0 +1,314 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, io::Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{address::Address, output::OutputId, payload::transaction::TransactionEssence},
+    wallet::account::{types::Transaction, Account, AccountDetails},
+};
+
+/// The format of an exported transaction history.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// All transactions with all their data as a JSON array.
+    Json,
+    /// One row per transaction with the columns `timestamp`, `transaction_id`, `direction`, `amount` and
+    /// `inclusion_state`.
+    Csv,
+}
+
+/// The order of the transactions in a [`TransactionPage`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionSort {
+    /// Sorted by ascending timestamp.
+    OldestFirst,
+    /// Sorted by descending timestamp.
+    NewestFirst,
+}
+
+/// A page of the transaction history, returned from [`Account::transaction_history_page()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPage {
+    /// The transactions of the page, empty if the page is out of range.
+    pub items: Vec<Transaction>,
+    /// The number of all transactions in the history.
+    pub total: usize,
+    /// The index of the page, starting at 0.
+    pub page: usize,
+    /// The max number of transactions per page.
+    pub page_size: usize,
+}
+
+impl Account {
+    /// Writes all incoming and outgoing transactions of the account, sorted by their timestamp, in the given format.
+    /// Only the local data is used, the account isn't synced.
+    pub async fn export_transaction_history(
+        &self,
+        format: ExportFormat,
+        writer: &mut (impl Write + Send),
+    ) -> crate::wallet::Result<()> {
+        self.details().await.write_transaction_history(format, writer)
+    }
+
+    /// Returns a page of the incoming and outgoing transactions of the account, sorted by their timestamp. Pages start
+    /// at index 0, a page out of range has no items.
+    pub async fn transaction_history_page(
+        &self,
+        page: usize,
+        page_size: usize,
+        sort: TransactionSort,
+    ) -> TransactionPage {
+        self.details().await.transaction_history_page(page, page_size, sort)
+    }
+}
+
+impl AccountDetails {
+    fn sorted_transactions(&self) -> Vec<&Transaction> {
+        let mut transactions = self
+            .transactions
+            .values()
+            .chain(self.incoming_transactions.values())
+            .collect::<Vec<_>>();
+        transactions.sort_by_key(|transaction| (transaction.timestamp, transaction.transaction_id));
+        transactions
+    }
+
+    pub(crate) fn transaction_history_page(
+        &self,
+        page: usize,
+        page_size: usize,
+        sort: TransactionSort,
+    ) -> TransactionPage {
+        let mut transactions = self.sorted_transactions();
+        if sort == TransactionSort::NewestFirst {
+            transactions.reverse();
+        }
+
+        TransactionPage {
+            items: transactions
+                .iter()
+                .skip(page.saturating_mul(page_size))
+                .take(page_size)
+                .map(|transaction| (*transaction).clone())
+                .collect(),
+            total: transactions.len(),
+            page,
+            page_size,
+        }
+    }
+
+    pub(crate) fn write_transaction_history(
+        &self,
+        format: ExportFormat,
+        writer: &mut impl Write,
+    ) -> crate::wallet::Result<()> {
+        let transactions = self.sorted_transactions();
+
+        match format {
+            ExportFormat::Json => serde_json::to_writer(writer, &transactions)?,
+            ExportFormat::Csv => {
+                let account_addresses = self
+                    .public_addresses
+                    .iter()
+                    .chain(&self.internal_addresses)
+                    .map(|address| address.address.inner)
+                    .collect::<HashSet<_>>();
+
+                writeln!(writer, "timestamp,transaction_id,direction,amount,inclusion_state")?;
+                for transaction in transactions {
+                    let direction = if transaction.incoming { "incoming" } else { "outgoing" };
+                    // Received amounts are the ones to the account, sent amounts the ones to someone else.
+                    let amount = transaction_amount(transaction, &account_addresses, transaction.incoming)?;
+
+                    writeln!(
+                        writer,
+                        "{},{},{direction},{amount},{:?}",
+                        transaction.timestamp, transaction.transaction_id, transaction.inclusion_state
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Sums the amounts of the transaction outputs that are owned by the account if `owned` is true, or that are owned by
+/// someone else otherwise.
+fn transaction_amount(
+    transaction: &Transaction,
+    account_addresses: &HashSet<Address>,
+    owned: bool,
+) -> crate::wallet::Result<u64> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    // The timestamp is in milliseconds
+    let time = (transaction.timestamp / 1000) as u32;
+    let mut amount = 0;
+
+    for (index, output) in essence.outputs().iter().enumerate() {
+        let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+        let (address, _) = output.required_and_unlocked_address(time, &output_id, None)?;
+
+        if account_addresses.contains(&address) == owned {
+            amount += output.amount();
+        }
+    }
+
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        types::block::{
+            input::{Input, UtxoInput},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutput, InputsCommitment, Output},
+            payload::{transaction::RegularTransactionEssence, TransactionPayload},
+            protocol::protocol_parameters,
+            rand::{address::rand_ed25519_address, transaction::rand_transaction_id},
+        },
+        wallet::account::types::InclusionState,
+    };
+
+    fn transaction(outputs: &[(Address, u64)], timestamp: u128, incoming: bool) -> Transaction {
+        let protocol_parameters = protocol_parameters();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs([Input::Utxo(UtxoInput::new(rand_transaction_id(), 0).unwrap())])
+                .with_outputs(
+                    outputs
+                        .iter()
+                        .map(|(address, amount)| {
+                            Output::Basic(
+                                BasicOutput::build_with_amount(*amount)
+                                    .add_unlock_condition(AddressUnlockCondition::new(*address))
+                                    .finish(protocol_parameters.token_supply())
+                                    .unwrap(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+        let payload = TransactionPayload::rand_mock(essence);
+
+        Transaction {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            network_id: protocol_parameters.network_id(),
+            timestamp,
+            inclusion_state: InclusionState::Confirmed,
+            incoming,
+            note: None,
+            inputs: Vec::new(),
+        }
+    }
+
+    fn account_details() -> (AccountDetails, Vec<Transaction>) {
+        let mut account_details = AccountDetails::mock();
+        let own_address = account_details.public_addresses[0].address.inner;
+        let other_address = Address::from(rand_ed25519_address());
+
+        // Sent 1 Mi with a remainder of 2 Mi
+        let outgoing = transaction(
+            &[(other_address, 1_000_000), (own_address, 2_000_000)],
+            1_000_000,
+            false,
+        );
+        // Received 3 Mi, while the sender got a remainder
+        let incoming = transaction(&[(own_address, 3_000_000), (other_address, 4_000_000)], 2_000_000, true);
+
+        account_details.transactions = HashMap::from([(outgoing.transaction_id, outgoing.clone())]);
+        account_details.incoming_transactions = HashMap::from([(incoming.transaction_id, incoming.clone())]);
+
+        (account_details, vec![outgoing, incoming])
+    }
+
+    #[test]
+    fn export_transaction_history_json() {
+        let (account_details, transactions) = account_details();
+
+        let mut json = Vec::new();
+        account_details
+            .write_transaction_history(ExportFormat::Json, &mut json)
+            .unwrap();
+
+        assert_eq!(serde_json::from_slice::<Vec<Transaction>>(&json).unwrap(), transactions);
+    }
+
+    #[test]
+    fn export_transaction_history_csv() {
+        let (account_details, transactions) = account_details();
+
+        let mut csv = Vec::new();
+        account_details
+            .write_transaction_history(ExportFormat::Csv, &mut csv)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            format!(
+                "timestamp,transaction_id,direction,amount,inclusion_state\n\
+                1000000,{},outgoing,1000000,Confirmed\n\
+                2000000,{},incoming,3000000,Confirmed\n",
+                transactions[0].transaction_id, transactions[1].transaction_id
+            )
+        );
+    }
+
+    #[test]
+    fn transaction_history_pages() {
+        let (account_details, transactions) = account_details();
+
+        let page = account_details.transaction_history_page(0, 1, TransactionSort::OldestFirst);
+        assert_eq!(
+            page,
+            TransactionPage {
+                items: vec![transactions[0].clone()],
+                total: 2,
+                page: 0,
+                page_size: 1,
+            }
+        );
+
+        let page = account_details.transaction_history_page(0, 1, TransactionSort::NewestFirst);
+        assert_eq!(page.items, [transactions[1].clone()]);
+
+        // The last page has fewer items than the page size
+        let page = account_details.transaction_history_page(0, 3, TransactionSort::OldestFirst);
+        assert_eq!(page.items, transactions);
+
+        // Pages out of range are empty
+        let page = account_details.transaction_history_page(2, 1, TransactionSort::OldestFirst);
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, 2);
+        let page = account_details.transaction_history_page(usize::MAX, usize::MAX, TransactionSort::OldestFirst);
+        assert!(page.items.is_empty());
+    }
+
+    #[test]
+    fn export_empty_transaction_history() {
+        let account_details = AccountDetails::mock();
+
+        let mut json = Vec::new();
+        account_details
+            .write_transaction_history(ExportFormat::Json, &mut json)
+            .unwrap();
+        assert_eq!(json, b"[]");
+
+        let mut csv = Vec::new();
+        account_details
+            .write_transaction_history(ExportFormat::Csv, &mut csv)
+            .unwrap();
+        assert_eq!(csv, b"timestamp,transaction_id,direction,amount,inclusion_state\n");
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::node_api::error::Error as NodeApiError,
+    types::block::{
+        address::Bech32Address,
+        output::{dto::OutputMetadataDto, OutputId},
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+    wallet::account::{operations::syncing::SyncOptions, types::OutputData, Account},
+};
+
+/// Max number of spent outputs that are compared with the node, the most recently spent ones are chosen.
+const SPENT_OUTPUTS_SAMPLE_SIZE: usize = 50;
+
+/// A discrepancy between the stored data of an account and the node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum IntegrityIssue {
+    /// An unspent output isn't known by the node.
+    #[serde(rename_all = "camelCase")]
+    MissingOutput { output_id: OutputId },
+    /// The stored spent flag of an output differs from the one of the node.
+    #[serde(rename_all = "camelCase")]
+    StaleSpentFlag { output_id: OutputId, stored_is_spent: bool },
+    /// The stored amount or native tokens of an output differ from the ones of the node.
+    #[serde(rename_all = "camelCase")]
+    OutputMismatch { output_id: OutputId },
+    /// Outputs are listed for an address, but aren't unspent outputs of the account.
+    #[serde(rename_all = "camelCase")]
+    AddressOutputsMismatch {
+        address: Bech32Address,
+        output_ids: Vec<OutputId>,
+    },
+    /// The balances of the addresses don't sum up to the balance of the account, because unspent outputs aren't listed
+    /// for an address or for more than one.
+    #[serde(rename_all = "camelCase")]
+    BalanceMismatch {
+        address_balances: u64,
+        account_balance: u64,
+    },
+    /// The block of a pending transaction isn't known by the node.
+    #[serde(rename_all = "camelCase")]
+    MissingPendingTransactionBlock {
+        transaction_id: TransactionId,
+        block_id: BlockId,
+    },
+}
+
+/// The result of [`Account::verify_integrity()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    /// The found discrepancies.
+    pub issues: Vec<IntegrityIssue>,
+    /// If the account was repaired.
+    pub repaired: bool,
+}
+
+impl Account {
+    /// Compares the stored data of the account with the node: all unspent outputs and a sample of the spent ones are
+    /// requested again, the output ids of the addresses are checked against the unspent outputs, the balances of the
+    /// addresses against the account balance and the blocks of pending transactions are looked up. If `repair` is true,
+    /// found issues are fixed by updating the account with the data of the node and syncing it afterwards.
+    pub async fn verify_integrity(&self, repair: bool) -> crate::wallet::Result<IntegrityReport> {
+        log::debug!("[verify_integrity]");
+        let network_id = self.client().get_network_id().await?;
+        let mut issues = Vec::new();
+
+        let (stored_outputs, addresses_with_unspent_outputs, pending_blocks) = {
+            let account_details = self.details().await;
+
+            let mut spent_outputs = account_details
+                .outputs
+                .values()
+                .filter(|output_data| output_data.is_spent && output_data.network_id == network_id)
+                .collect::<Vec<_>>();
+            spent_outputs.sort_by_key(|output_data| {
+                std::cmp::Reverse(output_data.metadata.milestone_timestamp_spent().unwrap_or_default())
+            });
+
+            let stored_outputs = account_details
+                .unspent_outputs
+                .values()
+                .filter(|output_data| output_data.network_id == network_id)
+                .chain(spent_outputs.into_iter().take(SPENT_OUTPUTS_SAMPLE_SIZE))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for address in &account_details.addresses_with_unspent_outputs {
+                let output_ids = address
+                    .output_ids
+                    .iter()
+                    .filter(|output_id| !account_details.unspent_outputs.contains_key(output_id))
+                    .copied()
+                    .collect::<Vec<_>>();
+                if !output_ids.is_empty() {
+                    issues.push(IntegrityIssue::AddressOutputsMismatch {
+                        address: address.address,
+                        output_ids,
+                    });
+                }
+            }
+
+            let account_balance = account_details
+                .unspent_outputs
+                .values()
+                .map(|output_data| output_data.output.amount())
+                .sum::<u64>();
+            let address_balances = account_details
+                .addresses_with_unspent_outputs
+                .iter()
+                .flat_map(|address| &address.output_ids)
+                .filter_map(|output_id| account_details.unspent_outputs.get(output_id))
+                .map(|output_data| output_data.output.amount())
+                .sum::<u64>();
+            if address_balances != account_balance {
+                issues.push(IntegrityIssue::BalanceMismatch {
+                    address_balances,
+                    account_balance,
+                });
+            }
+
+            let pending_blocks = account_details
+                .pending_transactions
+                .iter()
+                .filter_map(|transaction_id| {
+                    let block_id = account_details.transactions.get(transaction_id)?.block_id?;
+                    Some((*transaction_id, block_id))
+                })
+                .collect::<Vec<_>>();
+
+            (
+                stored_outputs,
+                account_details.addresses_with_unspent_outputs.clone(),
+                pending_blocks,
+            )
+        };
+
+        let output_ids = stored_outputs
+            .iter()
+            .map(|output_data| output_data.output_id)
+            .collect::<Vec<_>>();
+        let (node_outputs, _) = self.client().try_get_outputs(&output_ids).await?;
+        let node_outputs = node_outputs
+            .into_iter()
+            .map(|output| (*output.metadata().output_id(), output))
+            .collect::<HashMap<_, _>>();
+
+        // Outputs to update with the data of the node
+        let mut updated_outputs = Vec::new();
+        let mut spent_or_missing_outputs = HashMap::new();
+
+        for stored in stored_outputs {
+            let Some(node_output) = node_outputs.get(&stored.output_id) else {
+                // Spent outputs can be pruned by the node
+                if !stored.is_spent {
+                    issues.push(IntegrityIssue::MissingOutput {
+                        output_id: stored.output_id,
+                    });
+                    spent_or_missing_outputs.insert(stored.output_id, None);
+                }
+                continue;
+            };
+
+            let node_is_spent = node_output.metadata().is_spent();
+            let output_differs = stored.output.amount() != node_output.output().amount()
+                || stored.output.native_tokens() != node_output.output().native_tokens();
+
+            if stored.is_spent != node_is_spent {
+                issues.push(IntegrityIssue::StaleSpentFlag {
+                    output_id: stored.output_id,
+                    stored_is_spent: stored.is_spent,
+                });
+            }
+            if output_differs {
+                issues.push(IntegrityIssue::OutputMismatch {
+                    output_id: stored.output_id,
+                });
+            }
+
+            if node_is_spent && !stored.is_spent {
+                spent_or_missing_outputs
+                    .insert(stored.output_id, Some(OutputMetadataDto::from(node_output.metadata())));
+            } else if output_differs || stored.is_spent != node_is_spent {
+                updated_outputs.push(OutputData {
+                    output: node_output.output().clone(),
+                    metadata: node_output.metadata().clone(),
+                    is_spent: node_is_spent,
+                    ..stored
+                });
+            }
+        }
+
+        for (transaction_id, block_id) in pending_blocks {
+            match self.client().get_block_metadata(&block_id).await {
+                Ok(_) => {}
+                Err(crate::client::Error::Node(NodeApiError::NotFound(_))) => {
+                    issues.push(IntegrityIssue::MissingPendingTransactionBlock {
+                        transaction_id,
+                        block_id,
+                    });
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let repaired = repair && !issues.is_empty();
+        if repaired {
+            // Apply the data of the node like a sync would and then sync the addresses and pending transactions again
+            self.update_account(
+                addresses_with_unspent_outputs,
+                updated_outputs,
+                spent_or_missing_outputs,
+                &SyncOptions::default(),
+                None,
+            )
+            .await?;
+            self.sync(Some(SyncOptions {
+                force_syncing: true,
+                ..self.default_sync_options().await
+            }))
+            .await?;
+        }
+
+        Ok(IntegrityReport { issues, repaired })
+    }
+}
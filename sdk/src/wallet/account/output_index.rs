@@ -0,0 +1,417 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{unlock_condition::UnlockCondition, ChainId, OutputId, TokenId},
+    },
+    wallet::account::types::OutputData,
+};
+
+/// Indexes of the unspent outputs of an account, so they can be looked up by output kind, address, native token or
+/// chain id without iterating over all outputs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OutputIndex {
+    /// Output ids by their output kind
+    // missing for indexes stored by older versions, which are then rebuilt
+    #[serde(default, with = "entries")]
+    kinds: HashMap<u8, HashSet<OutputId>>,
+    /// Output ids by the addresses in their unlock conditions
+    #[serde(with = "entries")]
+    addresses: HashMap<Address, HashSet<OutputId>>,
+    /// Output ids by the native tokens they hold
+    #[serde(with = "entries")]
+    native_tokens: HashMap<TokenId, HashSet<OutputId>>,
+    /// Output id by chain id, null alias and nft ids are computed from the output id
+    #[serde(with = "entries")]
+    chains: HashMap<ChainId, OutputId>,
+}
+
+impl OutputIndex {
+    /// Builds the index from scratch.
+    pub(crate) fn new<'a>(outputs: impl IntoIterator<Item = &'a OutputData>) -> Self {
+        let mut index = Self::default();
+
+        for output_data in outputs {
+            index.insert(output_data);
+        }
+
+        index
+    }
+
+    /// The number of indexed outputs.
+    pub(crate) fn len(&self) -> usize {
+        self.kinds.values().map(HashSet::len).sum()
+    }
+
+    pub(crate) fn insert(&mut self, output_data: &OutputData) {
+        self.kinds
+            .entry(output_data.output.kind())
+            .or_default()
+            .insert(output_data.output_id);
+        for address in addresses(output_data) {
+            self.addresses
+                .entry(*address)
+                .or_default()
+                .insert(output_data.output_id);
+        }
+        for token_id in token_ids(output_data) {
+            self.native_tokens
+                .entry(*token_id)
+                .or_default()
+                .insert(output_data.output_id);
+        }
+        if let Some(chain_id) = output_data.chain_id() {
+            self.chains.insert(chain_id, output_data.output_id);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, output_data: &OutputData) {
+        remove_from_set(&mut self.kinds, &output_data.output.kind(), &output_data.output_id);
+        for address in addresses(output_data) {
+            remove_from_set(&mut self.addresses, address, &output_data.output_id);
+        }
+        for token_id in token_ids(output_data) {
+            remove_from_set(&mut self.native_tokens, token_id, &output_data.output_id);
+        }
+        if let Some(chain_id) = output_data.chain_id() {
+            // Only remove the entry if it wasn't replaced by a newer output of the chain already
+            if self.chains.get(&chain_id) == Some(&output_data.output_id) {
+                self.chains.remove(&chain_id);
+            }
+        }
+    }
+
+    /// Returns the ids of the outputs of the output kind.
+    pub(crate) fn output_ids_with_kind(&self, kind: u8) -> impl Iterator<Item = &OutputId> {
+        self.kinds.get(&kind).into_iter().flatten()
+    }
+
+    /// Returns the ids of the outputs that have the address in one of their unlock conditions.
+    pub(crate) fn output_ids_with_address(&self, address: &Address) -> impl Iterator<Item = &OutputId> {
+        self.addresses.get(address).into_iter().flatten()
+    }
+
+    /// Returns the ids of the outputs that hold the native token.
+    pub(crate) fn output_ids_with_native_token(&self, token_id: &TokenId) -> impl Iterator<Item = &OutputId> {
+        self.native_tokens.get(token_id).into_iter().flatten()
+    }
+
+    /// Returns the id of the latest output of the chain.
+    pub(crate) fn chain_output_id(&self, chain_id: &ChainId) -> Option<&OutputId> {
+        self.chains.get(chain_id)
+    }
+}
+
+fn addresses(output_data: &OutputData) -> impl Iterator<Item = &Address> {
+    output_data
+        .output
+        .unlock_conditions()
+        .into_iter()
+        .flat_map(|unlock_conditions| unlock_conditions.iter())
+        .filter_map(|unlock_condition| match unlock_condition {
+            UnlockCondition::Address(uc) => Some(uc.address()),
+            UnlockCondition::StorageDepositReturn(uc) => Some(uc.return_address()),
+            UnlockCondition::Timelock(_) => None,
+            UnlockCondition::Expiration(uc) => Some(uc.return_address()),
+            UnlockCondition::StateControllerAddress(uc) => Some(uc.address()),
+            UnlockCondition::GovernorAddress(uc) => Some(uc.address()),
+            UnlockCondition::ImmutableAliasAddress(uc) => Some(uc.address()),
+        })
+}
+
+fn token_ids(output_data: &OutputData) -> impl Iterator<Item = &TokenId> {
+    output_data
+        .output
+        .native_tokens()
+        .into_iter()
+        .flat_map(|native_tokens| native_tokens.iter())
+        .map(|native_token| native_token.token_id())
+}
+
+fn remove_from_set<K: Eq + std::hash::Hash>(map: &mut HashMap<K, HashSet<OutputId>>, key: &K, output_id: &OutputId) {
+    if let Some(output_ids) = map.get_mut(key) {
+        output_ids.remove(output_id);
+        if output_ids.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+/// (De)serializes a map as a list of entries, because JSON only supports string keys.
+mod entries {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub(super) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + std::hash::Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<(K, V)>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{seq::IteratorRandom, Rng};
+
+    use super::*;
+    use crate::{
+        types::block::{
+            address::AliasAddress,
+            output::{
+                unlock_condition::{
+                    AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                    ImmutableAliasAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                },
+                AliasId, AliasOutput, AliasOutputBuilder, BasicOutput, BasicOutputBuilder, FoundryOutput,
+                FoundryOutputBuilder, NativeToken, NftId, NftOutput, NftOutputBuilder, Output, SimpleTokenScheme,
+                TokenScheme,
+            },
+            protocol::protocol_parameters,
+            rand::{address::rand_address, output::rand_alias_id},
+        },
+        wallet::account::{AccountDetails, FilterOptions},
+    };
+
+    fn rand_output(addresses: &[Address], token_ids: &[TokenId]) -> Output {
+        let mut rng = rand::thread_rng();
+        let token_supply = protocol_parameters().token_supply();
+        let address = *addresses.iter().choose(&mut rng).unwrap();
+        let native_tokens = token_ids
+            .iter()
+            .filter(|_| rng.gen_bool(0.3))
+            .map(|token_id| NativeToken::new(*token_id, 100.into()).unwrap())
+            .collect::<Vec<_>>();
+
+        match rng.gen_range(0..4) {
+            0 => BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .with_native_tokens(native_tokens)
+                .finish_output(token_supply)
+                .unwrap(),
+            1 => NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(
+                    ExpirationUnlockCondition::new(*addresses.iter().choose(&mut rng).unwrap(), 1).unwrap(),
+                )
+                .finish_output(token_supply)
+                .unwrap(),
+            2 => AliasOutputBuilder::new_with_amount(1_000_000, rand_alias_id())
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+                .with_native_tokens(native_tokens)
+                .finish_output(token_supply)
+                .unwrap(),
+            _ => FoundryOutputBuilder::new_with_amount(
+                1_000_000,
+                rng.gen(),
+                TokenScheme::Simple(SimpleTokenScheme::new(100.into(), 0.into(), 100.into()).unwrap()),
+            )
+            .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(
+                rand_alias_id(),
+            )))
+            .finish_output(token_supply)
+            .unwrap(),
+        }
+    }
+
+    fn rand_output_data(addresses: &[Address], token_ids: &[TokenId]) -> OutputData {
+        OutputData::rand_mock(rand_output(addresses, token_ids), addresses[0])
+    }
+
+    #[test]
+    fn output_index_matches_brute_force_scan() {
+        let mut rng = rand::thread_rng();
+        let addresses = (0..5).map(|_| rand_address()).collect::<Vec<_>>();
+        let token_ids = (0..5).map(|_| TokenId::new(rng.gen())).collect::<Vec<_>>();
+        let mut account_details = AccountDetails::mock();
+
+        // Randomized sync rounds, each adding new outputs and spending some of the existing ones
+        for _ in 0..20 {
+            for _ in 0..rng.gen_range(0..20) {
+                account_details.insert_unspent_output(rand_output_data(&addresses, &token_ids));
+            }
+            let spent_count = rng.gen_range(0..10);
+            let spent_output_ids = account_details
+                .unspent_outputs
+                .keys()
+                .copied()
+                .choose_multiple(&mut rng, spent_count);
+            for output_id in spent_output_ids {
+                account_details.remove_unspent_output(&output_id);
+            }
+
+            assert_eq!(
+                account_details.output_index,
+                OutputIndex::new(account_details.unspent_outputs.values())
+            );
+
+            for address in &addresses {
+                let indexed = account_details
+                    .unspent_outputs_with_address(address)
+                    .map(|output_data| output_data.output_id)
+                    .collect::<HashSet<_>>();
+                let scanned = account_details
+                    .unspent_outputs
+                    .values()
+                    .filter(|output_data| addresses_of(output_data).contains(address))
+                    .map(|output_data| output_data.output_id)
+                    .collect::<HashSet<_>>();
+                assert_eq!(indexed, scanned);
+            }
+            for token_id in &token_ids {
+                let indexed = account_details
+                    .unspent_outputs_with_native_token(token_id)
+                    .map(|output_data| output_data.output_id)
+                    .collect::<HashSet<_>>();
+                let scanned = account_details
+                    .unspent_outputs
+                    .values()
+                    .filter(|output_data| token_ids_of(output_data).contains(token_id))
+                    .map(|output_data| output_data.output_id)
+                    .collect::<HashSet<_>>();
+                assert_eq!(indexed, scanned);
+            }
+            for output_data in account_details.unspent_outputs.values() {
+                if let Some(chain_id) = output_data.chain_id() {
+                    assert_eq!(
+                        account_details.unspent_chain_output(chain_id).map(|o| o.output_id),
+                        Some(output_data.output_id)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn filter_unspent_outputs_matches_brute_force_scan() {
+        let mut rng = rand::thread_rng();
+        let addresses = (0..3).map(|_| rand_address()).collect::<Vec<_>>();
+        let token_ids = [TokenId::new(rng.gen())];
+        let mut account_details = AccountDetails::mock();
+        for _ in 0..200 {
+            account_details.insert_unspent_output(rand_output_data(&addresses, &token_ids));
+        }
+        let timestamps = account_details
+            .unspent_outputs
+            .values()
+            .map(|output_data| output_data.metadata.milestone_timestamp_booked())
+            .collect::<Vec<_>>();
+        let alias_id = account_details
+            .unspent_outputs
+            .values()
+            .find_map(|output_data| match output_data.chain_id() {
+                Some(ChainId::Alias(alias_id)) => Some(alias_id),
+                _ => None,
+            });
+
+        for _ in 0..50 {
+            let filter = FilterOptions {
+                lower_bound_booked_timestamp: rng.gen_bool(0.5).then(|| *timestamps.iter().choose(&mut rng).unwrap()),
+                upper_bound_booked_timestamp: rng.gen_bool(0.5).then(|| *timestamps.iter().choose(&mut rng).unwrap()),
+                output_types: rng.gen_bool(0.5).then(|| {
+                    [
+                        BasicOutput::KIND,
+                        AliasOutput::KIND,
+                        FoundryOutput::KIND,
+                        NftOutput::KIND,
+                    ]
+                    .into_iter()
+                    .filter(|_| rng.gen_bool(0.5))
+                    .collect()
+                }),
+                alias_ids: alias_id.filter(|_| rng.gen_bool(0.5)).map(|alias_id| [alias_id].into()),
+                ..Default::default()
+            };
+
+            let indexed = account_details
+                .filter_unspent_outputs(&filter)
+                .map(|output_data| output_data.output_id)
+                .collect::<HashSet<_>>();
+            let chain_ids = filter.chain_ids().collect::<HashSet<_>>();
+            let scanned = account_details
+                .unspent_outputs
+                .values()
+                .filter(|output_data| {
+                    output_data
+                        .chain_id()
+                        .map_or(false, |chain_id| chain_ids.contains(&chain_id))
+                        || (filter.matches_output_type(&output_data.output)
+                            && filter.matches_booked_timestamp(output_data))
+                })
+                .map(|output_data| output_data.output_id)
+                .collect::<HashSet<_>>();
+            assert_eq!(indexed, scanned);
+        }
+    }
+
+    #[test]
+    fn output_index_serde_roundtrip() {
+        let addresses = (0..2).map(|_| rand_address()).collect::<Vec<_>>();
+        let token_ids = [TokenId::new(rand::thread_rng().gen())];
+        let outputs = (0..10)
+            .map(|_| rand_output_data(&addresses, &token_ids))
+            .collect::<Vec<_>>();
+        let index = OutputIndex::new(&outputs);
+
+        let json = serde_json::to_string(&index).unwrap();
+        assert_eq!(serde_json::from_str::<OutputIndex>(&json).unwrap(), index);
+    }
+
+    #[test]
+    fn unspent_chain_output_of_null_alias_id() {
+        let address = rand_address();
+        let mut account_details = AccountDetails::mock();
+        let output_data = OutputData::rand_mock(
+            AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+                .finish_output(protocol_parameters().token_supply())
+                .unwrap(),
+            address,
+        );
+        let output_id = output_data.output_id;
+        account_details.insert_unspent_output(output_data);
+
+        let alias_id = AliasId::from(&output_id);
+        assert_eq!(
+            account_details
+                .unspent_chain_output(ChainId::from(alias_id))
+                .unwrap()
+                .output_id,
+            output_id
+        );
+        assert!(
+            account_details
+                .unspent_chain_output(ChainId::from(AliasId::null()))
+                .is_none()
+        );
+    }
+
+    fn addresses_of(output_data: &OutputData) -> HashSet<Address> {
+        addresses(output_data).copied().collect()
+    }
+
+    fn token_ids_of(output_data: &OutputData) -> HashSet<TokenId> {
+        token_ids(output_data).copied().collect()
+    }
+}
@@ -1,13 +1,16 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+use crate::{
+    types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, Output, OutputId, TokenId},
+    wallet::account::types::OutputData,
+};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -32,6 +35,9 @@ pub struct Balance {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub(crate) potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// If the balance was returned while another sync of the account was still ongoing, so it can be outdated
+    #[serde(default)]
+    pub(crate) sync_in_progress: bool,
 }
 
 impl std::ops::AddAssign for Balance {
@@ -79,6 +85,9 @@ pub struct BalanceDto {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// If the balance was returned while another sync of the account was still ongoing, so it can be outdated
+    #[serde(default)]
+    pub sync_in_progress: bool,
 }
 
 impl From<&Balance> for BalanceDto {
@@ -95,6 +104,7 @@ impl From<&Balance> for BalanceDto {
             aliases: value.aliases.clone(),
             foundries: value.foundries.clone(),
             potentially_locked_outputs: value.potentially_locked_outputs.clone(),
+            sync_in_progress: value.sync_in_progress,
         }
     }
 }
@@ -149,6 +159,32 @@ impl std::ops::AddAssign for RequiredStorageDeposit {
     }
 }
 
+/// Amounts and native tokens of the outputs of a [`Balance`] per output type, returned from
+/// [`Balance::breakdown_by_output_type()`]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputTypeBalance {
+    /// Amount in basic outputs
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) basic: u64,
+    /// Amount in alias outputs
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) alias: u64,
+    /// Amount in nft outputs
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) nft: u64,
+    /// Amount in foundry outputs
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) foundry: u64,
+    /// Native tokens in all outputs
+    #[getset(get = "pub")]
+    pub(crate) native_tokens: BTreeMap<TokenId, U256>,
+}
+
 /// Native tokens fields for [`Balance`]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
 #[serde(rename_all = "camelCase")]
@@ -213,6 +249,49 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
     }
 }
 
+impl Balance {
+    /// Returns the total and available amount of a native token, `None` if the account doesn't hold it. The available
+    /// amount doesn't include the native tokens of locked outputs.
+    pub fn native_token(&self, token_id: &TokenId) -> Option<&NativeTokensBalance> {
+        self.native_tokens
+            .iter()
+            .find(|native_token_balance| native_token_balance.token_id == *token_id)
+    }
+
+    /// Sums up the amounts and native tokens of the given outputs per output type. Outputs that are potentially
+    /// locked aren't part of the balance and are skipped. Amounts are the full output amounts, also if a part of them
+    /// needs to be returned to a storage deposit return address.
+    pub fn breakdown_by_output_type(&self, outputs: &HashMap<OutputId, OutputData>) -> OutputTypeBalance {
+        let mut breakdown = OutputTypeBalance::default();
+
+        for (output_id, output_data) in outputs {
+            if self.potentially_locked_outputs.contains_key(output_id) {
+                continue;
+            }
+
+            let output = &output_data.output;
+            match output {
+                Output::Treasury(_) => continue,
+                Output::Basic(_) => breakdown.basic += output.amount(),
+                Output::Alias(_) => breakdown.alias += output.amount(),
+                Output::Nft(_) => breakdown.nft += output.amount(),
+                Output::Foundry(_) => breakdown.foundry += output.amount(),
+            }
+
+            for native_token in output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter())
+            {
+                let amount = breakdown.native_tokens.entry(*native_token.token_id()).or_default();
+                *amount = amount.saturating_add(native_token.amount());
+            }
+        }
+
+        breakdown
+    }
+}
+
 #[cfg(feature = "rand")]
 impl Balance {
     pub fn rand_mock() -> Self {
@@ -284,3 +363,129 @@ impl Balance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, AliasAddress},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, TimelockUnlockCondition,
+            },
+            AliasOutputBuilder, BasicOutputBuilder, FoundryOutputBuilder, NativeToken, NftOutputBuilder,
+            SimpleTokenScheme, TokenScheme,
+        },
+        protocol::protocol_parameters,
+        rand::{address::rand_address, bytes::rand_bytes_array, output::rand_alias_id},
+    };
+
+    fn output_data(output: Output, address: Address) -> (OutputId, OutputData) {
+        let output_data = OutputData::rand_mock(output, address);
+        (output_data.output_id, output_data)
+    }
+
+    #[test]
+    fn breakdown_by_output_type() {
+        let token_supply = protocol_parameters().token_supply();
+        let address = rand_address();
+        let token_id = TokenId::from(rand_bytes_array());
+        let alias_address = AliasAddress::from(rand_alias_id());
+
+        let locked = output_data(
+            BasicOutputBuilder::new_with_amount(5_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(TimelockUnlockCondition::new(u32::MAX).unwrap())
+                .finish_output(token_supply)
+                .unwrap(),
+            address,
+        );
+        let outputs = HashMap::from([
+            output_data(
+                BasicOutputBuilder::new_with_amount(1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_native_token(NativeToken::new(token_id, 10.into()).unwrap())
+                    .finish_output(token_supply)
+                    .unwrap(),
+                address,
+            ),
+            output_data(
+                BasicOutputBuilder::new_with_amount(2_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)
+                    .unwrap(),
+                address,
+            ),
+            output_data(
+                AliasOutputBuilder::new_with_amount(3_000_000, rand_alias_id())
+                    .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                    .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+                    .add_native_token(NativeToken::new(token_id, 20.into()).unwrap())
+                    .finish_output(token_supply)
+                    .unwrap(),
+                address,
+            ),
+            output_data(
+                NftOutputBuilder::new_with_amount(4_000_000, NftId::null())
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)
+                    .unwrap(),
+                address,
+            ),
+            output_data(
+                FoundryOutputBuilder::new_with_amount(
+                    6_000_000,
+                    1,
+                    TokenScheme::Simple(SimpleTokenScheme::new(100.into(), 0.into(), 100.into()).unwrap()),
+                )
+                .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(alias_address))
+                .finish_output(token_supply)
+                .unwrap(),
+                Address::Alias(alias_address),
+            ),
+            locked.clone(),
+        ]);
+        let balance = Balance {
+            potentially_locked_outputs: HashMap::from([(locked.0, false)]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            balance.breakdown_by_output_type(&outputs),
+            OutputTypeBalance {
+                basic: 3_000_000,
+                alias: 3_000_000,
+                nft: 4_000_000,
+                foundry: 6_000_000,
+                native_tokens: BTreeMap::from([(token_id, U256::from(30))]),
+            }
+        );
+    }
+
+    #[test]
+    fn native_token_balance() {
+        let token_id = TokenId::from(rand_bytes_array());
+        let native_token_balance = NativeTokensBalance {
+            token_id,
+            metadata: None,
+            total: U256::from(30),
+            available: U256::from(10),
+        };
+        let balance = Balance {
+            native_tokens: vec![NativeTokensBalance::default(), native_token_balance.clone()],
+            ..Default::default()
+        };
+
+        assert_eq!(balance.native_token(&token_id), Some(&native_token_balance));
+        assert_eq!(balance.native_token(&TokenId::from(rand_bytes_array())), None);
+    }
+
+    #[test]
+    fn breakdown_by_output_type_without_outputs() {
+        assert_eq!(
+            Balance::default().breakdown_by_output_type(&HashMap::new()),
+            OutputTypeBalance::default()
+        );
+    }
+}
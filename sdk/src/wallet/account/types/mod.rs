@@ -15,7 +15,8 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
     balance::{
-        Balance, BalanceDto, BaseCoinBalance, NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
+        Balance, BalanceDto, BaseCoinBalance, NativeTokensBalance, NativeTokensBalanceDto, OutputTypeBalance,
+        RequiredStorageDeposit,
     },
 };
 use crate::{
@@ -26,9 +27,12 @@ use crate::{
             address::{dto::AddressDto, Address},
             output::{
                 dto::{OutputDto, OutputMetadataDto},
-                AliasTransition, Output, OutputId, OutputMetadata,
+                AliasTransition, ChainId, Output, OutputId, OutputMetadata,
+            },
+            payload::{
+                milestone::{MilestoneId, MilestonePayload, TailTransactionHash},
+                transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
             },
-            payload::transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
             BlockId,
         },
     },
@@ -56,6 +60,14 @@ pub struct OutputData {
 }
 
 impl OutputData {
+    /// Returns the chain id of the output, if it has one.
+    /// Freshly created alias and nft outputs have a null id, in which case it is computed from the output id.
+    pub fn chain_id(&self) -> Option<ChainId> {
+        self.output
+            .chain_id()
+            .map(|chain_id| chain_id.or_from_output_id(&self.output_id))
+    }
+
     pub fn input_signing_data(
         &self,
         account: &AccountDetails,
@@ -97,6 +109,25 @@ impl OutputData {
     }
 }
 
+#[cfg(feature = "rand")]
+impl OutputData {
+    /// Returns an unspent mock of this type with the given output and address, and a random output id and metadata.
+    pub fn rand_mock(output: Output, address: Address) -> Self {
+        use crate::types::block::rand::output::{rand_output_id, rand_output_metadata};
+
+        Self {
+            output_id: rand_output_id(),
+            metadata: rand_output_metadata(),
+            output,
+            is_spent: false,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+}
+
 /// Dto for an output with metadata
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -193,6 +224,51 @@ impl From<&Transaction> for TransactionDto {
     }
 }
 
+/// Funds that were migrated from the legacy network with the receipt of a milestone. The output created for them has
+/// the milestone id as transaction id, there's no transaction payload for it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyMigration {
+    /// The output that was created for the migrated funds
+    pub output_id: OutputId,
+    /// The milestone containing the receipt
+    pub milestone_id: MilestoneId,
+    pub milestone_index: u32,
+    /// Milestone timestamp in milliseconds, like the timestamp of a transaction
+    pub timestamp: u128,
+    /// The hash of the tail transaction of the migration bundle in the legacy network
+    pub tail_transaction_hash: TailTransactionHash,
+    /// The migrated amount
+    pub amount: u64,
+}
+
+impl LegacyMigration {
+    /// Returns the migrations of all funds of the receipt of a milestone, none if it has no receipt.
+    pub(crate) fn from_milestone(milestone: &MilestonePayload) -> Vec<Self> {
+        let milestone_id = milestone.id();
+        let essence = milestone.essence();
+
+        essence.options().receipt().map_or_else(Vec::new, |receipt| {
+            receipt
+                .funds()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, funds)| {
+                    // The outputs are created in the order of the funds
+                    Some(Self {
+                        output_id: OutputId::new(TransactionId::from(milestone_id), index as u16).ok()?,
+                        milestone_id,
+                        milestone_index: *essence.index(),
+                        timestamp: essence.timestamp() as u128 * 1000,
+                        tail_transaction_hash: funds.tail_transaction_hash().clone(),
+                        amount: funds.amount(),
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
 /// Possible InclusionStates for transactions
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum InclusionState {
@@ -293,3 +369,40 @@ impl From<u32> for AccountIdentifier {
         Self::Index(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{protocol::protocol_parameters, rand::payload::rand_milestone_payload_with_receipt};
+
+    #[test]
+    fn legacy_migrations_from_milestone() {
+        let protocol_parameters = protocol_parameters();
+        let milestone = rand_milestone_payload_with_receipt(
+            protocol_parameters.protocol_version(),
+            protocol_parameters.token_supply(),
+        );
+        let milestone_id = milestone.id();
+
+        let migrations = LegacyMigration::from_milestone(&milestone);
+
+        assert_eq!(migrations.len(), 2);
+        for (index, (migration, funds)) in migrations
+            .iter()
+            .zip(milestone.essence().options().receipt().unwrap().funds())
+            .enumerate()
+        {
+            assert_eq!(
+                migration,
+                &LegacyMigration {
+                    output_id: OutputId::new(TransactionId::from(milestone_id), index as u16).unwrap(),
+                    milestone_id,
+                    milestone_index: *milestone.essence().index(),
+                    timestamp: milestone.essence().timestamp() as u128 * 1000,
+                    tail_transaction_hash: funds.tail_transaction_hash().clone(),
+                    amount: funds.amount(),
+                }
+            );
+        }
+    }
+}
@@ -4,7 +4,13 @@
 use std::collections::HashMap;
 
 use crate::{
-    types::block::output::{dto::OutputMetadataDto, OutputId},
+    types::block::{
+        address::Bech32Address,
+        input::Input,
+        output::{dto::OutputMetadataDto, OutputId},
+        payload::transaction::{TransactionEssence, TransactionId},
+        BlockId, ConvertTo,
+    },
     wallet::account::{
         operations::syncing::options::SyncOptions,
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
@@ -30,13 +36,51 @@ impl Account {
         Ok(())
     }
 
-    /// Update account with newly synced data and emit events for outputs
+    /// Set the number of consecutive public addresses without outputs that are generated and synced after the last
+    /// used one. Addresses that receive outputs while syncing extend the range, so syncing stops only once there are
+    /// `gap` empty addresses in a row. Isn't applied if [`SyncOptions::addresses`] are provided.
+    pub async fn set_address_gap_limit(&self, gap: u32) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.address_gap_limit = gap;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Set a label for an address of the account, replacing the previous one. Labels are kept when the account is
+    /// synced, an address can be looked up by its label with [`Account::address_by_label()`].
+    pub async fn label_address(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        label: &str,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+        let mut account_details = self.details_mut().await;
+        if !account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .any(|account_address| account_address.address == address)
+        {
+            return Err(crate::wallet::Error::AddressNotFoundInAccount(address));
+        }
+        account_details
+            .address_labels
+            .insert(address.to_string(), label.to_string());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Update account with newly synced data and emit events for outputs. If the data was synced up to a milestone,
+    /// the changed outputs are recorded with its index.
     pub(crate) async fn update_account(
         &self,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
         unspent_outputs: Vec<OutputData>,
         spent_or_unsynced_output_metadata_map: HashMap<OutputId, Option<OutputMetadataDto>>,
         options: &SyncOptions,
+        milestone_index: Option<u32>,
     ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] Update account with new synced transactions");
 
@@ -46,8 +90,9 @@ impl Account {
         let account_index = account_details.index;
 
         // update used field of the addresses
+        let mut reused_addresses = Vec::new();
         for address_with_unspent_outputs in addresses_with_unspent_outputs.iter() {
-            if address_with_unspent_outputs.internal {
+            let address = if address_with_unspent_outputs.internal {
                 let position = account_details
                     .internal_addresses
                     .binary_search_by_key(
@@ -60,7 +105,7 @@ impl Account {
                     .map_err(|_| {
                         crate::wallet::Error::AddressNotFoundInAccount(address_with_unspent_outputs.address)
                     })?;
-                account_details.internal_addresses[position].used = true;
+                &mut account_details.internal_addresses[position]
             } else {
                 let position = account_details
                     .public_addresses
@@ -74,7 +119,18 @@ impl Account {
                     .map_err(|_| {
                         crate::wallet::Error::AddressNotFoundInAccount(address_with_unspent_outputs.address)
                     })?;
-                account_details.public_addresses[position].used = true;
+                &mut account_details.public_addresses[position]
+            };
+            let already_used = std::mem::replace(&mut address.used, true);
+            // An address that was used before is only reused if it received outputs that we don't know yet
+            if already_used
+                && options.on_address_reuse.is_some()
+                && address_with_unspent_outputs
+                    .output_ids
+                    .iter()
+                    .any(|output_id| !account_details.outputs.contains_key(output_id))
+            {
+                reused_addresses.push(address_with_unspent_outputs.clone());
             }
         }
 
@@ -98,7 +154,7 @@ impl Account {
             // If we got the output response and it's still unspent, skip it
             if let Some(output_metadata_response) = output_metadata_response_opt {
                 if output_metadata_response.is_spent {
-                    account_details.unspent_outputs.remove(&output_id);
+                    account_details.remove_unspent_output(&output_id);
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
                         output_data.metadata = output_metadata_response.try_into()?;
                     }
@@ -113,8 +169,15 @@ impl Account {
                 if output.network_id == network_id {
                     log::debug!("[SYNC] Spent output {}", output_id);
                     account_details.locked_outputs.remove(&output_id);
-                    account_details.unspent_outputs.remove(&output_id);
+                    account_details.remove_unspent_output(&output_id);
                     // Update spent data fields
+                    if let Some(milestone_index) = milestone_index {
+                        account_details
+                            .record_milestones
+                            .spent_outputs
+                            .entry(output_id)
+                            .or_insert(milestone_index);
+                    }
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
                         output_data.metadata.set_spent(true);
                         output_data.is_spent = true;
@@ -141,6 +204,12 @@ impl Account {
                 .insert(output_data.output_id, output_data.clone())
                 .is_none()
             {
+                if let Some(milestone_index) = milestone_index {
+                    account_details
+                        .record_milestones
+                        .created_outputs
+                        .insert(output_data.output_id, milestone_index);
+                }
                 #[cfg(feature = "events")]
                 {
                     let transaction = account_details
@@ -164,9 +233,7 @@ impl Account {
                 }
             };
             if !output_data.is_spent {
-                account_details
-                    .unspent_outputs
-                    .insert(output_data.output_id, output_data);
+                account_details.insert_unspent_output(output_data);
             }
         }
 
@@ -178,21 +245,45 @@ impl Account {
             );
             self.save(Some(&account_details)).await?;
         }
+        drop(account_details);
+
+        // Called without the lock, so the callback can access the account
+        if let Some(on_address_reuse) = &options.on_address_reuse {
+            for address in &reused_addresses {
+                log::debug!("[SYNC] address {} was reused", address.address);
+                on_address_reuse.call(address);
+            }
+        }
+
         Ok(())
     }
 
-    /// Update account with newly synced transactions
+    /// Update account with newly synced transactions, recording changed inclusion states and spent outputs with the
+    /// index of the milestone up to which they were synced.
     pub(crate) async fn update_account_with_transactions(
         &self,
         updated_transactions: Vec<Transaction>,
         spent_output_ids: Vec<OutputId>,
         output_ids_to_unlock: Vec<OutputId>,
+        milestone_index: u32,
     ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] Update account with new synced transactions");
 
         let mut account_details = self.details_mut().await;
 
         for transaction in updated_transactions {
+            let transaction_id = transaction.payload.id();
+            if account_details
+                .transactions
+                .get(&transaction_id)
+                .map_or(true, |stored| stored.inclusion_state != transaction.inclusion_state)
+            {
+                account_details
+                    .record_milestones
+                    .transactions
+                    .insert(transaction_id, milestone_index);
+            }
+
             match transaction.inclusion_state {
                 InclusionState::Confirmed | InclusionState::Conflicting | InclusionState::UnknownPruned => {
                     let transaction_id = transaction.payload.id();
@@ -224,8 +315,13 @@ impl Account {
             if let Some(output) = account_details.outputs.get_mut(output_to_unlock) {
                 output.is_spent = true;
             }
+            account_details
+                .record_milestones
+                .spent_outputs
+                .entry(*output_to_unlock)
+                .or_insert(milestone_index);
             account_details.locked_outputs.remove(output_to_unlock);
-            account_details.unspent_outputs.remove(output_to_unlock);
+            account_details.remove_unspent_output(output_to_unlock);
             log::debug!("[SYNC] Unlocked spent output {}", output_to_unlock);
         }
 
@@ -248,6 +344,100 @@ impl Account {
         Ok(())
     }
 
+    /// Marks a pending transaction as conflicting without waiting for the next sync, its inputs that aren't spent
+    /// according to the account are unlocked. If another attachment of the transaction got included in the meantime,
+    /// the transaction is marked as confirmed instead and the id of the including block is returned.
+    pub(crate) async fn mark_transaction_conflicting(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<Option<BlockId>> {
+        let included_block_id = self
+            .client()
+            .find_included_attachment(transaction_id)
+            .await?
+            .map(|block| block.id());
+
+        let mut account_details = self.details_mut().await;
+
+        let Some(transaction) = account_details.transactions.get_mut(transaction_id) else {
+            return Ok(included_block_id);
+        };
+        let inclusion_state = match included_block_id {
+            Some(block_id) => {
+                transaction.block_id = Some(block_id);
+                InclusionState::Confirmed
+            }
+            None => InclusionState::Conflicting,
+        };
+        transaction.inclusion_state = inclusion_state;
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<_>>();
+        account_details.pending_transactions.remove(transaction_id);
+        log::debug!("[mark_transaction_conflicting] {transaction_id}: {inclusion_state:?}");
+        // Recorded as a change of the last sync, so it's part of the changes since then
+        if let Some(milestone_index) = account_details.last_processed_milestone {
+            account_details
+                .record_milestones
+                .transactions
+                .insert(*transaction_id, milestone_index);
+        }
+
+        // The inputs of a confirmed transaction are spent, they are updated by the next sync
+        if included_block_id.is_none() {
+            for input_id in input_ids {
+                if account_details
+                    .outputs
+                    .get(&input_id)
+                    .map_or(false, |output_data| !output_data.metadata.is_spent())
+                {
+                    account_details.locked_outputs.remove(&input_id);
+                }
+            }
+        }
+
+        #[cfg(feature = "events")]
+        self.emit(
+            account_details.index,
+            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                transaction_id: *transaction_id,
+                inclusion_state,
+            }),
+        )
+        .await;
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(included_block_id)
+    }
+
+    /// Update the highest milestone index that was fully processed by syncing and prune the older milestone indexes
+    /// of the records. The account is only saved if records changed in this sync, otherwise the milestone index is
+    /// saved with the next change.
+    pub(crate) async fn update_last_processed_milestone(&self, milestone_index: u32) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        if account_details.last_processed_milestone >= Some(milestone_index) {
+            return Ok(());
+        }
+        // Records without milestone index changed before the first processed milestone
+        if account_details.last_processed_milestone.is_none() {
+            account_details.record_milestones.untracked_before = Some(milestone_index);
+        }
+        account_details.last_processed_milestone = Some(milestone_index);
+        account_details.record_milestones.prune(milestone_index);
+        #[cfg(feature = "storage")]
+        if account_details.record_milestones.contains(milestone_index) {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(())
+    }
+
     /// Update account with newly generated addresses
     pub(crate) async fn update_account_addresses(
         &self,
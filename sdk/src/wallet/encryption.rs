@@ -0,0 +1,54 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::ciphers::{aes_gcm::Aes256Gcm, traits::Aead};
+
+/// Encrypts the plaintext with AES-256-GCM and a random nonce, the result is laid out as `nonce || tag || ciphertext`.
+pub(crate) fn encrypt(key: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>, crypto::Error> {
+    let mut nonce = [0; Aes256Gcm::NONCE_LENGTH];
+    crypto::utils::rand::fill(&mut nonce)?;
+    let mut tag = [0; Aes256Gcm::TAG_LENGTH];
+    let mut ciphertext = vec![0; plaintext.len()];
+
+    Aes256Gcm::try_encrypt(key, &nonce, &[], plaintext, &mut ciphertext, &mut tag)?;
+
+    Ok([nonce.as_slice(), &tag, &ciphertext].concat())
+}
+
+/// Decrypts data encrypted by [`encrypt()`].
+pub(crate) fn decrypt(key: &[u8; 32], data: &[u8]) -> Result<Vec<u8>, crypto::Error> {
+    if data.len() < Aes256Gcm::NONCE_LENGTH + Aes256Gcm::TAG_LENGTH {
+        return Err(crypto::Error::BufferSize {
+            name: "ciphertext",
+            needs: Aes256Gcm::NONCE_LENGTH + Aes256Gcm::TAG_LENGTH,
+            has: data.len(),
+        });
+    }
+    let (nonce, data) = data.split_at(Aes256Gcm::NONCE_LENGTH);
+    let (tag, ciphertext) = data.split_at(Aes256Gcm::TAG_LENGTH);
+    let mut plaintext = vec![0; ciphertext.len()];
+
+    let length = Aes256Gcm::try_decrypt(key, nonce, &[], &mut plaintext, ciphertext, tag)?;
+    plaintext.truncate(length);
+
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt() {
+        let plaintext = b"plaintext";
+        let encrypted = encrypt(&[1; 32], plaintext).unwrap();
+
+        assert_eq!(
+            encrypted.len(),
+            Aes256Gcm::NONCE_LENGTH + Aes256Gcm::TAG_LENGTH + plaintext.len()
+        );
+        assert_eq!(decrypt(&[1; 32], &encrypted).unwrap(), plaintext);
+        assert!(decrypt(&[2; 32], &encrypted).is_err());
+        assert!(decrypt(&[1; 32], &encrypted[..Aes256Gcm::NONCE_LENGTH]).is_err());
+    }
+}
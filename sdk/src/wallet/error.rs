@@ -8,7 +8,7 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{address::Bech32Address, output::TokenId, payload::transaction::TransactionId};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -19,6 +19,9 @@ pub enum Error {
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
+    /// Searching accounts failed during the account recovery, with the account indexes and the errors
+    #[error("account recovery failed for the accounts {0:?}")]
+    AccountRecoveryFailed(Vec<(u32, String)>),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
@@ -31,9 +34,18 @@ pub enum Error {
     /// Burning or melting failed
     #[error("burning or melting failed: {0}")]
     BurningOrMeltingFailed(String),
+    /// Claiming outputs in batches failed, with the transactions that were already sent
+    #[error("claiming outputs failed after {} sent transactions: {error}", transactions.len())]
+    ClaimingOutputsFailed {
+        transactions: Vec<crate::wallet::account::types::Transaction>,
+        error: Box<Error>,
+    },
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
+    /// Requirements of a composed transaction conflict with each other
+    #[error("conflicting transaction requirements: {0}")]
+    ConflictingRequirements(String),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
@@ -61,6 +73,9 @@ pub enum Error {
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid snapshot
+    #[error("invalid snapshot: {0}")]
+    InvalidSnapshot(&'static str),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -76,6 +91,9 @@ pub enum Error {
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
+    /// The summed amount of a native token overflowed.
+    #[error("native token amount overflow for token {token_id}")]
+    NativeTokenOverflow { token_id: TokenId },
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
@@ -100,6 +118,11 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// The snapshot was created by a newer SDK version.
+    #[error(
+        "snapshot version {found} was created by a newer SDK version, the highest supported version is {supported}"
+    )]
+    SnapshotVersionMismatch { found: u8, supported: u8 },
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -109,6 +132,9 @@ pub enum Error {
     /// Tokio task join error
     #[error("{0}")]
     TaskJoin(#[from] tokio::task::JoinError),
+    /// A composed transaction requires more inputs than allowed
+    #[error("{input_count} inputs are required, but at most {input_count_max} are allowed")]
+    TooManyInputs { input_count: usize, input_count_max: u16 },
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
@@ -39,6 +39,8 @@ impl EventEmitter {
                 WalletEventType::SpentOutput,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
+                WalletEventType::SyncProgress,
+                WalletEventType::AccountRecoveryProgress,
                 WalletEventType::ConsolidationRequired,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
@@ -70,16 +72,26 @@ impl EventEmitter {
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
     /// argument to each of them.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
+        self.emit_event(Event {
+            account_index,
+            sequence: None,
+            event,
+        });
+    }
+
+    /// Invokes all listeners of the type of the event, like [`EventEmitter::emit()`].
+    pub(crate) fn emit_event(&self, event: Event) {
+        let event_type = match &event.event {
             WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
             WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
+            WalletEvent::SyncProgress(_) => WalletEventType::SyncProgress,
+            WalletEvent::AccountRecoveryProgress(_) => WalletEventType::AccountRecoveryProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
             #[cfg(feature = "ledger_nano")]
             WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
         };
-        let event = Event { account_index, event };
         if let Some(handlers) = self.handlers.get(&event_type) {
             for handler in handlers {
                 handler(&event);
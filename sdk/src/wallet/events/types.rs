@@ -21,6 +21,10 @@ use crate::{
 pub struct Event {
     /// Associated account index.
     pub account_index: u32,
+    /// Sequence number of the event in the event log, `None` if the event log is disabled or for progress events,
+    /// which aren't stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
     /// The event
     pub event: WalletEvent,
 }
@@ -35,6 +39,18 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    SyncProgress(SyncProgressEvent),
+    AccountRecoveryProgress(AccountRecoveryProgressEvent),
+}
+
+impl WalletEvent {
+    /// Returns whether the event reports the progress of an operation.
+    pub(crate) fn is_progress(&self) -> bool {
+        matches!(
+            self,
+            Self::TransactionProgress(_) | Self::SyncProgress(_) | Self::AccountRecoveryProgress(_)
+        )
+    }
 }
 
 impl Serialize for WalletEvent {
@@ -47,6 +63,16 @@ impl Serialize for WalletEvent {
             progress: &'a TransactionProgressEvent,
         }
 
+        #[derive(Serialize)]
+        struct SyncProgressEvent_<'a> {
+            progress: &'a SyncProgressEvent,
+        }
+
+        #[derive(Serialize)]
+        struct AccountRecoveryProgressEvent_<'a> {
+            progress: &'a AccountRecoveryProgressEvent,
+        }
+
         #[derive(Serialize)]
         #[serde(untagged)]
         enum WalletEvent_<'a> {
@@ -57,6 +83,8 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(SyncProgressEvent_<'a>),
+            T7(AccountRecoveryProgressEvent_<'a>),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -91,6 +119,14 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::SyncProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncProgress as u8,
+                event: WalletEvent_::T6(SyncProgressEvent_ { progress: e }),
+            },
+            Self::AccountRecoveryProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::AccountRecoveryProgress as u8,
+                event: WalletEvent_::T7(AccountRecoveryProgressEvent_ { progress: e }),
+            },
         };
         event.serialize(serializer)
     }
@@ -103,6 +139,16 @@ impl<'de> Deserialize<'de> for WalletEvent {
             progress: TransactionProgressEvent,
         }
 
+        #[derive(Deserialize)]
+        struct SyncProgressEvent_ {
+            progress: SyncProgressEvent,
+        }
+
+        #[derive(Deserialize)]
+        struct AccountRecoveryProgressEvent_ {
+            progress: AccountRecoveryProgressEvent,
+        }
+
         let value = serde_json::Value::deserialize(d)?;
         Ok(
             match WalletEventType::try_from(
@@ -142,6 +188,18 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::SyncProgress => Self::SyncProgress(
+                    SyncProgressEvent_::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize SyncProgressEvent: {e}")))?
+                        .progress,
+                ),
+                WalletEventType::AccountRecoveryProgress => Self::AccountRecoveryProgress(
+                    AccountRecoveryProgressEvent_::deserialize(value)
+                        .map_err(|e| {
+                            serde::de::Error::custom(format!("cannot deserialize AccountRecoveryProgressEvent: {e}"))
+                        })?
+                        .progress,
+                ),
             },
         )
     }
@@ -158,6 +216,8 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    SyncProgress = 6,
+    AccountRecoveryProgress = 7,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -172,6 +232,8 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::SyncProgress,
+            7 => Self::AccountRecoveryProgress,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -204,6 +266,48 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+/// The progress of syncing an account, emitted while [`Account::sync()`](crate::wallet::Account::sync) is running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SyncProgressEvent {
+    /// The output ids of `synced` of the `total` addresses to sync were requested, emitted after every batch of
+    /// addresses.
+    AddressesSynced {
+        /// The number of addresses whose output ids were requested so far.
+        synced: usize,
+        /// The number of addresses to sync.
+        total: usize,
+    },
+    /// The outputs of the synced addresses were requested, also emitted for the addresses synced to keep the address
+    /// gap.
+    OutputsFetched {
+        /// The number of outputs that were requested.
+        count: usize,
+    },
+    /// The pending transactions were synced.
+    PendingTransactionsSynced,
+    /// A transaction to consolidate the outputs is sent, only with
+    /// [`SyncOptions::consolidate_outputs`](crate::wallet::account::SyncOptions::consolidate_outputs).
+    ConsolidationStarted {
+        /// The number of outputs that are consolidated.
+        outputs: usize,
+    },
+}
+
+/// The progress of recovering an account, emitted with the index of the account while
+/// [`Wallet::recover_accounts()`](crate::wallet::Wallet::recover_accounts) is running. The checked addresses are
+/// reported with [`SyncProgressEvent`]s.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AccountRecoveryProgressEvent {
+    /// The search for addresses with outputs started.
+    ScanningAccount,
+    /// The search finished and found `outputs` unspent outputs.
+    AccountScanned { outputs: usize },
+    /// The search failed, the recovery continues with the other accounts.
+    AccountFailed { error: String },
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TransactionProgressEvent {
     /// Performing input selection.
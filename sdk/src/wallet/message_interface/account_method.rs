@@ -33,7 +33,7 @@ use crate::{
                 transaction::{
                     high_level::{
                         create_alias::CreateAliasParamsDto,
-                        minting::{mint_native_token::MintNativeTokenParamsDto, mint_nfts::MintNftParamsDto},
+                        minting::{create_native_token::CreateNativeTokenParamsDto, mint_nfts::MintNftParamsDto},
                     },
                     prepare_output::OutputParamsDto,
                     TransactionOptionsDto,
@@ -246,11 +246,11 @@ pub enum AccountMethod {
         mint_amount: U256,
         options: Option<TransactionOptionsDto>,
     },
-    /// Mint native token.
+    /// Create a foundry output with minted native tokens.
     /// Expected response: [`MintTokenTransaction`](crate::wallet::message_interface::Response::MintTokenTransaction)
     #[serde(rename_all = "camelCase")]
     MintNativeToken {
-        params: MintNativeTokenParamsDto,
+        params: CreateNativeTokenParamsDto,
         options: Option<TransactionOptionsDto>,
     },
     /// Mint nft.
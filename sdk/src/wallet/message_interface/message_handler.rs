@@ -29,14 +29,14 @@ use crate::{
         address::{Hrp, ToBech32Ext},
         output::{
             dto::{OutputBuilderAmountDto, OutputDto},
-            AliasOutput, BasicOutput, FoundryOutput, NativeToken, NftOutput, Output, Rent,
+            AliasOutput, BasicOutput, FoundryOutput, NftOutput, Output, Rent,
         },
         ConvertTo, Error,
     },
     wallet::{
         account::{
             operations::transaction::{
-                high_level::{create_alias::CreateAliasParams, minting::mint_native_token::MintTokenTransactionDto},
+                high_level::{create_alias::CreateAliasParams, minting::create_native_token::MintTokenTransactionDto},
                 prepare_output::OutputParams,
                 TransactionOptions,
             },
@@ -46,7 +46,7 @@ use crate::{
         message_interface::{
             account_method::AccountMethod, dtos::AccountDetailsDto, message::Message, response::Response,
         },
-        MintNativeTokenParams, MintNftParams, Result, Wallet,
+        CreateNativeTokenParams, MintNftParams, Result, Wallet,
     },
 };
 
@@ -503,8 +503,9 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .burn(
-                            NativeToken::new(token_id, burn_amount)?,
+                        .burn_native_token(
+                            token_id,
+                            burn_amount,
                             options.map(TransactionOptions::try_from_dto).transpose()?,
                         )
                         .await?;
@@ -515,7 +516,7 @@ impl WalletMessageHandler {
             AccountMethod::BurnNft { nft_id, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .burn(nft_id, options.map(TransactionOptions::try_from_dto).transpose()?)
+                        .burn_nft(nft_id, options.map(TransactionOptions::try_from_dto).transpose()?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
@@ -547,7 +548,7 @@ impl WalletMessageHandler {
             AccountMethod::DestroyAlias { alias_id, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .burn(alias_id, options.map(TransactionOptions::try_from_dto).transpose()?)
+                        .destroy_alias(alias_id, options.map(TransactionOptions::try_from_dto).transpose()?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
@@ -556,7 +557,7 @@ impl WalletMessageHandler {
             AccountMethod::DestroyFoundry { foundry_id, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .burn(foundry_id, options.map(TransactionOptions::try_from_dto).transpose()?)
+                        .destroy_foundry(foundry_id, options.map(TransactionOptions::try_from_dto).transpose()?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
@@ -591,9 +592,7 @@ impl WalletMessageHandler {
                 })
             }
             AccountMethod::GetOutputsWithAdditionalUnlockConditions { outputs_to_claim } => {
-                let output_ids = account
-                    .get_unlockable_outputs_with_additional_unlock_conditions(outputs_to_claim)
-                    .await?;
+                let output_ids = account.claimable_outputs(outputs_to_claim).await?;
                 Ok(Response::OutputIds(output_ids))
             }
             AccountMethod::GetOutput { output_id } => {
@@ -665,7 +664,7 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .decrease_native_token_supply(
+                        .melt_native_token(
                             token_id,
                             U256::try_from(&melt_amount).map_err(|_| Error::InvalidField("melt_amount"))?,
                             options.map(TransactionOptions::try_from_dto).transpose()?,
@@ -682,7 +681,7 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .increase_native_token_supply(
+                        .mint_native_token(
                             token_id,
                             U256::try_from(&mint_amount).map_err(|_| Error::InvalidField("mint_amount"))?,
                             options.map(TransactionOptions::try_from_dto).transpose()?,
@@ -697,8 +696,8 @@ impl WalletMessageHandler {
             AccountMethod::MintNativeToken { params, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .mint_native_token(
-                            MintNativeTokenParams::try_from(params)?,
+                        .create_native_token(
+                            CreateNativeTokenParams::try_from(params)?,
                             options.map(TransactionOptions::try_from_dto).transpose()?,
                         )
                         .await?;
@@ -861,7 +860,7 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let signed_transaction_data = account
-                        .sign_transaction_essence(&PreparedTransactionData::try_from_dto(
+                        .sign_transaction(&PreparedTransactionData::try_from_dto(
                             prepared_transaction_data,
                             &account.client().get_protocol_parameters().await?,
                         )?)
@@ -25,7 +25,7 @@ use crate::{
     },
     wallet::{
         account::{
-            operations::transaction::high_level::minting::mint_native_token::MintTokenTransactionDto,
+            operations::transaction::high_level::minting::create_native_token::MintTokenTransactionDto,
             types::{address::AccountAddress, AddressWithUnspentOutputs, BalanceDto, TransactionDto},
             OutputDataDto,
         },
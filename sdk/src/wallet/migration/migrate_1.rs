@@ -0,0 +1,38 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::wallet::storage::{
+    constants::{ACCOUNTS_INDEXATION_KEY, ACCOUNT_INDEXATION_KEY, ACCOUNT_SCHEMA_VERSION},
+    Storage,
+};
+
+/// Prefixes the account records, which were stored as plain JSON before, with their schema version.
+pub struct Migrate;
+
+impl MigrationData for Migrate {
+    const ID: usize = 1;
+    const SDK_VERSION: &'static str = "0.4.0";
+    const DATE: time::Date = time::macros::date!(2023 - 06 - 05);
+}
+
+#[async_trait]
+impl Migration<Storage> for Migrate {
+    async fn migrate(storage: &Storage) -> Result<()> {
+        if let Some(account_indexes) = storage.get::<Vec<u32>>(ACCOUNTS_INDEXATION_KEY).await? {
+            for account_index in account_indexes {
+                let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
+                if let Some(account) = storage.get::<serde_json::Value>(&key).await? {
+                    if !account.is_object() {
+                        return Err(Error::Migration("malformatted account record".to_owned()));
+                    }
+
+                    let mut record = vec![ACCOUNT_SCHEMA_VERSION];
+                    serde_json::to_writer(&mut record, &account)?;
+                    storage.set_bytes(&key, &record).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod migrate_0;
+#[cfg(feature = "storage")]
+mod migrate_1;
 
 use std::collections::HashMap;
 
@@ -25,12 +27,13 @@ static MIGRATIONS: Lazy<Map<dyn anymap::any::Any + Send + Sync>> = Lazy::new(||
     #[cfg(feature = "storage")]
     {
         use super::storage::Storage;
-        const STORAGE_MIGRATIONS: [(Option<usize>, &'static dyn DynMigration<Storage>); 1] = [
+        const STORAGE_MIGRATIONS: [(Option<usize>, &'static dyn DynMigration<Storage>); 2] = [
             // In order to add a new storage migration, add an entry at the bottom of this list
             // and change the list length above.
             // The entry should be in the form of a key-value pair, from previous migration to next.
             // i.e. (Some(migrate_<N>::Migrate::ID), &migrate_<N+1>::Migrate)
             (None, &migrate_0::Migrate),
+            (Some(migrate_0::Migrate::ID), &migrate_1::Migrate),
         ];
         migrations.insert(std::collections::HashMap::from(STORAGE_MIGRATIONS));
     }
@@ -20,6 +20,8 @@ pub mod wallet;
 /// The ClientOptions to build the iota_client for interactions with the IOTA Tangle.
 pub use crate::client::ClientBuilder as ClientOptions;
 
+/// AES-256-GCM encryption of stored and exported data.
+pub(crate) mod encryption;
 /// The error module.
 pub mod error;
 /// The event module.
@@ -33,10 +35,11 @@ pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
+#[allow(deprecated)]
 pub use self::{
     account::{
         operations::transaction::high_level::{
-            minting::{mint_native_token::MintNativeTokenParams, mint_nfts::MintNftParams},
+            minting::{create_native_token::CreateNativeTokenParams, mint_nfts::MintNftParams},
             send_amount::SendAmountParams,
             send_native_tokens::SendNativeTokensParams,
             send_nft::SendNftParams,
@@ -44,8 +47,9 @@ pub use self::{
         Account,
     },
     error::Error,
-    wallet::{Wallet, WalletBuilder},
+    wallet::{Wallet, WalletBalance, WalletBuilder},
 };
+pub use self::account::operations::transaction::high_level::minting::create_native_token::MintNativeTokenParams;
 
 /// The wallet Result type.
 pub type Result<T> = std::result::Result<T, Error>;
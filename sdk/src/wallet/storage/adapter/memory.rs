@@ -8,8 +8,8 @@ use tokio::sync::RwLock;
 
 use crate::client::storage::StorageAdapter;
 
-/// A storage adapter that stores data in memory.
-#[derive(Debug, Default)]
+/// A storage adapter that stores data in memory. Clones share the same data.
+#[derive(Debug, Default, Clone)]
 pub struct Memory(Arc<RwLock<HashMap<String, Vec<u8>>>>);
 
 #[async_trait::async_trait]
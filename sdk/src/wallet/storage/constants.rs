@@ -23,9 +23,23 @@ pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
+pub(crate) const ACCOUNT_REMAINDER_ADDRESS_ROTATION: &str = "remainder-address-rotation";
+pub(crate) const ACCOUNT_LAST_SYNCED: &str = "last-synced";
+#[cfg(feature = "events")]
+pub(crate) const ACCOUNT_EVENTS: &str = "events";
+#[cfg(feature = "events")]
+pub(crate) const EVENT_SEQUENCE_KEY: &str = "iota-wallet-event-sequence";
+// Number of event sequence numbers that are reserved with a single write
+#[cfg(feature = "events")]
+pub(crate) const EVENT_SEQUENCE_RESERVATION: u64 = 1000;
 
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
+// Stored as first byte of every account record, older records are upgraded by the storage migrations
+pub(crate) const ACCOUNT_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
+// Stored as first byte of every encrypted record, records of older versions without it are encrypted with
+// XChaCha20-Poly1305
+pub(crate) const AES_GCM_RECORD_VERSION: u8 = 1;
 
 #[cfg(feature = "participation")]
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
@@ -1,19 +1,24 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+#[cfg(feature = "events")]
+use std::collections::VecDeque;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use futures::{StreamExt, TryStreamExt};
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use instant::Instant;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{Event, WalletEvent};
 use crate::{
     client::{
         secret::{SecretManager, SecretManagerDto},
         storage::StorageAdapter,
     },
     wallet::{
-        account::{AccountDetails, SyncOptions},
+        account::{AccountDetails, RemainderAddressRotation, SyncOptions},
         migration::migrate,
         storage::{constants::*, DynStorageAdapter, Storage},
         WalletBuilder,
@@ -50,6 +55,26 @@ pub(crate) struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
     account_indexes: Vec<u32>,
+    // min duration between two writes of the same account, writes in between are delayed until the next write or flush
+    pub(crate) debounce: Option<Duration>,
+    // hashes of the latest written account states, to skip writes when nothing changed
+    account_hashes: HashMap<u32, [u8; 32]>,
+    last_account_writes: HashMap<u32, Instant>,
+    // serialized accounts that still need to be written because of the debounce
+    pending_accounts: HashMap<u32, Vec<u8>>,
+    // max number of events stored per account, events aren't stored if `None`
+    #[cfg(feature = "events")]
+    pub(crate) event_log_size: Option<usize>,
+    // sequence number of the latest event
+    #[cfg(feature = "events")]
+    last_event_sequence: u64,
+    // highest sequence number that can be used without writing it first, sequence numbers are reserved in batches so
+    // they keep increasing after a restart without a write for every event
+    #[cfg(feature = "events")]
+    reserved_event_sequence: u64,
+    // events that still need to be written, they are written together with the account or on flush
+    #[cfg(feature = "events")]
+    pending_events: HashMap<u32, VecDeque<Event>>,
 }
 
 impl StorageManager {
@@ -77,10 +102,25 @@ impl StorageManager {
         };
 
         let account_indexes = storage.get(ACCOUNTS_INDEXATION_KEY).await?.unwrap_or_default();
+        #[cfg(feature = "events")]
+        let reserved_event_sequence = storage.get(EVENT_SEQUENCE_KEY).await?.unwrap_or_default();
 
         let storage_manager = Self {
             storage,
             account_indexes,
+            debounce: None,
+            account_hashes: HashMap::new(),
+            last_account_writes: HashMap::new(),
+            pending_accounts: HashMap::new(),
+            #[cfg(feature = "events")]
+            event_log_size: None,
+            // Sequence numbers up to the reserved one could have been used before the restart
+            #[cfg(feature = "events")]
+            last_event_sequence: reserved_event_sequence,
+            #[cfg(feature = "events")]
+            reserved_event_sequence,
+            #[cfg(feature = "events")]
+            pending_events: HashMap::new(),
         };
 
         Ok(storage_manager)
@@ -134,7 +174,7 @@ impl StorageManager {
     }
 
     pub async fn get_accounts(&mut self) -> crate::wallet::Result<Vec<AccountDetails>> {
-        if let Some(account_indexes) = self.storage.get(ACCOUNTS_INDEXATION_KEY).await? {
+        if let Some(account_indexes) = self.get(ACCOUNTS_INDEXATION_KEY).await? {
             if self.account_indexes.is_empty() {
                 self.account_indexes = account_indexes;
             }
@@ -142,32 +182,98 @@ impl StorageManager {
             return Ok(Vec::new());
         }
 
-        futures::stream::iter(&self.account_indexes)
-            .filter_map(|account_index| async {
-                let account_index = *account_index;
-                let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
-                self.get(&key).await.transpose()
-            })
-            .try_collect::<Vec<_>>()
-            .await
+        let mut accounts = Vec::new();
+        for account_index in self.account_indexes.clone() {
+            let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
+            // A delayed write is newer than the stored record
+            let record = match self.pending_accounts.get(&account_index) {
+                Some(record) => Some(record.clone()),
+                None => self.storage.get_bytes(&key).await?,
+            };
+            if let Some(record) = record {
+                accounts.push(from_record(&record)?);
+            }
+        }
+
+        Ok(accounts)
     }
 
     pub async fn save_account(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
+        let account_index = *account.index();
+
         // Only add account index if not already present
-        if !self.account_indexes.contains(account.index()) {
-            self.account_indexes.push(*account.index());
+        if !self.account_indexes.contains(&account_index) {
+            self.account_indexes.push(account_index);
+            self.storage.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await?;
+        }
+
+        let record = to_record(account)?;
+        let unchanged = self.account_hashes.get(&account_index) == Some(&Blake2b256::digest(&record).into());
+
+        // Nothing changed since the last write, so there is nothing to write and a delayed write isn't needed anymore
+        if unchanged {
+            self.pending_accounts.remove(&account_index);
+            #[cfg(feature = "events")]
+            self.write_events(account_index).await?;
+            return Ok(());
+        }
+
+        if let (Some(debounce), Some(last_write)) = (self.debounce, self.last_account_writes.get(&account_index)) {
+            if last_write.elapsed() < debounce {
+                log::debug!("[save_account] delaying write of account {account_index}");
+                self.pending_accounts.insert(account_index, record);
+                return Ok(());
+            }
         }
 
-        self.storage.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await?;
+        self.write_account(account_index, &record).await
+    }
+
+    /// Writes all accounts with delayed writes and all pending events to the storage. The writes that fail stay
+    /// delayed, so they are tried again with the next flush or save of the account.
+    pub async fn flush(&mut self) -> crate::wallet::Result<()> {
+        for (account_index, record) in self.pending_accounts.clone() {
+            self.write_account(account_index, &record).await?;
+        }
+        #[cfg(feature = "events")]
+        for account_index in self.pending_events.keys().copied().collect::<Vec<_>>() {
+            self.write_events(account_index).await?;
+        }
+        Ok(())
+    }
+
+    async fn write_account(&mut self, account_index: u32, record: &[u8]) -> crate::wallet::Result<()> {
         self.storage
-            .set(&format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()), account)
-            .await
+            .set_bytes(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"), record)
+            .await?;
+        // Only recorded once written, so a failed write isn't skipped as unchanged the next time
+        self.account_hashes
+            .insert(account_index, Blake2b256::digest(record).into());
+        self.pending_accounts.remove(&account_index);
+        self.last_account_writes.insert(account_index, Instant::now());
+        #[cfg(feature = "events")]
+        self.write_events(account_index).await?;
+        Ok(())
     }
 
     pub async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
         self.storage
             .delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"))
             .await?;
+        self.storage
+            .delete(&format!(
+                "{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_LAST_SYNCED}"
+            ))
+            .await?;
+        #[cfg(feature = "events")]
+        self.storage
+            .delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EVENTS}"))
+            .await?;
+        self.account_hashes.remove(&account_index);
+        self.last_account_writes.remove(&account_index);
+        self.pending_accounts.remove(&account_index);
+        #[cfg(feature = "events")]
+        self.pending_events.remove(&account_index);
         self.account_indexes.retain(|a| a != &account_index);
         self.storage.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
@@ -185,12 +291,134 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.storage.get(&key).await
     }
+
+    pub async fn set_remainder_address_rotation(
+        &self,
+        account_index: u32,
+        rotation: &RemainderAddressRotation,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_REMAINDER_ADDRESS_ROTATION}");
+        self.storage.set(&key, rotation).await
+    }
+
+    pub async fn get_remainder_address_rotation(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<RemainderAddressRotation>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_REMAINDER_ADDRESS_ROTATION}");
+        self.storage.get(&key).await
+    }
+
+    pub async fn set_last_synced(&self, account_index: u32, last_synced: u128) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_LAST_SYNCED}");
+        self.storage.set(&key, &last_synced).await
+    }
+
+    pub async fn get_last_synced(&self, account_index: u32) -> crate::wallet::Result<Option<u128>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_LAST_SYNCED}");
+        self.storage.get(&key).await
+    }
+
+    /// Adds an event with the next sequence number to the event log of its account, which only keeps the latest
+    /// `event_log_size` events. The event is written together with the next write of the account, or on flush, so a
+    /// sync writes its events once. Returns the sequence number, `None` if the event log is disabled.
+    #[cfg(feature = "events")]
+    pub async fn save_event(&mut self, account_index: u32, event: &WalletEvent) -> crate::wallet::Result<Option<u64>> {
+        let Some(event_log_size) = self.event_log_size.filter(|size| *size > 0) else {
+            return Ok(None);
+        };
+
+        let sequence = self.last_event_sequence + 1;
+        // The reserved sequence number is stored before it's used, so it's never reused after a restart
+        if sequence > self.reserved_event_sequence {
+            let reserved_event_sequence = self.last_event_sequence + EVENT_SEQUENCE_RESERVATION;
+            self.storage.set(EVENT_SEQUENCE_KEY, &reserved_event_sequence).await?;
+            self.reserved_event_sequence = reserved_event_sequence;
+        }
+        self.last_event_sequence = sequence;
+
+        let events = self.pending_events.entry(account_index).or_default();
+        events.push_back(Event {
+            account_index,
+            sequence: Some(sequence),
+            event: event.clone(),
+        });
+        while events.len() > event_log_size {
+            events.pop_front();
+        }
+
+        Ok(Some(sequence))
+    }
+
+    /// Writes the pending events of an account to its event log. The events stay pending if the write fails.
+    #[cfg(feature = "events")]
+    async fn write_events(&mut self, account_index: u32) -> crate::wallet::Result<()> {
+        let (Some(event_log_size), Some(pending_events)) =
+            (self.event_log_size, self.pending_events.get(&account_index))
+        else {
+            return Ok(());
+        };
+
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EVENTS}");
+        let mut events = self.storage.get::<VecDeque<Event>>(&key).await?.unwrap_or_default();
+        events.extend(pending_events.iter().cloned());
+        while events.len() > event_log_size {
+            events.pop_front();
+        }
+        self.storage.set(&key, &events).await?;
+        self.pending_events.remove(&account_index);
+
+        Ok(())
+    }
+
+    /// Returns the stored and pending events of all accounts with a sequence number above `sequence`, ordered by it.
+    #[cfg(feature = "events")]
+    pub async fn get_events_since(&self, sequence: u64) -> crate::wallet::Result<Vec<Event>> {
+        let mut events = Vec::new();
+        for account_index in &self.account_indexes {
+            let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EVENTS}");
+            if let Some(account_events) = self.storage.get::<VecDeque<Event>>(&key).await? {
+                events.extend(account_events);
+            }
+        }
+        events.extend(self.pending_events.values().flatten().cloned());
+        events.retain(|event| event.sequence.map_or(false, |s| s > sequence));
+        events.sort_by_key(|event| event.sequence);
+
+        Ok(events)
+    }
+}
+
+/// Serializes an account record with the current schema version.
+fn to_record(account: &AccountDetails) -> crate::wallet::Result<Vec<u8>> {
+    let mut record = vec![ACCOUNT_SCHEMA_VERSION];
+    serde_json::to_writer(&mut record, account)?;
+    Ok(record)
+}
+
+/// Deserializes an account record, older schema versions were already migrated when the storage was opened.
+fn from_record(record: &[u8]) -> crate::wallet::Result<AccountDetails> {
+    match record.split_first() {
+        Some((&ACCOUNT_SCHEMA_VERSION, account)) => Ok(serde_json::from_slice(account)?),
+        _ => Err(crate::wallet::Error::Migration(format!(
+            "unsupported account record schema version {:?}",
+            record.first()
+        ))),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
     use super::*;
-    use crate::wallet::storage::adapter::memory::Memory;
+    use crate::{
+        types::block::output::OutputId,
+        wallet::{
+            migration::{MigrationVersion, MIGRATION_VERSION_KEY},
+            storage::adapter::memory::Memory,
+        },
+    };
 
     #[tokio::test]
     async fn get() {
@@ -229,6 +457,298 @@ mod tests {
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
     }
 
+    #[tokio::test]
+    async fn save_get_last_synced() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        assert_eq!(storage_manager.get_last_synced(0).await.unwrap(), None);
+
+        storage_manager.set_last_synced(0, 1_686_000_000_000).await.unwrap();
+        assert_eq!(
+            storage_manager.get_last_synced(0).await.unwrap(),
+            Some(1_686_000_000_000)
+        );
+        assert_eq!(storage_manager.get_last_synced(1).await.unwrap(), None);
+
+        // The last synced time of a removed account must not be used by a new account with the same index
+        storage_manager.remove_account(0).await.unwrap();
+        assert_eq!(storage_manager.get_last_synced(0).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn save_encrypted_account() {
+        let storage = Memory::default();
+        let mut storage_manager = StorageManager::new(storage.clone(), [1; 32]).await.unwrap();
+        let account_details = AccountDetails::mock();
+
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), [account_details]);
+
+        // The record isn't stored in plaintext
+        let account_key = format!("{ACCOUNT_INDEXATION_KEY}0");
+        let record = storage.get_bytes(&account_key).await.unwrap().unwrap();
+        assert!(serde_json::from_slice::<AccountDetails>(&record).is_err());
+
+        let storage_without_key = Storage {
+            inner: Box::new(storage.clone()),
+            encryption_key: None,
+        };
+        assert!(storage_without_key.get::<AccountDetails>(&account_key).await.is_err());
+
+        let storage_with_other_key = Storage {
+            inner: Box::new(storage.clone()),
+            encryption_key: Some([2; 32]),
+        };
+        assert!(matches!(
+            storage_with_other_key.get::<AccountDetails>(&account_key).await,
+            Err(crate::wallet::Error::Crypto(_))
+        ));
+        assert!(matches!(
+            StorageManager::new(storage, [2; 32]).await,
+            Err(crate::wallet::Error::Crypto(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn migrate_account_record() {
+        let memory = Memory::default();
+        let account_details = AccountDetails::mock();
+        let account_key = format!("{ACCOUNT_INDEXATION_KEY}0");
+
+        // Accounts were stored as plain JSON before the storage migration 1
+        memory
+            .set(
+                MIGRATION_VERSION_KEY,
+                &MigrationVersion {
+                    id: 0,
+                    sdk_version: "0.4.0".to_owned(),
+                    date: time::macros::date!(2023 - 05 - 15),
+                },
+            )
+            .await
+            .unwrap();
+        memory.set(ACCOUNTS_INDEXATION_KEY, &vec![0u32]).await.unwrap();
+        memory.set(&account_key, &account_details).await.unwrap();
+
+        let storage = CountingStorage {
+            inner: memory.clone(),
+            ..Default::default()
+        };
+        let account_writes = storage.account_writes.clone();
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        // The record is stored again with the schema version when the storage is opened
+        assert_eq!(account_writes.load(Ordering::Relaxed), 1);
+        let record = memory.get_bytes(&account_key).await.unwrap().unwrap();
+        assert_eq!(record[0], ACCOUNT_SCHEMA_VERSION);
+        assert_eq!(
+            memory
+                .get::<MigrationVersion>(MIGRATION_VERSION_KEY)
+                .await
+                .unwrap()
+                .unwrap()
+                .id,
+            1
+        );
+
+        // Reading the accounts doesn't write them
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), [account_details.clone()]);
+        assert_eq!(account_writes.load(Ordering::Relaxed), 1);
+
+        // The migration only runs once
+        let storage = CountingStorage {
+            inner: memory,
+            ..Default::default()
+        };
+        let account_writes = storage.account_writes.clone();
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), [account_details]);
+        assert_eq!(account_writes.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn unsupported_account_record_version() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        storage_manager
+            .storage
+            .set(ACCOUNTS_INDEXATION_KEY, &vec![0u32])
+            .await
+            .unwrap();
+        let mut record = to_record(&AccountDetails::mock()).unwrap();
+        record[0] = 2;
+        storage_manager
+            .storage
+            .set_bytes(&format!("{ACCOUNT_INDEXATION_KEY}0"), &record)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            storage_manager.get_accounts().await,
+            Err(crate::wallet::Error::Migration(_))
+        ));
+    }
+
+    #[cfg(feature = "events")]
+    #[tokio::test]
+    async fn event_log() {
+        use crate::wallet::events::types::SyncProgressEvent;
+
+        fn event(count: usize) -> WalletEvent {
+            WalletEvent::SyncProgress(SyncProgressEvent::OutputsFetched { count })
+        }
+
+        let storage = Memory::default();
+        storage.set(ACCOUNTS_INDEXATION_KEY, &vec![0u32, 1]).await.unwrap();
+        let mut storage_manager = StorageManager::new(storage.clone(), None).await.unwrap();
+
+        // Disabled by default
+        assert_eq!(storage_manager.save_event(0, &event(0)).await.unwrap(), None);
+        assert!(storage_manager.get_events_since(0).await.unwrap().is_empty());
+
+        storage_manager.event_log_size = Some(2);
+        for count in 1..=3 {
+            assert_eq!(
+                storage_manager.save_event(0, &event(count)).await.unwrap(),
+                Some(count as u64)
+            );
+        }
+        assert_eq!(storage_manager.save_event(1, &event(4)).await.unwrap(), Some(4));
+
+        // Only the latest 2 events of each account are kept
+        let events = storage_manager.get_events_since(0).await.unwrap();
+        assert_eq!(
+            events
+                .iter()
+                .map(|e| (e.account_index, e.sequence.unwrap(), e.event.clone()))
+                .collect::<Vec<_>>(),
+            [(0, 2, event(2)), (0, 3, event(3)), (1, 4, event(4))]
+        );
+        assert_eq!(storage_manager.get_events_since(3).await.unwrap().len(), 1);
+
+        // The events are only written on flush or with the account
+        let events_key = format!("{ACCOUNT_INDEXATION_KEY}0-{ACCOUNT_EVENTS}");
+        assert!(storage.get::<VecDeque<Event>>(&events_key).await.unwrap().is_none());
+        storage_manager.flush().await.unwrap();
+        assert_eq!(
+            Vec::from(storage.get::<VecDeque<Event>>(&events_key).await.unwrap().unwrap()),
+            events[..2]
+        );
+
+        // The sequence number continues after the reserved ones after a restart
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        storage_manager.event_log_size = Some(2);
+        assert_eq!(storage_manager.get_events_since(0).await.unwrap(), events);
+        assert_eq!(
+            storage_manager.save_event(1, &event(5)).await.unwrap(),
+            Some(EVENT_SEQUENCE_RESERVATION + 1)
+        );
+        storage_manager.flush().await.unwrap();
+
+        // The events are removed with the account
+        storage_manager.remove_account(1).await.unwrap();
+        assert_eq!(storage_manager.get_events_since(0).await.unwrap(), events[..2]);
+    }
+
+    // Memory storage that counts the writes of the account with index 0 and can make them fail
+    #[derive(Debug, Default)]
+    struct CountingStorage {
+        inner: Memory,
+        account_writes: Arc<AtomicUsize>,
+        fail_account_writes: Arc<AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl StorageAdapter for CountingStorage {
+        type Error = crate::wallet::Error;
+
+        async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+            self.inner.get_bytes(key).await
+        }
+
+        async fn set_bytes(&self, key: &str, record: &[u8]) -> Result<(), Self::Error> {
+            if key == format!("{ACCOUNT_INDEXATION_KEY}0") {
+                if self.fail_account_writes.load(Ordering::Relaxed) {
+                    return Err(crate::wallet::Error::Storage("write failed".to_string()));
+                }
+                self.account_writes.fetch_add(1, Ordering::Relaxed);
+            }
+            self.inner.set_bytes(key, record).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), Self::Error> {
+            self.inner.delete(key).await
+        }
+    }
+
+    #[tokio::test]
+    async fn save_unchanged_account() {
+        let storage = CountingStorage::default();
+        let account_writes = storage.account_writes.clone();
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        let mut account_details = AccountDetails::mock();
+
+        for _ in 0..10 {
+            storage_manager.save_account(&account_details).await.unwrap();
+        }
+        assert_eq!(account_writes.load(Ordering::Relaxed), 1);
+
+        account_details
+            .internal_addresses
+            .push(account_details.public_addresses[0].clone());
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert_eq!(account_writes.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn save_account_after_failed_write() {
+        let storage = CountingStorage::default();
+        let account_writes = storage.account_writes.clone();
+        let fail_account_writes = storage.fail_account_writes.clone();
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        let account_details = AccountDetails::mock();
+
+        fail_account_writes.store(true, Ordering::Relaxed);
+        assert!(storage_manager.save_account(&account_details).await.is_err());
+
+        // The same state is written again, as it wasn't written before
+        fail_account_writes.store(false, Ordering::Relaxed);
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert_eq!(account_writes.load(Ordering::Relaxed), 1);
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), [account_details]);
+    }
+
+    #[tokio::test]
+    async fn save_account_debounced() {
+        let storage = CountingStorage::default();
+        let account_writes = storage.account_writes.clone();
+        let mut storage_manager = StorageManager::new(storage, None).await.unwrap();
+        storage_manager.debounce = Some(Duration::from_secs(3600));
+        let mut account_details = AccountDetails::mock();
+
+        storage_manager.save_account(&account_details).await.unwrap();
+        account_details
+            .internal_addresses
+            .push(account_details.public_addresses[0].clone());
+        storage_manager.save_account(&account_details).await.unwrap();
+        account_details.locked_outputs.insert(OutputId::null());
+        storage_manager.save_account(&account_details).await.unwrap();
+        assert_eq!(account_writes.load(Ordering::Relaxed), 1);
+        // Delayed writes are already returned when reading the accounts
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), [account_details.clone()]);
+
+        storage_manager.flush().await.unwrap();
+        assert_eq!(account_writes.load(Ordering::Relaxed), 2);
+        let accounts = storage_manager.get_accounts().await.unwrap();
+        assert_eq!(accounts[0].internal_addresses.len(), 1);
+        assert_eq!(accounts[0].locked_outputs.len(), 1);
+
+        // Going back to the written state doesn't need a write anymore
+        account_details.locked_outputs.clear();
+        storage_manager.save_account(&account_details).await.unwrap();
+        account_details.locked_outputs.insert(OutputId::null());
+        storage_manager.save_account(&account_details).await.unwrap();
+        storage_manager.flush().await.unwrap();
+        assert_eq!(account_writes.load(Ordering::Relaxed), 2);
+    }
+
     #[tokio::test]
     async fn save_get_wallet_data() {
         let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
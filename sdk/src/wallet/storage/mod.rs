@@ -15,9 +15,12 @@ mod participation;
 use async_trait::async_trait;
 use crypto::ciphers::chacha;
 
-use self::adapter::DynStorageAdapter;
-use crate::client::storage::StorageAdapter;
+use self::{adapter::DynStorageAdapter, constants::AES_GCM_RECORD_VERSION};
+use crate::{client::storage::StorageAdapter, wallet::encryption};
 
+/// A storage that encrypts all records with AES-256-GCM if an encryption key is set. The records are laid out as
+/// `version || nonce || tag || ciphertext`, records of older versions without the version byte were encrypted with
+/// XChaCha20-Poly1305 and can still be read.
 #[derive(Debug)]
 pub struct Storage {
     inner: Box<dyn DynStorageAdapter>,
@@ -32,6 +35,13 @@ impl StorageAdapter for Storage {
         match self.inner.as_ref().get_bytes(key).await? {
             Some(record) => {
                 if let Some(encryption_key) = &self.encryption_key {
+                    if let Some((&AES_GCM_RECORD_VERSION, encrypted)) = record.split_first() {
+                        if let Ok(decrypted) = encryption::decrypt(encryption_key, encrypted) {
+                            return Ok(Some(decrypted));
+                        }
+                    }
+                    // The nonce of an older record can start with the version byte by chance, so records that can't
+                    // be decrypted with AES-256-GCM are always tried with XChaCha20-Poly1305 too
                     return Ok(Some(chacha::aead_decrypt(encryption_key, &record)?));
                 }
 
@@ -43,7 +53,8 @@ impl StorageAdapter for Storage {
 
     async fn set_bytes(&self, key: &str, record: &[u8]) -> Result<(), Self::Error> {
         if let Some(encryption_key) = &self.encryption_key {
-            let encrypted_bytes = chacha::aead_encrypt(encryption_key, record)?;
+            let mut encrypted_bytes = vec![AES_GCM_RECORD_VERSION];
+            encrypted_bytes.extend(encryption::encrypt(encryption_key, record)?);
             self.inner.as_ref().set_bytes(key, &encrypted_bytes).await?
         } else {
             self.inner.as_ref().set_bytes(key, record).await?
@@ -120,4 +131,33 @@ mod tests {
 
         assert_eq!(Some(rec), storage.get::<Record>("key").await.unwrap());
     }
+
+    #[cfg(feature = "rand")]
+    #[tokio::test]
+    async fn get_record_encrypted_by_older_version() {
+        let encryption_key = crate::types::block::rand::bytes::rand_bytes_array::<32>();
+        let memory = Memory::default();
+        let storage = Storage {
+            inner: Box::new(memory.clone()),
+            encryption_key: Some(encryption_key),
+        };
+
+        // Records of older versions were encrypted with XChaCha20-Poly1305 and have no version byte, also try one whose
+        // nonce starts with the version byte
+        for _ in 0..1000 {
+            let record = chacha::aead_encrypt(&encryption_key, b"record").unwrap();
+            memory.set_bytes("key", &record).await.unwrap();
+            assert_eq!(storage.get_bytes("key").await.unwrap().unwrap(), b"record");
+            if record[0] == AES_GCM_RECORD_VERSION {
+                break;
+            }
+        }
+
+        // Written again, the record is encrypted with AES-256-GCM
+        storage.set_bytes("key", b"record").await.unwrap();
+        let record = memory.get_bytes("key").await.unwrap().unwrap();
+        assert_eq!(record[0], AES_GCM_RECORD_VERSION);
+        assert!(chacha::aead_decrypt(&encryption_key, &record).is_err());
+        assert_eq!(storage.get_bytes("key").await.unwrap().unwrap(), b"record");
+    }
 }
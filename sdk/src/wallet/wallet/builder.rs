@@ -6,7 +6,7 @@ use std::sync::{
     Arc,
 };
 #[cfg(feature = "storage")]
-use std::{collections::HashSet, path::PathBuf, sync::atomic::Ordering};
+use std::{collections::HashSet, path::PathBuf, sync::atomic::Ordering, time::Duration};
 
 use futures::{future::try_join_all, FutureExt};
 use serde::{Deserialize, Serialize};
@@ -46,8 +46,15 @@ pub struct WalletBuilder {
 pub(crate) struct StorageOptions {
     pub(crate) storage_path: PathBuf,
     pub(crate) storage_file_name: Option<String>,
-    pub(crate) storage_encryption_key: Option<[u8; 32]>,
+    // never stored, it needs to be provided every time to read the encrypted storage
+    #[serde(skip)]
+    pub(crate) encryption_key: Option<[u8; 32]>,
     pub(crate) manager_store: ManagerStorage,
+    #[serde(default)]
+    pub(crate) storage_debounce: Option<Duration>,
+    #[cfg(feature = "events")]
+    #[serde(default)]
+    pub(crate) event_log_size: Option<usize>,
 }
 
 #[cfg(feature = "storage")]
@@ -56,8 +63,11 @@ impl Default for StorageOptions {
         Self {
             storage_path: default_storage_path().into(),
             storage_file_name: None,
-            storage_encryption_key: None,
+            encryption_key: None,
             manager_store: ManagerStorage::default(),
+            storage_debounce: None,
+            #[cfg(feature = "events")]
+            event_log_size: None,
         }
     }
 }
@@ -100,10 +110,37 @@ impl WalletBuilder {
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     pub fn with_storage_path(mut self, path: &str) -> Self {
-        self.storage_options = Some(StorageOptions {
-            storage_path: path.into(),
-            ..Default::default()
-        });
+        self.storage_options.get_or_insert_with(Default::default).storage_path = path.into();
+        self
+    }
+
+    /// Set the min duration between two writes of the same account to the storage. Changes in between are delayed
+    /// until the next write after the duration passed or until [`Wallet::flush_storage()`] is called, which needs to
+    /// be done before shutting down.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_storage_debounce(mut self, debounce: impl Into<Option<Duration>>) -> Self {
+        self.storage_options
+            .get_or_insert_with(Default::default)
+            .storage_debounce = debounce.into();
+        self
+    }
+
+    /// Set the number of the latest events that are stored for each account, so they can be replayed with
+    /// [`Wallet::events_since()`] after a restart. Events aren't stored by default.
+    #[cfg(all(feature = "storage", feature = "events"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "storage", feature = "events"))))]
+    pub fn with_event_log_size(mut self, event_log_size: impl Into<Option<usize>>) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).event_log_size = event_log_size.into();
+        self
+    }
+
+    /// Set the key to encrypt all data in the storage with AES-256-GCM. The same key needs to be provided every time
+    /// the wallet is loaded from the storage.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_storage_encryption_key(mut self, encryption_key: impl Into<Option<[u8; 32]>>) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).encryption_key = encryption_key.into();
         self
     }
 
@@ -135,7 +172,15 @@ impl WalletBuilder {
         let storage = Memory::default();
 
         #[cfg(feature = "storage")]
-        let mut storage_manager = StorageManager::new(storage, None).await?;
+        let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key).await?;
+        #[cfg(feature = "storage")]
+        {
+            storage_manager.debounce = storage_options.storage_debounce;
+            #[cfg(feature = "events")]
+            {
+                storage_manager.event_log_size = storage_options.event_log_size;
+            }
+        }
 
         #[cfg(feature = "storage")]
         let mut read_manager_builder = storage_manager.get_wallet_data().await?;
@@ -13,7 +13,7 @@ use tokio::sync::RwLock;
 
 #[cfg(feature = "storage")]
 use self::builder::StorageOptions;
-pub use self::builder::WalletBuilder;
+pub use self::{builder::WalletBuilder, operations::balance::WalletBalance};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
@@ -125,18 +125,6 @@ impl Wallet {
         Ok(())
     }
 
-    /// Get the balance of all accounts added together
-    pub async fn balance(&self) -> crate::wallet::Result<Balance> {
-        let mut balance = Balance::default();
-        let accounts = self.accounts.read().await;
-
-        for account in accounts.iter() {
-            balance += account.balance().await?;
-        }
-
-        Ok(balance)
-    }
-
     /// Sync all accounts
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
         let mut balance = Balance::default();
@@ -189,9 +177,51 @@ impl WalletInner {
         Ok(())
     }
 
+    /// Writes the account changes that were delayed because of the storage debounce and the pending events. Needs to be
+    /// called before shutting down, the delayed changes are lost otherwise.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn flush_storage(&self) -> crate::wallet::Result<()> {
+        self.storage_manager.write().await.flush().await
+    }
+
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
-        self.event_emitter.read().await.emit(account_index, event);
+        // Stored before the listeners get it, so a listener never misses an event that it could replay. Progress events
+        // are outdated after a restart, so they aren't stored. Storing is best effort, a failed write must not abort the
+        // operation that emitted the event.
+        #[cfg(feature = "storage")]
+        let sequence = if self.storage_options.event_log_size.is_some() && !event.is_progress() {
+            self.storage_manager
+                .write()
+                .await
+                .save_event(account_index, &event)
+                .await
+                .unwrap_or_else(|e| {
+                    log::error!("[emit] storing the event failed: {e}");
+                    None
+                })
+        } else {
+            None
+        };
+        #[cfg(not(feature = "storage"))]
+        let sequence = None;
+
+        self.event_emitter.read().await.emit_event(Event {
+            account_index,
+            sequence,
+            event,
+        });
+    }
+
+    /// Returns the stored events of all accounts with a sequence number above `sequence`, ordered by it, to replay the
+    /// events that a listener missed, for example because the app was closed. Only the latest events set with
+    /// [`WalletBuilder::with_event_log_size()`](crate::wallet::WalletBuilder::with_event_log_size) are stored. The
+    /// sequence numbers keep increasing, but can skip numbers after a restart.
+    #[cfg(all(feature = "storage", feature = "events"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "storage", feature = "events"))))]
+    pub async fn events_since(&self, sequence: u64) -> crate::wallet::Result<Vec<Event>> {
+        self.storage_manager.read().await.get_events_since(sequence).await
     }
 
     /// Helper function to test events. Emits a provided event with account index 0.
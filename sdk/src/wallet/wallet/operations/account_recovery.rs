@@ -3,6 +3,8 @@
 
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{AccountRecoveryProgressEvent, WalletEvent};
 use crate::wallet::{
     task,
     wallet::{SyncOptions, Wallet},
@@ -24,6 +26,12 @@ impl Wallet {
     /// Returns:
     ///
     /// A vector of Account
+    ///
+    /// If the search of an account fails, the other accounts are still searched and
+    /// [`Error::AccountRecoveryFailed`](crate::wallet::Error::AccountRecoveryFailed) is returned with the indexes of
+    /// the failed accounts. These accounts are kept, because they could have outputs, so the recovery can be
+    /// retried. If every search failed, the accounts created by the recovery are removed again. The progress and
+    /// the errors are also emitted as `AccountRecoveryProgress` events.
     pub async fn recover_accounts(
         &self,
         account_start_index: u32,
@@ -34,16 +42,19 @@ impl Wallet {
         log::debug!("[recover_accounts]");
         let start_time = Instant::now();
         let mut max_account_index_to_keep = None;
+        let mut searched_accounts = 0;
+        let mut failed_accounts = Vec::new();
 
         // Search for addresses in current accounts
         for account in self.accounts.read().await.iter() {
+            let account_index = *account.details().await.index();
             // If the gap limit is 0, there is no need to search for funds
             if address_gap_limit > 0 {
-                account
-                    .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
-                    .await?;
+                searched_accounts += 1;
+                if let Err(error) = search_account(account, address_gap_limit, sync_options.clone()).await {
+                    failed_accounts.push((account_index, error.to_string()));
+                }
             }
-            let account_index = *account.details().await.index();
             match max_account_index_to_keep {
                 Some(max_account_index) => {
                     if account_index > max_account_index {
@@ -53,6 +64,7 @@ impl Wallet {
                 None => max_account_index_to_keep = Some(account_index),
             }
         }
+        let max_existing_account_index = max_account_index_to_keep;
 
         // Create accounts below account_start_index, because we don't want to have gaps in the accounts, but we also
         // don't want to sync them
@@ -67,9 +79,19 @@ impl Wallet {
                 account_gap_limit,
                 address_gap_limit,
                 &mut max_account_index_to_keep,
+                &mut failed_accounts,
                 sync_options.clone(),
             )
             .await;
+        if let Ok(searched_new_accounts) = new_accounts_discovery_result {
+            searched_accounts += searched_new_accounts;
+        }
+
+        // Without a successful search it's unknown which accounts have outputs, so only the existing ones are kept
+        let every_search_failed = searched_accounts > 0 && failed_accounts.len() == searched_accounts;
+        if every_search_failed {
+            max_account_index_to_keep = max_existing_account_index;
+        }
 
         // remove accounts without outputs
         let mut new_accounts = Vec::new();
@@ -101,19 +123,26 @@ impl Wallet {
 
         // Handle result after cleaning up the empty accounts
         new_accounts_discovery_result?;
+        if !failed_accounts.is_empty() {
+            failed_accounts.sort_by_key(|(index, _)| *index);
+            return Err(crate::wallet::Error::AccountRecoveryFailed(failed_accounts));
+        }
 
         log::debug!("[recover_accounts] finished in {:?}", start_time.elapsed());
         Ok(self.accounts.read().await.clone())
     }
 
-    /// Generate new accounts and search for unspent outputs
+    /// Generate new accounts and search for unspent outputs, returns the number of searched accounts and adds the
+    /// failed ones to `failed_accounts`
     async fn search_new_accounts(
         &self,
         account_gap_limit: u32,
         address_gap_limit: u32,
         max_account_index_to_keep: &mut Option<u32>,
+        failed_accounts: &mut Vec<(u32, String)>,
         sync_options: Option<SyncOptions>,
-    ) -> crate::wallet::Result<()> {
+    ) -> crate::wallet::Result<usize> {
+        let mut searched_accounts = 0;
         let mut updated_account_gap_limit = account_gap_limit;
         loop {
             log::debug!("[recover_accounts] generating {updated_account_gap_limit} new accounts");
@@ -125,9 +154,9 @@ impl Wallet {
                 async move {
                     task::spawn(async move {
                         let new_account = new_account.finish().await?;
-                        let account_outputs_count = new_account
-                            .search_addresses_with_outputs(address_gap_limit, sync_options_)
-                            .await?;
+                        let account_outputs_count = search_account(&new_account, address_gap_limit, sync_options_)
+                            .await
+                            .map_err(|error| error.to_string());
                         let account_index = *new_account.details().await.index();
                         crate::wallet::Result::Ok((account_index, account_outputs_count))
                     })
@@ -136,11 +165,16 @@ impl Wallet {
             }))
             .await?;
 
+            searched_accounts += results.len();
             let mut new_accounts_with_outputs = 0;
             let mut highest_account_index = 0;
             for (account_index, outputs_count) in results {
-                if outputs_count != 0 {
-                    new_accounts_with_outputs += 1;
+                // Accounts whose search failed are kept too, because they could have outputs
+                if outputs_count != Ok(0) {
+                    match outputs_count {
+                        Ok(_) => new_accounts_with_outputs += 1,
+                        Err(error) => failed_accounts.push((account_index, error)),
+                    }
 
                     match *max_account_index_to_keep {
                         Some(max_account_index) => {
@@ -171,6 +205,52 @@ impl Wallet {
             }
         }
 
-        Ok(())
+        Ok(searched_accounts)
+    }
+}
+
+/// Searches the addresses of an account for unspent outputs and returns their number. The error is also emitted, the
+/// recovery continues with the other accounts.
+async fn search_account(
+    account: &Account,
+    address_gap_limit: u32,
+    sync_options: Option<SyncOptions>,
+) -> crate::wallet::Result<usize> {
+    let account_index = *account.details().await.index();
+    #[cfg(feature = "events")]
+    account
+        .emit(
+            account_index,
+            WalletEvent::AccountRecoveryProgress(AccountRecoveryProgressEvent::ScanningAccount),
+        )
+        .await;
+
+    match account
+        .search_addresses_with_sync_options(address_gap_limit, sync_options)
+        .await
+    {
+        Ok(outputs) => {
+            #[cfg(feature = "events")]
+            account
+                .emit(
+                    account_index,
+                    WalletEvent::AccountRecoveryProgress(AccountRecoveryProgressEvent::AccountScanned { outputs }),
+                )
+                .await;
+            Ok(outputs)
+        }
+        Err(error) => {
+            log::debug!("[recover_accounts] searching account {account_index} failed: {error}");
+            #[cfg(feature = "events")]
+            account
+                .emit(
+                    account_index,
+                    WalletEvent::AccountRecoveryProgress(AccountRecoveryProgressEvent::AccountFailed {
+                        error: error.to_string(),
+                    }),
+                )
+                .await;
+            Err(error)
+        }
     }
 }
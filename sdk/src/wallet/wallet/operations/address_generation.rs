@@ -107,6 +107,16 @@ impl Wallet {
                     .await?
             }
             SecretManager::Placeholder(_) => return Err(crate::client::Error::PlaceholderSecretManager.into()),
+            SecretManager::WatchOnly(watch_only) => {
+                watch_only
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
         };
 
         Ok(Address::from(
@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{AliasId, FoundryId, NftId},
+    wallet::{
+        account::{operations::syncing::SyncOptions, types::Balance},
+        Wallet,
+    },
+};
+
+/// The balance of all accounts of a wallet, returned from [`Wallet::balance()`] and
+/// [`Wallet::balance_after_sync()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct WalletBalance {
+    /// The balances of all accounts added together
+    pub(crate) total: Balance,
+    /// The balance of each account, by account index
+    pub(crate) accounts: BTreeMap<u32, Balance>,
+    /// The index of the account owning each alias
+    pub(crate) aliases: HashMap<AliasId, u32>,
+    /// The index of the account owning each nft
+    pub(crate) nfts: HashMap<NftId, u32>,
+    /// The index of the account owning each foundry
+    pub(crate) foundries: HashMap<FoundryId, u32>,
+}
+
+impl WalletBalance {
+    fn add_account(&mut self, account_index: u32, balance: Balance) {
+        self.aliases
+            .extend(balance.aliases.iter().map(|alias_id| (*alias_id, account_index)));
+        self.nfts
+            .extend(balance.nfts.iter().map(|nft_id| (*nft_id, account_index)));
+        self.foundries
+            .extend(balance.foundries.iter().map(|foundry_id| (*foundry_id, account_index)));
+        self.total += balance.clone();
+        self.accounts.insert(account_index, balance);
+    }
+}
+
+impl Wallet {
+    /// Get the balance of all accounts added together and of each account, computed from the stored outputs.
+    pub async fn balance(&self) -> crate::wallet::Result<WalletBalance> {
+        let mut wallet_balance = WalletBalance::default();
+
+        for account in self.accounts.read().await.iter() {
+            let account_index = *account.details().await.index();
+            wallet_balance.add_account(account_index, account.balance().await?);
+        }
+
+        Ok(wallet_balance)
+    }
+
+    /// Sync all accounts and get their balance afterwards.
+    pub async fn balance_after_sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<WalletBalance> {
+        self.sync(options).await?;
+        self.balance().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wallet_balance() {
+        let (balance_0, balance_1) = (Balance::rand_mock(), Balance::rand_mock());
+        let mut wallet_balance = WalletBalance::default();
+        wallet_balance.add_account(0, balance_0.clone());
+        wallet_balance.add_account(1, balance_1.clone());
+
+        assert_eq!(
+            wallet_balance.total.base_coin.total(),
+            balance_0.base_coin.total() + balance_1.base_coin.total()
+        );
+        assert_eq!(
+            wallet_balance.total.base_coin.available(),
+            balance_0.base_coin.available() + balance_1.base_coin.available()
+        );
+        assert_eq!(wallet_balance.nfts.len(), balance_0.nfts.len() + balance_1.nfts.len());
+        for alias_id in &balance_1.aliases {
+            assert_eq!(wallet_balance.aliases[alias_id], 1);
+        }
+        assert_eq!(
+            wallet_balance.accounts,
+            BTreeMap::from([(0, balance_0), (1, balance_1)])
+        );
+
+        let json = serde_json::to_string(&wallet_balance).unwrap();
+        assert_eq!(serde_json::from_str::<WalletBalance>(&json).unwrap(), wallet_balance);
+    }
+}
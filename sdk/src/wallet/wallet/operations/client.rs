@@ -7,6 +7,7 @@ use std::collections::{HashMap, HashSet};
 use crate::wallet::WalletBuilder;
 use crate::{
     client::{
+        block_cache::BlockCache,
         node_manager::{
             builder::NodeManagerBuilder,
             node::{Node, NodeAuth, NodeDto},
@@ -36,13 +37,33 @@ impl Wallet {
             remote_pow_timeout,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
+            time_sync_tolerance,
+            time_check_disabled,
+            time_check_cache_ttl,
+            max_indexer_pages,
+            custom_bech32_hrp,
+            hrp_validation_disabled,
+            block_cache,
         } = client_options;
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
         *self.client.network_info.write().await = network_info;
         *self.client.api_timeout.write().await = api_timeout;
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        *self.client.time_sync_tolerance.write().await = time_sync_tolerance;
+        *self.client.time_check_disabled.write().await = time_check_disabled;
+        *self.client.time_check_cache_ttl.write().await = time_check_cache_ttl;
+        *self.client.max_indexer_pages.write().await = max_indexer_pages;
+        *self.client.custom_bech32_hrp.write().await = custom_bech32_hrp;
+        *self.client.hrp_validation_disabled.write().await = hrp_validation_disabled;
+        {
+            // Keep the cached entries if the options didn't change
+            let mut client_block_cache = self.client.block_cache.write().await;
+            if client_block_cache.as_ref().map(|block_cache| block_cache.options()) != block_cache {
+                *client_block_cache = block_cache.map(BlockCache::new);
+            }
+        }
         #[cfg(not(target_family = "wasm"))]
         {
             *self.client.pow_worker_count.write().await = pow_worker_count;
@@ -147,7 +168,7 @@ impl Wallet {
         }
 
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
 
         for account in self.accounts.write().await.iter_mut() {
@@ -4,6 +4,7 @@
 pub(crate) mod account_recovery;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
+pub(crate) mod balance;
 pub(crate) mod client;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
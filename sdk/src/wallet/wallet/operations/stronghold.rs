@@ -19,7 +19,8 @@ impl Wallet {
         Ok(())
     }
 
-    /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
+    /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it. A
+    /// wrong current password fails without changing the snapshot.
     pub async fn change_stronghold_password(
         &self,
         current_password: impl Into<Password> + Send,
@@ -29,13 +30,13 @@ impl Wallet {
         let new_password = new_password.into();
 
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
-            stronghold.set_password(current_password).await?;
-            stronghold.change_password(new_password).await?;
+            stronghold.change_password(current_password, new_password).await?;
         }
         Ok(())
     }
 
-    /// Sets the Stronghold password clear interval
+    /// Sets the Stronghold password clear interval, after which the password is cleared if it wasn't used for signing
+    /// or address generation. `None` or a zero interval disables the clearing.
     pub async fn set_stronghold_password_clear_interval(&self, timeout: Option<Duration>) -> crate::wallet::Result<()> {
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
             stronghold.set_timeout(timeout).await;
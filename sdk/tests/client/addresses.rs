@@ -5,10 +5,10 @@
 use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
 use iota_sdk::{
     client::{
-        api::GetAddressesOptions,
+        api::{Bip44PathPrefix, GetAddressesOptions},
         constants::{IOTA_BECH32_HRP, IOTA_COIN_TYPE, IOTA_TESTNET_BECH32_HRP, SHIMMER_BECH32_HRP, SHIMMER_COIN_TYPE},
         secret::{GenerateAddressOptions, SecretManager},
-        Client,
+        Client, Error,
     },
     types::block::address::{Address, Hrp},
 };
@@ -54,6 +54,41 @@ async fn public_key_to_address() {
     );
 }
 
+#[tokio::test]
+async fn custom_path_prefix() {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned();
+    let secret_manager = SecretManager::try_from_mnemonic(mnemonic).unwrap();
+    let default_options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_BECH32_HRP)
+        .with_range(0..1);
+
+    let default_address = &secret_manager
+        .generate_ed25519_addresses(default_options.clone())
+        .await
+        .unwrap()[0];
+    // Coin type 1 is used by all testnets
+    let testnet_address = &secret_manager
+        .generate_ed25519_addresses(
+            default_options
+                .clone()
+                .with_custom_path_prefix(Bip44PathPrefix::new(1, 0))
+                .unwrap(),
+        )
+        .await
+        .unwrap()[0];
+    let coin_type_address = &secret_manager
+        .generate_ed25519_addresses(default_options.clone().with_coin_type(1))
+        .await
+        .unwrap()[0];
+
+    assert_ne!(testnet_address, default_address);
+    assert_eq!(testnet_address, coin_type_address);
+    assert!(matches!(
+        default_options.with_custom_path_prefix(Bip44PathPrefix::new(1, 0).with_purpose(49)),
+        Err(Error::UnsupportedBip44Purpose(49))
+    ));
+}
+
 #[tokio::test]
 async fn mnemonic_address_generation_iota() {
     let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned();
@@ -0,0 +1,122 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{validate_block, GetAddressesOptions, PreparedTransactionData, ValidationError},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManager, SignTransactionEssence},
+        Client, Error,
+    },
+    pow::miner::get_miner,
+    types::block::{
+        address::ToBech32Ext,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            Payload, TransactionPayload,
+        },
+        protocol::{protocol_parameters, ProtocolParameters},
+        rand::parents::rand_parents,
+        BlockBuilder,
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    common::{info_json, setup_client_with_mock_node},
+    Build::Basic,
+};
+
+#[tokio::test]
+async fn validate_block() {
+    let client = setup_client_with_mock_node(|path| {
+        if path == "/api/core/v2/info" {
+            (200, info_json(7))
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic().unwrap()).unwrap();
+    let address = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await
+        .unwrap()[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+        .to_string();
+    let chain = Some(Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]));
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(1_000_000, &address, None, None, None, None, None, chain.clone())]);
+    let outputs = build_outputs([Basic(1_000_000, &address, None, None, None, None, None, None)]);
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)
+        .unwrap(),
+    );
+    let prepared_transaction_data = PreparedTransactionData {
+        essence: essence.clone(),
+        inputs_data: inputs.clone(),
+        remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
+    };
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await
+        .unwrap();
+    let payload = Payload::from(TransactionPayload::new(essence, unlocks).unwrap());
+    let input_outputs = inputs
+        .iter()
+        .map(|i| (*i.output_metadata.output_id(), i.output.clone()))
+        .collect::<HashMap<_, _>>();
+
+    // The block isn't mined
+    let parents = rand_parents();
+    let block = BlockBuilder::new(parents.clone())
+        .with_payload(payload.clone())
+        .finish()
+        .unwrap();
+    assert!(matches!(
+        client.validate_block(&block, None).await,
+        Err(Error::BlockValidation(ValidationError::PowScoreTooLow {
+            min_pow_score: 1500,
+            ..
+        }))
+    ));
+    // Offline, with PoW disabled
+    let offline_protocol_parameters = ProtocolParameters::builder().min_pow_score(0).finish().unwrap();
+    validate_block(&block, &offline_protocol_parameters, Some(&input_outputs)).unwrap();
+
+    let block = BlockBuilder::new(parents)
+        .with_payload(payload)
+        .finish_nonce(get_miner(1500))
+        .unwrap();
+    client.validate_block(&block, None).await.unwrap();
+    client.validate_block(&block, Some(&input_outputs)).await.unwrap();
+
+    // The referenced output isn't in the provided outputs
+    let output_id = *inputs[0].output_metadata.output_id();
+    assert!(matches!(
+        client.validate_block(&block, Some(&HashMap::new())).await,
+        Err(Error::BlockValidation(ValidationError::InputNotFound(id))) if id == output_id
+    ));
+}
@@ -5,9 +5,25 @@
 
 mod constants;
 
-use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter,
-    request_funds_from_faucet, secret::SecretManager, Client, Result,
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions, constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter,
+        request_funds_from_faucet, secret::SecretManager, Client, Result,
+    },
+    types::block::{
+        output::{
+            dto::{OutputDto, OutputMetadataDto},
+            Output, OutputId, OutputMetadata,
+        },
+        rand::{block::rand_block_id, output::rand_output_id},
+    },
 };
 
 pub use self::constants::{FAUCET_URL, NODE_LOCAL};
@@ -53,3 +69,284 @@ pub async fn create_client_and_secret_manager_with_funds(mnemonic: Option<String
     }
     panic!("Faucet no longer wants to hand over coins");
 }
+
+/// Spawns a mock node that answers every request with the status code and JSON body returned by the handler for the
+/// requested path, and returns its URL.
+pub fn spawn_mock_node(handler: impl Fn(&str) -> (u16, String) + Send + 'static) -> String {
+    spawn_mock_node_with_headers(move |path, _| handler(path))
+}
+
+/// Like [`spawn_mock_node`], but the handler also gets the request headers, with lowercase names.
+pub fn spawn_mock_node_with_headers(
+    handler: impl Fn(&str, &HashMap<String, String>) -> (u16, String) + Send + 'static,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            // Read the headers and skip the body
+            let mut headers = HashMap::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.insert(name.to_ascii_lowercase(), value.trim().to_string());
+                }
+                line.clear();
+            }
+            let content_length = headers
+                .get("content-length")
+                .map_or(0, |content_length| content_length.parse().unwrap());
+            reader.read_exact(&mut vec![0; content_length]).unwrap();
+
+            let path = request_line.split(' ').nth(1).unwrap_or_default();
+            let (status, body) = handler(path, &headers);
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} \r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    url
+}
+
+/// Spawns a mock node that accepts a single MQTT connection over a WebSocket and publishes the payloads on the topic
+/// once the client subscribed, and returns its `ws` URL.
+#[cfg(feature = "websocket")]
+pub fn spawn_mock_ws_node(topic: &str, payloads: Vec<Vec<u8>>) -> String {
+    use tungstenite::{
+        handshake::server::{Request, Response},
+        http::HeaderValue,
+        Message,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}/api/mqtt/v1", listener.local_addr().unwrap());
+    let topic = topic.to_string();
+
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut websocket = tungstenite::accept_hdr(stream, |_: &Request, mut response: Response| {
+            response
+                .headers_mut()
+                .insert("Sec-WebSocket-Protocol", HeaderValue::from_static("mqtt"));
+            Ok(response)
+        })
+        .unwrap();
+
+        while let Ok(message) = websocket.read() {
+            let packets = match message {
+                Message::Binary(packets) => packets,
+                _ => continue,
+            };
+            // A message can contain multiple MQTT packets
+            let mut packets = packets.as_slice();
+            while !packets.is_empty() {
+                let (length, length_size) = decode_mqtt_length(&packets[1..]);
+                let (packet, rest) = packets.split_at(1 + length_size + length);
+                packets = rest;
+                let body = &packet[1 + length_size..];
+
+                let responses = match packet[0] >> 4 {
+                    // CONNECT
+                    1 => vec![vec![0x20, 0x02, 0x00, 0x00]],
+                    // SUBSCRIBE, acknowledged with QoS 1 for every topic filter
+                    8 => {
+                        let (packet_id, mut filters) = body.split_at(2);
+                        let mut suback = packet_id.to_vec();
+                        while !filters.is_empty() {
+                            let filter_length = u16::from_be_bytes([filters[0], filters[1]]) as usize;
+                            filters = &filters[2 + filter_length + 1..];
+                            suback.push(0x01);
+                        }
+                        std::iter::once(mqtt_packet(0x90, suback))
+                            .chain(payloads.iter().map(|payload| {
+                                let mut publish = (topic.len() as u16).to_be_bytes().to_vec();
+                                publish.extend(topic.as_bytes());
+                                publish.extend(payload);
+                                mqtt_packet(0x30, publish)
+                            }))
+                            .collect()
+                    }
+                    // PINGREQ
+                    12 => vec![vec![0xd0, 0x00]],
+                    // DISCONNECT
+                    14 => return,
+                    _ => Vec::new(),
+                };
+                for response in responses {
+                    websocket.send(Message::Binary(response)).unwrap();
+                }
+            }
+        }
+    });
+
+    url
+}
+
+#[cfg(feature = "websocket")]
+fn decode_mqtt_length(bytes: &[u8]) -> (usize, usize) {
+    let mut length = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        length |= ((byte & 0x7f) as usize) << (7 * i);
+        if byte & 0x80 == 0 {
+            return (length, i + 1);
+        }
+    }
+    panic!("invalid MQTT remaining length");
+}
+
+#[cfg(feature = "websocket")]
+fn mqtt_packet(header: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut packet = vec![header];
+    let mut length = body.len();
+    loop {
+        let byte = (length % 128) as u8;
+        length /= 128;
+        if length == 0 {
+            packet.push(byte);
+            break;
+        }
+        packet.push(byte | 0x80);
+    }
+    packet.extend(body);
+    packet
+}
+
+/// The JSON of a node info response of a mock node, with the given index as latest and confirmed milestone.
+pub fn info_json(confirmed_milestone_index: u32) -> String {
+    serde_json::json!({
+        "name": "HORNET",
+        "version": "2.0.0",
+        "status": {
+            "isHealthy": true,
+            "latestMilestone": { "index": confirmed_milestone_index },
+            "confirmedMilestone": { "index": confirmed_milestone_index },
+            "pruningIndex": 0
+        },
+        "supportedProtocolVersions": [2],
+        "protocol": {
+            "version": 2,
+            "networkName": "testnet",
+            "bech32Hrp": "rms",
+            "minPowScore": 1500,
+            "belowMaxDepth": 15,
+            "rentStructure": { "vByteCost": 100, "vByteFactorKey": 10, "vByteFactorData": 1 },
+            "tokenSupply": "1813620509061365"
+        },
+        "pendingProtocolParameters": [],
+        "baseToken": {
+            "name": "Shimmer",
+            "tickerSymbol": "SMR",
+            "unit": "SMR",
+            "subunit": "glow",
+            "decimals": 6,
+            "useMetricPrefix": false
+        },
+        "metrics": { "blocksPerSecond": 1.0, "referencedBlocksPerSecond": 1.0, "referencedRate": 100.0 },
+        "features": []
+    })
+    .to_string()
+}
+
+/// The JSON of an output response of a mock node, for an unspent output that was booked in the milestone with the
+/// index 7, like the one of [`info_json(7)`](info_json).
+pub fn mock_output_json(output_id: OutputId, output: &Output) -> String {
+    mock_output_json_with_metadata(
+        &OutputMetadata::new(rand_block_id(), output_id, false, None, None, None, 1, 1, 7),
+        output,
+    )
+}
+
+/// The JSON of an output response of a mock node, with the given metadata.
+pub fn mock_output_json_with_metadata(metadata: &OutputMetadata, output: &Output) -> String {
+    serde_json::json!({
+        "metadata": OutputMetadataDto::from(metadata),
+        "output": OutputDto::from(output),
+    })
+    .to_string()
+}
+
+/// An output of a [`spawn_mock_node_with_address_outputs`], with the bech32 address it belongs to.
+pub struct MockOutput {
+    pub address: String,
+    pub output_id: OutputId,
+    // The indexer route of the output kind
+    route: &'static str,
+    json: String,
+}
+
+impl MockOutput {
+    /// Creates a mock output with a random output id.
+    pub fn new(address: impl ToString, output: &Output) -> Self {
+        let output_id = rand_output_id();
+        let route = match output {
+            Output::Alias(_) => "alias",
+            Output::Basic(_) => "basic",
+            Output::Foundry(_) => "foundry",
+            Output::Nft(_) => "nft",
+            Output::Treasury(_) => "treasury",
+        };
+
+        Self {
+            address: address.to_string(),
+            output_id,
+            route,
+            json: mock_output_json(output_id, output),
+        }
+    }
+}
+
+/// Spawns a mock node that answers indexer queries with the outputs of the queried kind and address, and returns its
+/// URL. Indexer queries for which `fails` returns true are answered with an error.
+pub fn spawn_mock_node_with_address_outputs(
+    outputs: Arc<Mutex<Vec<MockOutput>>>,
+    fails: impl Fn(&str) -> bool + Send + 'static,
+) -> String {
+    spawn_mock_node(move |path| {
+        if path == "/api/core/v2/info" {
+            return (200, info_json(7));
+        }
+        if path.starts_with("/api/indexer/v1/outputs/") && fails(path) {
+            return (500, String::new());
+        }
+        let outputs = outputs.lock().unwrap();
+        if let Some(query) = path.strip_prefix("/api/indexer/v1/outputs/") {
+            let output_ids = outputs
+                .iter()
+                .filter(|output| query.starts_with(&format!("{}?", output.route)) && path.contains(&output.address))
+                .map(|output| output.output_id)
+                .collect::<Vec<_>>();
+            (
+                200,
+                serde_json::json!({ "ledgerIndex": 7, "items": output_ids }).to_string(),
+            )
+        } else if let Some(output) = outputs
+            .iter()
+            .find(|output| path == format!("/api/core/v2/outputs/{}", output.output_id))
+        {
+            (200, output.json.clone())
+        } else {
+            (404, String::new())
+        }
+    })
+}
+
+/// Creates a client connected to a [`spawn_mock_node`].
+pub async fn setup_client_with_mock_node(handler: impl Fn(&str) -> (u16, String) + Send + 'static) -> Client {
+    Client::builder()
+        .with_node(&spawn_mock_node(handler))
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,827 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use futures::TryStreamExt;
+use iota_sdk::{
+    client::{
+        api::{input_selection::InputSelectionStrategy, BlockStatus, InclusionLookup, PostBlockOptions, RetryEvent},
+        node_api::error::Error as NodeApiError,
+        Client, Error,
+    },
+    types::block::{
+        address::Bech32Address,
+        input::{Input, UtxoInput},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputId, OutputMetadata, Rent,
+        },
+        parent::Parents,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+            Payload, TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        rand::{
+            address::rand_address,
+            block::{rand_block_id, rand_block_ids},
+            output::{rand_basic_output, rand_inputs_commitment, rand_output, rand_output_id, rand_output_metadata},
+            payload::rand_tagged_data_payload,
+        },
+        semantic::ConflictReason,
+        BlockBuilder, BlockDto, BlockId,
+    },
+};
+
+use crate::client::common::{mock_output_json_with_metadata, setup_client_with_mock_node, spawn_mock_node};
+
+const INCLUDED_TRANSACTION_ID: [u8; 32] = [1; 32];
+const CONFLICTING_BLOCK_ID: [u8; 32] = [2; 32];
+const PENDING_BLOCK_ID: [u8; 32] = [3; 32];
+
+fn block_metadata(block_id: BlockId, inclusion_state: &str) -> String {
+    format!(r#"{{"blockId":"{block_id}","parents":[],"isSolid":true{inclusion_state}}}"#)
+}
+
+#[tokio::test]
+async fn lookup_transaction() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(rand_tagged_data_payload()))
+        .finish()
+        .unwrap();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+
+    let client = setup_client_with_mock_node(move |path| {
+        let included_block_path = format!(
+            "/api/core/v2/transactions/{}/included-block",
+            TransactionId::from(INCLUDED_TRANSACTION_ID)
+        );
+        let conflicting_metadata_path = format!("/api/core/v2/blocks/{}/metadata", BlockId::from(CONFLICTING_BLOCK_ID));
+        let pending_metadata_path = format!("/api/core/v2/blocks/{}/metadata", BlockId::from(PENDING_BLOCK_ID));
+
+        if path == included_block_path {
+            (200, block_json.clone())
+        } else if path == conflicting_metadata_path {
+            (
+                200,
+                block_metadata(
+                    BlockId::from(CONFLICTING_BLOCK_ID),
+                    r#","ledgerInclusionState":"conflicting","conflictReason":1"#,
+                ),
+            )
+        } else if path == pending_metadata_path {
+            (200, block_metadata(BlockId::from(PENDING_BLOCK_ID), ""))
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+
+    let unknown_transaction_id = TransactionId::from([0; 32]);
+
+    assert_eq!(
+        client
+            .lookup_transaction(&TransactionId::from(INCLUDED_TRANSACTION_ID), &[])
+            .await
+            .unwrap(),
+        InclusionLookup::Included(block)
+    );
+    assert_eq!(
+        client
+            .lookup_transaction(
+                &unknown_transaction_id,
+                &[BlockId::from(PENDING_BLOCK_ID), BlockId::from(CONFLICTING_BLOCK_ID)]
+            )
+            .await
+            .unwrap(),
+        InclusionLookup::Conflicting(ConflictReason::InputUtxoAlreadySpent)
+    );
+    assert_eq!(
+        client
+            .lookup_transaction(
+                &unknown_transaction_id,
+                &[BlockId::from([4; 32]), BlockId::from(PENDING_BLOCK_ID)]
+            )
+            .await
+            .unwrap(),
+        InclusionLookup::Pending {
+            known_block_ids: vec![BlockId::from(PENDING_BLOCK_ID)]
+        }
+    );
+    assert_eq!(
+        client
+            .lookup_transaction(&unknown_transaction_id, &[BlockId::from([4; 32])])
+            .await
+            .unwrap(),
+        InclusionLookup::Pruned
+    );
+    assert_eq!(
+        client.lookup_transaction(&unknown_transaction_id, &[]).await.unwrap(),
+        InclusionLookup::Unknown
+    );
+}
+
+#[tokio::test]
+async fn estimate_transaction_fee() {
+    let client = Client::builder().finish().await.unwrap();
+    let protocol_parameters = client.get_protocol_parameters().await.unwrap();
+
+    assert_eq!(client.estimate_transaction_fee(&[]).await.unwrap(), 0);
+
+    let outputs = [
+        Output::from(rand_basic_output(protocol_parameters.token_supply())),
+        rand_output(protocol_parameters.token_supply()),
+        rand_output(protocol_parameters.token_supply()),
+    ];
+    // Outputs that already hold more than their minimum storage deposit still count with their minimum.
+    let expected = outputs
+        .iter()
+        .map(|output| output.rent_cost(protocol_parameters.rent_structure()))
+        .sum::<u64>();
+
+    assert_eq!(client.estimate_transaction_fee(&outputs).await.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn find_included_attachment() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(rand_tagged_data_payload()))
+        .finish()
+        .unwrap();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+
+    let client = setup_client_with_mock_node(move |path| {
+        let included_block_path = format!(
+            "/api/core/v2/transactions/{}/included-block",
+            TransactionId::from(INCLUDED_TRANSACTION_ID)
+        );
+
+        if path == included_block_path {
+            (200, block_json.clone())
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+
+    assert_eq!(
+        client
+            .find_included_attachment(&TransactionId::from(INCLUDED_TRANSACTION_ID))
+            .await
+            .unwrap(),
+        Some(block)
+    );
+    assert_eq!(
+        client
+            .find_included_attachment(&TransactionId::from([0; 32]))
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn reattach_conflicting() {
+    let block_json = Arc::new(Mutex::new(String::new()));
+    let block_json_ = block_json.clone();
+    let posted_blocks = Arc::new(AtomicUsize::new(0));
+    let posted_blocks_ = posted_blocks.clone();
+
+    let client = setup_client_with_mock_node(move |path| match path {
+        "/api/core/v2/blocks" => {
+            posted_blocks_.fetch_add(1, Ordering::SeqCst);
+            (400, String::new())
+        }
+        path if path.ends_with("/metadata") => (
+            200,
+            block_metadata(
+                BlockId::from([0; 32]),
+                r#","ledgerInclusionState":"conflicting","conflictReason":1,"shouldReattach":true"#,
+            ),
+        ),
+        path if path.starts_with("/api/core/v2/blocks/") => (200, block_json_.lock().unwrap().clone()),
+        _ => (404, String::new()),
+    })
+    .await;
+
+    // The transaction has to be built with the protocol parameters of the client
+    let protocol_parameters = client.get_protocol_parameters().await.unwrap();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs([Input::Utxo(UtxoInput::new(TransactionId::from([0; 32]), 0).unwrap())])
+            .add_output(Output::Basic(rand_basic_output(protocol_parameters.token_supply())))
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+    let transaction_payload = TransactionPayload::rand_mock(essence);
+    let transaction_id = transaction_payload.id();
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(transaction_payload))
+        .finish()
+        .unwrap();
+    *block_json.lock().unwrap() = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+
+    assert!(matches!(
+        client.reattach(&block.id()).await,
+        Err(Error::TransactionConflicting {
+            transaction_id: id,
+            reason: ConflictReason::InputUtxoAlreadySpent
+        }) if id == transaction_id
+    ));
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn reattach_requests_metadata_once() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(rand_tagged_data_payload()))
+        .finish()
+        .unwrap();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+    let metadata_requests = Arc::new(AtomicUsize::new(0));
+    let metadata_requests_ = metadata_requests.clone();
+
+    let client = setup_client_with_mock_node(move |path| match path {
+        path if path.ends_with("/metadata") => {
+            metadata_requests_.fetch_add(1, Ordering::SeqCst);
+            (200, block_metadata(BlockId::from([0; 32]), r#","shouldReattach":true"#))
+        }
+        path if path.starts_with("/api/core/v2/blocks/") => (200, block_json.clone()),
+        _ => (404, String::new()),
+    })
+    .await;
+
+    // Posting fails without tips, but the metadata was only requested to check if the block should be reattached
+    assert!(client.reattach(&block.id()).await.is_err());
+    assert_eq!(metadata_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn reattach_with_unspent_inputs() {
+    let block_json = Arc::new(Mutex::new(String::new()));
+    let block_json_ = block_json.clone();
+    // The input is unspent without a spending transaction and unknown to the node if it was pruned
+    let transaction_id_spent = Arc::new(Mutex::new(Some(TransactionId::from([5; 32]))));
+    let transaction_id_spent_ = transaction_id_spent.clone();
+    let input_pruned = Arc::new(AtomicBool::new(false));
+    let input_pruned_ = input_pruned.clone();
+    let posted_blocks = Arc::new(AtomicUsize::new(0));
+    let posted_blocks_ = posted_blocks.clone();
+    let input_id = OutputId::new(TransactionId::from([0; 32]), 0).unwrap();
+    let output = Output::Basic(rand_basic_output(protocol_parameters().token_supply()));
+
+    // Remote PoW, so the reattached block is posted right away
+    let node_url = spawn_mock_node(move |path| match path {
+        "/api/core/v2/blocks" => {
+            posted_blocks_.fetch_add(1, Ordering::SeqCst);
+            (400, String::new())
+        }
+        "/api/core/v2/tips" => (200, serde_json::json!({ "tips": rand_block_ids(2) }).to_string()),
+        path if path == format!("/api/core/v2/outputs/{input_id}") && !input_pruned_.load(Ordering::SeqCst) => {
+            let transaction_id_spent = *transaction_id_spent_.lock().unwrap();
+            let metadata = OutputMetadata::new(
+                rand_block_id(),
+                input_id,
+                transaction_id_spent.is_some(),
+                transaction_id_spent.map(|_| 2),
+                transaction_id_spent.map(|_| 2),
+                transaction_id_spent,
+                1,
+                1,
+                7,
+            );
+            (200, mock_output_json_with_metadata(&metadata, &output))
+        }
+        path if path.starts_with("/api/core/v2/blocks/") => (200, block_json_.lock().unwrap().clone()),
+        _ => (404, String::new()),
+    });
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+
+    // The transaction has to be built with the protocol parameters of the client
+    let protocol_parameters = client.get_protocol_parameters().await.unwrap();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs([Input::Utxo(
+                UtxoInput::new(*input_id.transaction_id(), input_id.index()).unwrap(),
+            )])
+            .add_output(Output::Basic(rand_basic_output(protocol_parameters.token_supply())))
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+    let transaction_payload = TransactionPayload::rand_mock(essence);
+    let transaction_id = transaction_payload.id();
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(transaction_payload))
+        .finish()
+        .unwrap();
+    *block_json.lock().unwrap() = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+
+    // The input was spent by another transaction
+    assert!(matches!(
+        client.reattach_with_unspent_inputs(&block.id()).await,
+        Err(Error::InputsAlreadySpent(input_ids)) if input_ids == [input_id]
+    ));
+
+    // The input was spent by the transaction itself
+    *transaction_id_spent.lock().unwrap() = Some(transaction_id);
+    assert!(matches!(
+        client.reattach_with_unspent_inputs(&block.id()).await,
+        Err(Error::NoNeedPromoteOrReattach(_))
+    ));
+
+    // The input was spent and pruned
+    input_pruned.store(true, Ordering::SeqCst);
+    assert!(matches!(
+        client.reattach_with_unspent_inputs(&block.id()).await,
+        Err(Error::InputsAlreadySpent(input_ids)) if input_ids == [input_id]
+    ));
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 0);
+
+    // With all inputs unspent the block is reattached, the mock node rejects it
+    input_pruned.store(false, Ordering::SeqCst);
+    *transaction_id_spent.lock().unwrap() = None;
+    assert!(client.reattach_with_unspent_inputs(&block.id()).await.is_err());
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn custom_remainder_address_hrp() {
+    let client = setup_client_with_mock_node(|_| (404, String::new())).await;
+    let token_supply = client.get_token_supply().await.unwrap();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .finish_output(token_supply)
+        .unwrap();
+    let remainder_address = Bech32Address::try_new("invalid", rand_address()).unwrap();
+
+    assert!(matches!(
+        client
+            .block()
+            .with_outputs([output])
+            .unwrap()
+            .with_custom_remainder_address(&remainder_address.to_string())
+            .unwrap()
+            .prepare_transaction()
+            .await,
+        Err(Error::Bech32HrpMismatch { provided, .. }) if provided == "invalid"
+    ));
+}
+
+#[tokio::test]
+async fn build_and_post_block_retries() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let block_id = block.id();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+    let tips_json = format!(r#"{{"tips":["{}"]}}"#, rand_block_ids(1)[0]);
+    let posted_blocks = Arc::new(AtomicUsize::new(0));
+    let posted_blocks_ = posted_blocks.clone();
+
+    let node_url = spawn_mock_node(move |path| match path {
+        "/api/core/v2/tips" => (200, tips_json.clone()),
+        // Reject the first post because of too old parents
+        "/api/core/v2/blocks" => match posted_blocks_.fetch_add(1, Ordering::SeqCst) {
+            0 => (
+                400,
+                r#"{"error":{"code":"400","message":"invalid block, error: parents are below max depth"}}"#.to_string(),
+            ),
+            _ => (201, format!(r#"{{"blockId":"{block_id}"}}"#)),
+        },
+        path if path == format!("/api/core/v2/blocks/{block_id}") => (200, block_json.clone()),
+        _ => (404, String::new()),
+    });
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+
+    let (posted_block_id, posted_block) = client
+        .build_and_post_block(None, PostBlockOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(posted_block_id, block_id);
+    assert_eq!(posted_block, block);
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 2);
+
+    // Without retries the rejection is returned
+    posted_blocks.store(0, Ordering::SeqCst);
+    assert!(
+        client
+            .build_and_post_block(
+                None,
+                PostBlockOptions {
+                    max_retries: 0,
+                    ..Default::default()
+                }
+            )
+            .await
+            .is_err()
+    );
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn promote_with_parents() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let block_id = block.id();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+    let requested_tips = Arc::new(AtomicUsize::new(0));
+    let requested_tips_ = requested_tips.clone();
+
+    let node_url = spawn_mock_node(move |path| match path {
+        "/api/core/v2/tips" => {
+            requested_tips_.fetch_add(1, Ordering::SeqCst);
+            (500, String::new())
+        }
+        "/api/core/v2/blocks" => (201, format!(r#"{{"blockId":"{block_id}"}}"#)),
+        path if path == format!("/api/core/v2/blocks/{block_id}") => (200, block_json.clone()),
+        _ => (404, String::new()),
+    });
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+
+    let (promote_block_id, _) = client
+        .promote_with_parents(&rand_block_ids(1)[0], rand_block_ids(2))
+        .await
+        .unwrap();
+    assert_eq!(promote_block_id, block_id);
+    assert_eq!(requested_tips.load(Ordering::SeqCst), 0);
+
+    // With the promoted block a block can only have 7 more parents
+    assert!(matches!(
+        client.promote_with_parents(&block_id, rand_block_ids(8)).await,
+        Err(Error::Block(iota_sdk::types::block::Error::InvalidParentCount(_)))
+    ));
+}
+
+#[tokio::test]
+async fn promote_with_parents_returns_rejection() {
+    let tips_json = format!(r#"{{"tips":["{}"]}}"#, rand_block_ids(1)[0]);
+    let requested_tips = Arc::new(AtomicUsize::new(0));
+    let requested_tips_ = requested_tips.clone();
+    let posted_blocks = Arc::new(AtomicUsize::new(0));
+    let posted_blocks_ = posted_blocks.clone();
+
+    let node_url = spawn_mock_node(move |path| match path {
+        "/api/core/v2/tips" => {
+            requested_tips_.fetch_add(1, Ordering::SeqCst);
+            (200, tips_json.clone())
+        }
+        // Reject the given parents as too old
+        "/api/core/v2/blocks" => {
+            posted_blocks_.fetch_add(1, Ordering::SeqCst);
+            (
+                400,
+                r#"{"error":{"code":"400","message":"invalid block, error: parents are below max depth"}}"#.to_string(),
+            )
+        }
+        _ => (404, String::new()),
+    });
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+
+    // The rejection is returned instead of replacing the given parents with the latest tips
+    let error = client
+        .promote_with_parents(&rand_block_ids(1)[0], rand_block_ids(2))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Node(NodeApiError::ResponseError { code: 400, .. })));
+    assert_eq!(posted_blocks.load(Ordering::SeqCst), 1);
+    assert_eq!(requested_tips.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn block_status() {
+    let statuses = [
+        (r#","ledgerInclusionState":"included""#, BlockStatus::Included),
+        (r#","ledgerInclusionState":"noTransaction""#, BlockStatus::Included),
+        (
+            r#","ledgerInclusionState":"conflicting","conflictReason":1"#,
+            BlockStatus::Conflicting,
+        ),
+        ("", BlockStatus::Pending),
+        (r#","shouldPromote":false,"shouldReattach":false"#, BlockStatus::Pending),
+        (
+            r#","shouldPromote":true,"shouldReattach":false"#,
+            BlockStatus::NeedsPromote,
+        ),
+        (
+            r#","shouldPromote":false,"shouldReattach":true"#,
+            BlockStatus::NeedsReattach,
+        ),
+    ];
+    let client = setup_client_with_mock_node(move |path| {
+        for (index, (fields, _)) in statuses.iter().enumerate() {
+            let block_id = BlockId::from([index as u8; 32]);
+            if path == format!("/api/core/v2/blocks/{block_id}/metadata") {
+                return (200, block_metadata(block_id, fields));
+            }
+        }
+        (404, String::new())
+    })
+    .await;
+
+    for (index, (_, status)) in statuses.iter().enumerate() {
+        assert_eq!(
+            client.block_status(&BlockId::from([index as u8; 32])).await.unwrap(),
+            *status
+        );
+    }
+}
+
+#[tokio::test]
+async fn retry_until_included_stream() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let block_id = block.id();
+    let reattached = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let reattached_id = reattached.id();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+    let reattached_json = serde_json::to_string(&BlockDto::from(&reattached)).unwrap();
+    let tips_json = format!(r#"{{"tips":["{}"]}}"#, rand_block_ids(1)[0]);
+    let metadata_requests = Arc::new(AtomicUsize::new(0));
+    let metadata_requests_ = metadata_requests.clone();
+
+    let node_url = spawn_mock_node(move |path| match path {
+        "/api/core/v2/tips" => (200, tips_json.clone()),
+        "/api/core/v2/blocks" => (201, format!(r#"{{"blockId":"{reattached_id}"}}"#)),
+        path if path == format!("/api/core/v2/blocks/{block_id}") => (200, block_json.clone()),
+        path if path == format!("/api/core/v2/blocks/{reattached_id}") => (200, reattached_json.clone()),
+        // The original block should only be reattached in the first attempt
+        path if path == format!("/api/core/v2/blocks/{block_id}/metadata") => {
+            let should_reattach = metadata_requests_.fetch_add(1, Ordering::SeqCst) == 0;
+            (
+                200,
+                format!(r#"{{"blockId":"{block_id}","parents":[],"isSolid":true,"shouldReattach":{should_reattach}}}"#),
+            )
+        }
+        path if path == format!("/api/core/v2/blocks/{reattached_id}/metadata") => (
+            200,
+            format!(r#"{{"blockId":"{reattached_id}","parents":[],"isSolid":true,"ledgerInclusionState":"included"}}"#),
+        ),
+        _ => (404, String::new()),
+    });
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+
+    let events = client
+        .retry_until_included_stream(&block_id, Some(0), None)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        [
+            RetryEvent::Reattached(reattached_id, reattached.clone()),
+            RetryEvent::Included(reattached_id, reattached.clone())
+        ]
+    );
+
+    metadata_requests.store(0, Ordering::SeqCst);
+    assert_eq!(
+        client.retry_until_included(&block_id, Some(0), None).await.unwrap(),
+        [(reattached_id, reattached)]
+    );
+}
+
+#[tokio::test]
+async fn retry_transaction_until_included() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(rand_tagged_data_payload()))
+        .finish()
+        .unwrap();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+
+    let client = setup_client_with_mock_node(move |path| {
+        let included_block_path = format!(
+            "/api/core/v2/transactions/{}/included-block",
+            TransactionId::from(INCLUDED_TRANSACTION_ID)
+        );
+        let conflicting_metadata_path = format!("/api/core/v2/blocks/{}/metadata", BlockId::from(CONFLICTING_BLOCK_ID));
+        let pending_block_path = format!("/api/core/v2/blocks/{}", BlockId::from(PENDING_BLOCK_ID));
+
+        if path == included_block_path || path == pending_block_path {
+            (200, block_json.clone())
+        } else if path == conflicting_metadata_path {
+            (
+                200,
+                block_metadata(
+                    BlockId::from(CONFLICTING_BLOCK_ID),
+                    r#","ledgerInclusionState":"conflicting","conflictReason":1"#,
+                ),
+            )
+        } else if path == format!("{pending_block_path}/metadata") {
+            // Gets included with the first attempt
+            (
+                200,
+                block_metadata(BlockId::from(PENDING_BLOCK_ID), r#","ledgerInclusionState":"included""#),
+            )
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+
+    // An included transaction is returned right away
+    assert_eq!(
+        client
+            .retry_transaction_until_included(&TransactionId::from(INCLUDED_TRANSACTION_ID), &[], Some(0), None)
+            .await
+            .unwrap(),
+        (block.id(), block.clone())
+    );
+
+    // The latest attachment known to the node is retried
+    assert_eq!(
+        client
+            .retry_transaction_until_included(
+                &TransactionId::from([0; 32]),
+                &[BlockId::from(PENDING_BLOCK_ID), BlockId::from([4; 32])],
+                Some(0),
+                None
+            )
+            .await
+            .unwrap(),
+        (BlockId::from(PENDING_BLOCK_ID), block)
+    );
+
+    assert!(matches!(
+        client
+            .retry_transaction_until_included(
+                &TransactionId::from([0; 32]),
+                &[BlockId::from(CONFLICTING_BLOCK_ID), BlockId::from(PENDING_BLOCK_ID)],
+                Some(0),
+                None
+            )
+            .await,
+        Err(Error::TransactionConflicting {
+            transaction_id,
+            reason: ConflictReason::InputUtxoAlreadySpent
+        }) if transaction_id == TransactionId::from([0; 32])
+    ));
+
+    // Without known attachment there is nothing to retry
+    assert!(matches!(
+        client
+            .retry_transaction_until_included(&TransactionId::from([0; 32]), &[BlockId::from([4; 32])], Some(0), None)
+            .await,
+        Err(Error::NoKnownAttachment(transaction_id)) if transaction_id == TransactionId::from([0; 32])
+    ));
+}
+
+#[tokio::test]
+async fn retry_until_included_stream_attempts_exhausted() {
+    let block_id = rand_block_ids(1)[0];
+    let client = setup_client_with_mock_node(move |path| match path {
+        path if path == format!("/api/core/v2/blocks/{block_id}/metadata") => (
+            200,
+            format!(r#"{{"blockId":"{block_id}","parents":[],"isSolid":true}}"#),
+        ),
+        _ => (404, String::new()),
+    })
+    .await;
+
+    let events = client
+        .retry_until_included_stream(&block_id, Some(0), Some(2))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(events, [RetryEvent::AttemptsExhausted]);
+
+    assert!(client.retry_until_included(&block_id, Some(0), Some(2)).await.is_err());
+}
+
+#[tokio::test]
+async fn retry_until_included_stream_conflicting_once() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let block_id = block.id();
+    let block_json = serde_json::to_string(&BlockDto::from(&block)).unwrap();
+    let metadata_requests = Arc::new(AtomicUsize::new(0));
+    let metadata_requests_ = metadata_requests.clone();
+    let client = setup_client_with_mock_node(move |path| match path {
+        path if path == format!("/api/core/v2/blocks/{block_id}") => (200, block_json.clone()),
+        path if path == format!("/api/core/v2/blocks/{block_id}/metadata") => {
+            metadata_requests_.fetch_add(1, Ordering::SeqCst);
+            (
+                200,
+                block_metadata(block_id, r#","ledgerInclusionState":"conflicting","conflictReason":1"#),
+            )
+        }
+        _ => (404, String::new()),
+    })
+    .await;
+
+    // The conflict is only reported and requested in the first attempt
+    let events = client
+        .retry_until_included_stream(&block_id, Some(0), Some(3))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        [RetryEvent::Conflicting(block_id), RetryEvent::AttemptsExhausted]
+    );
+    assert_eq!(metadata_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn find_outputs_validates_addresses_upfront() {
+    let indexer_requests = Arc::new(AtomicUsize::new(0));
+    let indexer_requests_ = indexer_requests.clone();
+    let client = setup_client_with_mock_node(move |path| {
+        if path.starts_with("/api/indexer") {
+            indexer_requests_.fetch_add(1, Ordering::SeqCst);
+        }
+        (404, String::new())
+    })
+    .await;
+
+    let addresses = [
+        "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+        "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zz",
+    ];
+
+    assert!(
+        client
+            .find_inputs(addresses, 1_000_000, InputSelectionStrategy::LargestFirst)
+            .await
+            .is_err()
+    );
+    assert!(client.find_outputs(&[], addresses).await.is_err());
+    // The invalid second address is rejected before the outputs of the first one are requested
+    assert_eq!(indexer_requests.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn find_outputs_partial() {
+    const FAILING_ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+    const ADDRESS: &str = "rms1qr2xsmt3v3eyp2ja80wd2sq8xx0fslefmxguf7tshzezzr5qsctzc2f5dg6";
+
+    let output_id = rand_output_id();
+    let output = Output::Basic(rand_basic_output(protocol_parameters().token_supply()));
+    let output_json = mock_output_json_with_metadata(&rand_output_metadata(), &output);
+    let client = setup_client_with_mock_node(move |path| {
+        if path.starts_with(&format!("/api/indexer/v1/outputs/basic?address={FAILING_ADDRESS}")) {
+            (500, String::new())
+        } else if path.starts_with("/api/indexer/v1/outputs/basic?address=") {
+            (200, format!(r#"{{"ledgerIndex":1,"items":["{output_id}"]}}"#))
+        } else if path == format!("/api/core/v2/outputs/{output_id}") {
+            (200, output_json.clone())
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+
+    // The default stays strict
+    assert!(client.find_outputs(&[], [ADDRESS, FAILING_ADDRESS]).await.is_err());
+
+    let partial_outputs = client
+        .find_outputs_partial(&[], [FAILING_ADDRESS, ADDRESS])
+        .await
+        .unwrap();
+    assert_eq!(partial_outputs.outputs.len(), 1);
+    assert_eq!(partial_outputs.outputs[0].output(), &output);
+    assert_eq!(partial_outputs.failed_addresses.len(), 1);
+    assert_eq!(partial_outputs.failed_addresses[0].0.to_string(), FAILING_ADDRESS);
+}
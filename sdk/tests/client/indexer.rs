@@ -0,0 +1,128 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{api::OutputKindFilter, node_api::indexer::query_parameters::QueryParameter, Client, Error},
+    types::block::{
+        address::{AliasAddress, Ed25519Address, ToBech32Ext},
+        output::{AliasId, OutputId},
+        payload::transaction::TransactionId,
+    },
+};
+
+use crate::client::common::{setup_client_with_mock_node, spawn_mock_node};
+
+fn output_id(index: u16) -> OutputId {
+    OutputId::new(TransactionId::from([1; 32]), index).unwrap()
+}
+
+// Returns two pages of output ids for every indexer request.
+fn two_pages(path: &str) -> (u16, String) {
+    if !path.starts_with("/api/indexer/") {
+        (404, String::new())
+    } else if path.contains("cursor=page2") {
+        (200, format!(r#"{{"ledgerIndex":2,"items":["{}"]}}"#, output_id(1)))
+    } else {
+        (
+            200,
+            format!(r#"{{"ledgerIndex":1,"cursor":"page2","items":["{}"]}}"#, output_id(0)),
+        )
+    }
+}
+
+#[tokio::test]
+async fn output_ids_follow_cursor() {
+    let client = setup_client_with_mock_node(two_pages).await;
+
+    let response = client.basic_output_ids([]).await.unwrap();
+    assert_eq!(response.items, [output_id(0), output_id(1)]);
+    assert_eq!(response.ledger_index, 2);
+    assert!(response.cursor.is_none());
+
+    // Only a single page is returned if a cursor is provided
+    let response = client
+        .basic_output_ids([QueryParameter::Cursor("page2".to_string())])
+        .await
+        .unwrap();
+    assert_eq!(response.items, [output_id(1)]);
+}
+
+#[tokio::test]
+async fn output_ids_max_pages() {
+    let client = Client::builder()
+        .with_node(&spawn_mock_node(two_pages))
+        .unwrap()
+        .with_ignore_node_health()
+        .with_max_indexer_pages(1)
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client.basic_output_ids([]).await,
+        Err(Error::MaxIndexerPagesReached(1))
+    ));
+}
+
+#[tokio::test]
+async fn output_ids_for_address() {
+    let client = setup_client_with_mock_node(|path| {
+        let items: &[u16] = if path.starts_with("/api/indexer/v1/outputs/basic?address=") {
+            &[2, 0]
+        } else if path.starts_with("/api/indexer/v1/outputs/basic?storageDepositReturnAddress=") {
+            &[0, 1]
+        } else if path.starts_with("/api/indexer/v1/outputs/nft?address=") {
+            &[3]
+        } else if path.starts_with("/api/indexer/v1/outputs/foundry?aliasAddress=") {
+            &[4]
+        } else if path.starts_with("/api/indexer/") {
+            &[]
+        } else {
+            return (404, String::new());
+        };
+        let items = items
+            .iter()
+            .map(|index| format!(r#""{}""#, output_id(*index)))
+            .collect::<Vec<_>>();
+
+        (200, format!(r#"{{"ledgerIndex":1,"items":[{}]}}"#, items.join(",")))
+    })
+    .await;
+    let ed25519_address = Ed25519Address::from([1; 32]).to_bech32_unchecked("rms");
+    let alias_address = AliasAddress::from(AliasId::from([1; 32])).to_bech32_unchecked("rms");
+
+    assert_eq!(
+        client
+            .output_ids_for_address(ed25519_address, OutputKindFilter::all())
+            .await
+            .unwrap(),
+        [output_id(0), output_id(1), output_id(2), output_id(3)]
+    );
+    assert_eq!(
+        client
+            .output_ids_for_address(
+                ed25519_address,
+                OutputKindFilter {
+                    nft: true,
+                    ..OutputKindFilter::none()
+                }
+            )
+            .await
+            .unwrap(),
+        [output_id(3)]
+    );
+    // Foundries controlled by the alias address itself
+    assert_eq!(
+        client
+            .output_ids_for_address(
+                alias_address,
+                OutputKindFilter {
+                    foundry: true,
+                    ..OutputKindFilter::none()
+                }
+            )
+            .await
+            .unwrap(),
+        [output_id(4)]
+    );
+}
@@ -4,7 +4,7 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    client::api::input_selection::{Error, InputSelection, Requirement},
+    client::api::input_selection::{Error, InputSelection, InputSelectionStrategy, Requirement},
     types::block::{
         address::{Address, AliasAddress, Bech32Address, NftAddress},
         output::{AliasId, NftId},
@@ -16,7 +16,7 @@ use crate::client::{
     addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq,
     Build::{Alias, Basic, Nft},
     ALIAS_ID_0, ALIAS_ID_1, BECH32_ADDRESS_ALIAS_1, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
-    BECH32_ADDRESS_NFT_1, BECH32_ADDRESS_REMAINDER, NFT_ID_0, NFT_ID_1,
+    BECH32_ADDRESS_NFT_1, BECH32_ADDRESS_REMAINDER, NFT_ID_0, NFT_ID_1, TOKEN_ID_1,
 };
 
 #[test]
@@ -1241,6 +1241,155 @@ fn single_mandatory_input() {
     assert!(unsorted_eq(&selected.outputs, &outputs));
 }
 
+#[test]
+fn mandatory_input_exceeding_amount() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs([*inputs[0].output_id()])
+    .select()
+    .unwrap();
+
+    // The exactly matching input isn't needed anymore, the excess of the mandatory one goes to the remainder
+    assert!(unsorted_eq(&selected.inputs, &[inputs[0].clone()]));
+    assert_eq!(selected.outputs.len(), 2);
+    assert!(selected.outputs.contains(&outputs[0]));
+    selected.outputs.iter().for_each(|output| {
+        if !outputs.contains(output) {
+            assert!(is_remainder_or_return(
+                output,
+                2_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                None
+            ));
+        }
+    });
+}
+
+#[test]
+fn mandatory_input_with_native_tokens() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs([*inputs[0].output_id()])
+    .select()
+    .unwrap();
+
+    // The native tokens of the mandatory input need a remainder, which requires another input for its amount
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 2);
+    assert!(selected.outputs.contains(&outputs[0]));
+    selected.outputs.iter().for_each(|output| {
+        if !outputs.contains(output) {
+            assert!(is_remainder_or_return(
+                output,
+                1_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                Some(vec![(TOKEN_ID_1, 100)])
+            ));
+        }
+    });
+}
+
+#[test]
+fn mandatory_input_not_available() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let unavailable_input = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs([*unavailable_input[0].output_id()])
+    .select();
+
+    assert!(matches!(
+        selected,
+        Err(Error::RequiredInputIsNotAvailable(output_id)) if output_id == *unavailable_input[0].output_id()
+    ));
+}
+
 #[test]
 fn too_many_inputs() {
     let protocol_parameters = protocol_parameters();
@@ -1390,3 +1539,98 @@ fn too_many_outputs_with_remainder() {
         iota_sdk::client::api::input_selection::Error::InvalidOutputCount(129)
     )
 }
+
+#[test]
+fn input_selection_strategies() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(5_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let select = |strategy| {
+        InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .strategy(strategy)
+        .select()
+        .unwrap()
+    };
+    let remainder_amount = |selected: &iota_sdk::client::api::input_selection::Selected| {
+        selected.remainder.as_ref().map(|remainder| remainder.output.amount())
+    };
+
+    // The default strategy
+    let selected = select(InputSelectionStrategy::SmallestFirst);
+    assert!(unsorted_eq(&selected.inputs, &inputs[..3]));
+    assert_eq!(remainder_amount(&selected), Some(2_000_000));
+
+    let selected = select(InputSelectionStrategy::LargestFirst);
+    assert!(unsorted_eq(&selected.inputs, &inputs[3..]));
+    assert_eq!(remainder_amount(&selected), Some(1_000_000));
+
+    let selected = select(InputSelectionStrategy::ExactMatchThenLargest);
+    assert!(unsorted_eq(&selected.inputs, &[inputs[0].clone(), inputs[2].clone()]));
+    assert_eq!(remainder_amount(&selected), None);
+    assert!(unsorted_eq(&selected.outputs, &outputs));
+}
+
+#[test]
+fn exact_match_strategy_fallback() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .strategy(InputSelectionStrategy::ExactMatchThenLargest)
+    .select()
+    .unwrap();
+
+    // No inputs sum up to the amount, so a remainder is created
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 2);
+    selected.outputs.iter().for_each(|output| {
+        if !outputs.contains(output) {
+            assert!(is_remainder_or_return(
+                output,
+                2_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                None
+            ));
+        }
+    });
+}
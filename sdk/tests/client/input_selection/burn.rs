@@ -7,11 +7,19 @@ use std::{
 };
 
 use iota_sdk::{
-    client::api::input_selection::{Burn, Error, InputSelection, Requirement},
+    client::{
+        api::input_selection::{Burn, BurnDto, Error, InputSelection, Requirement},
+        secret::types::InputSigningData,
+    },
     types::block::{
-        address::Address,
-        output::{AliasId, AliasTransition, ChainId, NftId, SimpleTokenScheme, TokenId},
+        address::{Address, AliasAddress},
+        output::{
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutputBuilder, AliasTransition, ChainId, FoundryId, NativeToken, NftId, OutputMetadata,
+            SimpleTokenScheme, TokenId, TokenScheme,
+        },
         protocol::protocol_parameters,
+        rand::{block::rand_block_id, output::rand_output_id},
     },
 };
 use primitive_types::U256;
@@ -19,7 +27,8 @@ use primitive_types::U256;
 use crate::client::{
     addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq,
     Build::{Alias, Basic, Foundry, Nft},
-    ALIAS_ID_0, ALIAS_ID_1, ALIAS_ID_2, BECH32_ADDRESS_ED25519_0, NFT_ID_0, NFT_ID_1, NFT_ID_2, TOKEN_ID_1, TOKEN_ID_2,
+    ALIAS_ID_0, ALIAS_ID_1, ALIAS_ID_2, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_REMAINDER,
+    NFT_ID_0, NFT_ID_1, NFT_ID_2, TOKEN_ID_1, TOKEN_ID_2,
 };
 
 #[test]
@@ -196,7 +205,7 @@ fn burn_alias_absent() {
 
     assert!(matches!(
         selected,
-        Err(Error::UnfulfillableRequirement(Requirement::Alias(alias_id, AliasTransition::Governance))) if alias_id == alias_id_1
+        Err(Error::BurnTargetsNotFound(missing)) if missing == [ChainId::from(alias_id_1)]
     ));
 }
 
@@ -350,6 +359,46 @@ fn burn_nft_present() {
     assert_eq!(selected.outputs, outputs);
 }
 
+#[test]
+fn burn_nft_remainder_address() {
+    let protocol_parameters = protocol_parameters();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let remainder_address = Address::try_from_bech32(BECH32_ADDRESS_REMAINDER).unwrap();
+
+    let inputs = build_inputs([Nft(
+        2_000_000,
+        nft_id_1,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_nft(nft_id_1))
+    .remainder_address(remainder_address)
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    // The amount and native tokens of the burned NFT are sent to the remainder address
+    assert_eq!(selected.outputs.len(), 1);
+    assert!(is_remainder_or_return(
+        &selected.outputs[0],
+        2_000_000,
+        BECH32_ADDRESS_REMAINDER,
+        Some(vec![(TOKEN_ID_1, 100)]),
+    ));
+}
+
 #[test]
 fn burn_nft_present_and_required() {
     let protocol_parameters = protocol_parameters();
@@ -479,7 +528,7 @@ fn burn_nft_absent() {
 
     assert!(matches!(
         selected,
-        Err(Error::UnfulfillableRequirement(Requirement::Nft(nft_id))) if nft_id == nft_id_1
+        Err(Error::BurnTargetsNotFound(missing)) if missing == [ChainId::from(nft_id_1)]
     ));
 }
 
@@ -722,7 +771,7 @@ fn burn_foundry_absent() {
 
     assert!(matches!(
         selected,
-        Err(Error::UnfulfillableRequirement(Requirement::Foundry(foundry_id))) if foundry_id == foundry_id_1
+        Err(Error::BurnTargetsNotFound(missing)) if missing == [ChainId::from(foundry_id_1)]
     ));
 }
 
@@ -941,3 +990,769 @@ fn burn_foundry_and_its_alias() {
         Err(Error::UnfulfillableRequirement(Requirement::Alias(alias_id, AliasTransition::State))) if alias_id == alias_id_1
     ));
 }
+
+#[test]
+fn burn_native_tokens_exactly_available() {
+    let protocol_parameters = protocol_parameters();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 60)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 40)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_native_token(token_id_1, 100))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 1);
+    assert!(selected.outputs[0].native_tokens().unwrap().is_empty());
+}
+
+#[test]
+fn burn_native_tokens_more_than_available() {
+    let protocol_parameters = protocol_parameters();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 60)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 40)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let selected = InputSelection::new(
+        inputs,
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_native_token(token_id_1, 101))
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::InsufficientNativeTokenToBurn {
+            token_id: token_id_1,
+            available: U256::from(100),
+            requested: U256::from(101),
+        }
+    );
+}
+
+#[test]
+fn burn_from_outputs() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_0 = NftId::from_str(NFT_ID_0).unwrap();
+
+    let inputs = build_inputs([
+        Alias(
+            1_000_000,
+            alias_id_1,
+            0,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 50), (TOKEN_ID_2, 10)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs([Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let burn = Burn::from_outputs(inputs.iter().map(|input| (input.output_id(), &input.output)));
+
+    assert_eq!(burn.aliases(), &HashSet::from([alias_id_1]));
+    assert_eq!(burn.nfts(), &HashSet::from([NftId::from(inputs[1].output_id())]));
+    assert!(burn.foundries().is_empty());
+    assert_eq!(burn.native_tokens().len(), 2);
+    assert_eq!(
+        burn.native_tokens()[&TokenId::from_str(TOKEN_ID_1).unwrap()],
+        U256::from(150)
+    );
+    assert_eq!(
+        burn.native_tokens()[&TokenId::from_str(TOKEN_ID_2).unwrap()],
+        U256::from(10)
+    );
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(burn)
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn burn_half_native_tokens_with_outputs() {
+    let protocol_parameters = protocol_parameters();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs([Basic(
+        500_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_native_token(token_id_1, 50))
+    .select()
+    .unwrap();
+
+    assert!(selected.inputs.contains(&inputs[1]));
+    assert_eq!(selected.outputs.len(), 2);
+    assert!(selected.outputs.contains(&outputs[0]));
+    selected.outputs.iter().for_each(|output| {
+        if !outputs.contains(output) {
+            assert!(is_remainder_or_return(
+                output,
+                selected.inputs.iter().map(|input| input.output.amount()).sum::<u64>() - 500_000,
+                BECH32_ADDRESS_ED25519_0,
+                Some(vec![(TOKEN_ID_1, 50)]),
+            ));
+        }
+    });
+}
+
+#[test]
+fn burn_half_native_tokens_of_nft() {
+    let protocol_parameters = protocol_parameters();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Nft(
+            1_000_000,
+            nft_id_1,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_native_token(token_id_1, 50))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 2);
+    selected.outputs.iter().for_each(|output| {
+        if output.is_nft() {
+            // The burned native token is moved out of the transitioned nft output
+            assert_eq!(*output.as_nft().nft_id(), nft_id_1);
+            assert_eq!(output.as_nft().native_tokens().len(), 0);
+        } else {
+            // The other half is kept on the remainder output
+            assert!(is_remainder_or_return(
+                output,
+                2_000_000 - selected.outputs.iter().find(|o| o.is_nft()).unwrap().amount(),
+                BECH32_ADDRESS_ED25519_0,
+                Some(vec![(TOKEN_ID_1, 50)]),
+            ));
+        }
+    });
+}
+
+#[test]
+fn burn_merge() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let alias_id_2 = AliasId::from_str(ALIAS_ID_2).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let token_id_2 = TokenId::from_str(TOKEN_ID_2).unwrap();
+
+    let burn = Burn::new()
+        .add_alias(alias_id_1)
+        .add_native_token(token_id_1, 20)
+        .add_native_token(token_id_2, U256::MAX - 1)
+        .merge(
+            Burn::new()
+                .add_alias(alias_id_1)
+                .add_alias(alias_id_2)
+                .add_nft(nft_id_1)
+                .add_native_token(token_id_1, 30)
+                .add_native_token(token_id_2, 1)
+                .add_melt_native_token(token_id_1, 5),
+        )
+        .unwrap();
+
+    assert_eq!(burn.aliases(), &HashSet::from([alias_id_1, alias_id_2]));
+    assert_eq!(burn.nfts(), &HashSet::from([nft_id_1]));
+    assert!(burn.foundries().is_empty());
+    assert_eq!(burn.native_tokens().len(), 2);
+    assert_eq!(burn.native_tokens()[&token_id_1], U256::from(50));
+    assert_eq!(burn.native_tokens()[&token_id_2], U256::MAX);
+    assert_eq!(burn.melt_native_tokens().len(), 1);
+    assert_eq!(burn.melt_native_tokens()[&token_id_1], U256::from(5));
+
+    // Overflowing amounts are an error
+    assert!(matches!(
+        burn.merge(Burn::new().add_native_token(token_id_2, 1)),
+        Err(iota_sdk::types::block::Error::NativeTokensOverflow)
+    ));
+    assert!(matches!(
+        Burn::new()
+            .add_melt_native_token(token_id_1, U256::MAX)
+            .merge(Burn::new().add_melt_native_token(token_id_1, 1)),
+        Err(iota_sdk::types::block::Error::NativeTokensOverflow)
+    ));
+}
+
+#[test]
+fn burn_try_from_and_try_extend() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let nft_id_2 = NftId::from_str(NFT_ID_2).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let token_id_2 = TokenId::from_str(TOKEN_ID_2).unwrap();
+
+    let mut burn = Burn::from(&[nft_id_1, nft_id_2][..]);
+    burn.try_extend([
+        Burn::try_from(vec![
+            NativeToken::new(token_id_1, U256::from(10)).unwrap(),
+            NativeToken::new(token_id_1, U256::from(5)).unwrap(),
+            NativeToken::new(token_id_2, U256::MAX).unwrap(),
+        ])
+        .unwrap(),
+        Burn::from(alias_id_1),
+    ])
+    .unwrap();
+
+    assert_eq!(burn.aliases(), &HashSet::from([alias_id_1]));
+    assert_eq!(burn.nfts(), &HashSet::from([nft_id_1, nft_id_2]));
+    assert_eq!(burn.native_tokens()[&token_id_1], U256::from(15));
+    assert_eq!(burn.native_tokens()[&token_id_2], U256::MAX);
+    // Merged values are preserved by the DTO
+    assert_eq!(Burn::try_from(BurnDto::from(&burn)).unwrap(), burn);
+
+    // Overflowing amounts fail and leave the burn unchanged
+    let unchanged = burn.clone();
+    assert!(matches!(
+        burn.try_extend([Burn::from(nft_id_1), Burn::new().add_native_token(token_id_2, 1)]),
+        Err(iota_sdk::types::block::Error::NativeTokensOverflow)
+    ));
+    assert_eq!(burn, unchanged);
+    assert!(matches!(
+        Burn::try_from(vec![
+            NativeToken::new(token_id_2, U256::MAX).unwrap(),
+            NativeToken::new(token_id_2, U256::from(1)).unwrap(),
+        ]),
+        Err(iota_sdk::types::block::Error::NativeTokensOverflow)
+    ));
+
+    let mut empty = Burn::new();
+    empty.try_extend(Vec::new()).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn burn_is_empty() {
+    assert!(Burn::new().is_empty());
+    assert!(Burn::new().set_native_tokens(HashMap::<_, U256>::new()).is_empty());
+
+    assert!(!Burn::new().add_alias(AliasId::from_str(ALIAS_ID_1).unwrap()).is_empty());
+    assert!(!Burn::new().add_nft(NftId::from_str(NFT_ID_1).unwrap()).is_empty());
+    assert!(
+        !Burn::new()
+            .add_native_token(TokenId::from_str(TOKEN_ID_1).unwrap(), 10)
+            .is_empty()
+    );
+    assert!(
+        !Burn::new()
+            .add_melt_native_token(TokenId::from_str(TOKEN_ID_1).unwrap(), 10)
+            .is_empty()
+    );
+    assert!(
+        !Burn::from_outputs([])
+            .merge(Burn::from(NftId::from_str(NFT_ID_1).unwrap()))
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn burn_melt_native_tokens() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id_1), 1, SimpleTokenScheme::KIND);
+    let token_id = TokenId::from(foundry_id);
+
+    let mut inputs = build_inputs([
+        Foundry(
+            1_000_000,
+            alias_id_1,
+            1,
+            SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(200)).unwrap(),
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(&token_id.to_string(), 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let alias_output = AliasOutputBuilder::new_with_amount(1_000_000, alias_id_1)
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(
+            Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(),
+        ))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(
+            Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(),
+        ))
+        .with_foundry_counter(1)
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    inputs.push(InputSigningData {
+        output: alias_output,
+        output_metadata: OutputMetadata::new(rand_block_id(), rand_output_id(), false, None, None, None, 0, 0, 0),
+        chain: None,
+    });
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_melt_native_token(token_id, 40))
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 3);
+    selected.outputs.iter().for_each(|output| {
+        if output.is_foundry() {
+            let TokenScheme::Simple(token_scheme) = output.as_foundry().token_scheme();
+            // The tokens are melted instead of being burned
+            assert_eq!(token_scheme.minted_tokens(), U256::from(100));
+            assert_eq!(token_scheme.melted_tokens(), U256::from(40));
+            assert_eq!(token_scheme.maximum_supply(), U256::from(200));
+        } else if output.is_basic() {
+            assert!(is_remainder_or_return(
+                output,
+                1_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                Some(vec![(&token_id.to_string(), 60)]),
+            ));
+        } else {
+            assert!(output.is_alias());
+        }
+    });
+}
+
+#[test]
+fn burn_melt_native_tokens_foundry_absent() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id_1), 1, SimpleTokenScheme::KIND);
+    let token_id = TokenId::from(foundry_id);
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(&token_id.to_string(), 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_melt_native_token(token_id, 40))
+    .select();
+
+    assert!(matches!(
+        selected,
+        Err(Error::FoundryToMeltNotAvailable(id)) if id == foundry_id
+    ));
+}
+
+#[test]
+fn burn_melt_native_tokens_of_burned_foundry() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id_1), 1, SimpleTokenScheme::KIND);
+    let token_id = TokenId::from(foundry_id);
+
+    let inputs = build_inputs([
+        Foundry(
+            1_000_000,
+            alias_id_1,
+            1,
+            SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(200)).unwrap(),
+            Some(vec![(&token_id.to_string(), 100)]),
+        ),
+        Alias(
+            1_000_000,
+            alias_id_1,
+            0,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let selected = InputSelection::new(
+        inputs,
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .burn(Burn::new().add_foundry(foundry_id).add_melt_native_token(token_id, 40))
+    .select();
+
+    assert!(matches!(
+        selected,
+        Err(Error::BurnAndTransition(ChainId::Foundry(id))) if id == foundry_id
+    ));
+}
+
+#[test]
+fn burn_dto_round_trip() {
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let token_id_2 = TokenId::from_str(TOKEN_ID_2).unwrap();
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id_1), 1, SimpleTokenScheme::KIND);
+
+    for burn in [
+        Burn::new(),
+        Burn::new().add_alias(alias_id_1),
+        Burn::new()
+            .add_alias(alias_id_1)
+            .add_nft(nft_id_1)
+            .add_foundry(foundry_id)
+            .add_native_token(token_id_1, 10)
+            .add_native_token(token_id_2, U256::MAX)
+            .add_melt_native_token(token_id_2, 5),
+    ] {
+        let dto = BurnDto::from(&burn);
+        assert_eq!(Burn::try_from(dto.clone()).unwrap(), burn);
+
+        let json = serde_json::to_string(&dto).unwrap();
+        assert_eq!(
+            Burn::try_from(serde_json::from_str::<BurnDto>(&json).unwrap()).unwrap(),
+            burn
+        );
+    }
+}
+
+#[test]
+fn burn_dto_empty_and_zero_amounts() {
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let token_id_2 = TokenId::from_str(TOKEN_ID_2).unwrap();
+
+    // Present but empty collections and zero amounts are the same as absent fields
+    let dto = serde_json::from_value::<BurnDto>(serde_json::json!({
+        "aliases": [],
+        "nfts": [],
+        "foundries": [],
+        "nativeTokens": { TOKEN_ID_1: "0x0", TOKEN_ID_2: "0xa" },
+        "meltNativeTokens": { TOKEN_ID_1: "0x0" },
+    }))
+    .unwrap();
+    let burn = Burn::try_from(dto).unwrap();
+
+    assert_eq!(burn, Burn::new().add_native_token(token_id_2, 10));
+    assert_eq!(Burn::try_from(BurnDto::from(&burn)).unwrap(), burn);
+    assert_eq!(
+        serde_json::to_value(BurnDto::from(&burn)).unwrap(),
+        serde_json::json!({ "nativeTokens": { TOKEN_ID_2: "0xa" } })
+    );
+
+    // Zero amounts are not stored by the builder methods either
+    assert!(Burn::new().add_native_token(token_id_1, 0).is_empty());
+    assert!(
+        Burn::new()
+            .add_native_token(token_id_1, 10)
+            .add_native_token(token_id_1, 0)
+            .is_empty()
+    );
+    assert!(Burn::new().add_melt_native_token(token_id_1, 0).is_empty());
+    assert!(
+        Burn::new()
+            .set_native_tokens(HashMap::from([(token_id_1, 0)]))
+            .is_empty()
+    );
+}
+
+#[test]
+fn validate_burn_reports_all_missing_targets() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let alias_id_2 = AliasId::from_str(ALIAS_ID_2).unwrap();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+    let nft_id_2 = NftId::from_str(NFT_ID_2).unwrap();
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id_2), 1, SimpleTokenScheme::KIND);
+
+    let inputs = build_inputs([
+        Alias(
+            1_000_000,
+            alias_id_1,
+            0,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id_1,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let input_selection = |burn: Burn| {
+        InputSelection::new(
+            inputs.clone(),
+            Vec::new(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .burn(burn)
+    };
+
+    assert!(
+        input_selection(Burn::new().add_alias(alias_id_1).add_nft(nft_id_1))
+            .validate_burn()
+            .is_ok()
+    );
+
+    let mut expected = vec![
+        ChainId::from(alias_id_2),
+        ChainId::from(nft_id_2),
+        ChainId::from(foundry_id),
+    ];
+    expected.sort();
+    assert_eq!(
+        input_selection(
+            Burn::new()
+                .add_alias(alias_id_1)
+                .add_alias(alias_id_2)
+                .add_nft(nft_id_1)
+                .add_nft(nft_id_2)
+                .add_foundry(foundry_id)
+        )
+        .validate_burn()
+        .unwrap_err(),
+        Error::BurnTargetsNotFound(expected)
+    );
+
+    // Forbidden inputs aren't available to burn
+    assert_eq!(
+        input_selection(Burn::new().add_nft(nft_id_1))
+            .forbidden_inputs(HashSet::from([*inputs[1].output_id()]))
+            .validate_burn()
+            .unwrap_err(),
+        Error::BurnTargetsNotFound(vec![ChainId::from(nft_id_1)])
+    );
+}
+
+#[test]
+fn validate_burn_native_token_shortfall() {
+    let protocol_parameters = protocol_parameters();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let token_id_2 = TokenId::from_str(TOKEN_ID_2).unwrap();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 60)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 40)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let input_selection = |burn: Burn| {
+        InputSelection::new(
+            inputs.clone(),
+            Vec::new(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .burn(burn)
+    };
+
+    assert!(
+        input_selection(Burn::new().add_native_token(token_id_1, 100))
+            .validate_burn()
+            .is_ok()
+    );
+    assert_eq!(
+        input_selection(Burn::new().add_native_token(token_id_1, 150))
+            .validate_burn()
+            .unwrap_err(),
+        Error::InsufficientNativeTokenToBurn {
+            token_id: token_id_1,
+            available: U256::from(100),
+            requested: U256::from(150),
+        }
+    );
+    assert_eq!(
+        input_selection(Burn::new().add_native_token(token_id_2, 1))
+            .validate_burn()
+            .unwrap_err(),
+        Error::InsufficientNativeTokenToBurn {
+            token_id: token_id_2,
+            available: U256::zero(),
+            requested: U256::from(1),
+        }
+    );
+}
@@ -973,11 +973,11 @@ fn burned_tokens_not_provided() {
 
     assert!(matches!(
         selected,
-        Err(Error::InsufficientNativeTokenAmount {
-        token_id,
-            found,
-            required,
-        }) if token_id == token_id_1 && found == U256::from(0) && required == U256::from(100)));
+        Err(Error::InsufficientNativeTokenToBurn {
+            token_id,
+            available,
+            requested,
+        }) if token_id == token_id_1 && available == U256::from(0) && requested == U256::from(100)));
 }
 
 #[test]
@@ -11,5 +11,6 @@ mod foundry_outputs;
 mod native_tokens;
 mod nft_outputs;
 mod outputs;
+mod preview;
 mod storage_deposit_return;
 mod timelock;
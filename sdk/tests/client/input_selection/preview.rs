@@ -0,0 +1,148 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::api::input_selection::{Burn, BurnDto, InputSelection, SelectionPreview},
+    types::block::{
+        address::Address,
+        output::{NftId, Rent},
+        protocol::protocol_parameters,
+    },
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs,
+    Build::{Basic, Nft},
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, NFT_ID_1,
+};
+
+#[test]
+fn preview_matches_selection() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        4_500_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let input_selection = || {
+        InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+    };
+
+    let preview = input_selection().preview().unwrap();
+    let selected = input_selection().select().unwrap();
+
+    assert_eq!(
+        preview.inputs,
+        selected
+            .inputs
+            .iter()
+            .map(|input| *input.output_id())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(preview.outputs, selected.outputs);
+    assert_eq!(preview.burned, BurnDto::default());
+    let remainder = selected.remainder.unwrap();
+    assert_eq!(preview.remainder, Some((remainder.address, remainder.output.amount())));
+    assert_eq!(
+        preview.remainder,
+        Some((Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(), 1_500_000))
+    );
+    // Consuming three basic outputs to create two frees the storage deposit of one
+    assert_eq!(
+        preview.storage_deposit_delta,
+        -i128::from(inputs[0].output.rent_cost(protocol_parameters.rent_structure()))
+    );
+}
+
+#[test]
+fn preview_with_burn() {
+    let protocol_parameters = protocol_parameters();
+    let nft_id_1 = NftId::from_str(NFT_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Nft(
+            1_000_000,
+            nft_id_1,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_500_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let burn = Burn::new().add_nft(nft_id_1);
+
+    let input_selection = || {
+        InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters.clone(),
+        )
+        .burn(burn.clone())
+    };
+
+    let preview = input_selection().preview().unwrap();
+    let selected = input_selection().select().unwrap();
+
+    assert_eq!(preview.inputs.len(), 2);
+    assert_eq!(
+        preview.inputs,
+        selected
+            .inputs
+            .iter()
+            .map(|input| *input.output_id())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(preview.outputs, selected.outputs);
+    assert_eq!(preview.burned, BurnDto::from(&burn));
+    assert_eq!(preview.remainder.map(|(_, amount)| amount), Some(500_000));
+
+    let rent_structure = protocol_parameters.rent_structure();
+    let storage_deposit_delta = selected
+        .outputs
+        .iter()
+        .map(|output| i128::from(output.rent_cost(rent_structure)))
+        .sum::<i128>()
+        - inputs
+            .iter()
+            .map(|input| i128::from(input.output.rent_cost(rent_structure)))
+            .sum::<i128>();
+    assert_eq!(preview.storage_deposit_delta, storage_deposit_delta);
+    assert!(preview.storage_deposit_delta < 0);
+
+    let json = serde_json::to_string(&preview).unwrap();
+    assert_eq!(serde_json::from_str::<SelectionPreview>(&json).unwrap(), preview);
+}
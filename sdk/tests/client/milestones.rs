@@ -0,0 +1,166 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use futures::{StreamExt, TryStreamExt};
+use iota_sdk::types::block::{
+    payload::milestone::{dto::MilestonePayloadDto, MilestoneIndex},
+    protocol::protocol_parameters,
+    rand::{
+        block::rand_block_id,
+        payload::{rand_milestone_payload, rand_milestone_payload_with_receipt},
+    },
+    BlockId,
+};
+
+use crate::client::common::{info_json, setup_client_with_mock_node};
+
+fn metadata_json(block_id: &BlockId, parents: &[BlockId], referenced_by_milestone_index: u32) -> String {
+    serde_json::json!({
+        "blockId": block_id.to_string(),
+        "parents": parents.iter().map(|parent| parent.to_string()).collect::<Vec<_>>(),
+        "isSolid": true,
+        "referencedByMilestoneIndex": referenced_by_milestone_index,
+    })
+    .to_string()
+}
+
+/// Mocks the past cones of milestones, returning the node responses by path and the block ids referenced by each
+/// milestone.
+fn mock_milestones(
+    protocol_version: u8,
+    indexes: impl IntoIterator<Item = u32>,
+) -> (HashMap<String, String>, HashMap<u32, HashSet<BlockId>>) {
+    let mut responses = HashMap::new();
+    let mut referenced = HashMap::new();
+
+    for index in indexes {
+        let milestone = rand_milestone_payload(protocol_version);
+        let parents = milestone.essence().parents().to_vec();
+        responses.insert(
+            format!("/api/core/v2/milestones/by-index/{index}"),
+            serde_json::to_string(&MilestonePayloadDto::from(&milestone)).unwrap(),
+        );
+
+        // A block referenced by the milestone, one referenced by the previous milestone and a pruned one
+        let referenced_block = rand_block_id();
+        let older_block = rand_block_id();
+        let pruned_block = rand_block_id();
+
+        for parent in &parents {
+            responses.insert(
+                format!("/api/core/v2/blocks/{parent}/metadata"),
+                metadata_json(parent, &[referenced_block, older_block], index),
+            );
+        }
+        responses.insert(
+            format!("/api/core/v2/blocks/{referenced_block}/metadata"),
+            metadata_json(&referenced_block, &[pruned_block, parents[0]], index),
+        );
+        responses.insert(
+            format!("/api/core/v2/blocks/{older_block}/metadata"),
+            metadata_json(&older_block, &[pruned_block], index - 1),
+        );
+
+        referenced.insert(index, parents.into_iter().chain([referenced_block]).collect());
+    }
+
+    (responses, referenced)
+}
+
+#[tokio::test]
+async fn block_ids_referenced_by_milestone() {
+    let (responses, referenced) = mock_milestones(2, [5]);
+    let client = setup_client_with_mock_node(move |path| match responses.get(path) {
+        Some(response) => (200, response.clone()),
+        None => (404, String::new()),
+    })
+    .await;
+
+    let block_ids = client
+        .block_ids_referenced_by_milestone(5)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    // Every block is yielded exactly once
+    assert_eq!(block_ids.len(), referenced[&5].len());
+    assert_eq!(block_ids.into_iter().collect::<HashSet<_>>(), referenced[&5]);
+}
+
+#[tokio::test]
+async fn follow_milestones() {
+    let (mut responses, referenced) = mock_milestones(2, [5, 6, 7]);
+    responses.insert("/api/core/v2/info".to_string(), info_json(7));
+    let client = setup_client_with_mock_node(move |path| match responses.get(path) {
+        Some(response) => (200, response.clone()),
+        None => (404, String::new()),
+    })
+    .await;
+
+    // All milestones confirmed since the start index are yielded in order
+    let milestones = client
+        .follow_milestones(5)
+        .take(3)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        milestones.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+        [MilestoneIndex::new(5), MilestoneIndex::new(6), MilestoneIndex::new(7)]
+    );
+    for (index, block_ids) in milestones {
+        assert_eq!(block_ids.into_iter().collect::<HashSet<_>>(), referenced[&*index]);
+    }
+}
+
+#[tokio::test]
+async fn milestone_with_receipt() {
+    let protocol_parameters = protocol_parameters();
+    let migration_milestone = rand_milestone_payload_with_receipt(
+        protocol_parameters.protocol_version(),
+        protocol_parameters.token_supply(),
+    );
+    let index = *migration_milestone.essence().index();
+    let mut responses = HashMap::from([(
+        format!("/api/core/v2/milestones/by-index/{index}"),
+        serde_json::to_string(&MilestonePayloadDto::from(&migration_milestone)).unwrap(),
+    )]);
+    let parents = migration_milestone
+        .essence()
+        .parents()
+        .iter()
+        .copied()
+        .collect::<HashSet<_>>();
+    for parent in &parents {
+        responses.insert(
+            format!("/api/core/v2/blocks/{parent}/metadata"),
+            metadata_json(parent, &[], index),
+        );
+    }
+    let client = setup_client_with_mock_node(move |path| match responses.get(path) {
+        Some(response) => (200, response.clone()),
+        None => (404, String::new()),
+    })
+    .await;
+
+    let milestone = client.get_milestone_by_index(index).await.unwrap();
+    assert_eq!(milestone, migration_milestone);
+    let receipt = milestone.essence().options().receipt().unwrap();
+    assert!(receipt.last());
+    assert_eq!(receipt.funds().len(), 2);
+    assert_eq!(
+        receipt.amount(),
+        receipt.funds().iter().map(|funds| funds.amount()).sum::<u64>()
+    );
+
+    // The past cone of a migration milestone is traversed like the one of any other milestone
+    let block_ids = client
+        .block_ids_referenced_by_milestone(index)
+        .try_collect::<HashSet<_>>()
+        .await
+        .unwrap();
+    assert_eq!(block_ids, parents);
+}
@@ -2,15 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod addresses;
+mod block_validation;
 mod client_builder;
-mod common;
+pub(crate) mod common;
 mod error;
+mod high_level;
+mod indexer;
 mod input_selection;
 mod input_signing_data;
+mod milestones;
 mod mnemonic;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod node_api_responses;
 mod secret_manager;
 mod signing;
 mod transactions;
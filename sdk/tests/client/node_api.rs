@@ -229,6 +229,69 @@ async fn test_get_output() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_try_get_outputs() {
+    let (_block_id, transaction_id) = setup_transaction_block().await;
+    let existing_output_id = OutputId::new(transaction_id, 0).unwrap();
+    let missing_output_id = OutputId::new(TransactionId::from([0; 32]), 0).unwrap();
+
+    let (outputs, not_found) = setup_client_with_node_health_ignored()
+        .await
+        .try_get_outputs(&[existing_output_id, missing_output_id])
+        .await
+        .unwrap();
+
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].metadata().output_id(), &existing_output_id);
+    assert_eq!(not_found, [missing_output_id]);
+}
+
+#[tokio::test]
+async fn get_outputs_ignore_errors() {
+    use iota_sdk::{
+        client::Error,
+        types::block::{
+            output::Output,
+            protocol::protocol_parameters,
+            rand::output::{rand_basic_output, rand_output_id, rand_output_metadata},
+        },
+    };
+
+    use crate::client::common::{mock_output_json_with_metadata, setup_client_with_mock_node};
+
+    let (existing_output_id, missing_output_id, failing_output_id) =
+        (rand_output_id(), rand_output_id(), rand_output_id());
+    let output_json = mock_output_json_with_metadata(
+        &rand_output_metadata(),
+        &Output::Basic(rand_basic_output(protocol_parameters().token_supply())),
+    );
+    let client = setup_client_with_mock_node(move |path| {
+        if path == format!("/api/core/v2/outputs/{existing_output_id}") {
+            (200, output_json.clone())
+        } else if path == format!("/api/core/v2/outputs/{failing_output_id}") {
+            (500, String::new())
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+
+    // Only the missing output is ignored
+    let outputs = client
+        .get_outputs_ignore_errors(&[missing_output_id, existing_output_id])
+        .await
+        .unwrap();
+    assert_eq!(outputs.len(), 1);
+
+    assert!(matches!(
+        client
+            .get_outputs_ignore_errors(&[existing_output_id, failing_output_id])
+            .await,
+        Err(Error::Node(_))
+    ));
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_peers() {
@@ -420,3 +483,88 @@ async fn test_mqtt() {
     }
     client.subscriber().disconnect().await.unwrap();
 }
+
+#[tokio::test]
+#[cfg(feature = "websocket")]
+async fn subscribe_blocks_unsupported_scheme() {
+    use iota_sdk::client::{node_api::ws::Error as WsError, Error};
+
+    let client = Client::builder().finish().await.unwrap();
+
+    let error = client
+        .subscribe_blocks("http://localhost:14265/api/mqtt/v1".parse().unwrap())
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(error, Error::WebSocket(WsError::UnsupportedScheme(scheme)) if scheme == "http"));
+}
+
+#[tokio::test]
+#[cfg(feature = "websocket")]
+async fn subscribe_output_updates() {
+    use futures::StreamExt;
+    use iota_sdk::types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            output::OutputWithMetadata,
+            rand::output::{rand_output, rand_output_metadata},
+        },
+    };
+
+    use crate::client::common::{info_json, setup_client_with_mock_node, spawn_mock_ws_node};
+
+    const ADDRESS: &str = "rms1qr2xsmt3v3eyp2ja80wd2sq8xx0fslefmxguf7tshzezzr5qsctzc2f5dg6";
+
+    let client = setup_client_with_mock_node(|path| {
+        if path == "/api/core/v2/info" {
+            (200, info_json(7))
+        } else {
+            (404, String::new())
+        }
+    })
+    .await;
+    let token_supply = client.get_token_supply().await.unwrap();
+    let outputs = std::iter::repeat_with(|| {
+        OutputWithMetadataResponse::from(OutputWithMetadata::new(
+            rand_output(token_supply),
+            rand_output_metadata(),
+        ))
+    })
+    .take(3)
+    .collect::<Vec<_>>();
+    let node_ws_url = spawn_mock_ws_node(
+        &format!("outputs/unlock/address/{ADDRESS}"),
+        outputs
+            .iter()
+            .map(|output| serde_json::to_vec(output).unwrap())
+            .collect(),
+    );
+
+    let updates = client
+        .subscribe_output_updates(node_ws_url.parse().unwrap(), ADDRESS)
+        .await
+        .unwrap();
+    let updates = updates.take(3).map(Result::unwrap).collect::<Vec<_>>().await;
+
+    assert_eq!(updates, outputs);
+}
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "websocket")]
+async fn test_subscribe_blocks() {
+    use futures::StreamExt;
+
+    let client = setup_client_with_node_health_ignored().await;
+
+    let blocks = client
+        .subscribe_blocks("ws://localhost:14265/api/mqtt/v1".parse().unwrap())
+        .await
+        .unwrap();
+    let blocks = blocks.take(3).collect::<Vec<_>>().await;
+
+    assert_eq!(blocks.len(), 3);
+    for block in blocks {
+        block.unwrap();
+    }
+}
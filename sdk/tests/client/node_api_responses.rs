@@ -0,0 +1,105 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{node_api::error::Error as NodeApiError, Error},
+    types::block::{
+        output::{
+            dto::{OutputDto, OutputMetadataDto},
+            Output, OutputId,
+        },
+        parent::Parents,
+        protocol::protocol_parameters,
+        rand::{
+            block::{rand_block_id, rand_block_ids},
+            output::{rand_basic_output, rand_output_id, rand_output_metadata},
+        },
+        BlockBuilder, BlockDto,
+    },
+};
+use serde_json::{json, Value};
+
+use crate::client::common::{info_json, setup_client_with_mock_node};
+
+fn assert_deserialization_error(error: Error, expected_path: &str, expected_route: &str) {
+    match error {
+        Error::Node(NodeApiError::ResponseDeserialization { path, url, .. }) => {
+            assert_eq!(path, expected_path);
+            assert!(url.contains(expected_route), "{url} doesn't contain {expected_route}");
+        }
+        error => panic!("unexpected error {error:?}"),
+    }
+}
+
+#[tokio::test]
+async fn malformed_block_response() {
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .finish()
+        .unwrap();
+    let mut block_json = serde_json::to_value(BlockDto::from(&block)).unwrap();
+    // A number instead of a hex string
+    block_json["parents"][1] = json!(1);
+
+    let client = setup_client_with_mock_node(move |_| (200, block_json.to_string())).await;
+    let block_id = rand_block_id();
+
+    assert_deserialization_error(
+        client.get_block(&block_id).await.unwrap_err(),
+        "/parents/1",
+        &format!("/api/core/v2/blocks/{block_id}"),
+    );
+}
+
+#[tokio::test]
+async fn malformed_output_response() {
+    let output = Output::Basic(rand_basic_output(protocol_parameters().token_supply()));
+    let mut output_json = json!({
+        "metadata": OutputMetadataDto::from(&rand_output_metadata()),
+        "output": OutputDto::from(&output),
+    });
+    let mut malformed_output_json = output_json.clone();
+    // A string instead of a number
+    output_json["metadata"]["milestoneIndexBooked"] = json!("1");
+    // A number instead of a string, only the path to the output is known because outputs are deserialized by their
+    // type
+    malformed_output_json["output"]["amount"] = json!(1);
+
+    let client = setup_client_with_mock_node(move |path| {
+        if path.ends_with("0000") {
+            (200, output_json.to_string())
+        } else {
+            (200, malformed_output_json.to_string())
+        }
+    })
+    .await;
+    let output_id = rand_output_id();
+    let output_id = OutputId::new(*output_id.transaction_id(), 0).unwrap();
+
+    assert_deserialization_error(
+        client.get_output(&output_id).await.unwrap_err(),
+        "/metadata/milestoneIndexBooked",
+        &format!("/api/core/v2/outputs/{output_id}"),
+    );
+
+    let output_id = OutputId::new(*output_id.transaction_id(), 1).unwrap();
+    assert_deserialization_error(
+        client.get_output(&output_id).await.unwrap_err(),
+        "/output",
+        &format!("/api/core/v2/outputs/{output_id}"),
+    );
+}
+
+#[tokio::test]
+async fn malformed_info_response() {
+    let mut info: Value = serde_json::from_str(&info_json(1)).unwrap();
+    // A string instead of a number
+    info["status"]["confirmedMilestone"]["index"] = json!("1");
+
+    let client = setup_client_with_mock_node(move |_| (200, info.to_string())).await;
+
+    assert_deserialization_error(
+        client.get_info().await.unwrap_err(),
+        "/status/confirmedMilestone/index",
+        "/api/core/v2/info",
+    );
+}
@@ -1,8 +1,12 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::keys::slip10::Chain;
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+    api::GetAddressesOptions,
+    constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+    secret::{SecretManage, SecretManager, SecretManagerDto},
+    Error, Result,
 };
 
 #[tokio::test]
@@ -105,3 +109,59 @@ async fn stronghold_mnemonic_missing() -> Result<()> {
     std::fs::remove_dir_all("stronghold_mnemonic_missing").ok();
     Ok(())
 }
+
+#[tokio::test]
+async fn watch_only_secret_manager_dto() -> Result<()> {
+    let mnemonic_secret_manager = SecretManager::try_from_mnemonic(
+        "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_string(),
+    )?;
+    let options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_account_index(0)
+        .with_range(0..2);
+
+    // The public keys of the addresses are part of their signatures
+    let mut public_keys = Vec::new();
+    for address_index in options.range.clone() {
+        let chain = Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, address_index]);
+        let signature = mnemonic_secret_manager.sign_ed25519(&[0; 32], &chain).await?;
+        public_keys.push(prefix_hex::encode(*signature.public_key()));
+    }
+
+    let dto = format!(r#"{{"watchOnly": {}}}"#, serde_json::to_string(&public_keys).unwrap());
+    let secret_manager: SecretManager = dto.parse()?;
+
+    assert_eq!(
+        secret_manager.generate_ed25519_addresses(options.clone()).await?,
+        mnemonic_secret_manager
+            .generate_ed25519_addresses(options.clone())
+            .await?
+    );
+    assert!(matches!(
+        secret_manager
+            .generate_ed25519_addresses(options.clone().with_range(0..3))
+            .await,
+        Err(Error::MissingWatchOnlyPublicKey(2))
+    ));
+    assert!(matches!(
+        secret_manager
+            .generate_ed25519_addresses(options.clone().with_account_index(1))
+            .await,
+        Err(Error::WatchOnlyAccountIndex(1))
+    ));
+    assert!(matches!(
+        secret_manager
+            .sign_ed25519(
+                &[0; 32],
+                &Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0])
+            )
+            .await,
+        Err(Error::WatchOnly)
+    ));
+    assert!(
+        matches!(SecretManagerDto::from(&secret_manager), SecretManagerDto::WatchOnly(keys) if keys == public_keys)
+    );
+
+    Ok(())
+}
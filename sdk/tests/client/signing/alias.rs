@@ -101,6 +101,7 @@ async fn sign_alias_state_transition() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
@@ -192,6 +193,7 @@ async fn sign_alias_governance_transition() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
@@ -318,6 +320,7 @@ async fn alias_reference_unlocks() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
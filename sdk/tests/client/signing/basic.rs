@@ -84,6 +84,7 @@ async fn single_ed25519_unlock() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
@@ -186,6 +187,7 @@ async fn ed25519_reference_unlocks() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
@@ -298,6 +300,7 @@ async fn two_signature_unlocks() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
@@ -387,6 +387,7 @@ async fn all_combined() -> Result<()> {
         essence,
         inputs_data: selected.inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let unlocks = secret_manager
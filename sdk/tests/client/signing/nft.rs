@@ -128,6 +128,7 @@ async fn nft_reference_unlocks() -> Result<()> {
         essence,
         inputs_data: inputs,
         remainder: None,
+        bech32_hrp: Some(SHIMMER_TESTNET_BECH32_HRP),
     };
 
     let current_time = 100;
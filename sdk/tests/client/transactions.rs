@@ -1,18 +1,28 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-// These are E2E test samples, so they are ignored by default.
+// Most of these are E2E test samples, so they are ignored by default.
 
+use std::sync::{Arc, Mutex};
+
+use crypto::keys::slip10::Chain;
 use iota_sdk::{
-    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Result},
+    client::{
+        api::GetAddressesOptions,
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE},
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{types::InputSigningData, SecretManager},
+        Client, Error, Result,
+    },
     types::block::{
         address::ToBech32Ext,
-        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId, OutputMetadata},
         payload::{transaction::TransactionEssence, Payload},
+        rand::{block::rand_block_id, transaction::rand_transaction_id},
     },
 };
 
-use crate::client::common::create_client_and_secret_manager_with_funds;
+use crate::client::common::{create_client_and_secret_manager_with_funds, info_json, spawn_mock_node};
 
 #[ignore]
 #[tokio::test]
@@ -68,3 +78,110 @@ async fn send_basic_output() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn dry_run_basic_output() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let token_supply = client.get_token_supply().await?;
+
+    let second_address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(1..2))
+        .await?[0];
+
+    assert!(matches!(
+        client.block().with_secret_manager(&secret_manager).dry_run().await,
+        Err(Error::MissingParameter("output"))
+    ));
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(second_address))
+        .finish_output(token_supply)?;
+
+    let transaction_payload = client
+        .block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone()])?
+        .dry_run()
+        .await?;
+
+    let TransactionEssence::Regular(essence) = transaction_payload.essence();
+    assert!(essence.outputs().contains(&output));
+
+    // The transaction wasn't posted, so it can't be found.
+    assert!(client.get_included_block(&transaction_payload.id()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_run_offline() -> Result<()> {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let requests_ = requests.clone();
+    let node_url = spawn_mock_node(move |path| {
+        if path == "/api/core/v2/info" {
+            return (200, info_json(1));
+        }
+        requests_.lock().unwrap().push(path.to_string());
+        (500, String::new())
+    });
+    let client = Client::builder()
+        .with_node(&node_url)?
+        .with_ignore_node_health()
+        .with_time_check_disabled()
+        .finish()
+        .await?;
+    let token_supply = client.get_token_supply().await?;
+
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let addresses = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..2),
+        )
+        .await?;
+
+    let input_signing_data = InputSigningData {
+        output: BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(addresses[0]))
+            .finish_output(token_supply)?,
+        output_metadata: OutputMetadata::new(
+            rand_block_id(),
+            OutputId::new(rand_transaction_id(), 0)?,
+            false,
+            None,
+            None,
+            None,
+            0,
+            0,
+            0,
+        ),
+        chain: Some(Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0])),
+    };
+    let input_id = *input_signing_data.output_id();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(addresses[1]))
+        .finish_output(token_supply)?;
+
+    let transaction_payload = client
+        .block()
+        .with_secret_manager(&secret_manager)
+        .with_input_signing_data(input_signing_data)?
+        .with_outputs([output.clone()])?
+        .dry_run()
+        .await?;
+
+    let TransactionEssence::Regular(essence) = transaction_payload.essence();
+    assert_eq!(essence.inputs().len(), 1);
+    assert_eq!(essence.inputs()[0].as_utxo().output_id(), &input_id);
+    // provided output + remainder output
+    assert_eq!(essence.outputs().len(), 2);
+    assert!(essence.outputs().contains(&output));
+    assert_eq!(transaction_payload.unlocks().len(), 1);
+    // Neither the input nor the time or anything else were requested from the node.
+    assert!(requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 use iota_sdk::{
     pow::{miner::get_miner, score::PowScorer},
     types::block::{
@@ -13,7 +14,7 @@ use iota_sdk::{
             parents::rand_parents,
             payload::{rand_tagged_data_payload, rand_treasury_transaction_payload},
         },
-        Block, BlockBuilder, Error,
+        Block, BlockBuilder, BlockId, Error,
     },
 };
 use packable::{error::UnpackError, PackableExt};
@@ -106,6 +107,19 @@ fn pack_unpack_valid() {
     );
 }
 
+#[test]
+fn pack_into() {
+    let block = BlockBuilder::new(rand_parents())
+        .with_payload(rand_tagged_data_payload())
+        .finish()
+        .unwrap();
+    let mut writer = Vec::new();
+    block.pack_into(&mut writer).unwrap();
+
+    assert_eq!(writer, block.pack_to_vec());
+    assert_eq!(block.id(), BlockId::new(Blake2b256::digest(writer).into()));
+}
+
 #[test]
 fn getters() {
     let protocol_parameters = protocol_parameters();
@@ -17,6 +17,7 @@ mod milestone_payload_essence;
 mod output_id;
 mod parents;
 mod payload;
+mod protocol_parameters;
 mod receipt_milestone_option;
 mod reference_unlock;
 mod rent;
@@ -0,0 +1,60 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::Hrp,
+    output::RentStructure,
+    protocol::{ProtocolParameters, ProtocolParametersBuilder},
+};
+
+#[test]
+fn defaults() {
+    assert_eq!(ProtocolParameters::default(), ProtocolParameters::mainnet_defaults());
+    assert_eq!(ProtocolParameters::mainnet_defaults().bech32_hrp(), "smr");
+    assert_eq!(ProtocolParameters::testnet_defaults().bech32_hrp(), "rms");
+    assert_eq!(
+        ProtocolParameters::builder().finish().unwrap(),
+        ProtocolParameters::mainnet_defaults()
+    );
+}
+
+#[test]
+fn builder() {
+    let protocol_parameters = ProtocolParameters::builder()
+        .protocol_version(3)
+        .network_name("private")
+        .bech32_hrp(Hrp::from_str_unchecked("tst"))
+        .min_pow_score(0)
+        .below_max_depth(10)
+        .rent_structure(RentStructure::new(500, 10, 1))
+        .token_supply(1_000_000)
+        .finish()
+        .unwrap();
+
+    assert_eq!(protocol_parameters.protocol_version(), 3);
+    assert_eq!(protocol_parameters.network_name(), "private");
+    assert_eq!(protocol_parameters.bech32_hrp(), "tst");
+    assert_eq!(protocol_parameters.min_pow_score(), 0);
+    assert_eq!(protocol_parameters.below_max_depth(), 10);
+    assert_eq!(protocol_parameters.rent_structure(), &RentStructure::new(500, 10, 1));
+    assert_eq!(protocol_parameters.token_supply(), 1_000_000);
+
+    // Only overrides the given parameters
+    let testnet = ProtocolParameters::testnet_defaults();
+    let protocol_parameters = ProtocolParametersBuilder::from(testnet.clone())
+        .token_supply(1_000_000)
+        .finish()
+        .unwrap();
+    assert_eq!(protocol_parameters.network_id(), testnet.network_id());
+    assert_eq!(protocol_parameters.token_supply(), 1_000_000);
+}
+
+#[test]
+fn builder_invalid_network_name() {
+    assert!(
+        ProtocolParameters::builder()
+            .network_name("a".repeat(256))
+            .finish()
+            .is_err()
+    );
+}
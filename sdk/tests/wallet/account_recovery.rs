@@ -3,8 +3,13 @@
 
 // Tests for recovering accounts from mnemonic without a backup
 
-use std::time::Duration;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::{AccountRecoveryProgressEvent, WalletEvent, WalletEventType};
 use iota_sdk::{
     client::{
         api::GetAddressesOptions,
@@ -12,10 +17,17 @@ use iota_sdk::{
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
         Client,
     },
-    wallet::Result,
+    types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        protocol::protocol_parameters,
+    },
+    wallet::{account::SyncOptions, Result},
 };
 
-use crate::wallet::common::{make_wallet, setup, tear_down};
+use crate::{
+    client::common::{info_json, spawn_mock_node, spawn_mock_node_with_address_outputs, MockOutput},
+    wallet::common::{make_wallet, setup, tear_down},
+};
 
 #[ignore]
 #[tokio::test]
@@ -100,3 +112,162 @@ async fn account_recovery_with_balance_and_empty_addresses() -> Result<()> {
     assert_eq!(3, account_with_balance.public_addresses().len());
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn search_addresses_with_outputs_beyond_gap() -> Result<()> {
+    let storage_path = "test-storage/search_addresses_with_outputs_beyond_gap";
+    setup(storage_path)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let client = Client::builder()
+        .with_node(crate::wallet::common::NODE_LOCAL)?
+        .finish()
+        .await?;
+
+    let secret_manager = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.clone())?);
+
+    let addresses = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(&client)
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(25..26),
+        )
+        .await?;
+
+    // Add funds to the address with key_index 25, which a normal sync doesn't reach
+    iota_sdk::client::request_funds_from_faucet(crate::wallet::common::FAUCET_URL, &addresses[0]).await?;
+
+    // Wait for faucet transaction
+    tokio::time::sleep(Duration::new(10, 0)).await;
+
+    let wallet = make_wallet(storage_path, Some(mnemonic), None).await?;
+    let account = wallet.create_account().finish().await?;
+    account.generate_ed25519_addresses(2, None).await?;
+
+    assert_eq!(account.sync(None).await?.base_coin().total(), 0);
+
+    // The address with outputs is 23 addresses after the last known one, the gap after it is kept
+    let balance = account.search_addresses_with_outputs(25, None).await?;
+    assert!(balance.base_coin().total() > 0);
+    assert_eq!(51, account.details().await.public_addresses().len());
+
+    // Searching from a later index doesn't remove the known addresses
+    let balance = account.search_addresses_with_outputs(5, Some(30)).await?;
+    assert!(balance.base_coin().total() > 0);
+    assert_eq!(51, account.details().await.public_addresses().len());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn search_addresses_with_outputs_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/search_addresses_with_outputs_with_mock_node";
+    setup(storage_path)?;
+
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_address_outputs(outputs.clone(), |_| false);
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    account
+        .set_default_sync_options(SyncOptions {
+            sync_only_most_basic_outputs: true,
+            ..Default::default()
+        })
+        .await?;
+
+    // Fund the public addresses with the key indexes 0 and 3, the latter isn't known to the account yet
+    let addresses = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?)
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(wallet.client())
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..4),
+        )
+        .await?;
+    for address in [addresses[0], addresses[3]] {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        outputs.lock().unwrap().push(MockOutput::new(address, &output));
+    }
+
+    // The address with the key index 3 is within the gap, afterwards the gap of 3 addresses after it is known
+    let balance = account.search_addresses_with_outputs(3, None).await?;
+    assert_eq!(balance.base_coin().total(), 2_000_000);
+    let public_addresses = account.details().await.public_addresses().clone();
+    assert_eq!(public_addresses.len(), 7);
+    assert_eq!(public_addresses.last().unwrap().key_index(), &6);
+    // No internal address has outputs, so there is no gap to keep for them
+    assert!(account.details().await.internal_addresses().is_empty());
+
+    // A smaller gap doesn't remove the known addresses
+    let balance = account.search_addresses_with_outputs(1, None).await?;
+    assert_eq!(balance.base_coin().total(), 2_000_000);
+    assert_eq!(account.details().await.public_addresses().len(), 7);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "events")]
+#[tokio::test]
+async fn account_recovery_failing_search() -> Result<()> {
+    let storage_path = "test-storage/account_recovery_failing_search";
+    setup(storage_path)?;
+
+    // The outputs of the addresses can't be requested
+    let node_url = spawn_mock_node(|path| {
+        if path == "/api/core/v2/info" {
+            (200, info_json(7))
+        } else {
+            (500, String::new())
+        }
+    });
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    wallet.create_account().finish().await?;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    wallet
+        .listen([WalletEventType::AccountRecoveryProgress], {
+            let events = events.clone();
+            move |event| {
+                if let WalletEvent::AccountRecoveryProgress(progress) = &event.event {
+                    events.lock().unwrap().push((event.account_index, progress.clone()));
+                }
+            }
+        })
+        .await;
+
+    // Every search failed, so only the existing account is kept
+    match wallet.recover_accounts(0, 2, 2, None).await {
+        Err(iota_sdk::wallet::Error::AccountRecoveryFailed(failed_accounts)) => assert_eq!(
+            failed_accounts.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            [0, 1, 2]
+        ),
+        other => panic!("unexpected recovery result {other:?}"),
+    }
+    let accounts = wallet.get_accounts().await?;
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(*accounts[0].details().await.index(), 0);
+
+    // The accounts are searched in parallel, the events of each account are in order
+    let events = events.lock().unwrap().clone();
+    assert_eq!(events.len(), 6);
+    for account_index in 0..3 {
+        let account_events = events
+            .iter()
+            .filter_map(|(index, progress)| (*index == account_index).then_some(progress))
+            .collect::<Vec<_>>();
+        assert_eq!(account_events[0], &AccountRecoveryProgressEvent::ScanningAccount);
+        assert!(matches!(
+            account_events[1],
+            AccountRecoveryProgressEvent::AccountFailed { .. }
+        ));
+    }
+
+    tear_down(storage_path)
+}
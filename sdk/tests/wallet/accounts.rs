@@ -1,7 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Result;
+use iota_sdk::wallet::{Error, Result};
 #[cfg(feature = "stronghold")]
 use {
     iota_sdk::client::{
@@ -167,6 +167,51 @@ async fn account_rename_alias() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn account_label_address() -> Result<()> {
+    let storage_path = "test-storage/account_label_address";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+        let address = account.addresses().await?[0].clone();
+
+        account.label_address(address.address(), "exchange deposit").await?;
+        assert_eq!(
+            account.address_by_label("exchange deposit").await?,
+            Some(address.clone())
+        );
+        assert_eq!(account.address_by_label("payroll").await?, None);
+
+        // Setting another label replaces the previous one
+        account.label_address(address.address(), "payroll").await?;
+        assert_eq!(account.address_by_label("exchange deposit").await?, None);
+        assert_eq!(account.address_by_label("payroll").await?, Some(address));
+
+        // Only addresses of the account can be labeled
+        assert!(matches!(
+            account
+                .label_address(
+                    "rms1qpsvxm4q9p3xe4tkqjr04a64j0gxvhe0prt06vxwp0spkxfc8nr5gs28u0l",
+                    "payroll"
+                )
+                .await,
+            Err(Error::AddressNotFoundInAccount(_))
+        ));
+    }
+
+    #[cfg(feature = "storage")]
+    {
+        // The labels are restored from the storage
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let account = wallet.get_account("Alice").await?;
+        assert!(account.address_by_label("payroll").await?.is_some());
+    }
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_first_address_exists() -> Result<()> {
     let storage_path = "test-storage/account_first_address_exists";
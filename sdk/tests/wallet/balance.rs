@@ -9,7 +9,7 @@ use iota_sdk::{
     },
     wallet::{
         account::types::{Balance, BalanceDto},
-        Result,
+        Result, SendAmountParams,
     },
 };
 
@@ -123,6 +123,75 @@ fn balance_add_assign() {
     }
 }
 
+#[ignore]
+#[tokio::test]
+async fn wallet_balance() -> Result<()> {
+    let storage_path = "test-storage/wallet_balance";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+    let wallet_balance = wallet.balance_after_sync(None).await?;
+
+    assert_eq!(wallet_balance.accounts().len(), 2);
+    let mut total = 0;
+    for account in &accounts {
+        let balance = account.balance().await?;
+        assert_eq!(&wallet_balance.accounts()[account.details().await.index()], &balance);
+        total += balance.base_coin().total();
+    }
+    assert_eq!(wallet_balance.total().base_coin().total(), total);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn balance_excludes_locked_outputs_within_sync_interval() -> Result<()> {
+    let storage_path = "test-storage/balance_excludes_locked_outputs_within_sync_interval";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let balance_before_tx = account_0.sync(None).await?;
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+    // Synced within the `MIN_SYNC_INTERVAL` or before the transaction got confirmed, so the inputs are still locked
+    let balance_after_tx = account_0.sync(None).await?;
+
+    let account_details = account_0.details().await;
+    let locked_amount = account_details
+        .locked_outputs()
+        .iter()
+        .filter_map(|output_id| account_details.unspent_outputs().get(output_id))
+        .map(|output_data| output_data.output.amount())
+        .sum::<u64>();
+    assert_eq!(account_details.locked_outputs().len(), tx.inputs.len());
+    assert!(locked_amount > 0);
+    assert_eq!(
+        balance_after_tx.base_coin().total(),
+        balance_before_tx.base_coin().total()
+    );
+    assert_eq!(
+        balance_after_tx.base_coin().available(),
+        balance_before_tx.base_coin().available() - locked_amount
+    );
+    assert_eq!(&balance_after_tx, &account_0.balance().await?);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn balance_expiration() -> Result<()> {
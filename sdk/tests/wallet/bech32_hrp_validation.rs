@@ -1,13 +1,23 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::{Arc, Mutex};
+
 use iota_sdk::{
-    client::Error as ClientError,
-    types::block::address::{Bech32Address, ToBech32Ext},
-    wallet::{account::OutputParams, Error, Result, SendAmountParams},
+    client::{
+        api::GetAddressesOptions,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client, Error as ClientError,
+    },
+    types::block::address::{Bech32Address, Hrp, ToBech32Ext},
+    wallet::{account::OutputParams, ClientOptions, Error, Result, SendAmountParams, Wallet},
 };
 
-use crate::wallet::common::{make_wallet, setup, tear_down};
+use crate::{
+    client::common::{info_json, spawn_mock_node},
+    wallet::common::{make_wallet, setup, tear_down},
+};
 
 #[ignore]
 #[tokio::test]
@@ -88,3 +98,92 @@ async fn bech32_hrp_prepare_output() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn custom_bech32_hrp() -> Result<()> {
+    let storage_path = "test-storage/custom_bech32_hrp";
+    setup(storage_path)?;
+
+    let custom_hrp = Hrp::from_str_unchecked("prvtn");
+    let node_hrp = Hrp::from_str_unchecked("rms");
+
+    let requested_indexer_paths = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node({
+        let requested_indexer_paths = requested_indexer_paths.clone();
+        move |path| {
+            if path == "/api/core/v2/info" {
+                (200, info_json(7))
+            } else if path.starts_with("/api/indexer/v1/outputs/") {
+                requested_indexer_paths.lock().unwrap().push(path.to_string());
+                (200, r#"{"ledgerIndex":7,"items":[]}"#.to_string())
+            } else {
+                (404, String::new())
+            }
+        }
+    });
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            Client::generate_mnemonic()?,
+        )?))
+        .with_client_options(
+            ClientOptions::new()
+                .with_node(&node_url)?
+                .with_ignore_node_health()
+                .with_custom_bech32_hrp(custom_hrp),
+        )
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+    let client = wallet.client();
+
+    // Generating
+    assert_eq!(client.get_bech32_hrp().await?, custom_hrp);
+    assert_eq!(client.get_network_bech32_hrp().await?, node_hrp);
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+    assert_eq!(address.hrp(), &custom_hrp);
+    let options = GetAddressesOptions::from_client(client).await?.with_range(0..1);
+    assert_eq!(options.bech32_hrp, custom_hrp);
+
+    // Parsing
+    assert_eq!(Bech32Address::try_from_str(address.to_string())?, address);
+    client.bech32_hrp_matches(&custom_hrp).await?;
+    client.bech32_hrp_matches(&node_hrp).await?;
+    assert!(matches!(
+        client.bech32_hrp_matches(&Hrp::from_str_unchecked("other")).await,
+        Err(ClientError::Bech32HrpMismatch { .. })
+    ));
+
+    // Syncing queries the node with its own HRP
+    account.sync(None).await?;
+    let node_address = address.inner().to_bech32(node_hrp).to_string();
+    let requested_indexer_paths = requested_indexer_paths.lock().unwrap().clone();
+    assert!(!requested_indexer_paths.is_empty());
+    assert!(
+        requested_indexer_paths
+            .iter()
+            .all(|path| path.contains(&node_address) && !path.contains(&custom_hrp.to_string()))
+    );
+    assert_eq!(account.last_processed_milestone().await, Some(7));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn hrp_validation_disabled() -> Result<()> {
+    let client = Client::builder()
+        .with_custom_bech32_hrp(Hrp::from_str_unchecked("prvtn"))
+        .with_hrp_validation_disabled()
+        .finish()
+        .await?;
+
+    client.bech32_hrp_matches(&Hrp::from_str_unchecked("other")).await?;
+    assert_eq!(client.get_bech32_hrp().await?, Hrp::from_str_unchecked("prvtn"));
+
+    Ok(())
+}
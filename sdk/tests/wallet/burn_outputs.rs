@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::api::input_selection::Burn,
+    client::{
+        api::input_selection::{Burn, Error as InputSelectionError},
+        Error as ClientError,
+    },
     types::block::output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
-        NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
+        ChainId, NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
     },
-    wallet::{Account, MintNativeTokenParams, MintNftParams, Result},
+    wallet::{Account, CreateNativeTokenParams, Error, MintNftParams, Result},
     U256,
 };
 
@@ -41,7 +44,7 @@ async fn mint_and_burn_nft() -> Result<()> {
     println!("account balance -> {}", serde_json::to_string(&balance).unwrap());
     assert!(search.is_some());
 
-    let transaction = account.burn(nft_id, None).await.unwrap();
+    let transaction = account.burn_nft(nft_id, None).await.unwrap();
     account
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -53,6 +56,31 @@ async fn mint_and_burn_nft() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn burn_nft_not_controlled() -> Result<()> {
+    let storage_path = "test-storage/burn_nft_not_controlled";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let nft_id = NftId::new([1; NftId::LENGTH]);
+    let error = account.burn_nft(nft_id, None).await.unwrap_err();
+
+    match error {
+        Error::Client(error) => match *error {
+            ClientError::InputSelection(InputSelectionError::BurnTargetsNotFound(missing)) => {
+                assert_eq!(missing, vec![ChainId::from(nft_id)]);
+            }
+            _ => panic!("expected BurnTargetsNotFound error variant"),
+        },
+        _ => panic!("expected Client error variant"),
+    }
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn mint_and_burn_expired_nft() -> Result<()> {
@@ -118,14 +146,14 @@ async fn mint_and_decrease_native_token_supply() -> Result<()> {
     account.sync(None).await?;
 
     let circulating_supply = U256::from(60i32);
-    let params = MintNativeTokenParams {
+    let params = CreateNativeTokenParams {
         alias_id: None,
         circulating_supply,
         maximum_supply: U256::from(100i32),
         foundry_metadata: None,
     };
 
-    let mint_transaction = account.mint_native_token(params, None).await.unwrap();
+    let mint_transaction = account.create_native_token(params, None).await.unwrap();
 
     account
         .retry_transaction_until_included(&mint_transaction.transaction.transaction_id, None, None)
@@ -142,7 +170,7 @@ async fn mint_and_decrease_native_token_supply() -> Result<()> {
     // Melt some of the circulating supply
     let melt_amount = U256::from(40i32);
     let transaction = account
-        .decrease_native_token_supply(mint_transaction.token_id, melt_amount, None)
+        .melt_native_token(mint_transaction.token_id, melt_amount, None)
         .await
         .unwrap();
 
@@ -160,7 +188,7 @@ async fn mint_and_decrease_native_token_supply() -> Result<()> {
     // Then melt the rest of the supply
     let melt_amount = circulating_supply - melt_amount;
     let transaction = account
-        .decrease_native_token_supply(mint_transaction.token_id, melt_amount, None)
+        .melt_native_token(mint_transaction.token_id, melt_amount, None)
         .await
         .unwrap();
 
@@ -191,7 +219,7 @@ async fn destroy_foundry(account: &Account) -> Result<()> {
     // idea
     let foundry_id = *balance.foundries().first().unwrap();
 
-    let transaction = account.burn(foundry_id, None).await.unwrap();
+    let transaction = account.destroy_foundry(foundry_id, None).await.unwrap();
     account
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -213,7 +241,7 @@ async fn destroy_alias(account: &Account) -> Result<()> {
     // Let's destroy the first alias we can find
     let alias_id = *balance.aliases().first().unwrap();
     println!("alias_id -> {alias_id}");
-    let transaction = account.burn(alias_id, None).await.unwrap();
+    let transaction = account.destroy_alias(alias_id, None).await.unwrap();
     account
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -247,8 +275,8 @@ async fn mint_and_burn_native_tokens() -> Result<()> {
     account.sync(None).await?;
 
     let mint_tx = account
-        .mint_native_token(
-            MintNativeTokenParams {
+        .create_native_token(
+            CreateNativeTokenParams {
                 alias_id: None,
                 circulating_supply: native_token_amount,
                 maximum_supply: native_token_amount,
@@ -0,0 +1,384 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use iota_sdk::{
+    client::api::input_selection::Burn,
+    types::block::{
+        address::{Address, ToBech32Ext},
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, NativeToken, NativeTokens, NftId, NftOutputBuilder, Output, OutputId, TokenId,
+            UnlockCondition,
+        },
+        payload::transaction::TransactionEssence,
+        protocol::protocol_parameters,
+        rand::{address::rand_address, bytes::rand_bytes_array},
+        Error as BlockError,
+    },
+    wallet::{
+        account::{OutputsToClaim, SyncOptions, TransactionOptions},
+        Account, Error, MintNftParams, Result, SendAmountParams, Wallet,
+    },
+};
+use primitive_types::U256;
+
+use crate::{
+    client::common::{spawn_mock_node_with_address_outputs, MockOutput},
+    wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down},
+};
+
+#[ignore]
+#[tokio::test]
+async fn compose_claim_and_send() -> Result<()> {
+    let storage_path = "test-storage/compose_claim_and_send";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+
+    let token_supply = accounts[1].client().get_token_supply().await?;
+    let outputs = [NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+        .with_unlock_conditions([
+            UnlockCondition::Address(AddressUnlockCondition::new(
+                *accounts[0].addresses().await?[0].address().as_ref(),
+            )),
+            UnlockCondition::Expiration(ExpirationUnlockCondition::new(
+                *accounts[1].addresses().await?[0].address().as_ref(),
+                accounts[1].client().get_time_checked().await? + 5000,
+            )?),
+        ])
+        .finish_output(token_supply)?];
+
+    let tx = accounts[1].send(outputs, None).await?;
+    accounts[1]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[0].sync(None).await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 1);
+    let base_coin_amount_before = balance.base_coin().available();
+
+    // Claim the nft and send some amount back in a single transaction
+    let amount = 1_000_000;
+    let prepared_transaction = accounts[0]
+        .compose()
+        .claim(accounts[0].claimable_outputs(OutputsToClaim::Nfts).await?)
+        .send([SendAmountParams::new(
+            *accounts[1].addresses().await?[0].address(),
+            amount,
+        )?])
+        .finish(None)
+        .await?;
+    let tx = accounts[0].sign_and_submit_transaction(prepared_transaction).await?;
+    accounts[0]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[0].sync(None).await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 0);
+    assert_eq!(balance.nfts().len(), 1);
+    assert_eq!(balance.base_coin().available(), base_coin_amount_before - amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn compose_send_and_burn() -> Result<()> {
+    let storage_path = "test-storage/compose_send_and_burn";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+
+    let tx = accounts[0]
+        .mint_nfts(
+            [MintNftParams::new().with_address(*accounts[0].addresses().await?[0].address())],
+            None,
+        )
+        .await?;
+    accounts[0]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let nft_id = NftId::from(&OutputId::new(tx.transaction_id, 0u16)?);
+    let balance = accounts[0].sync(None).await?;
+    assert!(balance.nfts().contains(&nft_id));
+
+    // Send some amount and burn the nft in a single transaction
+    let amount = 1_000_000;
+    let prepared_transaction = accounts[0]
+        .compose()
+        .send([SendAmountParams::new(
+            *accounts[1].addresses().await?[0].address(),
+            amount,
+        )?])
+        .burn(nft_id)
+        .finish(None)
+        .await?;
+    let tx = accounts[0].sign_and_submit_transaction(prepared_transaction).await?;
+    accounts[0]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[0].sync(None).await?;
+    assert!(!balance.nfts().contains(&nft_id));
+    let balance = accounts[1].sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 2 * amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn compose_claim_and_burn_conflict() -> Result<()> {
+    let storage_path = "test-storage/compose_claim_and_burn_conflict";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+
+    let token_supply = accounts[1].client().get_token_supply().await?;
+    let outputs = [NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+        .with_unlock_conditions([
+            UnlockCondition::Address(AddressUnlockCondition::new(
+                *accounts[0].addresses().await?[0].address().as_ref(),
+            )),
+            UnlockCondition::Expiration(ExpirationUnlockCondition::new(
+                *accounts[1].addresses().await?[0].address().as_ref(),
+                accounts[1].client().get_time_checked().await? + 5000,
+            )?),
+        ])
+        .finish_output(token_supply)?];
+
+    let tx = accounts[1].send(outputs, None).await?;
+    accounts[1]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let nft_id = NftId::from(&OutputId::new(tx.transaction_id, 0u16)?);
+
+    accounts[0].sync(None).await?;
+    let result = accounts[0]
+        .compose()
+        .claim(accounts[0].claimable_outputs(OutputsToClaim::Nfts).await?)
+        .burn(Burn::new().add_nft(nft_id))
+        .finish(None)
+        .await;
+    assert!(matches!(result, Err(Error::ConflictingRequirements(_))));
+
+    tear_down(storage_path)
+}
+
+/// Creates an account that is synced with a mock node, which has the outputs created by `outputs` for the first address
+/// of the account. Returns the ids of the outputs.
+async fn account_with_outputs(
+    storage_path: &str,
+    outputs: impl FnOnce(Address) -> Result<Vec<Output>>,
+) -> Result<(Wallet, Account, Vec<OutputId>)> {
+    let mock_outputs = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_address_outputs(mock_outputs.clone(), |_| false);
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+
+    let mut output_ids = Vec::new();
+    for output in outputs(*address.inner())? {
+        let output = MockOutput::new(address, &output);
+        output_ids.push(output.output_id);
+        mock_outputs.lock().unwrap().push(output);
+    }
+
+    account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    Ok((wallet, account, output_ids))
+}
+
+/// Creates an account that is synced with a mock node, which has a basic output with 2 Mi and a claimable nft output
+/// with an expiration for the first address of the account. Returns the ids of both outputs.
+async fn account_with_claimable_nft(storage_path: &str) -> Result<(Wallet, Account, OutputId, OutputId)> {
+    let token_supply = protocol_parameters().token_supply();
+    let (wallet, account, output_ids) = account_with_outputs(storage_path, |address| {
+        Ok(vec![
+            BasicOutputBuilder::new_with_amount(2_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?,
+            NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+                .with_unlock_conditions([
+                    UnlockCondition::Address(AddressUnlockCondition::new(address)),
+                    UnlockCondition::Expiration(ExpirationUnlockCondition::new(rand_address(), u32::MAX)?),
+                ])
+                .finish_output(token_supply)?,
+        ])
+    })
+    .await?;
+
+    let balance = account.balance().await?;
+    assert_eq!(balance.base_coin().available(), 2_000_000);
+    assert_eq!(balance.potentially_locked_outputs().len(), 1);
+
+    Ok((wallet, account, output_ids[0], output_ids[1]))
+}
+
+#[tokio::test]
+async fn compose_claim_and_send_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/compose_claim_and_send_with_mock_node";
+    setup(storage_path)?;
+
+    let (wallet, account, basic_output_id, nft_output_id) = account_with_claimable_nft(storage_path).await?;
+    let address = *account.addresses().await?[0].address().inner();
+    assert_eq!(account.claimable_outputs(OutputsToClaim::Nfts).await?, [nft_output_id]);
+
+    // Claim the nft and send some amount in a single transaction
+    let recipient = rand_address();
+    let amount = 1_000_000;
+    let prepared_transaction = account
+        .compose()
+        .claim([nft_output_id])
+        .send([SendAmountParams::new(
+            recipient.to_bech32(wallet.client().get_bech32_hrp().await?),
+            amount,
+        )?])
+        .finish(None)
+        .await?;
+
+    let input_ids = prepared_transaction
+        .inputs_data
+        .iter()
+        .map(|input| *input.output_id())
+        .collect::<Vec<_>>();
+    assert_eq!(input_ids.len(), 2);
+    assert!(input_ids.contains(&basic_output_id));
+    assert!(input_ids.contains(&nft_output_id));
+
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+    // The claimed nft only has an address unlock condition left and the sent amount goes to the recipient
+    assert!(essence.outputs().iter().any(|output| matches!(
+        output,
+        Output::Nft(nft_output)
+            if nft_output.nft_id() == &NftId::from(&nft_output_id)
+                && nft_output.unlock_conditions().len() == 1
+                && nft_output.address() == &address
+    )));
+    assert!(essence.outputs().iter().any(|output| {
+        output.amount() == amount
+            && output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map(|unlock_condition| *unlock_condition.address())
+                == Some(recipient)
+    }));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn compose_claim_and_burn_conflict_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/compose_claim_and_burn_conflict_with_mock_node";
+    setup(storage_path)?;
+
+    let (_wallet, account, _, nft_output_id) = account_with_claimable_nft(storage_path).await?;
+
+    let result = account
+        .compose()
+        .claim([nft_output_id])
+        .burn(Burn::new().add_nft(NftId::from(&nft_output_id)))
+        .finish(None)
+        .await;
+    assert!(matches!(result, Err(Error::ConflictingRequirements(_))));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn compose_too_many_native_tokens_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/compose_too_many_native_tokens_with_mock_node";
+    setup(storage_path)?;
+
+    let token_supply = protocol_parameters().token_supply();
+    let native_token = || NativeToken::new(TokenId::new(rand_bytes_array()), U256::from(100));
+    // The claimed nft keeps its native tokens and the native tokens of the claimed basic output end up in the
+    // remainder, together they're more than a transaction can have
+    let (_wallet, account, output_ids) = account_with_outputs(storage_path, |address| {
+        let expiration = ExpirationUnlockCondition::new(rand_address(), u32::MAX)?;
+        Ok(vec![
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(expiration)
+                .add_native_token(native_token()?)
+                .finish_output(token_supply)?,
+            NftOutputBuilder::new_with_amount(10_000_000, NftId::null())
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(expiration)
+                .with_native_tokens(
+                    (0..NativeTokens::COUNT_MAX)
+                        .map(|_| native_token())
+                        .collect::<std::result::Result<Vec<_>, _>>()?,
+                )
+                .finish_output(token_supply)?,
+        ])
+    })
+    .await?;
+
+    let result = account.compose().claim(output_ids).finish(None).await;
+    assert!(matches!(
+        result,
+        Err(Error::Block(error)) if matches!(*error, BlockError::InvalidNativeTokenCount(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn compose_too_many_inputs_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/compose_too_many_inputs_with_mock_node";
+    setup(storage_path)?;
+
+    let token_supply = protocol_parameters().token_supply();
+    let (_wallet, account, output_ids) = account_with_outputs(storage_path, |address| {
+        let mut outputs = vec![
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?,
+        ];
+        for _ in 0..INPUT_COUNT_MAX {
+            outputs.push(
+                BasicOutputBuilder::new_with_amount(1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_unlock_condition(ExpirationUnlockCondition::new(rand_address(), u32::MAX)?)
+                    .finish_output(token_supply)?,
+            );
+        }
+        Ok(outputs)
+    })
+    .await?;
+
+    // The claimed outputs alone are within the limit, but not together with the mandatory input
+    let result = account
+        .compose()
+        .claim(output_ids[1..].iter().copied())
+        .finish(TransactionOptions {
+            mandatory_inputs: Some(vec![output_ids[0]]),
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::TooManyInputs {
+            input_count,
+            input_count_max: INPUT_COUNT_MAX,
+        }) if input_count == INPUT_COUNT_MAX as usize + 1
+    ));
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{Result, SendAmountParams};
+use std::collections::HashSet;
+
+use iota_sdk::{
+    types::block::payload::transaction::TransactionEssence,
+    wallet::{Error, Result, SendAmountParams},
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -33,7 +38,32 @@ async fn consolidation() -> Result<()> {
     assert_eq!(balance.base_coin().available(), 10 * amount);
     assert_eq!(account_1.unspent_outputs(None).await?.len(), 10);
 
+    // Nothing is consolidated below the threshold
+    assert!(matches!(
+        account_1.preview_consolidate_outputs(false, Some(11)).await,
+        Err(Error::NoOutputsToConsolidate {
+            available_outputs: 10,
+            consolidation_threshold: 11,
+        })
+    ));
+    let preview = account_1.preview_consolidate_outputs(false, Some(10)).await?;
+    assert_eq!(preview.inputs.len(), 10);
+    assert_eq!(preview.outputs.len(), 1);
+    assert_eq!(preview.outputs[0].amount(), 10 * amount);
+    assert_eq!(preview.remainder, None);
+    // The preview doesn't lock the outputs
+    assert_eq!(account_1.balance().await?.base_coin().available(), 10 * amount);
+
     let tx = account_1.consolidate_outputs(true, None).await?;
+    let TransactionEssence::Regular(essence) = tx.payload.essence();
+    assert_eq!(
+        essence
+            .inputs()
+            .iter()
+            .map(|input| *input.as_utxo().output_id())
+            .collect::<HashSet<_>>(),
+        preview.inputs.into_iter().collect::<HashSet<_>>()
+    );
     account_1
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -14,8 +14,8 @@ use iota_sdk::{
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AccountRecoveryProgressEvent, AddressData, NewOutputEvent, SpentOutputEvent, SyncProgressEvent,
+            TransactionInclusionEvent, TransactionProgressEvent, WalletEvent,
         },
     },
 };
@@ -104,6 +104,7 @@ fn wallet_events_serde() {
                 essence: (&essence).into(),
                 inputs_data: Vec::new(),
                 remainder: None,
+                bech32_hrp: Some(*protocol_parameters.bech32_hrp()),
             })),
         ));
     }
@@ -121,4 +122,144 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent::AddressesSynced {
+        synced: 50,
+        total: 120,
+    }));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent::OutputsFetched {
+        count: 7,
+    }));
+
+    assert_serde_eq(WalletEvent::SyncProgress(SyncProgressEvent::PendingTransactionsSynced));
+
+    assert_serde_eq(WalletEvent::AccountRecoveryProgress(
+        AccountRecoveryProgressEvent::ScanningAccount,
+    ));
+
+    assert_serde_eq(WalletEvent::AccountRecoveryProgress(
+        AccountRecoveryProgressEvent::AccountScanned { outputs: 3 },
+    ));
+
+    assert_serde_eq(WalletEvent::AccountRecoveryProgress(
+        AccountRecoveryProgressEvent::AccountFailed {
+            error: "node error".to_string(),
+        },
+    ));
+}
+
+#[cfg(feature = "rocksdb")]
+#[tokio::test]
+async fn replay_events_after_crash_during_sync() -> iota_sdk::wallet::Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use iota_sdk::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        types::block::output::BasicOutputBuilder,
+        wallet::{account::SyncOptions, events::types::Event, ClientOptions, Wallet},
+    };
+
+    use crate::{
+        client::common::{spawn_mock_node_with_address_outputs, MockOutput},
+        wallet::common::{setup, tear_down},
+    };
+
+    let storage_path = "test-storage/replay_events_after_crash_during_sync";
+    setup(storage_path)?;
+
+    // Requests for the outputs of other addresses than the one of the funded output fail
+    let funded_outputs = Arc::new(Mutex::new(Vec::<MockOutput>::new()));
+    let node_url = spawn_mock_node_with_address_outputs(funded_outputs.clone(), {
+        let funded_outputs = funded_outputs.clone();
+        move |path| {
+            !funded_outputs
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|output| path.contains(&output.address))
+        }
+    });
+    async fn make_wallet(storage_path: &str, mnemonic: &str, node_url: &str) -> iota_sdk::wallet::Result<Wallet> {
+        Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+                mnemonic.to_string(),
+            )?))
+            .with_client_options(ClientOptions::new().with_node(node_url)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path)
+            .with_event_log_size(100)
+            .finish()
+            .await
+    }
+    let mnemonic = Client::generate_mnemonic()?;
+
+    let wallet = make_wallet(storage_path, &mnemonic, &node_url).await?;
+    let account = wallet.create_account().finish().await?;
+    // After the first address received the output another address is needed to keep the gap, its sync fails
+    account.set_address_gap_limit(1).await?;
+    let address = *account.addresses().await?[0].address();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap();
+    funded_outputs.lock().unwrap().push(MockOutput::new(address, &output));
+
+    let live_events = Arc::new(Mutex::new(Vec::new()));
+    wallet
+        .listen([], {
+            let live_events = live_events.clone();
+            move |event| live_events.lock().unwrap().push(event.clone())
+        })
+        .await;
+    assert!(
+        account
+            .sync(Some(SyncOptions {
+                force_syncing: true,
+                ..Default::default()
+            }))
+            .await
+            .is_err()
+    );
+
+    // Only the new output is stored, the progress events aren't
+    let live_events = live_events.lock().unwrap().clone();
+    assert!(
+        live_events
+            .iter()
+            .any(|event| matches!(event.event, WalletEvent::SyncProgress(_)) && event.sequence.is_none())
+    );
+    let stored_events = live_events
+        .into_iter()
+        .filter(|event| event.sequence.is_some())
+        .collect::<Vec<_>>();
+    assert!(matches!(
+        &stored_events[..],
+        [Event {
+            event: WalletEvent::NewOutput(_),
+            ..
+        }]
+    ));
+    assert_eq!(wallet.events_since(0).await?, stored_events);
+
+    // The app crashes before the listener processed the events of the sync
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, &mnemonic, &node_url).await?;
+    assert_eq!(wallet.events_since(0).await?, stored_events);
+    let last_sequence = stored_events[0].sequence.unwrap();
+    assert!(wallet.events_since(last_sequence).await?.is_empty());
+
+    // New events continue the sequence, the sequence numbers reserved before the restart are skipped
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    let events = wallet.events_since(last_sequence).await?;
+    assert_eq!(events.len(), 1);
+    assert!(events[0].sequence.unwrap() > last_sequence);
+
+    tear_down(storage_path)
 }
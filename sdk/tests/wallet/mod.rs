@@ -9,6 +9,7 @@ mod bech32_hrp_validation;
 mod burn_outputs;
 mod claim_outputs;
 mod common;
+mod compose;
 mod consolidation;
 mod error;
 #[cfg(feature = "events")]
@@ -18,6 +19,7 @@ mod message_interface;
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
 mod output_preparation;
+mod snapshot;
 mod syncing;
 mod transactions;
 #[allow(clippy::module_inception)]
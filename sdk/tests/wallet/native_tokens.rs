@@ -1,8 +1,11 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use iota_sdk::{
-    wallet::{account::SyncOptions, MintNativeTokenParams, Result},
+    types::block::output::FoundryId,
+    wallet::{account::SyncOptions, CreateNativeTokenParams, Result},
     U256,
 };
 
@@ -25,8 +28,8 @@ async fn mint_and_increase_native_token_supply() -> Result<()> {
     account.sync(None).await?;
 
     let mint_tx = account
-        .mint_native_token(
-            MintNativeTokenParams {
+        .create_native_token(
+            CreateNativeTokenParams {
                 alias_id: None,
                 circulating_supply: U256::from(50),
                 maximum_supply: U256::from(100),
@@ -51,7 +54,7 @@ async fn mint_and_increase_native_token_supply() -> Result<()> {
     );
 
     let mint_tx = account
-        .increase_native_token_supply(mint_tx.token_id, U256::from(50), None)
+        .mint_native_token(mint_tx.token_id, U256::from(50), None)
         .await?;
     account
         .retry_transaction_until_included(&mint_tx.transaction.transaction_id, None, None)
@@ -67,6 +70,48 @@ async fn mint_and_increase_native_token_supply() -> Result<()> {
             .available(),
         U256::from(100)
     );
+    assert_eq!(account.native_token_balance(&mint_tx.token_id).await?, U256::from(100));
+    assert_eq!(
+        account.native_token_balances().await?,
+        BTreeMap::from([(mint_tx.token_id, U256::from(100))])
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn create_native_token_without_alias_output() -> Result<()> {
+    let storage_path = "test-storage/create_native_token_without_alias_output";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    assert!(account.balance().await?.aliases().is_empty());
+
+    // The alias output that controls the foundry is created first
+    let mint_tx = account
+        .create_native_token(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: U256::from(50),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&mint_tx.transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.aliases().len(), 1);
+    assert_eq!(
+        FoundryId::from(mint_tx.token_id).alias_address().alias_id(),
+        &balance.aliases()[0]
+    );
+    assert_eq!(account.native_token_balance(&mint_tx.token_id).await?, U256::from(50));
 
     tear_down(storage_path)
 }
@@ -90,8 +135,8 @@ async fn native_token_foundry_metadata() -> Result<()> {
     let foundry_metadata = [1, 3, 3, 7];
 
     let mint_tx = account
-        .mint_native_token(
-            MintNativeTokenParams {
+        .create_native_token(
+            CreateNativeTokenParams {
                 alias_id: None,
                 circulating_supply: U256::from(50),
                 maximum_supply: U256::from(100),
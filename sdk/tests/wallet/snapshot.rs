@@ -0,0 +1,91 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::Client,
+    wallet::{Error, Result},
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn snapshot_and_restore() -> Result<()> {
+    let storage_path_0 = "test-storage/snapshot_and_restore_0";
+    let storage_path_1 = "test-storage/snapshot_and_restore_1";
+    setup(storage_path_0)?;
+    setup(storage_path_1)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet_0 = make_wallet(storage_path_0, Some(mnemonic.clone()), None).await?;
+    let account_0 = wallet_0.create_account().finish().await?;
+    account_0.generate_ed25519_addresses(2, None).await?;
+
+    let wallet_1 = make_wallet(storage_path_1, Some(mnemonic), None).await?;
+    let account_1 = wallet_1.create_account().finish().await?;
+    assert_ne!(*account_0.details().await, *account_1.details().await);
+
+    for password in [None, Some("password")] {
+        let snapshot = account_0.snapshot(password).await?;
+        account_1.restore_from_snapshot(&snapshot, password).await?;
+        assert_eq!(*account_0.details().await, *account_1.details().await);
+    }
+
+    // The restored state is saved
+    #[cfg(feature = "rocksdb")]
+    {
+        drop(account_1);
+        drop(wallet_1);
+        let wallet_1 = make_wallet(storage_path_1, None, None).await?;
+        let account_1 = wallet_1.get_account(0).await?;
+        assert_eq!(*account_0.details().await, *account_1.details().await);
+    }
+
+    tear_down(storage_path_0)?;
+    tear_down(storage_path_1)
+}
+
+#[tokio::test]
+async fn restore_invalid_snapshot() -> Result<()> {
+    let storage_path = "test-storage/restore_invalid_snapshot";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let details = account_0.details().await.clone();
+
+    let snapshot = account_0.snapshot(Some("password")).await?;
+
+    // Wrong or missing password
+    assert!(matches!(
+        account_0.restore_from_snapshot(&snapshot, Some("wrong")).await,
+        Err(Error::Crypto(_))
+    ));
+    assert!(matches!(
+        account_0.restore_from_snapshot(&snapshot, None).await,
+        Err(Error::MissingParameter("password"))
+    ));
+
+    // Created by a newer version
+    let mut newer_snapshot = snapshot.clone();
+    newer_snapshot[0] += 1;
+    assert!(matches!(
+        account_0.restore_from_snapshot(&newer_snapshot, Some("password")).await,
+        Err(Error::SnapshotVersionMismatch { found: 2, supported: 1 })
+    ));
+
+    // Snapshot of another account
+    assert!(matches!(
+        account_1.restore_from_snapshot(&snapshot, Some("password")).await,
+        Err(Error::InvalidSnapshot(_))
+    ));
+    assert!(matches!(
+        account_0.restore_from_snapshot(&[], None).await,
+        Err(Error::InvalidSnapshot(_))
+    ));
+
+    // Nothing was changed
+    assert_eq!(*account_0.details().await, details);
+
+    tear_down(storage_path)
+}
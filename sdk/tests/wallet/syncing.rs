@@ -1,18 +1,51 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use crypto::keys::slip10::Chain;
 use iota_sdk::{
-    types::block::output::{
-        unlock_condition::{
-            AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
-            StateControllerAddressUnlockCondition, StorageDepositReturnUnlockCondition,
+    client::{
+        api::GetAddressesOptions,
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE},
+        secret::{mnemonic::MnemonicSecretManager, watch_only::WatchOnlySecretManager, SecretManage, SecretManager},
+        Client,
+    },
+    types::block::{
+        address::Address,
+        input::{Input, UtxoInput},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, StorageDepositReturnUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, OutputId, OutputMetadata,
+            UnlockCondition,
         },
-        AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
+        parent::Parents,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+            Payload, TransactionPayload,
+        },
+        protocol::{protocol_parameters, ProtocolParameters},
+        rand::{block::rand_block_ids, output::rand_inputs_commitment},
+        BlockBuilder, BlockDto,
+    },
+    wallet::{
+        account::{AddressReuseCallback, IntegrityReport, SyncOptions, DEFAULT_ADDRESS_GAP_LIMIT},
+        ClientOptions, Result, SendAmountParams, Wallet,
     },
-    wallet::{account::SyncOptions, Result},
 };
 
-use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
+use crate::{
+    client::common::{
+        info_json, mock_output_json_with_metadata, spawn_mock_node, spawn_mock_node_with_address_outputs, MockOutput,
+    },
+    wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down},
+};
 
 #[tokio::test]
 #[cfg(feature = "rocksdb")]
@@ -148,3 +181,548 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn verify_integrity_without_outputs() -> Result<()> {
+    let storage_path = "test-storage/verify_integrity_without_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Nothing needs to be requested from the node
+    let report = account.verify_integrity(true).await?;
+    assert_eq!(report, IntegrityReport::default());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn verify_integrity() -> Result<()> {
+    let storage_path = "test-storage/verify_integrity";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let report = account_0.verify_integrity(false).await?;
+    assert!(report.issues.is_empty());
+    assert!(!report.repaired);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn sync_without_pending_transactions() -> Result<()> {
+    let storage_path = "test-storage/sync_without_pending_transactions";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                1_000_000,
+            )?],
+            None,
+        )
+        .await?;
+    // Wait on the client, so the account doesn't update the transaction
+    account_0
+        .client()
+        .retry_until_included(&tx.block_id.unwrap(), None, None)
+        .await?;
+
+    // The included transaction stays pending if that phase is skipped
+    account_0
+        .sync(Some(SyncOptions {
+            sync_pending_transactions: false,
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert!(
+        account_0
+            .pending_transactions()
+            .await
+            .iter()
+            .any(|pending| pending.transaction_id == tx.transaction_id)
+    );
+
+    account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert!(account_0.pending_transactions().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn concurrent_syncs() -> Result<()> {
+    let storage_path = "test-storage/concurrent_syncs";
+    setup(storage_path)?;
+
+    let indexer_requests = Arc::new(AtomicUsize::new(0));
+    let node_url = spawn_mock_node({
+        let indexer_requests = indexer_requests.clone();
+        move |path| {
+            if path == "/api/core/v2/info" {
+                (200, info_json(7))
+            } else if path.starts_with("/api/indexer/v1/outputs/") {
+                indexer_requests.fetch_add(1, Ordering::SeqCst);
+                (200, r#"{"ledgerIndex":7,"items":[]}"#.to_string())
+            } else {
+                (404, String::new())
+            }
+        }
+    });
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let options = SyncOptions {
+        force_syncing: true,
+        ..Default::default()
+    };
+    account.sync(Some(options.clone())).await?;
+    let requests_per_sync = indexer_requests.swap(0, Ordering::SeqCst);
+    assert!(requests_per_sync > 0);
+
+    // All syncs wait for the first one and return its balance
+    let balances = futures::future::try_join_all((0..20).map(|_| account.sync(Some(options.clone())))).await?;
+    assert_eq!(indexer_requests.swap(0, Ordering::SeqCst), requests_per_sync);
+    assert!(balances.iter().all(|balance| !balance.sync_in_progress()));
+
+    // All syncs except the first one return immediately
+    let options = SyncOptions {
+        wait_for_ongoing_sync: false,
+        ..options
+    };
+    let balances = futures::future::try_join_all((0..20).map(|_| account.sync(Some(options.clone())))).await?;
+    assert_eq!(indexer_requests.load(Ordering::SeqCst), requests_per_sync);
+    assert!(!balances[0].sync_in_progress());
+    assert!(balances[1..].iter().all(|balance| *balance.sync_in_progress()));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "rocksdb")]
+#[tokio::test]
+async fn sync_with_future_last_synced() -> Result<()> {
+    use iota_sdk::{client::storage::StorageAdapter, wallet::storage::adapter::rocksdb::RocksdbStorageAdapter};
+
+    let storage_path = "test-storage/sync_with_future_last_synced";
+    setup(storage_path)?;
+
+    let indexer_requests = Arc::new(AtomicUsize::new(0));
+    let node_url = spawn_mock_node({
+        let indexer_requests = indexer_requests.clone();
+        move |path| {
+            if path == "/api/core/v2/info" {
+                (200, info_json(7))
+            } else if path.starts_with("/api/indexer/v1/outputs/") {
+                indexer_requests.fetch_add(1, Ordering::SeqCst);
+                (200, r#"{"ledgerIndex":7,"items":[]}"#.to_string())
+            } else {
+                (404, String::new())
+            }
+        }
+    });
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), Some(&node_url)).await?;
+    wallet.create_account().finish().await?;
+    drop(wallet);
+
+    // The storage was copied from a machine with a clock that is an hour ahead
+    let future_last_synced = iota_sdk::utils::unix_timestamp_now().as_millis() + 3_600_000;
+    RocksdbStorageAdapter::new(storage_path)?
+        .set("iota-wallet-account-0-last-synced", &future_last_synced)
+        .await?;
+
+    let wallet = make_wallet(storage_path, Some(mnemonic), Some(&node_url)).await?;
+    let account = wallet.get_account(0).await?;
+    account.sync(None).await?;
+    assert!(indexer_requests.load(Ordering::SeqCst) > 0);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn address_reuse_callback() -> Result<()> {
+    let storage_path = "test-storage/address_reuse_callback";
+    setup(storage_path)?;
+
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_address_outputs(outputs.clone(), |_| false);
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+
+    let add_output = || {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        outputs.lock().unwrap().push(MockOutput::new(address, &output));
+    };
+
+    let reused_addresses = Arc::new(Mutex::new(Vec::new()));
+    let options = SyncOptions {
+        force_syncing: true,
+        sync_only_most_basic_outputs: true,
+        on_address_reuse: Some(AddressReuseCallback::new({
+            let reused_addresses = reused_addresses.clone();
+            move |address| reused_addresses.lock().unwrap().push(address.clone())
+        })),
+        ..Default::default()
+    };
+
+    // The first output only marks the address as used
+    add_output();
+    account.sync(Some(options.clone())).await?;
+    assert!(reused_addresses.lock().unwrap().is_empty());
+
+    // Syncing the same outputs again isn't a reuse
+    account.sync(Some(options.clone())).await?;
+    assert!(reused_addresses.lock().unwrap().is_empty());
+
+    add_output();
+    account.sync(Some(options)).await?;
+    let reused_addresses = reused_addresses.lock().unwrap();
+    assert_eq!(reused_addresses.len(), 1);
+    assert_eq!(reused_addresses[0].address(), &address);
+    assert_eq!(reused_addresses[0].output_ids().len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn sync_address_gap_limit() -> Result<()> {
+    let storage_path = "test-storage/sync_address_gap_limit";
+    setup(storage_path)?;
+
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_address_outputs(outputs.clone(), |_| false);
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(account.address_gap_limit().await, DEFAULT_ADDRESS_GAP_LIMIT);
+
+    // Fund the addresses with the key indexes 0 and 2, before the account generated the latter
+    let addresses = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?)
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(wallet.client())
+                .await?
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..3),
+        )
+        .await?;
+    for address in [addresses[0], addresses[2]] {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        outputs.lock().unwrap().push(MockOutput::new(address, &output));
+    }
+
+    let options = SyncOptions {
+        force_syncing: true,
+        sync_only_most_basic_outputs: true,
+        ..Default::default()
+    };
+
+    // Without a gap only the generated address is synced
+    let balance = account.sync(Some(options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(account.addresses().await?.len(), 1);
+
+    // The address with the key index 2 is within the gap, so three more addresses after it are synced, which are all
+    // empty
+    account.set_address_gap_limit(3).await?;
+    assert_eq!(account.address_gap_limit().await, 3);
+    let balance = account.sync(Some(options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 2_000_000);
+    assert_eq!(account.addresses().await?.len(), 6);
+
+    // The gap is already there
+    account.sync(Some(options)).await?;
+    assert_eq!(account.addresses().await?.len(), 6);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn sync_max_parallel_requests() -> Result<()> {
+    let storage_path = "test-storage/sync_max_parallel_requests";
+    setup(storage_path)?;
+
+    let outputs = Arc::new(Mutex::new(Vec::new()));
+    let failing_address = Arc::new(Mutex::new(None::<String>));
+    let node_url = spawn_mock_node_with_address_outputs(outputs.clone(), {
+        let failing_address = failing_address.clone();
+        move |path| matches!(&*failing_address.lock().unwrap(), Some(address) if path.contains(address.as_str()))
+    });
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    account.generate_ed25519_addresses(4, None).await?;
+    let addresses = account.addresses().await?;
+    assert_eq!(addresses.len(), 5);
+
+    // Every address gets an output with a different amount
+    let mut output_ids = Vec::new();
+    for (i, address) in addresses.iter().enumerate() {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000 * (i as u64 + 1))
+            .add_unlock_condition(AddressUnlockCondition::new(*address.address().inner()))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        let output = MockOutput::new(address.address(), &output);
+        output_ids.push(output.output_id);
+        outputs.lock().unwrap().push(output);
+    }
+
+    let options = SyncOptions {
+        force_syncing: true,
+        sync_only_most_basic_outputs: true,
+        max_parallel_requests: 2,
+        ..Default::default()
+    };
+
+    // The results of the addresses arrive in any order, but every output is still assigned to its own address
+    let balance = account.sync(Some(options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 15_000_000);
+    let addresses_with_unspent_outputs = account.addresses_with_unspent_outputs().await?;
+    assert_eq!(addresses_with_unspent_outputs.len(), 5);
+    for address in &addresses_with_unspent_outputs {
+        assert_eq!(address.output_ids(), &[output_ids[*address.key_index() as usize]]);
+    }
+    // The addresses are found by their key index, so they are all marked as used
+    assert!(account.addresses().await?.iter().all(|address| *address.used()));
+
+    // A failing request of a single address fails the whole sync, while the other addresses are synced in parallel
+    *failing_address.lock().unwrap() = Some(addresses[3].address().to_string());
+    assert!(account.sync(Some(options)).await.is_err());
+    assert_eq!(account.balance().await?.base_coin().total(), 15_000_000);
+
+    tear_down(storage_path)
+}
+
+/// Returns the mock node responses for an output with the amount for the address, which was created by an incoming
+/// transaction, by their path. The first response is the output and the second the block with the transaction.
+fn incoming_transaction_responses(
+    protocol_parameters: &ProtocolParameters,
+    address: Address,
+    amount: u64,
+    milestone_timestamp_booked: u32,
+) -> (OutputId, [(String, String); 2]) {
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs([Input::Utxo(UtxoInput::new(TransactionId::from([0; 32]), 0).unwrap())])
+            .add_output(output.clone())
+            .finish(protocol_parameters)
+            .unwrap(),
+    );
+    let transaction_payload = TransactionPayload::rand_mock(essence);
+    let transaction_id = transaction_payload.id();
+    let output_id = OutputId::new(transaction_id, 0).unwrap();
+    let block = BlockBuilder::new(Parents::from_vec(rand_block_ids(2)).unwrap())
+        .with_payload(Payload::from(transaction_payload))
+        .finish()
+        .unwrap();
+    let metadata = OutputMetadata::new(
+        block.id(),
+        output_id,
+        false,
+        None,
+        None,
+        None,
+        1,
+        milestone_timestamp_booked,
+        7,
+    );
+
+    (
+        output_id,
+        [
+            (
+                format!("/api/core/v2/outputs/{output_id}"),
+                mock_output_json_with_metadata(&metadata, &output),
+            ),
+            (
+                format!("/api/core/v2/transactions/{transaction_id}/included-block"),
+                serde_json::to_string(&BlockDto::from(&block)).unwrap(),
+            ),
+        ],
+    )
+}
+
+/// Spawns a mock node that returns the outputs for all basic output queries, with the responses by their path.
+fn spawn_mock_node_with_outputs(
+    output_ids: Arc<Mutex<Vec<OutputId>>>,
+    responses: Arc<Mutex<Vec<(String, String)>>>,
+) -> String {
+    spawn_mock_node(move |path| {
+        if path == "/api/core/v2/info" {
+            (200, info_json(7))
+        } else if path.starts_with("/api/indexer/v1/outputs/basic?") {
+            (
+                200,
+                serde_json::json!({ "ledgerIndex": 7, "items": *output_ids.lock().unwrap() }).to_string(),
+            )
+        } else if let Some((_, response)) = responses.lock().unwrap().iter().find(|(p, _)| p == path) {
+            (200, response.clone())
+        } else {
+            (404, String::new())
+        }
+    })
+}
+
+#[tokio::test]
+async fn sync_incoming_transaction_timestamp() -> Result<()> {
+    let storage_path = "test-storage/sync_incoming_transaction_timestamp";
+    setup(storage_path)?;
+
+    let output_ids = Arc::new(Mutex::new(Vec::new()));
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_outputs(output_ids.clone(), responses.clone());
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let milestone_timestamp_booked = 1_600_000_000;
+    let (output_id, output_responses) = incoming_transaction_responses(
+        &wallet.client().get_protocol_parameters().await?,
+        *account.addresses().await?[0].address().inner(),
+        1_000_000,
+        milestone_timestamp_booked,
+    );
+    output_ids.lock().unwrap().push(output_id);
+    responses.lock().unwrap().extend(output_responses);
+
+    account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            sync_only_most_basic_outputs: true,
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    // The timestamp of an incoming transaction is the one of the milestone that booked its output, not the sync time
+    let incoming_transactions = account.incoming_transactions().await;
+    assert_eq!(incoming_transactions.len(), 1);
+    assert_eq!(incoming_transactions[0].transaction_id, *output_id.transaction_id());
+    assert_eq!(
+        incoming_transactions[0].timestamp,
+        milestone_timestamp_booked as u128 * 1000
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn sync_watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/sync_watch_only_account";
+    setup(storage_path)?;
+
+    let output_ids = Arc::new(Mutex::new(Vec::new()));
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let node_url = spawn_mock_node_with_outputs(output_ids.clone(), responses.clone());
+
+    // The public keys of the addresses are part of their signatures
+    let mnemonic_secret_manager =
+        SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?);
+    let mut public_keys = Vec::new();
+    for address_index in 0..2 {
+        let chain = Chain::from_u32_hardened([HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, address_index]);
+        let signature = mnemonic_secret_manager.sign_ed25519(&[0; 32], &chain).await?;
+        public_keys.push(prefix_hex::encode(*signature.public_key()));
+    }
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::WatchOnly(
+            WatchOnlySecretManager::try_from_hex_public_keys(&public_keys)?,
+        ))
+        .with_client_options(ClientOptions::new().with_node(&node_url)?)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+    assert_eq!(
+        address,
+        mnemonic_secret_manager
+            .generate_ed25519_addresses(
+                GetAddressesOptions::from_client(wallet.client())
+                    .await?
+                    .with_coin_type(SHIMMER_COIN_TYPE)
+                    .with_range(0..1),
+            )
+            .await?[0]
+    );
+    // Only the public keys of the first account are known
+    assert!(wallet.create_account().finish().await.is_err());
+
+    let (output_id, output_responses) = incoming_transaction_responses(
+        &wallet.client().get_protocol_parameters().await?,
+        *address.inner(),
+        1_000_000,
+        1_600_000_000,
+    );
+    output_ids.lock().unwrap().push(output_id);
+    responses.lock().unwrap().extend(output_responses);
+
+    let balance = account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            sync_only_most_basic_outputs: true,
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(account.balance().await?, balance);
+
+    let incoming_transactions = account.incoming_transactions().await;
+    assert_eq!(incoming_transactions.len(), 1);
+    assert_eq!(incoming_transactions[0].transaction_id, *output_id.transaction_id());
+    assert_eq!(account.outputs(None).await?.len(), 1);
+
+    tear_down(storage_path)
+}
@@ -1,9 +1,33 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendAmountParams, SendNftParams};
-
-use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use iota_sdk::{
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        Error as ClientError,
+    },
+    types::block::{
+        output::{
+            feature::MetadataFeature, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, OutputId,
+        },
+        payload::transaction::TransactionEssence,
+        Error as BlockError,
+    },
+    wallet::{
+        account::{RemainderValueStrategy, TransactionOptions, TransactionPage, TransactionSort},
+        Error, MintNftParams, Result, SendAmountParams, SendNftParams,
+    },
+};
+
+use crate::{
+    client::common::{info_json, spawn_mock_node},
+    wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down},
+};
 
 #[ignore]
 #[tokio::test]
@@ -121,6 +145,157 @@ async fn send_amount_custom_input() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_amount_remainder_change_address() -> Result<()> {
+    let storage_path = "test-storage/send_amount_remainder_change_address";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount(
+            [SendAmountParams::new(
+                *account_1.addresses().await?[0].address(),
+                amount,
+            )?],
+            Some(TransactionOptions {
+                remainder_value_strategy: RemainderValueStrategy::ChangeAddress,
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    // The remainder was sent to an internal address, which is marked as used after syncing
+    let change_address = account_0
+        .addresses()
+        .await?
+        .into_iter()
+        .find(|address| *address.internal())
+        .unwrap();
+    assert!(*change_address.used());
+    assert!(
+        account_0
+            .unspent_outputs(None)
+            .await?
+            .iter()
+            .any(|output| output.address == *change_address.address().inner())
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_amount_sweep_into_existing() -> Result<()> {
+    let storage_path = "test-storage/send_amount_sweep_into_existing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Split the funds into small outputs
+    let own_address = *account_0.addresses().await?[0].address();
+    let tx = account_0
+        .send_amount(vec![SendAmountParams::new(own_address, 1_000_000)?; 3], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+    let mut output_count = account_0.unspent_outputs(None).await?.len();
+    assert!(output_count >= 4);
+
+    let options = TransactionOptions {
+        remainder_value_strategy: RemainderValueStrategy::SweepIntoExisting,
+        ..Default::default()
+    };
+    let outputs = [BasicOutputBuilder::new_with_amount(1_500_000)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            *account_1.addresses().await?[0].address().as_ref(),
+        ))
+        .finish_output(account_0.client().get_token_supply().await?)?];
+    for _ in 0..2 {
+        let preview = account_0.preview_transaction(outputs.clone(), options.clone()).await?;
+        let swept_input = preview.swept_input.unwrap();
+        assert!(preview.inputs.contains(&swept_input));
+
+        let tx = account_0.send(outputs.clone(), options.clone()).await?;
+        account_0
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+        account_0.sync(None).await?;
+
+        // All inputs are consumed into a single remainder
+        let TransactionEssence::Regular(essence) = tx.payload.essence();
+        assert!(essence.inputs().len() >= 2);
+        let unspent_outputs = account_0.unspent_outputs(None).await?;
+        assert_eq!(unspent_outputs.len(), output_count + 1 - essence.inputs().len());
+        assert!(!unspent_outputs.iter().any(|output| output.output_id == swept_input));
+        output_count = unspent_outputs.len();
+    }
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn prepare_sign_and_submit_transaction() -> Result<()> {
+    let storage_path = "test-storage/prepare_sign_and_submit_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let params = [SendAmountParams::new(
+        *account_1.addresses().await?[0].address(),
+        amount,
+    )?];
+
+    // The inputs are locked until the abandoned transaction is unlocked
+    let prepared_transaction_data = account_0.prepare_send_amount(params.clone(), None).await?;
+    assert_eq!(
+        prepared_transaction_data.bech32_hrp,
+        Some(wallet.get_bech32_hrp().await?)
+    );
+    for input in &prepared_transaction_data.inputs_data {
+        assert!(account_0.details().await.locked_outputs().contains(input.output_id()));
+    }
+    account_0.unlock_inputs(&prepared_transaction_data).await?;
+    assert!(account_0.details().await.locked_outputs().is_empty());
+
+    // The prepared data is serialized to be signed on another machine
+    let prepared_transaction_data =
+        PreparedTransactionData::try_from_dto_unverified(serde_json::from_str(&serde_json::to_string(
+            &PreparedTransactionDataDto::from(&account_0.prepare_send_amount(params, None).await?),
+        )?)?)?;
+    let signed_transaction_data = account_0.sign_transaction(&prepared_transaction_data).await?;
+    let tx = account_0.submit_and_store_transaction(signed_transaction_data).await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_nft() -> Result<()> {
@@ -162,3 +337,175 @@ async fn send_nft() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn send_nft_with_computed_id() -> Result<()> {
+    let storage_path = "test-storage/send_nft_with_computed_id";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let nft_options = [MintNftParams::new()
+        .with_address(*accounts[0].addresses().await?[0].address())
+        .with_metadata(b"some nft metadata".to_vec())];
+
+    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap();
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    // The minted output still has a null nft id, compute the actual one from its output id
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let output_index = essence.outputs().iter().position(|output| output.is_nft()).unwrap();
+    let nft_id = NftId::from(&OutputId::new(transaction.transaction_id, output_index as u16)?);
+
+    let balance = accounts[0].sync(None).await?;
+    assert!(balance.nfts().contains(&nft_id));
+    assert!(accounts[0].unspent_nft_output(&nft_id).await?.is_some());
+
+    // Send to account 1
+    let transaction = accounts[0]
+        .send_nft(
+            [SendNftParams::new(
+                *accounts[1].addresses().await?[0].address(),
+                nft_id,
+            )?],
+            None,
+        )
+        .await
+        .unwrap();
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[1].sync(None).await?;
+    assert_eq!(balance.nfts(), &[nft_id]);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn transaction_history_page_empty() -> Result<()> {
+    let storage_path = "test-storage/transaction_history_page_empty";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let page = account
+        .transaction_history_page(0, 10, TransactionSort::NewestFirst)
+        .await;
+    assert_eq!(
+        page,
+        TransactionPage {
+            items: Vec::new(),
+            total: 0,
+            page: 0,
+            page_size: 10,
+        }
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn transaction_history_page() -> Result<()> {
+    let storage_path = "test-storage/transaction_history_page";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let mut transaction_ids = Vec::new();
+    for _ in 0..3 {
+        let tx = account_0
+            .send_amount(
+                [SendAmountParams::new(
+                    *account_1.addresses().await?[0].address(),
+                    1_000_000,
+                )?],
+                None,
+            )
+            .await?;
+        account_0
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+        transaction_ids.push(tx.transaction_id);
+    }
+
+    let page = account_0
+        .transaction_history_page(0, 2, TransactionSort::OldestFirst)
+        .await;
+    assert_eq!(page.total, 3);
+    assert_eq!(
+        page.items.iter().map(|tx| tx.transaction_id).collect::<Vec<_>>(),
+        transaction_ids[..2]
+    );
+
+    // The last page has fewer transactions than the page size
+    let page = account_0
+        .transaction_history_page(1, 2, TransactionSort::OldestFirst)
+        .await;
+    assert_eq!(
+        page.items.iter().map(|tx| tx.transaction_id).collect::<Vec<_>>(),
+        transaction_ids[2..]
+    );
+
+    let page = account_0
+        .transaction_history_page(0, 1, TransactionSort::NewestFirst)
+        .await;
+    assert_eq!(page.items[0].transaction_id, transaction_ids[2]);
+
+    // Pages out of range are empty
+    let page = account_0
+        .transaction_history_page(3, 1, TransactionSort::OldestFirst)
+        .await;
+    assert!(page.items.is_empty());
+    assert_eq!(page.total, 3);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn mint_nfts_invalid_params() -> Result<()> {
+    let storage_path = "test-storage/mint_nfts_invalid_params";
+    setup(storage_path)?;
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let node_url = spawn_mock_node({
+        let requests = requests.clone();
+        move |path| {
+            requests.fetch_add(1, Ordering::SeqCst);
+            if path == "/api/core/v2/info" {
+                (200, info_json(7))
+            } else {
+                (404, String::new())
+            }
+        }
+    });
+
+    let wallet = make_wallet(storage_path, None, Some(&node_url)).await?;
+    let account = wallet.create_account().finish().await?;
+    let requests_before_minting = requests.load(Ordering::SeqCst);
+
+    let params = [MintNftParams::new().with_metadata(vec![0; *MetadataFeature::LENGTH_RANGE.end() as usize + 1])];
+    assert!(matches!(
+        account.mint_nfts(params, None).await,
+        Err(Error::Block(error)) if matches!(*error, BlockError::InvalidMetadataFeatureLength(_))
+    ));
+
+    let params =
+        [MintNftParams::new().try_with_issuer("tst1qqqxdxwaq8ewc7xaph7x9mjpd9fysr0qsj4fpc6kw20wxswmjt7t5r4eupa")?];
+    assert!(matches!(
+        account.mint_nfts(params, None).await,
+        Err(Error::Client(error)) if matches!(*error, ClientError::Bech32HrpMismatch { .. })
+    ));
+
+    assert_eq!(requests.load(Ordering::SeqCst), requests_before_minting);
+
+    tear_down(storage_path)
+}
@@ -1,14 +1,19 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs, io, path::Path};
+use std::{fs, io, path::Path, time::Duration};
 
 use iota_sdk::{
-    client::stronghold::StrongholdAdapter,
-    wallet::{Result, Wallet},
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        stronghold::StrongholdAdapter,
+        Client,
+    },
+    wallet::{ClientOptions, Result, Wallet},
 };
 
-use crate::wallet::common::{setup, tear_down};
+use crate::wallet::common::{make_wallet, setup, tear_down, NODE_LOCAL};
 
 // Db created with wallet.rs commit 8dd389ddeed0d95bb493c38f376b41a6a9127148
 #[cfg(all(feature = "stronghold", feature = "rocksdb"))]
@@ -216,6 +221,36 @@ async fn check_existing_db_2() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn write_delayed_account_changes_on_flush() -> Result<()> {
+    let storage_path = "test-storage/write_delayed_account_changes_on_flush";
+    setup(storage_path)?;
+    let mnemonic = Client::generate_mnemonic()?;
+
+    {
+        let wallet = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+                mnemonic.clone(),
+            )?))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path)
+            .with_storage_debounce(Duration::from_secs(3600))
+            .finish()
+            .await?;
+        let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+        // Delayed, because the account was just written when it got created
+        account.generate_ed25519_addresses(1, None).await?;
+        wallet.flush_storage().await?;
+    }
+
+    let wallet = make_wallet(storage_path, Some(mnemonic), None).await?;
+    assert_eq!(wallet.get_account("Alice").await?.addresses().await?.len(), 2);
+
+    tear_down(storage_path)
+}
+
 fn copy_folder(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> io::Result<()> {
     fs::create_dir_all(&dest)?;
     for entry in fs::read_dir(src)? {